@@ -1,8 +1,312 @@
-/// Variants of the `Action` enum encapsulate the different actions a user can select, exposing the
-/// different capabilities of the library.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::FromStr)]
-pub enum Action {
-    Load,
-    Download,
-    NextError,
+use bears_ecology::{Archive, ErrorKind};
+use bears_species::{Dataset, Frequency, ParameterName};
+use clap::builder::TypedValueParser;
+
+/// The `Cli` struct holds the command line arguments of the `bears` binary.  Each subcommand of
+/// [`Command`] exposes a capability of the library.
+///
+/// * **command** - The subcommand to execute.
+/// * **json** - Print output as JSON instead of plain text.
+/// * **metrics** - Serve metrics at `/metrics` on this address while the command runs.  Available
+///   with the `metrics` feature.
+#[derive(Debug, Clone, PartialEq, Eq, clap::Parser, derive_getters::Getters)]
+#[command(author, version, about, long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[arg(long, global = true, help = "Print output as JSON.")]
+    json: bool,
+    #[cfg(feature = "metrics")]
+    #[arg(
+        long,
+        global = true,
+        value_name = "ADDR",
+        help = "Serve metrics at /metrics on this address, such as 127.0.0.1:9187."
+    )]
+    metrics: Option<std::net::SocketAddr>,
+}
+
+/// Variants of the `Command` enum encapsulate the different actions a user can select, exposing
+/// the different capabilities of the library.  The doc comment of each variant is the help text
+/// of the subcommand.
+#[derive(Debug, Clone, PartialEq, Eq, clap::Subcommand)]
+pub enum Command {
+    /// List the datasets of the BEA API, with descriptions if `datasets.json` has been downloaded.
+    Datasets,
+    /// List the parameter names of a dataset.
+    Params {
+        #[arg(value_parser = variants::<Dataset>())]
+        dataset: Dataset,
+    },
+    /// List the valid values of a parameter, read from the `parameter_values` folder, or refresh
+    /// the folder from the BEA API.
+    Values(ValuesArgs),
+    /// Request a single table from the BEA API and list the series it contains.
+    Get(GetArgs),
+    /// Download every target of a dataset into the `data` folder.
+    Download {
+        #[arg(value_parser = variants::<Dataset>())]
+        dataset: Dataset,
+        #[arg(
+            long,
+            value_parser = variants::<Archive>(),
+            num_args = 0..=1,
+            default_missing_value = "Dated",
+            help = "Download every target again and archive new releases, by date or Content."
+        )]
+        archive: Option<Archive>,
+    },
+    /// Load the downloaded files of a dataset.
+    Load {
+        #[arg(value_parser = variants::<Dataset>())]
+        dataset: Dataset,
+    },
+    /// Export series from the local data store, as tab-separated text, CSV with `--output` or
+    /// JSON with `--json`.
+    Export(ExportArgs),
+    /// Report the coverage of the local data store for each dataset.
+    Status {
+        #[arg(
+            short,
+            long,
+            value_parser = variants::<Dataset>(),
+            help = "Report on a single dataset."
+        )]
+        dataset: Option<Dataset>,
+        #[arg(
+            long,
+            default_value_t = 90,
+            value_parser = days(),
+            help = "Age in days after which a file is stale."
+        )]
+        stale_days: i64,
+    },
+    /// Retry loading the files of a dataset that previously failed to load, or with `--download`
+    /// request the targets that failed to download again.
+    Retry(RetryArgs),
+    /// Rewrite the history logs in the current event format.
+    Migrate,
+    /// Regenerate the key enums maintained from parameter values.
+    Generate {
+        #[arg(
+            short,
+            long,
+            help = "Workspace root, defaults to the working directory."
+        )]
+        source: Option<std::path::PathBuf>,
+    },
+    /// Print a completion script for a shell.
+    Completions { shell: clap_complete::Shell },
+}
+
+impl Command {
+    /// The `requires_data` method returns false for subcommands that run without the `BEA_DATA`
+    /// directory, so that the binary can skip initializing the history log.  Code generation
+    /// reads parameter values from `BEA_DATA` when available, and skips them otherwise.
+    pub fn requires_data(&self) -> bool {
+        !matches!(
+            self,
+            Self::Datasets | Self::Params { .. } | Self::Generate { .. } | Self::Completions { .. }
+        )
+    }
+}
+
+/// The `ValuesArgs` struct holds the arguments of [`Command::Values`].  Lists the values of
+/// `param` in `dataset`, unless the `refresh` subcommand is given.
+///
+/// * **refresh** - Refresh the parameter value cache instead.
+/// * **dataset** - The dataset of the parameter.
+/// * **param** - The parameter to list.
+#[derive(Debug, Clone, PartialEq, Eq, clap::Args, derive_getters::Getters)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct ValuesArgs {
+    #[command(subcommand)]
+    refresh: Option<ValuesCommand>,
+    #[arg(required = true, value_parser = variants::<Dataset>())]
+    dataset: Option<Dataset>,
+    #[arg(required = true, value_parser = variants::<ParameterName>())]
+    param: Option<ParameterName>,
+}
+
+/// Variants of the `ValuesCommand` enum are the subcommands of [`Command::Values`].
+#[derive(Debug, Clone, PartialEq, Eq, clap::Subcommand)]
+pub enum ValuesCommand {
+    /// Download missing or outdated files of the `parameter_values` folder, recording each in
+    /// `manifest.json`.  An interrupted refresh resumes where it stopped.
+    Refresh {
+        #[arg(
+            long,
+            value_parser = days(),
+            help = "Fetch files older than this many days again."
+        )]
+        max_age_days: Option<i64>,
+        #[arg(
+            long,
+            default_value_t = 1000,
+            help = "Minimum milliseconds between requests."
+        )]
+        interval_ms: u64,
+        #[arg(long, help = "Fetch every file, ignoring the manifest.")]
+        force: bool,
+    },
+}
+
+/// The `RetryArgs` struct holds the arguments of [`Command::Retry`].  The filters and the
+/// backoff apply to downloads only.
+///
+/// * **dataset** - The dataset to retry.
+/// * **first** - Retry loading only the first failed file.
+/// * **download** - Request failed downloads again instead of loading failed files.
+/// * **kind** - Retry only failures of this [`ErrorKind`](bears_ecology::ErrorKind).
+/// * **reason** - Retry only failures whose reason contains this text.
+/// * **max_age_days** - Retry only failures within this many days.
+/// * **rounds** - Maximum number of requests per target.
+/// * **backoff_secs** - Seconds to wait before the second round, doubling for each later round.
+/// * **invalid_after** - Identical API errors after which a target is permanently invalid.
+#[derive(Debug, Clone, PartialEq, Eq, clap::Args, derive_getters::Getters)]
+pub struct RetryArgs {
+    #[arg(value_parser = variants::<Dataset>())]
+    dataset: Dataset,
+    #[arg(
+        long,
+        conflicts_with = "download",
+        help = "Retry only the first failed file."
+    )]
+    first: bool,
+    #[arg(long, help = "Request failed downloads again.")]
+    download: bool,
+    #[arg(
+        long,
+        requires = "download",
+        value_parser = variants::<ErrorKind>(),
+        help = "Retry only failures of this kind."
+    )]
+    kind: Option<ErrorKind>,
+    #[arg(
+        long,
+        requires = "download",
+        help = "Retry only failures whose reason contains this text."
+    )]
+    reason: Option<String>,
+    #[arg(
+        long,
+        requires = "download",
+        value_parser = days(),
+        help = "Retry only failures within this many days."
+    )]
+    max_age_days: Option<i64>,
+    #[arg(
+        long,
+        default_value_t = 3,
+        help = "Maximum number of requests per target."
+    )]
+    rounds: usize,
+    #[arg(
+        long,
+        default_value_t = 60,
+        help = "Seconds to wait before the second round, doubling for each later round."
+    )]
+    backoff_secs: u64,
+    #[arg(
+        long,
+        default_value_t = 3,
+        help = "Identical API errors after which a target is permanently invalid."
+    )]
+    invalid_after: usize,
+}
+
+/// The `GetArgs` struct holds the arguments of [`Command::Get`].
+///
+/// * **dataset** - The dataset to request.
+/// * **table** - The table name or id, passed as `TableName` or `TableID` depending on the
+///   parameters of the dataset.  NIPA table names are checked against
+///   [`NipaTableName`](bears_species::NipaTableName).
+/// * **year** - Years to request, defaulting to all years.
+/// * **frequency** - Frequencies to request, for datasets with a frequency parameter.
+/// * **param** - Additional parameters as `Name=Value` pairs.
+/// * **save** - Save the response to the `data` folder of the `BEA_DATA` directory.
+#[derive(Debug, Clone, PartialEq, Eq, clap::Args, derive_getters::Getters)]
+pub struct GetArgs {
+    #[arg(value_parser = variants::<Dataset>())]
+    dataset: Dataset,
+    #[arg(short, long, help = "Table name or id.")]
+    table: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Years to request, defaults to ALL."
+    )]
+    year: Vec<String>,
+    #[arg(short, long, value_delimiter = ',', value_parser = variants::<Frequency>())]
+    frequency: Vec<Frequency>,
+    #[arg(
+        short,
+        long,
+        value_parser = parameter,
+        help = "Additional parameter as Name=Value."
+    )]
+    param: Vec<(ParameterName, String)>,
+    #[arg(long, help = "Save the response to the data folder.")]
+    save: bool,
+}
+
+/// The `ExportArgs` struct holds the arguments of [`Command::Export`].  Each filter narrows the
+/// [`Query`](bears_species::Query) run against the local data store.
+///
+/// * **dataset** - Export series of this dataset.
+/// * **table** - Export series reported in this table.
+/// * **series** - Export the series with this identifier.
+/// * **frequency** - Export series observed at this frequency.
+/// * **from** - First year to export.
+/// * **to** - Last year to export.
+/// * **output** - Write CSV to this path instead of printing to standard output.
+#[derive(Debug, Clone, PartialEq, Eq, clap::Args, derive_getters::Getters)]
+pub struct ExportArgs {
+    #[arg(short, long, value_parser = variants::<Dataset>())]
+    dataset: Option<Dataset>,
+    #[arg(short, long)]
+    table: Option<String>,
+    #[arg(short, long)]
+    series: Option<String>,
+    #[arg(short, long, value_parser = variants::<Frequency>())]
+    frequency: Option<Frequency>,
+    #[arg(long, help = "First year to export.")]
+    from: Option<i16>,
+    #[arg(long, help = "Last year to export.")]
+    to: Option<i16>,
+    #[arg(short, long, help = "Write CSV to a file.")]
+    output: Option<std::path::PathBuf>,
+}
+
+/// The `variants` function parses a variant of `T` from its name, listing every variant as a
+/// possible value in the help text and in shell completions.
+pub fn variants<T>() -> impl TypedValueParser<Value = T>
+where
+    T: strum::IntoEnumIterator + std::fmt::Display + std::str::FromStr + Clone + Send + Sync,
+    T: 'static,
+    <T as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    let names = T::iter().map(|variant| variant.to_string());
+    clap::builder::PossibleValuesParser::new(names).try_map(|name| name.parse::<T>())
+}
+
+/// Longest span of days accepted by the command line, a century.  Bounds the ages converted to a
+/// [`jiff::SignedDuration`], which would overflow for very large counts.
+pub const MAX_DAYS: i64 = 36_525;
+
+/// The `days` function parses a count of days between zero and [`MAX_DAYS`].
+pub fn days() -> clap::builder::RangedI64ValueParser<i64> {
+    clap::value_parser!(i64).range(0..=MAX_DAYS)
+}
+
+/// Parses a `Name=Value` pair into a parameter name and its value.
+fn parameter(s: &str) -> Result<(ParameterName, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected Name=Value, found {s}"))?;
+    let name = name
+        .parse::<ParameterName>()
+        .map_err(|e| format!("unknown parameter {name}: {e}"))?;
+    Ok((name, value.to_owned()))
 }
@@ -1,5 +1,8 @@
 mod command;
+mod run;
 mod value;
 
-pub use command::Action;
+pub use command::{
+    Cli, Command, ExportArgs, GetArgs, RetryArgs, ValuesArgs, ValuesCommand, variants,
+};
 pub use value::{ValueSet, ValueSets};
@@ -0,0 +1,408 @@
+use crate::{Cli, Command, ExportArgs, GetArgs, RetryArgs, ValuesCommand};
+use bears_ecology::{
+    History, Index, Mode, RefreshOptions, Request, RetryFilter, RetryPolicy, Scope, StatusReport,
+    bea_data, init_queue, initial_download, initial_load, read_response, refresh_values,
+    retry_download, retry_load,
+};
+use bears_species::{
+    BeaErr, BeaResponse, Check, Dataset, DeriveFromStr, KeyGenerator, KeyMissing, KeyTarget,
+    NipaTableName, ParameterName, Query, ReqwestError, Results, SerdeJson,
+};
+use clap::CommandFactory;
+use strum::IntoEnumIterator;
+
+impl Cli {
+    /// The `run` method executes the selected [`Command`], printing the results to standard
+    /// output as plain text, or as JSON if the `json` flag is set.
+    #[tracing::instrument(skip_all)]
+    pub async fn run(&self) -> Result<(), BeaErr> {
+        let json = *self.json();
+        match self.command() {
+            Command::Datasets => datasets(json),
+            Command::Params { dataset } => params(*dataset, json),
+            Command::Values(args) => match (args.refresh(), args.dataset(), args.param()) {
+                (Some(refresh), _, _) => refresh_cache(refresh, json).await,
+                (None, Some(dataset), Some(param)) => values(*dataset, *param, json),
+                // Clap requires the dataset and parameter without a subcommand.
+                _ => Ok(()),
+            },
+            Command::Get(args) => get(args, json).await,
+            Command::Download { dataset, archive } => {
+                tracing::info!("Downloading {dataset}.");
+                initial_download(*dataset, *archive).await
+            }
+            Command::Load { dataset } => {
+                tracing::info!("Loading {dataset}.");
+                let result = initial_load(*dataset, None).await?;
+                tracing::info!("{} datasets loaded.", result.len());
+                Ok(())
+            }
+            Command::Export(args) => export(args, json),
+            Command::Status {
+                dataset,
+                stale_days,
+            } => {
+                let stale_after = jiff::SignedDuration::from_hours(24 * stale_days);
+                let report = StatusReport::from_env(*dataset, stale_after)?;
+                if json {
+                    println!("{}", report.to_json()?);
+                } else {
+                    print!("{report}");
+                }
+                Ok(())
+            }
+            Command::Retry(args) => retry(args, json).await,
+            Command::Migrate => {
+                let count = History::migrate_all()?;
+                tracing::info!("{count} history events rewritten in the current format.");
+                Ok(())
+            }
+            Command::Generate { source } => generate(source.clone()),
+            Command::Completions { shell } => {
+                clap_complete::generate(
+                    *shell,
+                    &mut <Self as CommandFactory>::command(),
+                    "bears",
+                    &mut std::io::stdout(),
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Prints `value` to standard output as pretty-printed JSON.
+fn print_json<T: serde::Serialize>(value: &T) -> Result<(), BeaErr> {
+    let contents = serde_json::to_string_pretty(value)
+        .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+    println!("{contents}");
+    Ok(())
+}
+
+/// Lists each dataset, using the descriptions in `datasets.json` when available.
+fn datasets(json: bool) -> Result<(), BeaErr> {
+    let path = bea_data().ok().map(|path| path.join("datasets.json"));
+    let details = match path {
+        Some(path) if path.exists() => read_response(&path)?.datasets(),
+        _ => None,
+    };
+    let rows = match details {
+        Some(details) => details
+            .iter()
+            .map(|d| {
+                (
+                    d.dataset_name().to_owned(),
+                    d.dataset_description().to_owned(),
+                )
+            })
+            .collect::<Vec<(String, String)>>(),
+        None => Dataset::iter()
+            .map(|dataset| (dataset.to_string(), String::new()))
+            .collect(),
+    };
+    if json {
+        let rows = rows
+            .iter()
+            .map(
+                |(name, description)| serde_json::json!({"name": name, "description": description}),
+            )
+            .collect::<Vec<serde_json::Value>>();
+        return print_json(&rows);
+    }
+    rows.iter()
+        .map(|(name, description)| println!("{}", format!("{name:<26} {description}").trim_end()))
+        .for_each(drop);
+    Ok(())
+}
+
+/// Lists the parameter names of `dataset`.
+fn params(dataset: Dataset, json: bool) -> Result<(), BeaErr> {
+    let names = dataset
+        .names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<String>>();
+    if json {
+        return print_json(&names);
+    }
+    names.iter().map(|name| println!("{name}")).for_each(drop);
+    Ok(())
+}
+
+/// Lists the valid values of `param` in `dataset`, read from the `parameter_values` folder.
+fn values(dataset: Dataset, param: ParameterName, json: bool) -> Result<(), BeaErr> {
+    let folder = bea_data()?.join("parameter_values");
+    let mut path = folder.join(format!("{dataset}_{param}_values.json"));
+    // Datasets without filtered values keep the response of `GetParameterValues`.
+    let legacy = folder.join(format!("{dataset}_{param}_parameter_values.json"));
+    if !path.exists() && legacy.exists() {
+        path = legacy;
+    }
+    let Some(values) = read_response(&path)?.parameter_values() else {
+        let clue = format!("parameter values in {}", path.display());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    };
+    // Each table serializes as an object keyed by its variant name.
+    let rows = values
+        .iter()
+        .map(|table| {
+            let value = serde_json::to_value(table)
+                .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+            Ok(match value {
+                serde_json::Value::Object(map) if map.len() == 1 => map
+                    .into_iter()
+                    .map(|(_, inner)| inner)
+                    .next()
+                    .unwrap_or_default(),
+                other => other,
+            })
+        })
+        .collect::<Result<Vec<serde_json::Value>, BeaErr>>()?;
+    if json {
+        return print_json(&rows);
+    }
+    for (table, row) in values.iter().zip(rows) {
+        match table.parameter_fields() {
+            Some(fields) => println!("{:<16} {}", fields.key(), fields.desc()),
+            None => println!("{row}"),
+        }
+    }
+    Ok(())
+}
+
+/// Refreshes the parameter value cache, printing a summary of the files fetched.
+async fn refresh_cache(command: &ValuesCommand, json: bool) -> Result<(), BeaErr> {
+    let ValuesCommand::Refresh {
+        max_age_days,
+        interval_ms,
+        force,
+    } = command;
+    let max_age = max_age_days.map(|days| jiff::SignedDuration::from_hours(24 * days));
+    let interval = std::time::Duration::from_millis(*interval_ms);
+    let options = RefreshOptions::new(max_age, interval, *force);
+    let summary = refresh_values(options).await?;
+    if json {
+        return print_json(&summary);
+    }
+    println!("{summary}");
+    Ok(())
+}
+
+/// Requests a single table, printing the series in the response.
+async fn get(args: &GetArgs, json: bool) -> Result<(), BeaErr> {
+    let dataset = *args.dataset();
+    let mut app = Request::Data.init()?;
+    app.with_dataset(dataset);
+    let names = dataset.names();
+    let mut params = std::collections::BTreeMap::new();
+    if let Some(table) = args.table() {
+        if dataset == Dataset::Nipa {
+            table
+                .parse::<NipaTableName>()
+                .map_err(|e| DeriveFromStr::new(table.clone(), e, line!(), file!().to_string()))?;
+        }
+        let name = if names.contains(&ParameterName::TableName) {
+            ParameterName::TableName
+        } else {
+            ParameterName::TableID
+        };
+        params.insert(name.to_string(), table.clone());
+    }
+    if names.contains(&ParameterName::Year) {
+        let year = if args.year().is_empty() {
+            "ALL".to_owned()
+        } else {
+            args.year().join(",")
+        };
+        params.insert(ParameterName::Year.to_string(), year);
+    }
+    if !args.frequency().is_empty() {
+        let frequency = args
+            .frequency()
+            .iter()
+            .map(|frequency| frequency.value())
+            .collect::<Vec<String>>()
+            .join(",");
+        params.insert(ParameterName::Frequency.to_string(), frequency);
+    }
+    args.param()
+        .iter()
+        .map(|(name, value)| params.insert(name.to_string(), value.clone()))
+        .for_each(drop);
+    app.with_params(params);
+
+    let response = app.get().await?;
+    let contents = response
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|source| {
+            let url = app.url().to_string();
+            ReqwestError::new(url, "get".to_string(), source, line!(), file!().to_string())
+        })?;
+    let bea = BeaResponse::try_from(&contents)?;
+    if *args.save() {
+        app.save(contents.clone())?;
+        tracing::info!("Response saved to {}.", app.destination(false)?.display());
+    }
+    if json {
+        return print_json(&contents);
+    }
+    match bea.results() {
+        Results::Data(data) => {
+            for key in data.keys() {
+                println!(
+                    "{:<24} {:<10} {} {}",
+                    key.id(),
+                    key.frequency().to_string(),
+                    key.start(),
+                    key.end()
+                );
+            }
+        }
+        Results::ApiError(error) => println!("{error}"),
+        Results::MneError(error) => println!("{error}"),
+        Results::RequestsExceeded(error) => println!("{error}"),
+        _ => print_json(&contents)?,
+    }
+    Ok(())
+}
+
+/// A single observation of an exported series.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+struct ExportRow {
+    id: String,
+    description: String,
+    frequency: String,
+    period: String,
+    value: Option<f64>,
+    unit: String,
+    unit_mult: Option<i64>,
+}
+
+/// Exports the series in the local data store matching the filters in `args`.
+fn export(args: &ExportArgs, json: bool) -> Result<(), BeaErr> {
+    let mut query = Query::default();
+    if let Some(dataset) = args.dataset() {
+        query = query.with_dataset(*dataset);
+    }
+    if let Some(table) = args.table() {
+        query = query.with_table(table);
+    }
+    if let Some(series) = args.series() {
+        query = query.with_series(series);
+    }
+    if let Some(frequency) = args.frequency() {
+        query = query.with_frequency(*frequency);
+    }
+    let start = args.from().map(|year| jiff::civil::date(year, 1, 1));
+    let end = args.to().map(|year| jiff::civil::date(year, 12, 31));
+    query = query.with_range(start, end);
+
+    let set = Index::from_env()?.query(&query)?;
+    let mut rows = set
+        .values()
+        .flat_map(|series| {
+            series
+                .observations()
+                .iter()
+                .map(|(period, quantity)| ExportRow {
+                    id: series.id().to_owned(),
+                    description: series.description().to_owned(),
+                    frequency: series.frequency().to_string(),
+                    period: period.to_string(),
+                    value: quantity.value(),
+                    unit: series.unit().to_owned(),
+                    unit_mult: *series.unit_mult(),
+                })
+        })
+        .collect::<Vec<ExportRow>>();
+    tracing::info!(
+        "{} series, {} observations exported.",
+        set.len(),
+        rows.len()
+    );
+    if let Some(path) = args.output() {
+        return bears_species::to_csv(&mut rows, path);
+    }
+    if json {
+        return print_json(&rows);
+    }
+    for row in rows {
+        let value = row.value.map(|v| v.to_string()).unwrap_or_default();
+        println!("{}\t{}\t{}\t{value}", row.id, row.frequency, row.period);
+    }
+    Ok(())
+}
+
+/// Retries the failed loads or, with the `download` flag, the failed downloads in `args`.
+async fn retry(args: &RetryArgs, json: bool) -> Result<(), BeaErr> {
+    let dataset = *args.dataset();
+    if *args.download() {
+        let mut filter = RetryFilter::default();
+        filter
+            .with_kind(*args.kind())
+            .with_reason(args.reason().clone())
+            .with_max_age(
+                args.max_age_days()
+                    .map(|days| jiff::SignedDuration::from_hours(24 * days)),
+            );
+        let backoff = std::time::Duration::from_secs(*args.backoff_secs());
+        let policy = RetryPolicy::new(*args.rounds(), backoff, *args.invalid_after());
+        let summary = retry_download(dataset, &filter, &policy).await?;
+        if json {
+            return print_json(&summary);
+        }
+        println!("{summary}");
+        summary
+            .invalid()
+            .iter()
+            .map(|path| println!("Invalid: {}", path.display()))
+            .for_each(drop);
+        return Ok(());
+    }
+    if !*args.first() {
+        let data = retry_load(dataset).await?;
+        tracing::info!("{} files loaded on retry.", data.len());
+        return Ok(());
+    }
+    let mut queue = init_queue(dataset)?;
+    let history = History::try_from((dataset, Mode::Load))?;
+    queue.errors(&history, Scope::History)?;
+    if let Some(req) = queue.first() {
+        tracing::info!("Loading first failed file.");
+        req.load()?;
+        tracing::info!("File successfully loaded.");
+    } else {
+        tracing::info!("No failed files to retry.");
+    }
+    Ok(())
+}
+
+/// Regenerates the key enums under the workspace `root`, defaulting to the working directory.
+/// Every target is attempted, and a [`Check`] error names the targets that could not be written.
+fn generate(root: Option<std::path::PathBuf>) -> Result<(), BeaErr> {
+    let root = root.unwrap_or_else(|| ".".into());
+    let parameter_values = bea_data().ok().map(|path| path.join("parameter_values"));
+    let generator = KeyGenerator::new(root, parameter_values);
+    let mut failed = Vec::new();
+    for target in KeyTarget::iter() {
+        if !generator.is_generated(target)? {
+            tracing::info!("{target} is maintained by hand, skipping.");
+            continue;
+        }
+        match generator.write(target) {
+            Ok(true) => tracing::info!("{target} regenerated."),
+            Ok(false) => tracing::info!("{target} is up to date."),
+            Err(e) => {
+                tracing::error!("Could not generate {target}: {e}");
+                failed.push(target.to_string());
+            }
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        let desc = format!("could not generate {}", failed.join(", "));
+        Err(Check::new(desc).into())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Options, ParameterKind, bea_data};
+use crate::{Archive, Options, ParameterKind, Vintages, bea_data};
 use bears_species::{
     BTreeKeyMissing, BeaErr, BeaResponse, Dataset, DeriveFromStr, IoError, JsonParseError,
     JsonParseErrorKind, KeyMissing, Method, MillionsOptions, ParameterName, RateLimit,
@@ -26,9 +26,13 @@ use std::str::FromStr;
 ///   options fields ([`App::with_options`] and [`App::with_params`]), as these methods keep the `query` field updated and in sync with new
 ///   changes.
 /// * **size_hint** - Indicates the expected size of the request payload based on a download
-///   [`History`](crate::History).  In the absence of a download history, this field is `None`.  Used
-///   during rate limiting by the [`Tracker`](crate::Tracker) type to avoid exceeding the 100 MB per
-///   minute rate threshold of the BEA REST server.
+///   [`History`](crate::History), or estimated from similar targets by a
+///   [`SizeModel`](crate::SizeModel).  In the absence of a download history, this field is `None`.
+///   Used during rate limiting by the [`Tracker`](crate::Tracker) type to avoid exceeding the
+///   100 MB per minute rate threshold of the BEA REST server.
+/// * **archive** - When set, [`App::save`] also keeps a snapshot of each download in the vintage
+///   archive, named according to the [`Archive`] variant.  In the absence of an archive mode,
+///   this field is `None`, and each download overwrites the previous release.
 ///
 ///   ## Usage
 ///
@@ -54,6 +58,8 @@ pub struct App {
     url: url::Url,
     query: BTreeMap<String, String>,
     size_hint: Option<u64>,
+    #[serde(default)]
+    archive: Option<Archive>,
 }
 
 impl App {
@@ -86,6 +92,7 @@ impl App {
             url,
             query,
             size_hint: None,
+            archive: None,
         }
     }
 
@@ -430,24 +437,53 @@ impl App {
     /// For one-off requests, tracking is unnecessary and it is better to use the [`App::get`]
     /// method directly.
     pub async fn download(&self, id: uuid::Uuid) -> Result<ResultStatus, BeaErr> {
+        let status = self.fetch(id).await?;
+        #[cfg(feature = "metrics")]
+        crate::Metrics::global().record(self.dataset().ok(), crate::Mode::Download, &status);
+        Ok(status)
+    }
+
+    /// Sends the request and saves the response for [`App::download`].
+    #[tracing::instrument(skip_all)]
+    async fn fetch(&self, id: uuid::Uuid) -> Result<ResultStatus, BeaErr> {
         tracing::trace!("Calling download.");
         let query = self.query();
         tracing::trace!("Params are {:#?}", query);
         let method = self.method()?;
         match method {
             Method::GetData => {
-                let data = self.get().await?;
+                let data = match self.get().await {
+                    Ok(data) => data,
+                    Err(error) => {
+                        let detail = ErrorDetail::transport(ErrorKind::Transport, error, None);
+                        tracing::warn!("{detail}");
+                        return Ok(ResultStatus::Error(id, Some(detail)));
+                    }
+                };
+                let http_status = Some(data.status().as_u16());
                 let length = data.content_length().unwrap();
                 match data.json::<serde_json::Value>().await {
                     Ok(json) => match BeaResponse::try_from(&json) {
                         Ok(response) => match response.results() {
                             Results::ApiError(error) => {
                                 tracing::error!("{error}");
-                                return Ok(ResultStatus::Error(id));
+                                let detail = ErrorDetail::new(
+                                    ErrorKind::Api,
+                                    Some(*error.code()),
+                                    error.description().to_owned(),
+                                    http_status,
+                                );
+                                return Ok(ResultStatus::Error(id, Some(detail)));
                             }
                             Results::MneError(error) => {
                                 tracing::trace!("{error}");
-                                return Ok(ResultStatus::Error(id));
+                                let detail = ErrorDetail::new(
+                                    ErrorKind::Api,
+                                    Some(*error.number()),
+                                    error.error().to_owned(),
+                                    http_status,
+                                );
+                                return Ok(ResultStatus::Error(id, Some(detail)));
                             }
                             Results::RequestsExceeded(error) => {
                                 let error =
@@ -471,24 +507,36 @@ impl App {
                         }
                     },
                     Err(source) => {
+                        // A body that arrives but is not JSON is a parse failure, any other
+                        // failure to read the body is a transport failure.
+                        let kind = if source.is_decode() {
+                            ErrorKind::Parse
+                        } else {
+                            ErrorKind::Transport
+                        };
                         let url = self.url().to_string();
                         let method = "get".to_string();
                         let error =
                             ReqwestError::new(url, method, source, line!(), file!().to_string());
-                        tracing::warn!("{error}");
                         // return Err(error.into());
-                        return Ok(ResultStatus::Error(id));
+                        let detail = ErrorDetail::transport(kind, error, http_status);
+                        tracing::warn!("{detail}");
+                        return Ok(ResultStatus::Error(id, Some(detail)));
                     }
                 }
             }
             _ => {
                 tracing::info!("{method} not implemented.");
-                Ok(ResultStatus::Error(id))
+                let message = format!("{method} not implemented");
+                let detail = ErrorDetail::new(ErrorKind::Unsupported, None, message, None);
+                Ok(ResultStatus::Error(id, Some(detail)))
             }
         }
     }
 
-    /// The `save` method writes a [`serde_json::Value`] to the `BEA_DATA` directory.
+    /// The `save` method writes a [`serde_json::Value`] to the `BEA_DATA` directory.  If the
+    /// `archive` field is set, the contents are also recorded as a new vintage of the target,
+    /// unless they match the latest vintage.
     pub fn save(&self, json: serde_json::Value) -> Result<(), BeaErr> {
         tracing::trace!("Calling save.");
         let method = self.method()?;
@@ -498,8 +546,11 @@ impl App {
                     .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
 
                 let path = self.destination(true)?;
-                std::fs::write(&path, contents)
-                    .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+                std::fs::write(&path, &contents)
+                    .map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+                if let Some(archive) = self.archive {
+                    Vintages::from_target(&path)?.record(&contents, archive)?;
+                }
             }
             _ => {
                 tracing::info!("Not implemented for {method}.");
@@ -508,6 +559,18 @@ impl App {
         Ok(())
     }
 
+    /// The `vintages` method returns the index of archived snapshots for the target of self.
+    pub fn vintages(&self) -> Result<Vintages, BeaErr> {
+        let path = self.destination(false)?;
+        Vintages::from_target(&path)
+    }
+
+    /// The `load_vintage` method reads the archived snapshot of the target that was current on
+    /// `date`, or the most recent snapshot retrieved on or before `date`.
+    pub fn load_vintage(&self, date: jiff::civil::Date) -> Result<BeaResponse, BeaErr> {
+        self.vintages()?.load_as_of(date)
+    }
+
     /// The `load` method reads a [`BeaResponse`] from the `BEA_DATA` directory.  Uses the `App`
     /// configuration to determine the file destination.
     pub fn load(&self) -> Result<BeaResponse, BeaErr> {
@@ -546,7 +609,6 @@ impl App {
 /// tasks.
 #[derive(
     Debug,
-    Copy,
     Clone,
     PartialEq,
     Eq,
@@ -565,11 +627,12 @@ pub enum ResultStatus {
     #[display("Success")]
     Success(uuid::Uuid, u64),
     /// The event resulted in an error.
-    /// Used for MneError and ReqwestError outcomes.
-    /// Contains and event id.
+    /// Used for ApiError, MneError and ReqwestError outcomes.
+    /// Contains an event id and the details of the error.
     /// The event id is used to update the status of a pending event.
+    /// The details are missing for events logged before they were recorded.
     #[display("Error")]
-    Error(uuid::Uuid),
+    Error(uuid::Uuid, Option<ErrorDetail>),
     /// The task was skipped.
     /// Used when the download path exists and overwrite is false.
     /// Contains and event id.
@@ -586,12 +649,22 @@ pub enum ResultStatus {
     Abort,
 }
 
+impl ResultStatus {
+    /// The `detail` method returns the details of an error status, if recorded.
+    pub fn detail(&self) -> Option<&ErrorDetail> {
+        match self {
+            Self::Error(_, detail) => detail.as_ref(),
+            _ => None,
+        }
+    }
+}
+
 impl FromStr for ResultStatus {
     type Err = JsonParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let status = match s {
             "Success" => Self::Success(uuid::Uuid::new_v4(), 0),
-            "Error" => Self::Error(uuid::Uuid::new_v4()),
+            "Error" => Self::Error(uuid::Uuid::new_v4(), None),
             "Pass" => Self::Pass(uuid::Uuid::new_v4()),
             "Pending" => Self::Pending,
             "Abort" => Self::Abort,
@@ -603,3 +676,139 @@ impl FromStr for ResultStatus {
         Ok(status)
     }
 }
+
+/// The `ErrorKind` enum classifies the failure of a request by where it occurred.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum ErrorKind {
+    /// The request did not reach the BEA server, or the response did not arrive.
+    Transport,
+    /// The response arrived but could not be read, or a file could not be loaded as data.
+    Parse,
+    /// The BEA server returned an error payload in place of the requested data.
+    Api,
+    /// The request uses a method or dataset that the library does not implement.
+    Unsupported,
+}
+
+/// The `ErrorDetail` struct records why a request failed, so that the history can distinguish
+/// combinations of parameters that the BEA server rejects from transient failures.
+///
+/// * **kind** - Where the failure occurred.
+/// * **code** - The BEA API error code, or the error number of an MNE error payload.
+/// * **message** - The error description from the BEA server, or the local error message.
+/// * **http_status** - The HTTP status of the response, if one arrived.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct ErrorDetail {
+    kind: ErrorKind,
+    code: Option<i32>,
+    message: String,
+    http_status: Option<u16>,
+}
+
+impl ErrorDetail {
+    /// The `transport` method records a request that failed with `error`, with a message built
+    /// from the url of the request and the chain of source errors.  The query of a BEA request
+    /// carries the API key of the user, so the message omits the query and the request
+    /// parameters, and is safe to write to the history log.
+    pub fn transport(kind: ErrorKind, error: ReqwestError, http_status: Option<u16>) -> Self {
+        let mut causes = Vec::new();
+        let mut cause = std::error::Error::source(&error.source);
+        while let Some(source) = cause {
+            causes.push(source.to_string());
+            cause = source.source();
+        }
+        let url = redact(&error.url);
+        let mut message = format!(
+            "{} request to {url}: {}",
+            error.method,
+            error.source.without_url()
+        );
+        causes
+            .iter()
+            .map(|cause| {
+                message.push_str(": ");
+                message.push_str(cause);
+            })
+            .for_each(drop);
+        Self::new(kind, None, redact(&message), http_status)
+    }
+
+    /// The `reason` method groups errors for reporting.  Error payloads from the BEA server are
+    /// grouped by code and description.  Transport and parse failures are grouped by kind and
+    /// HTTP status, since their messages name the individual request.
+    pub fn reason(&self) -> String {
+        match self.kind {
+            ErrorKind::Api => match self.code {
+                Some(code) => format!("{} {code}: {}", self.kind, self.message),
+                None => format!("{}: {}", self.kind, self.message),
+            },
+            ErrorKind::Unsupported => format!("{}: {}", self.kind, self.message),
+            ErrorKind::Transport | ErrorKind::Parse => match self.http_status {
+                Some(status) => format!("{} (HTTP {status})", self.kind),
+                None => self.kind.to_string(),
+            },
+        }
+    }
+}
+
+/// The `redact` function removes the query from each url in `message`, along with any request
+/// parameters listed by a [`ReqwestError`], since the query of a BEA request carries the API key
+/// of the user.  Applied to error messages before they are written to the history log.
+pub(crate) fn redact(message: &str) -> String {
+    let end = [" including headers ", " with body ", " with form body "]
+        .iter()
+        .filter_map(|marker| message.find(marker))
+        .min()
+        .unwrap_or(message.len());
+    message[..end]
+        .split(' ')
+        .map(|word| match word.find('?') {
+            Some(index) if word.contains("://") => {
+                let closing = word.len() - word.trim_end_matches([')', ',', '.']).len();
+                format!("{}{}", &word[..index], &word[word.len() - closing..])
+            }
+            _ => word.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+impl std::fmt::Display for ErrorDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} error", self.kind)?;
+        if let Some(code) = self.code {
+            write!(f, " {code}")?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(status) = self.http_status {
+            write!(f, " (HTTP {status})")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{App, ErrorKind, Event, Mode, ResultStatus, bea_data};
+use bears_species::{BeaErr, Dataset, IoError, SerdeJson};
+
+/// The `Attempts` struct holds every [`Event`] recorded for a single target, ordered by time.
+/// Only events with a `Success` or `Error` status count as outcomes for the derived statistics,
+/// since `Pass`, `Pending` and `Abort` events do not attempt a request.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::From,
+)]
+#[from(Vec<Event>)]
+pub struct Attempts(Vec<Event>);
+
+impl Attempts {
+    /// The `outcomes` method returns the attempts with a success or error status, oldest first.
+    pub fn outcomes(&self) -> impl DoubleEndedIterator<Item = &Event> {
+        self.iter().filter(|event| {
+            matches!(
+                event.status(),
+                ResultStatus::Success(_, _) | ResultStatus::Error(_, _)
+            )
+        })
+    }
+
+    /// The `first_success` method returns the earliest successful attempt, if any.
+    pub fn first_success(&self) -> Option<&Event> {
+        self.iter()
+            .find(|event| matches!(event.status(), ResultStatus::Success(_, _)))
+    }
+
+    /// The `last_outcome` method returns the most recent attempt with a success or error status.
+    pub fn last_outcome(&self) -> Option<&Event> {
+        self.outcomes().next_back()
+    }
+
+    /// The `failures` method returns the number of attempts with an error status.
+    pub fn failures(&self) -> usize {
+        self.outcomes()
+            .filter(|event| matches!(event.status(), ResultStatus::Error(_, _)))
+            .count()
+    }
+
+    /// The `failure_streak` method returns the number of consecutive errors since the most recent
+    /// success, counting only attempts made at or after `since`.  Use [`jiff::Timestamp::MIN`]
+    /// to count the full streak.
+    pub fn failure_streak(&self, since: jiff::Timestamp) -> usize {
+        self.outcomes()
+            .rev()
+            .take_while(|event| {
+                *event.time() >= since && matches!(event.status(), ResultStatus::Error(_, _))
+            })
+            .count()
+    }
+
+    /// The `identical_errors` method returns the number of consecutive API errors since the most
+    /// recent success that share the [`ErrorDetail::reason`](crate::ErrorDetail::reason) of the
+    /// latest one.  Returns 0 if the latest outcome is not an API error.
+    pub fn identical_errors(&self) -> usize {
+        let reason = |event: &Event| {
+            event
+                .status()
+                .detail()
+                .filter(|detail| *detail.kind() == ErrorKind::Api)
+                .map(|detail| detail.reason())
+        };
+        let Some(last) = self.last_outcome().and_then(reason) else {
+            return 0;
+        };
+        self.outcomes()
+            .rev()
+            .take_while(|event| reason(event).as_ref() == Some(&last))
+            .count()
+    }
+
+    /// The `flakiness` method returns the share of consecutive outcomes that change between
+    /// success and error, from 0.0 for a target that always fails or always succeeds to 1.0 for
+    /// a target that alternates.  Returns 0.0 for fewer than two outcomes.
+    pub fn flakiness(&self) -> f64 {
+        let outcomes = self
+            .outcomes()
+            .map(|event| matches!(event.status(), ResultStatus::Success(_, _)))
+            .collect::<Vec<bool>>();
+        if outcomes.len() < 2 {
+            return 0.0;
+        }
+        let changes = outcomes
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count();
+        changes as f64 / (outcomes.len() - 1) as f64
+    }
+}
+
+/// The `AttemptHistory` struct keeps every [`Event`] in the history logs, grouped into
+/// [`Attempts`] by the `Event` path.  Where [`History`](crate::History) keeps the last event for
+/// each target, `AttemptHistory` can tell a target that failed repeatedly before succeeding from
+/// one that always succeeded.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Deref,
+    derive_more::DerefMut,
+)]
+pub struct AttemptHistory(std::collections::BTreeMap<std::path::PathBuf, Attempts>);
+
+impl AttemptHistory {
+    /// The `from_env` method reads every history log in the `history` folder of the `BEA_DATA`
+    /// directory.  Events logged to more than one file are kept once.
+    #[tracing::instrument]
+    pub fn from_env() -> Result<Self, BeaErr> {
+        let path = bea_data()?.join("history");
+        let entries = std::fs::read_dir(&path)
+            .map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+        let mut history = Self::default();
+        for entry in entries {
+            let entry =
+                entry.map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+            let log = entry.path();
+            if log.extension().is_some_and(|ext| ext == "log") {
+                history.read(&log)?;
+            }
+        }
+        Ok(history)
+    }
+
+    /// The `read` method adds the events in the history log at `path`, skipping events already
+    /// present, and keeps the attempts for each target ordered by time.
+    #[tracing::instrument(skip(self))]
+    pub fn read(&mut self, path: &std::path::Path) -> Result<(), BeaErr> {
+        let file = std::fs::read_to_string(path)
+            .map_err(|e| IoError::new(path.into(), e, line!(), file!().into()))?;
+        for line in file.lines() {
+            let json: serde_json::Value = serde_json::from_str(line)
+                .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+            let event = Event::try_from(&json)?;
+            self.insert(event);
+        }
+        Ok(())
+    }
+
+    /// The `insert` method adds `event` to the attempts for its path, unless an event with the
+    /// same id is already present.
+    pub fn insert(&mut self, event: Event) {
+        let attempts = self.entry(event.path().to_owned()).or_default();
+        if attempts.iter().any(|other| other.id() == event.id()) {
+            return;
+        }
+        let index = attempts.partition_point(|other| other.time() <= event.time());
+        attempts.insert(index, event);
+    }
+
+    /// The `attempts` method returns the attempts at the destination of `app`, if any.
+    pub fn attempts(&self, app: &App) -> Result<Option<&Attempts>, BeaErr> {
+        let path = app.destination(false)?;
+        Ok(self.get(&path))
+    }
+
+    /// The `with_mode` method returns the subset of attempts where the mode of the event matches
+    /// `mode`, dropping targets left without attempts.
+    pub fn with_mode(&self, mode: Mode) -> Self {
+        let mut history = Self::default();
+        self.values()
+            .flat_map(|attempts| attempts.iter())
+            .filter(|event| *event.mode() == mode)
+            .map(|event| history.insert(event.clone()))
+            .for_each(drop);
+        history
+    }
+}
+
+impl From<Vec<Event>> for AttemptHistory {
+    fn from(value: Vec<Event>) -> Self {
+        let mut history = Self::default();
+        value
+            .into_iter()
+            .map(|event| history.insert(event))
+            .for_each(drop);
+        history
+    }
+}
+
+impl TryFrom<&std::path::PathBuf> for AttemptHistory {
+    type Error = BeaErr;
+
+    fn try_from(path: &std::path::PathBuf) -> Result<Self, Self::Error> {
+        let mut history = Self::default();
+        history.read(path)?;
+        Ok(history)
+    }
+}
+
+impl TryFrom<(Dataset, Mode)> for AttemptHistory {
+    type Error = BeaErr;
+
+    fn try_from(ctx: (Dataset, Mode)) -> Result<Self, Self::Error> {
+        let (dataset, mode) = ctx;
+        let path = crate::History::path(dataset, mode)?;
+        Self::try_from(&path)
+    }
+}
+
+/// The `SkipPolicy` struct decides which targets
+/// [`Queue::active_subset`](crate::Queue::active_subset) leaves out of a run, based on their
+/// [`Attempts`].
+///
+/// * **failures** - Number of consecutive failures after which a target is skipped.
+/// * **window** - Only failures within this duration before the present count toward the
+///   streak, so that targets are retried once their failures age out.
+/// * **invalid_after** - Number of identical API errors after which a target is permanently
+///   invalid, as in [`RetryPolicy`](crate::RetryPolicy), and skipped regardless of the window.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct SkipPolicy {
+    failures: usize,
+    window: jiff::SignedDuration,
+    invalid_after: usize,
+}
+
+impl SkipPolicy {
+    /// The `skip` method returns true if the failure streak of `attempts` within the window
+    /// before `now` has reached the failure threshold, or if the target is permanently invalid
+    /// after repeating the same API error.
+    pub fn skip(&self, attempts: &Attempts, now: jiff::Timestamp) -> bool {
+        let since = now.checked_sub(self.window).unwrap_or(jiff::Timestamp::MIN);
+        attempts.failure_streak(since) >= self.failures || self.is_invalid(attempts)
+    }
+
+    /// The `is_invalid` method returns true if the BEA server has rejected the target with the
+    /// same API error at least `invalid_after` times in a row, matching
+    /// [`RetryPolicy::is_invalid`](crate::RetryPolicy::is_invalid).
+    pub fn is_invalid(&self, attempts: &Attempts) -> bool {
+        attempts.identical_errors() >= self.invalid_after
+    }
+}
+
+impl Default for SkipPolicy {
+    /// Skips targets that failed three consecutive times in the last 30 days, and targets
+    /// rejected three times with the same API error.
+    fn default() -> Self {
+        Self::new(3, jiff::SignedDuration::from_hours(24 * 30), 3)
+    }
+}
+
+impl From<&crate::RetryPolicy> for SkipPolicy {
+    /// Skips the targets that `policy` marks as permanently invalid, along with the default
+    /// failure streak rule.
+    fn from(policy: &crate::RetryPolicy) -> Self {
+        Self {
+            invalid_after: *policy.invalid_after(),
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{App, History, ParameterKind, Queue, ResultStatus};
+use bears_species::{AreaOrCountry, BeaErr};
+use std::collections::BTreeMap;
+
+/// Query parameters naming the table of a request.
+const TABLE_PARAMETERS: [&str; 2] = ["TableName", "TableID"];
+/// Query parameters naming the country or area of a request.
+const COUNTRY_PARAMETERS: [&str; 2] = ["Country", "AreaOrCountry"];
+/// Number of neighbouring countries used to estimate the size of a request.
+const NEIGHBOURS: usize = 3;
+
+/// The `EstimateBasis` enum records which targets a [`SizeEstimate`] was drawn from, from the
+/// most to the least similar.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum EstimateBasis {
+    /// The target itself has been downloaded.
+    Known,
+    /// Targets of the same table and parameters in the nearest countries or areas.
+    Neighbours,
+    /// Targets in the same table family of the dataset.
+    Family,
+    /// Any target in the dataset.
+    Dataset,
+}
+
+impl EstimateBasis {
+    /// Confidence in a single sample drawn on this basis.
+    fn weight(&self) -> f64 {
+        match self {
+            Self::Known => 1.0,
+            Self::Neighbours => 0.9,
+            Self::Family => 0.6,
+            Self::Dataset => 0.3,
+        }
+    }
+}
+
+/// The `SizeEstimate` struct predicts the payload size of a request from the sizes of similar
+/// targets in the download [`History`].
+///
+/// * **size** - The median size of the similar targets in bytes.
+/// * **upper** - The 90th percentile size of the similar targets in bytes.
+/// * **basis** - The similarity of the targets used.
+/// * **samples** - The number of targets used.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct SizeEstimate {
+    size: u64,
+    upper: u64,
+    basis: EstimateBasis,
+    samples: usize,
+}
+
+impl SizeEstimate {
+    /// The `from_sizes` method summarizes the sizes of the targets drawn on `basis`, returning
+    /// `None` if `sizes` is empty.
+    fn from_sizes(mut sizes: Vec<u64>, basis: EstimateBasis) -> Option<Self> {
+        if sizes.is_empty() {
+            return None;
+        }
+        sizes.sort();
+        let size = sizes[(sizes.len() - 1) / 2];
+        let upper = sizes[(sizes.len() * 9).div_ceil(10) - 1];
+        Some(Self::new(size, upper, basis, sizes.len()))
+    }
+
+    /// The `confidence` method ranges from near 0.0 for a guess from a single target anywhere
+    /// in the dataset to 1.0 for a target that has been downloaded before.  Confidence grows with
+    /// the similarity of the targets used and with their number.
+    pub fn confidence(&self) -> f64 {
+        match self.basis {
+            EstimateBasis::Known => 1.0,
+            _ => self.basis.weight() * self.samples as f64 / (self.samples + 1) as f64,
+        }
+    }
+
+    /// The `limit` method returns the number of bytes the rate limiter should reserve for the
+    /// request: the median size when the confidence is at least one half, and the 90th percentile
+    /// otherwise, so that poorly informed guesses err toward throttling.
+    pub fn limit(&self) -> u64 {
+        if self.confidence() >= 0.5 {
+            self.size
+        } else {
+            self.upper
+        }
+    }
+}
+
+/// The similarity keys of a request, derived from its query parameters.
+struct Features {
+    dataset: String,
+    family: Option<String>,
+    sibling: String,
+    country: Option<String>,
+    exact: String,
+}
+
+impl Features {
+    fn from_app(app: &App) -> Self {
+        let query = app.query();
+        let dataset = query
+            .get(&ParameterKind::Dataset.header())
+            .cloned()
+            .unwrap_or_default();
+        let ignored = [
+            ParameterKind::UserId.header(),
+            ParameterKind::ResultFormat.header(),
+        ];
+        let params = query
+            .iter()
+            .filter(|(key, _)| !ignored.contains(key))
+            .collect::<Vec<(&String, &String)>>();
+        let table = params
+            .iter()
+            .find(|(key, _)| TABLE_PARAMETERS.contains(&key.as_str()))
+            .map(|(_, value)| value.as_str());
+        let country = params
+            .iter()
+            .find(|(key, _)| COUNTRY_PARAMETERS.contains(&key.as_str()))
+            .map(|(_, value)| value.to_string());
+        let sibling = params
+            .iter()
+            .filter(|(key, _)| !COUNTRY_PARAMETERS.contains(&key.as_str()))
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<String>>()
+            .join("&");
+        let exact = params
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<String>>()
+            .join("&");
+        Self {
+            family: table.map(|table| format!("{dataset}&{}", Self::family(table))),
+            dataset,
+            sibling,
+            country,
+            exact,
+        }
+    }
+
+    /// Tables in a family share a name up to the last two characters, such as the NIPA tables
+    /// T10101 to T10111 of section 1.1.
+    fn family(table: &str) -> &str {
+        let chars = table.chars().count();
+        if chars > 3 {
+            let end = table
+                .char_indices()
+                .nth(chars - 2)
+                .map(|(i, _)| i)
+                .unwrap_or(table.len());
+            &table[..end]
+        } else {
+            table
+        }
+    }
+
+    /// Distance between two country or area codes.  Numeric codes such as the MNE country codes
+    /// are grouped by region, so nearby codes are neighbours.  Named areas such as the ITA and IIP
+    /// `AreaOrCountry` values are ranked by [`AreaOrCountry::distance`].  Returns `None` for codes
+    /// without a known location, which are not neighbours of any other code.
+    fn distance(&self, other: &Option<String>) -> Option<i64> {
+        let (Some(country), Some(other)) = (&self.country, other) else {
+            return Some(0);
+        };
+        if let (Ok(a), Ok(b)) = (country.parse::<i64>(), other.parse::<i64>()) {
+            return Some((a - b).abs());
+        }
+        let (a, b) = (
+            country.parse::<AreaOrCountry>().ok()?,
+            other.parse::<AreaOrCountry>().ok()?,
+        );
+        a.distance(&b)
+            .and_then(|distance| i64::try_from(distance).ok())
+    }
+}
+
+/// The `SizeModel` struct predicts the payload size of requests that have not been downloaded
+/// from the sizes of similar targets that have.  A request is compared first to targets of the
+/// same table and parameters in the nearest countries, then to targets in the same table family,
+/// and last to any target in the same dataset.  Countries without a known location have no
+/// neighbours, so their requests fall back to the table family or the dataset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SizeModel {
+    exact: BTreeMap<String, u64>,
+    siblings: BTreeMap<String, Vec<(Option<String>, u64)>>,
+    families: BTreeMap<String, Vec<u64>>,
+    datasets: BTreeMap<String, Vec<u64>>,
+}
+
+impl SizeModel {
+    /// The `from_history` method trains the model on the successful downloads in `history` of the
+    /// requests in `queue`.
+    #[tracing::instrument(skip_all)]
+    pub fn from_history(history: &History, queue: &Queue) -> Result<Self, BeaErr> {
+        let mut model = Self::default();
+        for app in queue.iter() {
+            let path = app.destination(false)?;
+            if let Some(event) = history.get(&path)
+                && let ResultStatus::Success(_, size) = event.status()
+            {
+                model.observe(app, event.length().unwrap_or(*size));
+            }
+        }
+        tracing::info!("Size model trained on {} targets.", model.exact.len());
+        Ok(model)
+    }
+
+    /// The `observe` method adds the payload `size` of the request `app` to the model.
+    pub fn observe(&mut self, app: &App, size: u64) {
+        let features = Features::from_app(app);
+        self.exact.insert(features.exact, size);
+        self.siblings
+            .entry(features.sibling)
+            .or_default()
+            .push((features.country, size));
+        if let Some(family) = features.family {
+            self.families.entry(family).or_default().push(size);
+        }
+        self.datasets
+            .entry(features.dataset)
+            .or_default()
+            .push(size);
+    }
+
+    /// The `estimate` method predicts the payload size of `app`, returning `None` if the model has
+    /// no targets in the dataset of `app`.
+    pub fn estimate(&self, app: &App) -> Option<SizeEstimate> {
+        if let Some(size) = app.size_hint() {
+            return Some(SizeEstimate::new(*size, *size, EstimateBasis::Known, 1));
+        }
+        let features = Features::from_app(app);
+        if let Some(size) = self.exact.get(&features.exact) {
+            return Some(SizeEstimate::new(*size, *size, EstimateBasis::Known, 1));
+        }
+        if let Some(siblings) = self.siblings.get(&features.sibling) {
+            let mut siblings = siblings
+                .iter()
+                .filter_map(|(country, size)| Some((features.distance(country)?, *size)))
+                .collect::<Vec<(i64, u64)>>();
+            siblings.sort_by_key(|(distance, _)| *distance);
+            let sizes = siblings
+                .into_iter()
+                .take(NEIGHBOURS)
+                .map(|(_, size)| size)
+                .collect::<Vec<u64>>();
+            if !sizes.is_empty() {
+                return SizeEstimate::from_sizes(sizes, EstimateBasis::Neighbours);
+            }
+        }
+        if let Some(sizes) = features
+            .family
+            .as_ref()
+            .and_then(|family| self.families.get(family))
+        {
+            return SizeEstimate::from_sizes(sizes.clone(), EstimateBasis::Family);
+        }
+        self.datasets
+            .get(&features.dataset)
+            .and_then(|sizes| SizeEstimate::from_sizes(sizes.clone(), EstimateBasis::Dataset))
+    }
+}
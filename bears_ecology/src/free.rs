@@ -1,6 +1,5 @@
-use crate::{App, Options};
-use bears_species::{BeaErr, EnvError, IoError, UrlParseError};
-use tracing_subscriber::{Layer, layer::SubscriberExt, util::SubscriberInitExt};
+use crate::{App, Options, TelemetryConfig};
+use bears_species::{BeaErr, EnvError, UrlParseError};
 
 pub fn bea_data() -> Result<std::path::PathBuf, EnvError> {
     dotenvy::dotenv().ok();
@@ -11,57 +10,21 @@ pub fn bea_data() -> Result<std::path::PathBuf, EnvError> {
 }
 
 /// Initiates a subscriber for the tracing library. Used to instrument internal library functions
-/// for debugging and diagnostics.
+/// for debugging and diagnostics.  Installs the default [`TelemetryConfig`], writing history
+/// events to `history/history.log` in the `BEA_DATA` directory.
 #[tracing::instrument]
 pub fn trace_init() -> Result<(), BeaErr> {
     dotenvy::dotenv().ok();
-    let path = bea_data()?;
-    let path = path.join("history");
-    if !path.exists() {
-        std::fs::DirBuilder::new()
-            .create(&path)
-            .map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
-        tracing::info!("History directory created.");
-    }
-    let path = path.join("history.log");
-    let history = std::fs::OpenOptions::new()
-        .append(true)
-        .create(true)
-        .open(&path)
-        .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
-    let history = tracing_subscriber::fmt::layer()
-        .json()
-        .with_writer(std::sync::Arc::new(history))
-        .with_filter(tracing_subscriber::filter::filter_fn(|metadata| {
-            metadata.target() == "download_history" || metadata.target() == "load_history"
-        }));
-    let default_env =
-        tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "bea=info".into());
-    if tracing_subscriber::registry()
-        .with(default_env)
-        .with(
-            tracing_subscriber::fmt::layer().with_filter(tracing_subscriber::filter::filter_fn(
-                |metadata| {
-                    metadata.target() != "download_history" && metadata.target() != "load_history"
-                },
-            )),
-        )
-        .with(history)
-        .try_init()
-        .is_ok()
-    {};
-    tracing::trace!("Loading Bea...");
-    Ok(())
+    TelemetryConfig::default().init()
 }
 
 /// Helper function
-/// Initiates logging
 /// Reads environmental variables from .env
 /// Creates an instance of App
+/// Does not install a subscriber, call [`trace_init`] or [`TelemetryConfig::init`] first to
+/// record history.
 #[tracing::instrument]
 pub fn init() -> Result<App, BeaErr> {
-    trace_init()?;
-    tracing::info!("Test logging initialized.");
     dotenvy::dotenv().ok();
     let url = "BEA_URL".to_string();
     let url = std::env::var(&url)
@@ -21,8 +21,6 @@ impl History {
     /// History logs live in the `history` folder of the `BEA_DATA` directory.
     /// The `from_env` method is an internal function used to establish the appropriate directory
     /// for writing and reading log files.
-    ///
-    /// Called by [`Queue::active_subset`].
     #[tracing::instrument]
     pub fn from_env() -> Result<Self, BeaErr> {
         dotenvy::dotenv().ok();
@@ -31,6 +29,92 @@ impl History {
         Self::try_from(&path)
     }
 
+    /// The `path` method returns the location of the history log for `dataset` and `mode` in the
+    /// `history` folder of the `BEA_DATA` directory.
+    pub fn path(dataset: Dataset, mode: Mode) -> Result<std::path::PathBuf, BeaErr> {
+        dotenvy::dotenv().ok();
+        let path = bea_data()?;
+        let path = path.join("history");
+        Ok(path.join(Self::file_name(dataset, mode)))
+    }
+
+    /// The `file_name` method returns the name of the history log for `dataset` and `mode`.
+    pub fn file_name(dataset: Dataset, mode: Mode) -> String {
+        format!("history_{dataset}_{mode}.log")
+    }
+
+    /// The `migrate` method rewrites the history log at `path` with each event in the structured
+    /// format of the current [`EVENT_SCHEMA`](crate::EVENT_SCHEMA), recovering events from older
+    /// lines that carry only a formatted message.  Lines that cannot be read as an event are moved
+    /// to a file with the extension `log.rejected`, so that readers of the history do not stop on
+    /// them.  The original log is kept with the extension `log.bak`, unless a backup from an
+    /// earlier migration is already present.  Returns the number of events rewritten.
+    #[tracing::instrument]
+    pub fn migrate(path: &std::path::Path) -> Result<usize, BeaErr> {
+        let file = std::fs::read_to_string(path)
+            .map_err(|e| IoError::new(path.into(), e, line!(), file!().into()))?;
+        let mut count = 0;
+        let mut lines = Vec::new();
+        let mut rejected = Vec::new();
+        for line in file.lines().filter(|line| !line.trim().is_empty()) {
+            let event = serde_json::from_str::<serde_json::Value>(line)
+                .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()).into())
+                .and_then(|json| Event::try_from(&json));
+            match event {
+                Ok(event) => {
+                    lines.push(format!("{}\n", event.to_json()));
+                    count += 1;
+                }
+                Err(e) => {
+                    tracing::warn!("Rejecting unreadable line of {}: {e}", path.display());
+                    rejected.push(format!("{line}\n"));
+                }
+            }
+        }
+        let backup = path.with_extension("log.bak");
+        if backup.exists() {
+            tracing::info!("Keeping the earlier backup {}.", backup.display());
+        } else {
+            std::fs::copy(path, &backup)
+                .map_err(|e| IoError::new(backup, e, line!(), file!().into()))?;
+        }
+        if !rejected.is_empty() {
+            let reject = path.with_extension("log.rejected");
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&reject)
+                .map_err(|e| IoError::new(reject.clone(), e, line!(), file!().into()))?;
+            std::io::Write::write_all(&mut file, rejected.concat().as_bytes())
+                .map_err(|e| IoError::new(reject.clone(), e, line!(), file!().into()))?;
+            tracing::warn!("{} lines moved to {}.", rejected.len(), reject.display());
+        }
+        let contents = lines.concat();
+        std::fs::write(path, contents)
+            .map_err(|e| IoError::new(path.into(), e, line!(), file!().into()))?;
+        tracing::info!("{count} events migrated in {}.", path.display());
+        Ok(count)
+    }
+
+    /// The `migrate_all` method calls [`Self::migrate`] on each history log in the `history`
+    /// folder of the `BEA_DATA` directory.  Returns the number of events rewritten.
+    #[tracing::instrument]
+    pub fn migrate_all() -> Result<usize, BeaErr> {
+        let path = bea_data()?.join("history");
+        let entries = std::fs::read_dir(&path)
+            .map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+        let mut count = 0;
+        for entry in entries {
+            let entry =
+                entry.map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+            let log = entry.path();
+            if log.extension().is_some_and(|ext| ext == "log") {
+                count += Self::migrate(&log)?;
+            }
+        }
+        Ok(count)
+    }
+
     /// The `is_success` method only returns a bool indicating whether the operation at the
     /// destination of `app` was successful if the event is present in `self`, otherwise returning
     /// `None`.
@@ -58,7 +142,7 @@ impl History {
     /// destination of `app` was a failure if the event is present in `self`, otherwise returning
     /// `None`.
     ///
-    /// Called by [`Queue::errors`] and [`Queue::active_subset`].
+    /// Called by [`Queue::errors`].
     #[tracing::instrument(skip_all)]
     pub fn is_error(&self, app: &App) -> Result<Option<bool>, BeaErr> {
         // get the path destination associated with app
@@ -67,7 +151,7 @@ impl History {
             // path is present in event history
             match event.status() {
                 // event was a failure, return true
-                ResultStatus::Error(_) => Ok(Some(true)),
+                ResultStatus::Error(_, _) => Ok(Some(true)),
                 // event was not a failure, return false
                 _ => Ok(Some(false)),
             }
@@ -102,15 +186,40 @@ impl History {
                         total_size += size;
                     }
                 }
-                ResultStatus::Error(_) => error += 1,
+                ResultStatus::Error(_, _) => error += 1,
                 _ => {}
             }
         }
         tracing::info!("Successes: {success}");
         tracing::info!("Errors: {error}");
+        for (reason, paths) in self.errors_by_reason() {
+            tracing::info!("  {reason}: {}", paths.len());
+        }
         tracing::info!("Total Size: {}", bytesize::ByteSize::b(total_size));
     }
 
+    /// The `errors_by_reason` method groups the paths of events with an error status by the
+    /// [`ErrorDetail::reason`](crate::ErrorDetail::reason) of the error.  Errors logged before
+    /// their details were recorded are grouped under "Unrecorded".
+    #[tracing::instrument(skip_all)]
+    pub fn errors_by_reason(&self) -> std::collections::BTreeMap<String, Vec<&std::path::Path>> {
+        let mut reasons = std::collections::BTreeMap::new();
+        self.values()
+            .filter(|event| matches!(event.status(), ResultStatus::Error(_, _)))
+            .map(|event| {
+                let reason = match event.status().detail() {
+                    Some(detail) => detail.reason(),
+                    None => "Unrecorded".to_string(),
+                };
+                reasons
+                    .entry(reason)
+                    .or_insert_with(Vec::new)
+                    .push(event.path().as_path())
+            })
+            .for_each(drop);
+        reasons
+    }
+
     /// Returns the number of buckets required to sort the `Queue` into sets of 100 requests, the
     /// maximum number of requests per minute allowed by the BEA API server.
     #[tracing::instrument(skip_all)]
@@ -186,11 +295,8 @@ impl TryFrom<(Dataset, Mode)> for History {
     type Error = BeaErr;
 
     fn try_from(ctx: (Dataset, Mode)) -> Result<Self, Self::Error> {
-        dotenvy::dotenv().ok();
         let (dataset, mode) = ctx;
-        let path = bea_data()?;
-        let path = path.join("history");
-        let path = path.join(format!("history_{dataset}_{mode}.log"));
+        let path = Self::path(dataset, mode)?;
         Self::try_from(&path)
     }
 }
@@ -0,0 +1,197 @@
+use crate::vintage::read_response;
+use crate::{History, Mode, ResultStatus, bea_data};
+use bears_species::{BeaErr, Dataset, IoError, Query, SerdeJson, SeriesKey, SeriesSet};
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::collections::BTreeMap;
+use strum::IntoEnumIterator;
+
+/// The `IndexedFile` struct records the series contained in a downloaded file.
+///
+/// * **dataset** - The dataset of the file.
+/// * **time** - Time of the download event that produced the file, used to detect files that
+///   have been downloaded again since they were indexed.
+/// * **keys** - The series contained in the file.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct IndexedFile {
+    dataset: Dataset,
+    time: jiff::Timestamp,
+    keys: Vec<SeriesKey>,
+}
+
+/// The `Index` struct maps each file downloaded to the `BEA_DATA` directory to the series it
+/// contains, so that a [`Query`] opens only the files holding matching series.  The index is
+/// stored as `index.json` in the `history` folder of the `BEA_DATA` directory, alongside the
+/// event history it is built from.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::From,
+)]
+#[from(BTreeMap<std::path::PathBuf, IndexedFile>)]
+pub struct Index(BTreeMap<std::path::PathBuf, IndexedFile>);
+
+impl Index {
+    /// Name of the index file within the history folder.
+    pub const FILE: &str = "index.json";
+
+    /// The `path` method returns the location of the index in the `BEA_DATA` directory.
+    pub fn path() -> Result<std::path::PathBuf, BeaErr> {
+        Ok(bea_data()?.join("history").join(Self::FILE))
+    }
+
+    /// The `from_env` method reads the stored index, building and saving a new index if none has
+    /// been stored.  Files downloaded since the index was stored are added by [`Self::update`].
+    #[tracing::instrument]
+    pub fn from_env() -> Result<Self, BeaErr> {
+        let path = Self::path()?;
+        let mut index = if path.exists() {
+            Self::try_from(&path)?
+        } else {
+            Self::default()
+        };
+        if index.update()? > 0 {
+            index.save()?;
+        }
+        Ok(index)
+    }
+
+    /// The `update` method indexes each file with a successful download event in the history of
+    /// any dataset that is not yet in the index or has been downloaded again since it was indexed,
+    /// and removes files no longer in the history.  Files that cannot be read are logged and left
+    /// out, so the next update tries them again.  Returns the number of files indexed.
+    #[tracing::instrument(skip_all)]
+    pub fn update(&mut self) -> Result<usize, BeaErr> {
+        let mut current = BTreeMap::new();
+        for dataset in Dataset::iter() {
+            if !History::path(dataset, Mode::Download)?.exists() {
+                continue;
+            }
+            History::try_from((dataset, Mode::Download))?
+                .values()
+                .filter(|event| matches!(event.status(), ResultStatus::Success(_, _)))
+                .map(|event| current.insert(event.path().to_owned(), (dataset, *event.time())))
+                .for_each(drop);
+        }
+        self.retain(|path, _| current.contains_key(path));
+        let stale = current
+            .into_iter()
+            .filter(|(path, (_, time))| self.get(path).is_none_or(|file| file.time < *time))
+            .collect::<Vec<(std::path::PathBuf, (Dataset, jiff::Timestamp))>>();
+        let indexed = stale
+            .into_par_iter()
+            .filter_map(|(path, (dataset, time))| {
+                let response = match read_response(&path) {
+                    Ok(response) => response,
+                    Err(source) => {
+                        tracing::error!("Skipping {}: {source}", path.display());
+                        return None;
+                    }
+                };
+                let keys = match response.data() {
+                    Some(data) => data.keys(),
+                    None => Vec::new(),
+                };
+                Some((path, IndexedFile::new(dataset, time, keys)))
+            })
+            .collect::<Vec<(std::path::PathBuf, IndexedFile)>>();
+        let count = indexed.len();
+        self.extend(indexed);
+        tracing::info!("{count} files indexed, {} in index.", self.len());
+        Ok(count)
+    }
+
+    /// The `save` method writes the index to the history folder of the `BEA_DATA` directory.
+    #[tracing::instrument(skip_all)]
+    pub fn save(&self) -> Result<(), BeaErr> {
+        let path = Self::path()?;
+        let contents = serde_json::to_vec(self)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+        std::fs::write(&path, contents)
+            .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+        Ok(())
+    }
+
+    /// The `search` method returns the series matching `query`, keyed by the file containing
+    /// them.  Files without a matching series are omitted.
+    pub fn search(&self, query: &Query) -> BTreeMap<&std::path::Path, Vec<&SeriesKey>> {
+        self.iter()
+            .map(|(path, file)| {
+                let keys = file
+                    .keys
+                    .iter()
+                    .filter(|key| query.matches(file.dataset, key))
+                    .collect::<Vec<&SeriesKey>>();
+                (path.as_path(), keys)
+            })
+            .filter(|(_, keys)| !keys.is_empty())
+            .collect()
+    }
+
+    /// The `query` method loads the series matching `query`, opening only the files that contain
+    /// them, and drops observations outside the time range of the query.  Series with the same
+    /// identifier and frequency in different files are merged.
+    #[tracing::instrument(skip(self))]
+    pub fn query(&self, query: &Query) -> Result<SeriesSet, BeaErr> {
+        let mut set = SeriesSet::default();
+        let matches = self.search(query);
+        tracing::info!("Opening {} files.", matches.len());
+        for (path, keys) in matches {
+            let Some(data) = read_response(path)?.data() else {
+                continue;
+            };
+            let series = data.series();
+            for key in keys {
+                let Some(found) = series.series(key.id(), *key.frequency()) else {
+                    continue;
+                };
+                found
+                    .observations()
+                    .iter()
+                    .filter(|(period, _)| query.contains(*period.date()))
+                    .map(|(period, quantity)| {
+                        set.observe(
+                            found.id().to_owned(),
+                            found.description(),
+                            found.unit(),
+                            *found.unit_mult(),
+                            *period,
+                            *quantity,
+                        )
+                    })
+                    .for_each(drop);
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl TryFrom<&std::path::PathBuf> for Index {
+    type Error = BeaErr;
+
+    fn try_from(value: &std::path::PathBuf) -> Result<Self, Self::Error> {
+        let file = std::fs::File::open(value)
+            .map_err(|e| IoError::new(value.clone(), e, line!(), file!().into()))?;
+        let rdr = std::io::BufReader::new(file);
+        serde_json::from_reader(rdr)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()).into())
+    }
+}
@@ -1,20 +1,45 @@
 mod app;
+mod attempt;
 mod config;
+mod estimate;
 mod free;
 mod history;
+mod index;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod progress;
 mod queue;
+mod refresh;
 mod request;
+mod retry;
+mod status;
+mod telemetry;
 mod tracker;
+mod vintage;
 
-pub use app::{App, ResultStatus};
+pub use app::{App, ErrorDetail, ErrorKind, ResultStatus};
+pub use attempt::{AttemptHistory, Attempts, SkipPolicy};
 pub use config::{Options, ParameterKind};
+pub use estimate::{EstimateBasis, SizeEstimate, SizeModel};
 pub use free::{bea_data, file_size, init, trace_init};
 pub use history::{Chunk, Chunks, History};
+pub use index::{Index, IndexedFile};
+#[cfg(feature = "metrics")]
+pub use metrics::{DatasetLabels, ErrorLabels, Metrics, ModeLabels, TargetLabels, serve_metrics};
 pub use progress::Style;
 pub use queue::{Mode, Overwrite, Queue, Scope};
+pub use refresh::{
+    FILTERED_DATASETS, Manifest, ManifestEntry, RefreshOptions, RefreshStep, RefreshSummary,
+    RefreshTarget, refresh_values,
+};
 pub use request::{
     Request, download_with_history, get_datasets, init_queue, initial_download, initial_load,
     parameter_values, parameters, retry_load, values, values_gdp, values_subset, values_ugdp,
 };
-pub use tracker::{Event, SizeEvent, Tracker};
+pub use retry::{RetryFilter, RetryPolicy, RetrySummary, retry_download};
+pub use status::{DatasetStatus, StatusReport};
+pub use telemetry::{
+    BoxedLayer, ConsoleFormat, DOWNLOAD_TARGET, HistorySink, LOAD_TARGET, TelemetryConfig,
+};
+pub use tracker::{EVENT_SCHEMA, Event, SizeEvent, Tracker};
+pub use vintage::{Archive, Vintage, Vintages, load_as_of, read_response, vintages};
@@ -0,0 +1,268 @@
+use crate::{Mode, ResultStatus};
+use bears_species::{BeaErr, Check, Dataset, IoError};
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::registry::Registry;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// Content type of the OpenMetrics text format.
+const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Labels of metrics counted per dataset and mode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub struct TargetLabels {
+    dataset: String,
+    mode: String,
+}
+
+/// Labels of errors, counted per dataset, mode and [`ErrorKind`](crate::ErrorKind).  Requests
+/// refused by the BEA rate limit have the kind `RequestsExceeded`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub struct ErrorLabels {
+    dataset: String,
+    mode: String,
+    kind: String,
+}
+
+/// Labels of metrics counted per mode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub struct ModeLabels {
+    mode: String,
+}
+
+/// Labels of metrics counted per dataset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub struct DatasetLabels {
+    dataset: String,
+}
+
+/// The `Metrics` struct holds the counters and gauges of the downloader and loader, exported in
+/// the OpenMetrics text format with the `bears_` prefix.  Available with the `metrics` feature.
+/// The library records to the instance returned by [`Metrics::global`], which
+/// [`serve_metrics`] exposes on a local `/metrics` endpoint.
+///
+/// * **requests** - Requests and file loads completed, per dataset and mode.
+/// * **bytes** - Bytes downloaded or loaded, per dataset and mode.
+/// * **errors** - Failed requests and loads, per dataset, mode and error kind.
+/// * **rate_limit_waits** - Waits of the downloader to stay within the BEA rate limit, counted
+///   once per wait however many pauses it takes.
+/// * **rate_limit_wait_seconds** - Time spent in those waits.
+/// * **queue_depth** - Members of the running queue not yet dispatched or skipped, per mode.
+/// * **in_flight** - Dispatched requests and loads awaiting a status, per mode.
+/// * **load_parse_failures** - Files that could not be loaded as data, per dataset.
+#[derive(Debug)]
+pub struct Metrics {
+    registry: Registry,
+    requests: Family<TargetLabels, Counter>,
+    bytes: Family<TargetLabels, Counter>,
+    errors: Family<ErrorLabels, Counter>,
+    rate_limit_waits: Counter,
+    rate_limit_wait_seconds: Counter<f64, std::sync::atomic::AtomicU64>,
+    queue_depth: Family<ModeLabels, Gauge>,
+    in_flight: Family<ModeLabels, Gauge>,
+    load_parse_failures: Family<DatasetLabels, Counter>,
+}
+
+impl Metrics {
+    /// The `new` method creates a set of metrics registered with a new registry.  Use
+    /// [`Metrics::global`] for the instance the library records to.
+    pub fn new() -> Self {
+        let mut registry = Registry::with_prefix("bears");
+        let requests = Family::<TargetLabels, Counter>::default();
+        let bytes = Family::<TargetLabels, Counter>::default();
+        let errors = Family::<ErrorLabels, Counter>::default();
+        let rate_limit_waits = Counter::default();
+        let rate_limit_wait_seconds = Counter::<f64, std::sync::atomic::AtomicU64>::default();
+        let queue_depth = Family::<ModeLabels, Gauge>::default();
+        let in_flight = Family::<ModeLabels, Gauge>::default();
+        let load_parse_failures = Family::<DatasetLabels, Counter>::default();
+        let help = "Requests and file loads completed";
+        registry.register("requests", help, requests.clone());
+        registry.register("bytes", "Bytes downloaded or loaded", bytes.clone());
+        let help = "Failed requests and loads by error kind";
+        registry.register("errors", help, errors.clone());
+        let help = "Waits to stay within the BEA rate limit";
+        registry.register("rate_limit_waits", help, rate_limit_waits.clone());
+        let help = "Time spent pausing for the BEA rate limit";
+        registry.register(
+            "rate_limit_wait_seconds",
+            help,
+            rate_limit_wait_seconds.clone(),
+        );
+        let help = "Queue members not yet dispatched or skipped";
+        registry.register("queue_depth", help, queue_depth.clone());
+        let help = "Dispatched requests and loads awaiting a status";
+        registry.register("in_flight", help, in_flight.clone());
+        let help = "Files that could not be loaded as data";
+        registry.register("load_parse_failures", help, load_parse_failures.clone());
+        Self {
+            registry,
+            requests,
+            bytes,
+            errors,
+            rate_limit_waits,
+            rate_limit_wait_seconds,
+            queue_depth,
+            in_flight,
+            load_parse_failures,
+        }
+    }
+
+    /// The `global` method returns the metrics the library records to, created on first use.
+    pub fn global() -> &'static Self {
+        static METRICS: std::sync::OnceLock<Metrics> = std::sync::OnceLock::new();
+        METRICS.get_or_init(Self::new)
+    }
+
+    /// The `record` method counts the outcome `status` of a request or load in `dataset`.
+    /// Statuses other than success, error and abort are ignored.
+    pub fn record(&self, dataset: Option<Dataset>, mode: Mode, status: &ResultStatus) {
+        let dataset = dataset.map(|d| d.to_string()).unwrap_or_default();
+        let target = TargetLabels {
+            dataset: dataset.clone(),
+            mode: mode.to_string(),
+        };
+        let kind = match status {
+            ResultStatus::Success(_, size) => {
+                self.requests.get_or_create(&target).inc();
+                self.bytes.get_or_create(&target).inc_by(*size);
+                return;
+            }
+            ResultStatus::Error(_, detail) => match detail {
+                Some(detail) => detail.kind().to_string(),
+                None => "Unknown".to_string(),
+            },
+            ResultStatus::Abort => "RequestsExceeded".to_string(),
+            ResultStatus::Pass(_) | ResultStatus::Pending => return,
+        };
+        self.requests.get_or_create(&target).inc();
+        if mode == Mode::Load && kind == crate::ErrorKind::Parse.to_string() {
+            let labels = DatasetLabels {
+                dataset: dataset.clone(),
+            };
+            self.load_parse_failures.get_or_create(&labels).inc();
+        }
+        let labels = ErrorLabels {
+            dataset,
+            mode: mode.to_string(),
+            kind,
+        };
+        self.errors.get_or_create(&labels).inc();
+    }
+
+    /// The `rate_limit_wait` method counts a wait of the downloader to stay within the BEA rate
+    /// limit.  A wait may span several pauses, each added by [`Metrics::rate_limit_pause`].
+    pub fn rate_limit_wait(&self) {
+        self.rate_limit_waits.inc();
+    }
+
+    /// The `rate_limit_pause` method adds a pause of length `pause` to the time spent waiting for
+    /// the BEA rate limit.
+    pub fn rate_limit_pause(&self, pause: std::time::Duration) {
+        self.rate_limit_wait_seconds.inc_by(pause.as_secs_f64());
+    }
+
+    /// The `queue_depth` method sets the number of queue members waiting in `mode`.
+    pub fn queue_depth(&self, mode: Mode, depth: usize) {
+        let labels = ModeLabels {
+            mode: mode.to_string(),
+        };
+        let depth = i64::try_from(depth).unwrap_or(i64::MAX);
+        self.queue_depth.get_or_create(&labels).set(depth);
+    }
+
+    /// The `dequeued` method counts one less queue member waiting in `mode`, whether it is
+    /// dispatched or skipped.
+    pub fn dequeued(&self, mode: Mode) {
+        let labels = ModeLabels {
+            mode: mode.to_string(),
+        };
+        self.queue_depth.get_or_create(&labels).dec();
+    }
+
+    /// The `dispatched` method counts a request or load in `mode` as in flight.
+    pub fn dispatched(&self, mode: Mode) {
+        let labels = ModeLabels {
+            mode: mode.to_string(),
+        };
+        self.in_flight.get_or_create(&labels).inc();
+    }
+
+    /// The `received` method counts a request or load in `mode` as no longer in flight.
+    pub fn received(&self, mode: Mode) {
+        let labels = ModeLabels {
+            mode: mode.to_string(),
+        };
+        self.in_flight.get_or_create(&labels).dec();
+    }
+
+    /// The `encode` method renders the metrics in the OpenMetrics text format.
+    pub fn encode(&self) -> Result<String, BeaErr> {
+        let mut contents = String::new();
+        prometheus_client::encoding::text::encode(&mut contents, &self.registry)
+            .map_err(|e| Check::new(format!("Could not encode metrics: {e}")))?;
+        Ok(contents)
+    }
+
+    /// The `serve` method answers requests for `/metrics` on `listener` with the encoded metrics,
+    /// and any other path with a 404 status.  Runs until the task is dropped.
+    #[tracing::instrument(skip_all)]
+    pub async fn serve(&'static self, listener: tokio::net::TcpListener) -> Result<(), BeaErr> {
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    tracing::warn!("Metrics connection failed: {e}");
+                    continue;
+                }
+            };
+            tokio::spawn(async move {
+                if let Err(e) = self.respond(stream).await {
+                    tracing::warn!("Metrics request from {peer} failed: {e}");
+                }
+            });
+        }
+    }
+
+    /// Reads the request line from `stream` and writes the response.
+    async fn respond(&self, mut stream: tokio::net::TcpStream) -> Result<(), std::io::Error> {
+        let mut buffer = [0; 1024];
+        let read = stream.read(&mut buffer).await?;
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        let target = request.lines().next().unwrap_or_default();
+        let (status, content_type, body) = if target.starts_with("GET /metrics ") {
+            match self.encode() {
+                Ok(body) => ("200 OK", CONTENT_TYPE, body),
+                Err(e) => ("500 Internal Server Error", "text/plain", e.to_string()),
+            }
+        } else {
+            ("404 Not Found", "text/plain", "Not found.".to_string())
+        };
+        let length = body.len();
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {length}\r\n\
+             Connection: close\r\n\r\n{body}"
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The `serve_metrics` function serves the [`Metrics::global`] metrics at `/metrics` on `addr`,
+/// such as `127.0.0.1:9187`.  Available with the `metrics` feature.
+#[tracing::instrument]
+pub async fn serve_metrics(addr: std::net::SocketAddr) -> Result<(), BeaErr> {
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| IoError::new(addr.to_string().into(), e, line!(), file!().into()))?;
+    tracing::info!("Serving metrics at http://{addr}/metrics.");
+    Metrics::global().serve(listener).await
+}
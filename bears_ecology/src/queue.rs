@@ -1,4 +1,7 @@
-use crate::{App, Event, History, ResultStatus, SizeEvent, Tracker, file_size};
+use crate::{
+    App, Archive, AttemptHistory, ErrorDetail, ErrorKind, Event, History, ResultStatus, SizeEvent,
+    SizeModel, SkipPolicy, Tracker, file_size,
+};
 use bears_species::{BeaErr, BeaErrorKind, Data};
 use indicatif::ProgressIterator;
 use rand::SeedableRng;
@@ -59,15 +62,24 @@ impl Queue {
         Ok(())
     }
 
+    /// Subset of queue that the default [`SkipPolicy`] does not skip, given the attempts in the
+    /// history logs.  Targets that the BEA server rejected repeatedly with the same API error stay
+    /// out until a later download succeeds.
     #[tracing::instrument(skip_all)]
     pub fn active_subset(&mut self, scope: Scope) -> Result<(), BeaErr> {
-        let history = History::from_env()?;
-        history.summary();
-        self.retain(|app| match history.is_error(app) {
-            Ok(opt) => match opt {
-                Some(val) => !val,
-                None => scope.default_scope(),
-            },
+        self.active_subset_with(scope, &SkipPolicy::default())
+    }
+
+    /// Subset of queue that `policy` does not skip, given the download attempts in the history
+    /// logs.  Members without attempts are kept according to `scope`.
+    #[tracing::instrument(skip_all)]
+    pub fn active_subset_with(&mut self, scope: Scope, policy: &SkipPolicy) -> Result<(), BeaErr> {
+        let history = AttemptHistory::from_env()?.with_mode(Mode::Download);
+        tracing::info!("Attempts recorded for {} targets.", history.len());
+        let now = jiff::Timestamp::now();
+        self.retain(|app| match history.attempts(app) {
+            Ok(Some(attempts)) => !policy.skip(attempts, now),
+            Ok(None) => scope.default_scope(),
             Err(source) => {
                 tracing::error!("{source}");
                 false
@@ -76,6 +88,38 @@ impl Queue {
         Ok(())
     }
 
+    /// Sets the `size_hint` of members without one to the bytes reserved by the [`SizeModel`]
+    /// estimate of their payload, so that the rate limiter meters requests that have not been
+    /// downloaded before.  Returns the number of members estimated.
+    #[tracing::instrument(skip_all)]
+    pub fn with_size_estimates(&mut self, model: &SizeModel) -> usize {
+        let mut count = 0;
+        self.iter_mut()
+            .filter(|app| app.size_hint().is_none())
+            .map(|app| {
+                if let Some(estimate) = model.estimate(app) {
+                    tracing::trace!("Estimated size: {estimate:?}");
+                    app.with_size_hint(Some(estimate.limit()));
+                    count += 1;
+                }
+            })
+            .for_each(drop);
+        tracing::info!("Sizes estimated for {count} requests.");
+        count
+    }
+
+    /// Sets the `archive` mode of every member, so that [`App::save`] keeps a snapshot of each
+    /// download in the vintage archive.
+    #[tracing::instrument(skip_all)]
+    pub fn with_archive(&mut self, archive: Archive) {
+        self.iter_mut()
+            .map(|app| {
+                app.with_archive(Some(archive));
+            })
+            .for_each(drop);
+        tracing::info!("Archiving downloads as {archive} vintages.");
+    }
+
     /// Filters the `Queue` for members with a destination path matching the [`Event`] path in
     /// 'event'.
     #[tracing::instrument(skip_all)]
@@ -198,11 +242,15 @@ impl Queue {
         mode: Mode,
     ) -> Result<(), BeaErr> {
         while let Some(status) = rx.recv().await {
+            #[cfg(feature = "metrics")]
+            if status != ResultStatus::Pending {
+                crate::Metrics::global().received(mode);
+            }
             match status {
-                ResultStatus::Success(_, _) | ResultStatus::Error(_) => {
+                ResultStatus::Success(_, _) | ResultStatus::Error(_, _) => {
+                    tracing::trace!("Update: {status}.");
                     let mut tracker = tracker.lock().await;
                     tracker.update_status(status, mode);
-                    tracing::trace!("Update: {status}.");
                 }
                 ResultStatus::Pass(_) | ResultStatus::Pending => {}
                 ResultStatus::Abort => {
@@ -235,7 +283,11 @@ impl Queue {
         overwrite: Overwrite,
     ) -> Result<Vec<tokio::task::JoinHandle<()>>, BeaErr> {
         let mut futures = Vec::new();
+        #[cfg(feature = "metrics")]
+        crate::Metrics::global().queue_depth(Mode::Download, self.len());
         for app in self.iter() {
+            #[cfg(feature = "metrics")]
+            crate::Metrics::global().dequeued(Mode::Download);
             let app = app.clone();
             let tx = tx.clone();
             let path = app.destination(false)?;
@@ -254,6 +306,10 @@ impl Queue {
                     slack = tracker.check_slack();
                     size_available = tracker.size_available();
                 }
+                #[cfg(feature = "metrics")]
+                if slack == 0 || (size_available <= next_size && next_size < 100_000_000) {
+                    crate::Metrics::global().rate_limit_wait();
+                }
                 while slack == 0 || (size_available <= next_size && next_size < 100_000_000) {
                     tracing::trace!("Limiting call rate.");
                     {
@@ -278,6 +334,8 @@ impl Queue {
                     tracker.calls.push(event);
                 }
 
+                #[cfg(feature = "metrics")]
+                crate::Metrics::global().dispatched(Mode::Download);
                 let fut = tokio::spawn(async move {
                     let mut result = ResultStatus::Pass(id);
                     tracing::trace!("Calling download for {path:#?}");
@@ -296,6 +354,8 @@ impl Queue {
                 futures.push(fut);
             }
         }
+        #[cfg(feature = "metrics")]
+        crate::Metrics::global().queue_depth(Mode::Download, 0);
         Ok(futures)
     }
 
@@ -311,7 +371,11 @@ impl Queue {
         )
         .unwrap();
         let mut handles = Vec::new();
+        #[cfg(feature = "metrics")]
+        crate::Metrics::global().queue_depth(Mode::Load, self.len());
         for app in self.iter().progress_with_style(style) {
+            #[cfg(feature = "metrics")]
+            crate::Metrics::global().dequeued(Mode::Load);
             let path = app.destination(false)?;
             if path.exists() {
                 let event = Event::new(&path, Mode::Load);
@@ -321,6 +385,8 @@ impl Queue {
                     let mut tracker = tracker.lock().await;
                     tracker.calls.push(event);
                 }
+                #[cfg(feature = "metrics")]
+                crate::Metrics::global().dispatched(Mode::Load);
                 // Clone arc references to pass to spawn
                 let data = data.clone();
                 let tx = tx.clone();
@@ -341,14 +407,21 @@ impl Queue {
                                 status = ResultStatus::Success(id, size);
                             } else {
                                 tracing::error!("Load failure: Not Data type.");
-                                status = ResultStatus::Error(id);
+                                let message = "Not Data type".to_string();
+                                let detail =
+                                    ErrorDetail::new(ErrorKind::Parse, None, message, None);
+                                status = ResultStatus::Error(id, Some(detail));
                             }
                         }
                         Err(source) => {
                             tracing::error!("{source}");
-                            status = ResultStatus::Error(id);
+                            let message = source.to_string();
+                            let detail = ErrorDetail::new(ErrorKind::Parse, None, message, None);
+                            status = ResultStatus::Error(id, Some(detail));
                         }
                     }
+                    #[cfg(feature = "metrics")]
+                    crate::Metrics::global().record(app.dataset().ok(), Mode::Load, &status);
                     match tx.send(status).await {
                         Ok(_) => {}
                         Err(source) => {
@@ -359,6 +432,8 @@ impl Queue {
                 handles.push(handle);
             }
         }
+        #[cfg(feature = "metrics")]
+        crate::Metrics::global().queue_depth(Mode::Load, 0);
         Ok(handles)
     }
 
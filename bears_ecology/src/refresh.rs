@@ -0,0 +1,417 @@
+use crate::{App, Request, bea_data};
+use bears_species::{
+    BeaErr, BeaResponse, Dataset, GdpByIndustry, IoError, ParameterName, RateLimit, ReqwestError,
+    Results, SerdeJson, UnderlyingGdpByIndustry,
+};
+use std::collections::BTreeMap;
+use strum::IntoEnumIterator;
+
+/// Datasets where the BEA has implemented a `GetParameterValuesFiltered` response for each
+/// parameter name associated with the dataset.
+pub const FILTERED_DATASETS: [Dataset; 7] = [
+    Dataset::APIDatasetMetadata,
+    Dataset::Iip,
+    Dataset::Ita,
+    Dataset::InputOutput,
+    Dataset::IntlServSTA,
+    Dataset::IntlServTrade,
+    Dataset::Regional,
+];
+
+/// Datasets where the valid values of some parameters vary by table id.
+const TABLE_DATASETS: [Dataset; 2] = [Dataset::GDPbyIndustry, Dataset::UnderlyingGDPbyIndustry];
+
+/// Parameters with valid values that vary by table id in [`TABLE_DATASETS`].
+const TABLE_PARAMETERS: [ParameterName; 2] = [ParameterName::Industry, ParameterName::Year];
+
+/// The `RefreshStep` enum lists the steps that populate the parameter value cache, in the order
+/// they run.  Later steps read the files written by earlier ones.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum RefreshStep {
+    /// The list of datasets, saved as `datasets.json`.
+    Datasets,
+    /// The parameter names of each dataset, saved to the `parameters` folder.
+    Parameters,
+    /// The values of each parameter from `GetParameterValues`.
+    ParameterValues,
+    /// The values of each parameter from `GetParameterValuesFiltered`, for the datasets in
+    /// [`FILTERED_DATASETS`].
+    Values,
+    /// The values of the GDP by industry parameters for each table id.
+    TableValues,
+}
+
+impl RefreshStep {
+    /// The `targets` method returns the requests of the step, reading table ids from the
+    /// parameter values under `bea_data` for the [`RefreshStep::TableValues`] step.
+    #[tracing::instrument(skip(bea_data))]
+    pub fn targets(&self, bea_data: &std::path::Path) -> Result<Vec<RefreshTarget>, BeaErr> {
+        let step = *self;
+        let mut targets = Vec::new();
+        match self {
+            Self::Datasets => {
+                let app = Request::Dataset.init()?;
+                targets.push(RefreshTarget::new(step, "datasets.json".into(), app));
+            }
+            Self::Parameters => {
+                let app = Request::Parameter.init()?;
+                for dataset in Dataset::iter() {
+                    let mut app = app.clone();
+                    app.with_dataset(dataset);
+                    let path = format!("parameters/{dataset}_parameters.json");
+                    targets.push(RefreshTarget::new(step, path.into(), app));
+                }
+            }
+            Self::ParameterValues => {
+                let app = Request::ParameterValue.init()?;
+                for dataset in Dataset::iter() {
+                    for name in dataset.names() {
+                        let mut app = app.clone();
+                        let mut options = app.options().clone();
+                        let _ = options.with_dataset(dataset).with_param_name(name);
+                        app.with_options(options);
+                        let path =
+                            format!("parameter_values/{dataset}_{name}_parameter_values.json");
+                        targets.push(RefreshTarget::new(step, path.into(), app));
+                    }
+                }
+            }
+            Self::Values => {
+                let app = Request::ParameterValueFilter.init()?;
+                for dataset in FILTERED_DATASETS {
+                    for name in dataset.names() {
+                        let mut app = app.clone();
+                        let mut options = app.options().clone();
+                        let _ = options.with_dataset(dataset).with_target(name);
+                        app.with_options(options);
+                        let path = format!("parameter_values/{dataset}_{name}_values.json");
+                        targets.push(RefreshTarget::new(step, path.into(), app));
+                    }
+                }
+            }
+            Self::TableValues => {
+                let app = Request::ParameterValueFilter.init()?;
+                for dataset in TABLE_DATASETS {
+                    let table_ids = match dataset {
+                        Dataset::GDPbyIndustry => GdpByIndustry::read_table_id(bea_data)?,
+                        _ => UnderlyingGdpByIndustry::read_table_id(bea_data)?,
+                    };
+                    for name in TABLE_PARAMETERS {
+                        for id in &table_ids {
+                            let mut app = app.clone();
+                            let mut options = app.options().clone();
+                            let _ = options
+                                .with_dataset(dataset)
+                                .with_target(name)
+                                .with_table_id(*id.value());
+                            app.with_options(options);
+                            let folder = format!("parameter_values/{dataset}_{name}");
+                            let file =
+                                format!("{dataset}_{name}_byTableId_{}_values.json", id.value());
+                            let path = std::path::PathBuf::from(folder).join(file);
+                            targets.push(RefreshTarget::new(step, path, app));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(targets)
+    }
+}
+
+/// The `RefreshTarget` struct is a single request of a [`RefreshStep`].
+///
+/// * **step** - The step the request belongs to.
+/// * **path** - Location of the response, relative to the `BEA_DATA` directory.
+/// * **app** - The configured request.
+#[derive(Debug, Clone, PartialEq, Eq, derive_getters::Getters, derive_new::new)]
+pub struct RefreshTarget {
+    step: RefreshStep,
+    path: std::path::PathBuf,
+    app: App,
+}
+
+impl RefreshTarget {
+    /// The `fetch` method sends the request and writes the response under `bea_data`, returning
+    /// the [`ManifestEntry`] of the request.  Responses the BEA rejects with an error are recorded
+    /// in the entry and not written.  Returns an error if the BEA reports that the rate limit has
+    /// been exceeded.
+    #[tracing::instrument(skip(self, bea_data), fields(path = %self.path.display()))]
+    pub async fn fetch(&self, bea_data: &std::path::Path) -> Result<ManifestEntry, BeaErr> {
+        let response = self.app.get().await?;
+        let json = response
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|source| {
+                let url = self.app.url().to_string();
+                let body = self
+                    .app
+                    .params()
+                    .into_iter()
+                    .collect::<Vec<(String, String)>>();
+                let mut error =
+                    ReqwestError::new(url, "get".to_string(), source, line!(), file!().to_string());
+                let _ = error.with_body(body);
+                error
+            })?;
+        let now = jiff::Timestamp::now();
+        if let Ok(bea) = BeaResponse::try_from(&json) {
+            let error = match bea.results() {
+                Results::ApiError(error) => Some(error.to_string()),
+                Results::MneError(error) => Some(error.to_string()),
+                Results::RequestsExceeded(error) => {
+                    let error = RateLimit::new(error.to_string(), line!(), file!().to_string());
+                    return Err(error.into());
+                }
+                _ => None,
+            };
+            if let Some(error) = error {
+                tracing::warn!("{error}");
+                return Ok(ManifestEntry::new(self.step, now, 0, Some(error)));
+            }
+        }
+        let contents = serde_json::to_vec(&json)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+        let path = bea_data.join(&self.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| IoError::new(parent.into(), e, line!(), file!().into()))?;
+        }
+        std::fs::write(&path, &contents)
+            .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+        Ok(ManifestEntry::new(
+            self.step,
+            now,
+            contents.len() as u64,
+            None,
+        ))
+    }
+}
+
+/// The `ManifestEntry` struct records the last request for a file in the parameter value cache.
+///
+/// * **step** - The [`RefreshStep`] that requested the file.
+/// * **fetched** - Time of the request.
+/// * **bytes** - Size of the file written, or zero if the request was rejected.
+/// * **error** - The error reported by the BEA, if the request was rejected.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct ManifestEntry {
+    step: RefreshStep,
+    fetched: jiff::Timestamp,
+    bytes: u64,
+    error: Option<String>,
+}
+
+/// The `Manifest` struct records when each file of the parameter value cache was fetched, keyed by
+/// the path of the file relative to the `BEA_DATA` directory.  Stored as `manifest.json` in the
+/// `parameter_values` folder, and saved after each request so that an interrupted refresh resumes
+/// where it stopped.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::From,
+)]
+#[from(BTreeMap<std::path::PathBuf, ManifestEntry>)]
+pub struct Manifest(BTreeMap<std::path::PathBuf, ManifestEntry>);
+
+impl Manifest {
+    /// Name of the manifest file within the `parameter_values` folder.
+    pub const FILE: &str = "manifest.json";
+
+    /// The `path` method returns the location of the manifest under `bea_data`.
+    pub fn path(bea_data: &std::path::Path) -> std::path::PathBuf {
+        bea_data.join("parameter_values").join(Self::FILE)
+    }
+
+    /// The `from_env` method reads the manifest in the `BEA_DATA` directory, returning an empty
+    /// manifest if none has been saved.
+    #[tracing::instrument]
+    pub fn from_env() -> Result<Self, BeaErr> {
+        let path = Self::path(&bea_data()?);
+        if path.exists() {
+            Self::try_from(&path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// The `save` method writes the manifest to `path`, creating the parent folder if needed.
+    #[tracing::instrument(skip(self))]
+    pub fn save(&self, path: &std::path::Path) -> Result<(), BeaErr> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| IoError::new(parent.into(), e, line!(), file!().into()))?;
+        }
+        let contents = serde_json::to_vec_pretty(self)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+        std::fs::write(path, contents)
+            .map_err(|e| IoError::new(path.into(), e, line!(), file!().into()))?;
+        Ok(())
+    }
+
+    /// The `is_current` method returns true if the file at `path` under `bea_data` was fetched at
+    /// or after `since`, or at any time if `since` is `None`.  A written file must still be on
+    /// disk, while a rejected request counts as current without a file.
+    pub fn is_current(
+        &self,
+        bea_data: &std::path::Path,
+        path: &std::path::Path,
+        since: Option<jiff::Timestamp>,
+    ) -> bool {
+        self.get(path).is_some_and(|entry| {
+            since.is_none_or(|since| entry.fetched >= since)
+                && (entry.error.is_some() || bea_data.join(path).exists())
+        })
+    }
+}
+
+impl TryFrom<&std::path::PathBuf> for Manifest {
+    type Error = BeaErr;
+
+    fn try_from(value: &std::path::PathBuf) -> Result<Self, Self::Error> {
+        let file = std::fs::File::open(value)
+            .map_err(|e| IoError::new(value.clone(), e, line!(), file!().into()))?;
+        let rdr = std::io::BufReader::new(file);
+        serde_json::from_reader(rdr)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()).into())
+    }
+}
+
+/// The `RefreshOptions` struct configures [`refresh_values`].
+///
+/// * **max_age** - Files fetched longer ago than this are fetched again.  If `None`, only files
+///   missing from the manifest or from disk are fetched.
+/// * **interval** - Minimum time between requests, keeping the refresh within the BEA limit of
+///   100 requests per minute.
+/// * **force** - Fetch every file, ignoring the manifest.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    derive_getters::Getters,
+    derive_setters::Setters,
+    derive_new::new,
+)]
+#[setters(prefix = "with_", borrow_self)]
+pub struct RefreshOptions {
+    max_age: Option<jiff::SignedDuration>,
+    interval: std::time::Duration,
+    force: bool,
+}
+
+impl Default for RefreshOptions {
+    /// Fetches missing files only, one request per second.
+    fn default() -> Self {
+        Self::new(None, std::time::Duration::from_secs(1), false)
+    }
+}
+
+/// The `RefreshSummary` struct counts the outcomes of a call to [`refresh_values`].
+///
+/// * **fetched** - Files written.
+/// * **rejected** - Requests rejected by the BEA.
+/// * **current** - Files skipped because the manifest shows they are current.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct RefreshSummary {
+    fetched: usize,
+    rejected: usize,
+    current: usize,
+}
+
+impl std::fmt::Display for RefreshSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} files fetched, {} rejected, {} current.",
+            self.fetched, self.rejected, self.current
+        )
+    }
+}
+
+/// The `refresh_values` function populates or updates the parameter value cache in the
+/// `BEA_DATA` directory, running each [`RefreshStep`] in order.  Files the [`Manifest`] shows as
+/// current are skipped, so a refresh interrupted by an error or by the BEA rate limit resumes
+/// where it stopped when run again.
+#[tracing::instrument]
+pub async fn refresh_values(options: RefreshOptions) -> Result<RefreshSummary, BeaErr> {
+    let bea_data = bea_data()?;
+    let path = Manifest::path(&bea_data);
+    let mut manifest = Manifest::from_env()?;
+    let since = options.max_age.map(|age| {
+        jiff::Timestamp::now()
+            .checked_sub(age)
+            .unwrap_or(jiff::Timestamp::MIN)
+    });
+    let mut summary = RefreshSummary::default();
+    let mut last = None;
+    for step in RefreshStep::iter() {
+        let targets = step.targets(&bea_data)?;
+        tracing::info!("{step}: {} targets.", targets.len());
+        for target in targets {
+            if !options.force && manifest.is_current(&bea_data, &target.path, since) {
+                summary.current += 1;
+                continue;
+            }
+            if let Some(last) = last {
+                let elapsed = tokio::time::Instant::now() - last;
+                tokio::time::sleep(options.interval.saturating_sub(elapsed)).await;
+            }
+            last = Some(tokio::time::Instant::now());
+            let entry = target.fetch(&bea_data).await?;
+            if entry.error.is_some() {
+                summary.rejected += 1;
+            } else {
+                summary.fetched += 1;
+            }
+            manifest.insert(target.path, entry);
+            manifest.save(&path)?;
+        }
+    }
+    tracing::info!("{summary}");
+    Ok(summary)
+}
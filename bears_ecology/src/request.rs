@@ -1,4 +1,7 @@
-use crate::{App, History, Mode, Options, Overwrite, Queue, Scope, bea_data, init};
+use crate::{
+    App, Archive, FILTERED_DATASETS, History, Mode, Options, Overwrite, Queue, Scope, SizeModel,
+    bea_data, init,
+};
 use bears_species::{
     BeaErr, BeaResponse, Data, Dataset, DatasetMissing, FixedAssets, GdpByIndustry, Iip,
     InputOutput, IoError, Ita, Method, Mne, NiUnderlyingDetail, Nipa, ParameterName, ReqwestError,
@@ -177,11 +180,29 @@ pub async fn get_datasets() -> Result<(), BeaErr> {
     }
 }
 
+/// Downloads every target of `dataset` not already present in the `data` folder.  When
+/// `archive` is set, every target is downloaded again, so that new releases of files already
+/// present reach the archive, and each download is kept as a vintage named according to the
+/// [`Archive`] variant.  Releases unchanged since the last vintage are not archived again.
 #[tracing::instrument(skip_all)]
-pub async fn initial_download(dataset: Dataset) -> Result<(), BeaErr> {
-    let queue = init_queue(dataset)?;
+pub async fn initial_download(dataset: Dataset, archive: Option<Archive>) -> Result<(), BeaErr> {
+    let mut queue = init_queue(dataset)?;
     tracing::info!("Queue length: {}", queue.len());
-    queue.download(Overwrite::No).await?;
+    let overwrite = match archive {
+        Some(archive) => {
+            queue.with_archive(archive);
+            Overwrite::Yes
+        }
+        None => Overwrite::No,
+    };
+    // estimate the sizes of new requests from earlier downloads, if any
+    let path = History::path(dataset, Mode::Download)?;
+    if path.exists() {
+        let history = History::try_from(&path)?;
+        let model = SizeModel::from_history(&history, &queue)?;
+        queue.with_size_estimates(&model);
+    }
+    queue.download(overwrite).await?;
     Ok(())
 }
 
@@ -480,16 +501,7 @@ pub async fn values() -> Result<(), BeaErr> {
 pub async fn values_subset() -> Result<(), BeaErr> {
     let req = Request::ParameterValueFilter;
     let mut app = req.init()?;
-    let datasets = vec![
-        Dataset::APIDatasetMetadata,
-        Dataset::Iip,
-        Dataset::Ita,
-        Dataset::InputOutput,
-        Dataset::IntlServSTA,
-        Dataset::IntlServTrade,
-        Dataset::Regional,
-    ];
-    for dataset in &datasets {
+    for dataset in &FILTERED_DATASETS {
         let names = dataset.names();
         for name in names {
             value(*dataset, &mut app, name).await?;
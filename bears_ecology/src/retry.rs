@@ -0,0 +1,224 @@
+use crate::{AttemptHistory, Attempts, ErrorKind, Mode, Overwrite, ResultStatus, init_queue};
+use bears_species::{BeaErr, Dataset};
+
+/// The `RetryFilter` struct selects the failed targets that [`retry_download`] requests again.
+/// A target matches if its most recent attempt failed, and the failure meets each criterion set.
+///
+/// * **kind** - Retry only failures of this kind.
+/// * **reason** - Retry only failures whose [`ErrorDetail::reason`](crate::ErrorDetail::reason)
+///   contains this text.
+/// * **max_age** - Retry only failures within this duration before the present.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    derive_getters::Getters,
+    derive_setters::Setters,
+    derive_new::new,
+)]
+#[setters(prefix = "with_", borrow_self)]
+pub struct RetryFilter {
+    kind: Option<ErrorKind>,
+    reason: Option<String>,
+    max_age: Option<jiff::SignedDuration>,
+}
+
+impl RetryFilter {
+    /// The `matches` method returns true if the most recent outcome in `attempts` is an error
+    /// meeting the criteria of the filter, with the age of the error measured from `now`.
+    pub fn matches(&self, attempts: &Attempts, now: jiff::Timestamp) -> bool {
+        let Some(event) = attempts.last_outcome() else {
+            return false;
+        };
+        let ResultStatus::Error(_, detail) = event.status() else {
+            return false;
+        };
+        if let Some(age) = self.max_age
+            && *event.time() < now.checked_sub(age).unwrap_or(jiff::Timestamp::MIN)
+        {
+            return false;
+        }
+        if let Some(kind) = self.kind
+            && detail.as_ref().map(|detail| *detail.kind()) != Some(kind)
+        {
+            return false;
+        }
+        if let Some(reason) = &self.reason
+            && !detail
+                .as_ref()
+                .is_some_and(|detail| detail.reason().contains(reason.as_str()))
+        {
+            return false;
+        }
+        true
+    }
+}
+
+/// The `RetryPolicy` struct sets how [`retry_download`] requests failed targets again.
+///
+/// * **rounds** - Maximum number of times to request each target.
+/// * **backoff** - Delay before the second round, doubling before each later round.
+/// * **invalid_after** - Number of identical API errors after which a target is permanently
+///   invalid and no longer requested.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_getters::Getters,
+    derive_setters::Setters,
+    derive_new::new,
+)]
+#[setters(prefix = "with_", borrow_self)]
+pub struct RetryPolicy {
+    rounds: usize,
+    backoff: std::time::Duration,
+    invalid_after: usize,
+}
+
+impl RetryPolicy {
+    /// The `delay` method returns the wait before round `round`, counting from zero.  The first
+    /// round starts immediately, and the wait doubles with each later round.
+    pub fn delay(&self, round: usize) -> std::time::Duration {
+        match round {
+            0 => std::time::Duration::ZERO,
+            round => {
+                let factor = u32::try_from(round - 1)
+                    .ok()
+                    .and_then(|exp| 2u32.checked_pow(exp))
+                    .unwrap_or(u32::MAX);
+                self.backoff.saturating_mul(factor)
+            }
+        }
+    }
+
+    /// The `is_invalid` method returns true if the BEA server has rejected the target with the
+    /// same API error at least `invalid_after` times in a row.  Since the attempts are read from
+    /// the history logs, the mark persists until a later download of the target succeeds.
+    pub fn is_invalid(&self, attempts: &Attempts) -> bool {
+        attempts.identical_errors() >= self.invalid_after
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Requests each target up to three times, waiting one and then two minutes, and gives up on
+    /// targets after three identical API errors.
+    fn default() -> Self {
+        Self::new(3, std::time::Duration::from_secs(60), 3)
+    }
+}
+
+/// The `RetrySummary` struct reports the outcome of a call to [`retry_download`].
+///
+/// * **selected** - Failed targets matching the [`RetryFilter`].
+/// * **recovered** - Selected targets whose latest download succeeded.
+/// * **failing** - Selected targets that still fail.
+/// * **invalid** - Selected targets marked permanently invalid by the [`RetryPolicy`].
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct RetrySummary {
+    selected: usize,
+    recovered: usize,
+    failing: usize,
+    invalid: Vec<std::path::PathBuf>,
+}
+
+impl std::fmt::Display for RetrySummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} targets selected, {} recovered, {} still failing, {} invalid.",
+            self.selected,
+            self.recovered,
+            self.failing,
+            self.invalid.len()
+        )
+    }
+}
+
+/// Reads the download attempts from the history logs.
+fn downloads() -> Result<AttemptHistory, BeaErr> {
+    Ok(AttemptHistory::from_env()?.with_mode(Mode::Download))
+}
+
+/// The `retry_download` function requests the failed targets of `dataset` that match `filter`
+/// again, in up to [`RetryPolicy::rounds`] rounds with exponential backoff between rounds.  Each
+/// round drops the targets that succeeded, and those that [`RetryPolicy::is_invalid`] marks as
+/// permanently invalid, including targets marked before the call.
+#[tracing::instrument(skip_all)]
+pub async fn retry_download(
+    dataset: Dataset,
+    filter: &RetryFilter,
+    policy: &RetryPolicy,
+) -> Result<RetrySummary, BeaErr> {
+    let now = jiff::Timestamp::now();
+    let mut history = downloads()?;
+    let mut queue = init_queue(dataset)?;
+    queue.retain(|app| match history.attempts(app) {
+        Ok(Some(attempts)) => filter.matches(attempts, now),
+        Ok(None) => false,
+        Err(source) => {
+            tracing::error!("{source}");
+            false
+        }
+    });
+    let selected = queue.clone();
+    tracing::info!("Failed targets selected: {}", selected.len());
+
+    for round in 0..policy.rounds {
+        if round > 0 {
+            history = downloads()?;
+        }
+        queue.retain(|app| match history.attempts(app) {
+            Ok(Some(attempts)) => {
+                !policy.is_invalid(attempts)
+                    && attempts
+                        .last_outcome()
+                        .is_some_and(|event| matches!(event.status(), ResultStatus::Error(_, _)))
+            }
+            _ => false,
+        });
+        if queue.is_empty() {
+            break;
+        }
+        let delay = policy.delay(round);
+        if !delay.is_zero() {
+            tracing::info!("Waiting {} seconds before the next round.", delay.as_secs());
+            tokio::time::sleep(delay).await;
+        }
+        tracing::info!("Round {}: retrying {} targets.", round + 1, queue.len());
+        queue.download(Overwrite::Yes).await?;
+    }
+
+    let history = downloads()?;
+    let mut summary = RetrySummary {
+        selected: selected.len(),
+        ..Default::default()
+    };
+    for app in selected.iter() {
+        let Some(attempts) = history.attempts(app)? else {
+            continue;
+        };
+        match attempts.last_outcome().map(|event| event.status()) {
+            Some(ResultStatus::Success(_, _)) => summary.recovered += 1,
+            _ if policy.is_invalid(attempts) => summary.invalid.push(app.destination(false)?),
+            _ => summary.failing += 1,
+        }
+    }
+    tracing::info!("{summary}");
+    Ok(summary)
+}
@@ -0,0 +1,228 @@
+use crate::{History, Mode, ResultStatus, bea_data, init_queue};
+use bears_species::{BeaErr, Dataset, SerdeJson};
+use strum::IntoEnumIterator;
+
+/// The `DatasetStatus` struct describes the files of a dataset in the `BEA_DATA` directory,
+/// compared to the targets of the full request queue and to the download and load histories.
+///
+/// * **dataset** - The dataset described.
+/// * **targets** - Number of targets in the request queue.
+/// * **present** - Targets with a file on disk.
+/// * **missing** - Targets without a file, although the download history does not record a
+///   failure, such as files deleted after download.
+/// * **failing** - Targets without a file whose last download failed.
+/// * **untried** - Targets without a file or a download event.
+/// * **loaded** - Present files whose last load succeeded.
+/// * **load_failing** - Present files whose last load failed.
+/// * **bytes** - Total size of the present files.
+/// * **oldest** - Modification time of the oldest present file.
+/// * **newest** - Modification time of the newest present file.
+/// * **stale** - Present files modified before the staleness cutoff, oldest first.
+#[derive(
+    Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct DatasetStatus {
+    dataset: Dataset,
+    targets: usize,
+    present: usize,
+    missing: usize,
+    failing: usize,
+    untried: usize,
+    loaded: usize,
+    load_failing: usize,
+    bytes: u64,
+    oldest: Option<jiff::Timestamp>,
+    newest: Option<jiff::Timestamp>,
+    stale: Vec<std::path::PathBuf>,
+}
+
+impl DatasetStatus {
+    /// The `from_paths` method tallies the target files at `paths` against the `downloads` and
+    /// `loads` histories, counting files modified before `stale_before` as stale.
+    #[tracing::instrument(skip_all)]
+    pub fn from_paths(
+        dataset: Dataset,
+        paths: &[std::path::PathBuf],
+        downloads: &History,
+        loads: &History,
+        stale_before: jiff::Timestamp,
+    ) -> Self {
+        let mut status = Self {
+            dataset,
+            targets: paths.len(),
+            present: 0,
+            missing: 0,
+            failing: 0,
+            untried: 0,
+            loaded: 0,
+            load_failing: 0,
+            bytes: 0,
+            oldest: None,
+            newest: None,
+            stale: Vec::new(),
+        };
+        let mut stale = Vec::new();
+        for path in paths {
+            let Ok(metadata) = std::fs::metadata(path) else {
+                match downloads.get(path).map(|event| event.status()) {
+                    Some(ResultStatus::Error(_, _)) => status.failing += 1,
+                    Some(_) => status.missing += 1,
+                    None => status.untried += 1,
+                }
+                continue;
+            };
+            status.present += 1;
+            status.bytes += metadata.len();
+            match loads.get(path).map(|event| event.status()) {
+                Some(ResultStatus::Success(_, _)) => status.loaded += 1,
+                Some(ResultStatus::Error(_, _)) => status.load_failing += 1,
+                _ => {}
+            }
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| jiff::Timestamp::try_from(time).ok());
+            if let Some(modified) = modified {
+                status.oldest = Some(status.oldest.map_or(modified, |t| t.min(modified)));
+                status.newest = Some(status.newest.map_or(modified, |t| t.max(modified)));
+                if modified < stale_before {
+                    stale.push((modified, path.clone()));
+                }
+            }
+        }
+        stale.sort();
+        status.stale = stale.into_iter().map(|(_, path)| path).collect();
+        status
+    }
+
+    /// The `from_env` method tallies the targets of the request queue for `dataset` in the
+    /// `BEA_DATA` directory.  Histories that have not been recorded are treated as empty.
+    #[tracing::instrument]
+    pub fn from_env(dataset: Dataset, stale_before: jiff::Timestamp) -> Result<Self, BeaErr> {
+        let queue = init_queue(dataset)?;
+        let paths = queue
+            .iter()
+            .map(|app| app.destination(false))
+            .collect::<Result<Vec<std::path::PathBuf>, BeaErr>>()?;
+        let history = |mode| -> Result<History, BeaErr> {
+            if History::path(dataset, mode)?.exists() {
+                History::try_from((dataset, mode))
+            } else {
+                Ok(History::from(Vec::new()))
+            }
+        };
+        let downloads = history(Mode::Download)?;
+        let loads = history(Mode::Load)?;
+        Ok(Self::from_paths(
+            dataset,
+            &paths,
+            &downloads,
+            &loads,
+            stale_before,
+        ))
+    }
+
+    /// Share of targets with a file on disk, as a percentage.
+    pub fn coverage(&self) -> f64 {
+        if self.targets == 0 {
+            0.0
+        } else {
+            100.0 * self.present as f64 / self.targets as f64
+        }
+    }
+}
+
+/// The `StatusReport` struct holds the [`DatasetStatus`] of each dataset with a request queue.
+/// Displays as a table, one row per dataset, and serializes to JSON with [`Self::to_json`].
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::From,
+)]
+#[from(Vec<DatasetStatus>)]
+pub struct StatusReport(Vec<DatasetStatus>);
+
+impl StatusReport {
+    /// The `from_env` method reports on `dataset`, or on every dataset with a request queue if
+    /// `dataset` is `None`, counting files older than `stale_after` as stale.  Datasets whose
+    /// queue cannot be built are skipped with a warning.
+    #[tracing::instrument]
+    pub fn from_env(
+        dataset: Option<Dataset>,
+        stale_after: jiff::SignedDuration,
+    ) -> Result<Self, BeaErr> {
+        // Fail early when the data directory is not set, rather than skipping every dataset.
+        bea_data()?;
+        let now = jiff::Timestamp::now();
+        let stale_before = now.checked_sub(stale_after).unwrap_or(jiff::Timestamp::MIN);
+        let datasets = match dataset {
+            Some(dataset) => vec![dataset],
+            None => Dataset::iter().collect(),
+        };
+        let mut report = Vec::new();
+        for dataset in datasets {
+            match DatasetStatus::from_env(dataset, stale_before) {
+                Ok(status) => report.push(status),
+                Err(e) => tracing::warn!("Skipping {dataset}: {e}"),
+            }
+        }
+        Ok(Self(report))
+    }
+
+    /// The `to_json` method serializes the report as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, BeaErr> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()).into())
+    }
+}
+
+impl std::fmt::Display for StatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let date = |time: &Option<jiff::Timestamp>| match time {
+            Some(time) => time.strftime("%Y-%m-%d").to_string(),
+            None => "-".to_string(),
+        };
+        writeln!(
+            f,
+            "{:<26} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>7} {:>10} {:>10} {:>10} {:>6}",
+            "Dataset",
+            "Targets",
+            "Present",
+            "Missing",
+            "Failing",
+            "Untried",
+            "Loaded",
+            "Cover",
+            "Size",
+            "Oldest",
+            "Newest",
+            "Stale"
+        )?;
+        for status in self.iter() {
+            writeln!(
+                f,
+                "{:<26} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>6.1}% {:>10} {:>10} {:>10} {:>6}",
+                status.dataset.to_string(),
+                status.targets,
+                status.present,
+                status.missing,
+                status.failing,
+                status.untried,
+                status.loaded,
+                status.coverage(),
+                bytesize::ByteSize::b(status.bytes).to_string(),
+                date(&status.oldest),
+                date(&status.newest),
+                status.stale.len()
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{History, Mode, bea_data};
+use bears_species::{BeaErr, Dataset, IoError};
+use strum::IntoEnumIterator;
+use tracing_subscriber::filter::FilterExt;
+use tracing_subscriber::fmt::writer::EitherWriter;
+use tracing_subscriber::{Layer, Registry, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Target of the download events written to the history log.
+pub const DOWNLOAD_TARGET: &str = "download_history";
+/// Target of the load events written to the history log.
+pub const LOAD_TARGET: &str = "load_history";
+
+/// A boxed layer of a [`Registry`] subscriber, as returned by [`TelemetryConfig::layers`].
+pub type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync + 'static>;
+
+/// The `HistorySink` enum selects where [`TelemetryConfig`] writes the download and load events
+/// that [`History`] and [`AttemptHistory`](crate::AttemptHistory) read back.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum HistorySink {
+    /// Discard history events.
+    Off,
+    /// Write download and load events to `history/history.log` in the `BEA_DATA` directory.
+    #[default]
+    Shared,
+    /// Write each event to the log of its dataset and mode in this directory, named as by
+    /// [`History::file_name`], using the `dataset` field of the event.  Events without a dataset
+    /// are written to `history.log` in the directory.  With the `history` folder of the
+    /// `BEA_DATA` directory, `History::try_from((dataset, mode))` reads the events without
+    /// copying logs.  Logs are created on the first event of their dataset.
+    Dataset(std::path::PathBuf),
+    /// Write download and load events to the log at this path.
+    File(std::path::PathBuf),
+    /// Write download events and load events to separate logs.
+    Split {
+        download: std::path::PathBuf,
+        load: std::path::PathBuf,
+    },
+}
+
+impl HistorySink {
+    /// The `routes` method resolves the sink to the log of each set of events.
+    fn routes(&self) -> Result<Vec<(std::path::PathBuf, HistoryFilter)>, BeaErr> {
+        const BOTH: &[&str] = &[DOWNLOAD_TARGET, LOAD_TARGET];
+        const DOWNLOAD: &[&str] = &[DOWNLOAD_TARGET];
+        const LOAD: &[&str] = &[LOAD_TARGET];
+        let any = |targets| HistoryFilter::new(targets, None);
+        let routes = match self {
+            Self::Off => Vec::new(),
+            Self::Shared => vec![(bea_data()?.join("history").join("history.log"), any(BOTH))],
+            Self::Dataset(dir) => {
+                let mut routes = vec![(
+                    dir.join("history.log"),
+                    HistoryFilter::new(BOTH, Some(None)),
+                )];
+                for dataset in Dataset::iter() {
+                    for (mode, targets) in [(Mode::Download, DOWNLOAD), (Mode::Load, LOAD)] {
+                        let path = dir.join(History::file_name(dataset, mode));
+                        routes.push((path, HistoryFilter::new(targets, Some(Some(dataset)))));
+                    }
+                }
+                routes
+            }
+            Self::File(path) => vec![(path.clone(), any(BOTH))],
+            Self::Split { download, load } => {
+                vec![(download.clone(), any(DOWNLOAD)), (load.clone(), any(LOAD))]
+            }
+        };
+        Ok(routes)
+    }
+}
+
+/// The `HistoryFilter` struct selects the events written to one log of a [`HistorySink`].
+///
+/// * **targets** - Targets of the events written to the log.
+/// * **dataset** - When set, only events whose `dataset` field names this dataset are written,
+///   or events without a `dataset` field for `Some(None)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, derive_new::new)]
+struct HistoryFilter {
+    targets: &'static [&'static str],
+    dataset: Option<Option<Dataset>>,
+}
+
+impl<S> tracing_subscriber::layer::Filter<S> for HistoryFilter {
+    fn enabled(
+        &self,
+        metadata: &tracing::Metadata<'_>,
+        _: &tracing_subscriber::layer::Context<'_, S>,
+    ) -> bool {
+        self.targets.contains(&metadata.target())
+    }
+
+    fn event_enabled(
+        &self,
+        event: &tracing::Event<'_>,
+        _: &tracing_subscriber::layer::Context<'_, S>,
+    ) -> bool {
+        let Some(dataset) = self.dataset else {
+            return true;
+        };
+        let mut visitor = DatasetField::default();
+        event.record(&mut visitor);
+        visitor.0 == dataset.map(|dataset| dataset.to_string())
+    }
+}
+
+/// Reads the `dataset` field of a history event.
+#[derive(Debug, Default)]
+struct DatasetField(Option<String>);
+
+impl tracing::field::Visit for DatasetField {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "dataset" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "dataset" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// The `HistoryLog` struct opens its log on the first event written to it, so that the logs of
+/// a [`HistorySink::Dataset`] are only created for datasets with events.  Events are discarded
+/// if the log cannot be opened.
+#[derive(Debug)]
+struct HistoryLog {
+    path: std::path::PathBuf,
+    file: std::sync::OnceLock<Option<std::fs::File>>,
+}
+
+impl HistoryLog {
+    fn new(path: std::path::PathBuf) -> Self {
+        Self {
+            path,
+            file: std::sync::OnceLock::new(),
+        }
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for HistoryLog {
+    type Writer = EitherWriter<&'a std::fs::File, std::io::Sink>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        let file = self.file.get_or_init(|| {
+            std::fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(&self.path)
+                .ok()
+        });
+        match file {
+            Some(file) => EitherWriter::A(file),
+            None => EitherWriter::B(std::io::sink()),
+        }
+    }
+}
+
+/// The `ConsoleFormat` enum selects how [`TelemetryConfig`] prints application logs.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum ConsoleFormat {
+    /// Human readable lines.
+    #[default]
+    Human,
+    /// One JSON object per line.
+    Json,
+}
+
+/// The `TelemetryConfig` struct configures the tracing subscriber of the library.  History
+/// events are written to the [`HistorySink`] regardless of the console filter, so that
+/// application logs can be quieted or redirected without losing the history.
+///
+/// * **history** - Where to write download and load events.
+/// * **console** - Format of application logs printed to standard output, or `None` to print
+///   nothing.
+/// * **filter** - Default filter directive for application logs and exported spans, used when
+///   the `RUST_LOG` environmental variable is not set.
+/// * **tracer** - OpenTelemetry tracer to export spans to.  Available with the `otel` feature.
+///
+/// The default configuration matches [`trace_init`](crate::trace_init).  Use
+/// [`TelemetryConfig::library`] to install nothing, leaving the choice of subscriber to the
+/// application, and [`TelemetryConfig::layers`] to add the layers to a subscriber of your own.
+#[derive(Debug, Clone, derive_getters::Getters, derive_setters::Setters)]
+#[setters(prefix = "with_", borrow_self, into)]
+pub struct TelemetryConfig {
+    history: HistorySink,
+    console: Option<ConsoleFormat>,
+    filter: String,
+    #[cfg(feature = "otel")]
+    tracer: Option<opentelemetry_sdk::trace::SdkTracer>,
+}
+
+impl TelemetryConfig {
+    /// The `library` method returns a configuration that installs nothing.  The library still
+    /// emits its spans and events, for a subscriber installed by the application.
+    pub fn library() -> Self {
+        Self {
+            history: HistorySink::Off,
+            console: None,
+            filter: "bea=info".to_string(),
+            #[cfg(feature = "otel")]
+            tracer: None,
+        }
+    }
+
+    /// The `is_library` method returns true if the configuration installs no layers.
+    pub fn is_library(&self) -> bool {
+        #[cfg(feature = "otel")]
+        if self.tracer.is_some() {
+            return false;
+        }
+        self.history == HistorySink::Off && self.console.is_none()
+    }
+
+    /// Filter for application logs and exported spans.
+    fn env_filter(&self) -> tracing_subscriber::EnvFilter {
+        tracing_subscriber::EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(&self.filter))
+    }
+
+    /// The `layers` method builds the layers of the configuration, opening the history logs and
+    /// creating their directories as needed.
+    pub fn layers(&self) -> Result<Vec<BoxedLayer>, BeaErr> {
+        let mut layers = Vec::new();
+        if let Some(format) = self.console {
+            let filter = self
+                .env_filter()
+                .and(tracing_subscriber::filter::filter_fn(|metadata| {
+                    metadata.target() != DOWNLOAD_TARGET && metadata.target() != LOAD_TARGET
+                }));
+            let layer = match format {
+                ConsoleFormat::Human => {
+                    tracing_subscriber::fmt::layer().with_filter(filter).boxed()
+                }
+                ConsoleFormat::Json => tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_filter(filter)
+                    .boxed(),
+            };
+            layers.push(layer);
+        }
+        for (path, filter) in self.history.routes()? {
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+                && !parent.exists()
+            {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| IoError::new(parent.into(), e, line!(), file!().into()))?;
+                tracing::info!("History directory created.");
+            }
+            let layer = tracing_subscriber::fmt::layer().json();
+            let layer = if filter.dataset.is_some() {
+                layer
+                    .with_writer(HistoryLog::new(path))
+                    .with_filter(filter)
+                    .boxed()
+            } else {
+                let log = std::fs::OpenOptions::new()
+                    .append(true)
+                    .create(true)
+                    .open(&path)
+                    .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+                layer
+                    .with_writer(std::sync::Arc::new(log))
+                    .with_filter(filter)
+                    .boxed()
+            };
+            layers.push(layer);
+        }
+        #[cfg(feature = "otel")]
+        if let Some(tracer) = &self.tracer {
+            let layer = tracing_opentelemetry::layer()
+                .with_tracer(tracer.clone())
+                .with_filter(self.env_filter());
+            layers.push(layer.boxed());
+        }
+        Ok(layers)
+    }
+
+    /// The `init` method installs the configured layers as the global subscriber.  Installs
+    /// nothing in library mode.  If a global subscriber is already installed, it is kept.
+    #[tracing::instrument(skip_all)]
+    pub fn init(&self) -> Result<(), BeaErr> {
+        if self.is_library() {
+            return Ok(());
+        }
+        let layers = self.layers()?;
+        if tracing_subscriber::registry()
+            .with(layers)
+            .try_init()
+            .is_err()
+        {
+            tracing::debug!("Global subscriber already installed.");
+        }
+        tracing::trace!("Loading Bea...");
+        Ok(())
+    }
+}
+
+impl Default for TelemetryConfig {
+    /// Writes history to the shared log and prints human readable logs filtered by `bea=info`.
+    fn default() -> Self {
+        let mut config = Self::library();
+        config
+            .with_history(HistorySink::Shared)
+            .with_console(Some(ConsoleFormat::Human));
+        config
+    }
+}
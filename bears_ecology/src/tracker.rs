@@ -1,10 +1,11 @@
-use crate::{Mode, ResultStatus};
+use crate::{ErrorDetail, ErrorKind, Mode, ResultStatus, app::redact};
 use bears_species::{
-    BeaErr, DeriveFromStr, Jiff, JsonParseError, JsonParseErrorKind, KeyMissing, NotObject,
-    ParseInt, map_to_string,
+    BeaErr, Dataset, DeriveFromStr, Jiff, JsonParseError, JsonParseErrorKind, KeyMissing,
+    NotObject, ParseInt, map_to_int, map_to_string,
 };
 use jiff::ToSpan;
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 // Cannot exceed 30 errors per minute.
 // Theory: calls may get ahead of tracker
@@ -15,6 +16,10 @@ pub const ERROR_CAP: usize = 7;
 // Cannot exceed 100 calls per minute.
 // 14 ahead of 85 = 99
 pub const CALL_CAP: usize = 30;
+/// Version of the structured fields written for each [`Event`] in the history log.  Lines written
+/// before the fields were versioned carry only a formatted message, and are read as version 0.
+/// Version 2 adds the [`ErrorDetail`] of error events.
+pub const EVENT_SCHEMA: i64 = 2;
 
 #[derive(
     Debug,
@@ -46,32 +51,20 @@ impl Tracker {
                 if let Some(event) = self.calls.iter_mut().find(|event| event.id == id) {
                     event.status = status;
                     event.length = Some(length);
-                    match mode {
-                        Mode::Download => tracing::info!(target: "download_history", "{event}"),
-                        Mode::Load => tracing::info!(target: "load_history", "{event}"),
-                    }
+                    event.log(mode);
                 } else if let Some(event) = self.cache.iter_mut().find(|event| event.id == id) {
                     event.status = status;
                     event.length = Some(length);
-                    match mode {
-                        Mode::Download => tracing::info!(target: "download_history", "{event}"),
-                        Mode::Load => tracing::info!(target: "load_history", "{event}"),
-                    }
+                    event.log(mode);
                 }
             }
-            ResultStatus::Error(id) => {
+            ResultStatus::Error(id, _) => {
                 if let Some(event) = self.calls.iter_mut().find(|event| event.id == id) {
                     event.status = status;
-                    match mode {
-                        Mode::Download => tracing::info!(target: "download_history", "{event}"),
-                        Mode::Load => tracing::info!(target: "load_history", "{event}"),
-                    }
+                    event.log(mode);
                 } else if let Some(event) = self.cache.iter_mut().find(|event| event.id == id) {
                     event.status = status;
-                    match mode {
-                        Mode::Download => tracing::info!(target: "download_history", "{event}"),
-                        Mode::Load => tracing::info!(target: "load_history", "{event}"),
-                    }
+                    event.log(mode);
                 }
                 if let Some(event) = self.calls.iter().find(|event| event.id == id) {
                     self.errors.push(event.clone());
@@ -191,13 +184,12 @@ impl Tracker {
             tracing::trace!("Oldest call is {oldest} millis away from expiring.");
             pause = pause.min(oldest);
         }
-        if pause > 5000 {
-            tracing::trace!("Pausing for 5000 millis.");
-            tokio::time::sleep(tokio::time::Duration::from_millis(5000)).await;
-        } else {
-            tracing::trace!("Pausing for {pause} millis.");
-            tokio::time::sleep(tokio::time::Duration::from_millis(pause)).await;
-        }
+        let pause = pause.min(5000);
+        tracing::trace!("Pausing for {pause} millis.");
+        let pause = tokio::time::Duration::from_millis(pause);
+        #[cfg(feature = "metrics")]
+        crate::Metrics::global().rate_limit_pause(pause);
+        tokio::time::sleep(pause).await;
     }
 }
 
@@ -253,6 +245,13 @@ impl Event {
         }
     }
 
+    /// The `dataset` method returns the dataset of the event, named by the folder holding its
+    /// path in the `data` directory, or `None` if the folder does not name a dataset.
+    pub fn dataset(&self) -> Option<Dataset> {
+        let folder = self.path.parent()?.file_name()?.to_str()?;
+        Dataset::iter().find(|dataset| dataset.to_string() == folder)
+    }
+
     pub fn len_as_str(&self) -> String {
         match self.length {
             Some(num) => num.to_string(),
@@ -274,50 +273,220 @@ impl Event {
         }
     }
 
+    /// The `log` method writes the event to the history log of `mode` as structured fields,
+    /// tagged with the [`EVENT_SCHEMA`] version.  The formatted message is kept for readability,
+    /// but is not read back.  Error fields are omitted unless the status records them.  The
+    /// `dataset` field, when known, lets a [`HistorySink`](crate::HistorySink) route the event to
+    /// the log of its dataset.
+    pub fn log(&self, mode: Mode) {
+        let path = self.path.display();
+        let dataset = self.dataset().map(tracing::field::display);
+        let detail = self.status.detail();
+        let error_kind = detail.map(|detail| tracing::field::display(detail.kind()));
+        let error_code = detail.and_then(|detail| *detail.code());
+        let error_message = detail.map(|detail| redact(detail.message()));
+        let error_message = error_message.as_deref();
+        let http_status = detail.and_then(|detail| *detail.http_status());
+        match mode {
+            Mode::Download => tracing::info!(
+                target: "download_history",
+                schema = EVENT_SCHEMA,
+                id = %self.id,
+                length = self.length,
+                mode = %self.mode,
+                path = %path,
+                status = %self.status,
+                time = %self.time,
+                dataset,
+                error_kind,
+                error_code,
+                error_message,
+                http_status,
+                "{self}"
+            ),
+            Mode::Load => tracing::info!(
+                target: "load_history",
+                schema = EVENT_SCHEMA,
+                id = %self.id,
+                length = self.length,
+                mode = %self.mode,
+                path = %path,
+                status = %self.status,
+                time = %self.time,
+                dataset,
+                error_kind,
+                error_code,
+                error_message,
+                http_status,
+                "{self}"
+            ),
+        }
+    }
+
+    /// The `to_json` method returns the line that [`Self::log`] writes to the history log, used
+    /// to rewrite history logs in the current format.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut fields = serde_json::json!({
+            "message": self.to_string(),
+            "schema": EVENT_SCHEMA,
+            "id": self.id.to_string(),
+            "mode": self.mode.to_string(),
+            "path": self.path.display().to_string(),
+            "status": self.status.to_string(),
+            "time": self.time.to_string(),
+        });
+        if let Some(length) = self.length {
+            fields["length"] = length.into();
+        }
+        if let Some(dataset) = self.dataset() {
+            fields["dataset"] = dataset.to_string().into();
+        }
+        if let Some(detail) = self.status.detail() {
+            fields["error_kind"] = detail.kind().to_string().into();
+            if let Some(code) = detail.code() {
+                fields["error_code"] = (*code).into();
+            }
+            fields["error_message"] = redact(detail.message()).into();
+            if let Some(status) = detail.http_status() {
+                fields["http_status"] = (*status).into();
+            }
+        }
+        let target = match self.mode {
+            Mode::Download => "download_history",
+            Mode::Load => "load_history",
+        };
+        serde_json::json!({
+            "timestamp": self.time.to_string(),
+            "level": "INFO",
+            "fields": fields,
+            "target": target,
+        })
+    }
+
+    /// The `read_json` method reads an event from the fields of a history log line, reading the
+    /// structured fields of lines with a schema version and falling back to the formatted message
+    /// of older lines.
     pub fn read_json(m: &serde_json::Map<String, serde_json::Value>) -> Result<Self, BeaErr> {
-        let msg = map_to_string("message", m)?;
-        // skip the key in key: value pair sequence
-        // skip the first field "id"
-        let result = msg
-            .split(",")
-            .flat_map(|pair| pair.split(":").skip(1))
-            .skip(1)
-            .collect::<Vec<&str>>();
-        tracing::trace!("Raw Event: {result:#?}");
-        let id = uuid::Uuid::new_v4();
-        if result.len() < 7 {
-            let error = KeyMissing::new("invalid Event".to_string(), line!(), file!().to_string());
-            let error = JsonParseErrorKind::from(error);
-            let error = JsonParseError::from(error);
-            Err(error.into())
+        if m.contains_key("schema") {
+            Self::read_fields(m)
         } else {
-            let length = Self::len_from_str(result[0].trim())?;
-            let mode = result[1].trim().to_string();
-            let mode = Mode::from_str(&mode)
-                .map_err(|e| DeriveFromStr::new(mode, e, line!(), file!().to_string()))?;
-            let path = result[2].trim().trim_matches('"');
-            tracing::trace!("Path is {path}");
-            let path = std::path::PathBuf::from(path);
-            let status = result[3].trim().to_string();
-            let status = ResultStatus::from_str(&status)?;
-            let time = result[4].trim().to_string();
-            let time = format!("{time}:{}:{}", result[5], result[6]);
-            let time = match jiff::Timestamp::from_str(&time) {
-                Ok(stamp) => stamp,
-                Err(source) => {
-                    let error = Jiff::new(time, source);
-                    return Err(error.into());
-                }
-            };
-            Ok(Self {
-                id,
-                length,
-                mode,
-                path,
-                status,
-                time,
-            })
+            Self::read_message(m)
+        }
+    }
+
+    /// Reads an event from the structured fields written by [`Self::log`].
+    fn read_fields(m: &serde_json::Map<String, serde_json::Value>) -> Result<Self, BeaErr> {
+        let schema = map_to_int("schema", m)?;
+        if schema > EVENT_SCHEMA {
+            let clue = format!("Event schema {schema} is newer than {EVENT_SCHEMA}");
+            return Err(Self::invalid(clue));
+        }
+        let id = Self::id_from_str(&map_to_string("id", m)?)?;
+        let length = match m.get("length") {
+            Some(_) => Some(map_to_int("length", m)? as u64),
+            None => None,
+        };
+        let mode = map_to_string("mode", m)?;
+        let mode = Mode::from_str(&mode)
+            .map_err(|e| DeriveFromStr::new(mode, e, line!(), file!().to_string()))?;
+        let path = std::path::PathBuf::from(map_to_string("path", m)?);
+        let status = ResultStatus::from_str(&map_to_string("status", m)?)?;
+        let time = Self::time_from_str(&map_to_string("time", m)?)?;
+        let mut status = Self::with_id(status, id, length);
+        if let ResultStatus::Error(_, detail) = &mut status {
+            *detail = Self::read_error(m)?;
         }
+        Ok(Self {
+            id,
+            length,
+            mode,
+            path,
+            status,
+            time,
+        })
+    }
+
+    /// Reads the [`ErrorDetail`] of an error event, if the fields record one.
+    fn read_error(
+        m: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<Option<ErrorDetail>, BeaErr> {
+        if !m.contains_key("error_kind") {
+            return Ok(None);
+        }
+        let kind = map_to_string("error_kind", m)?;
+        let kind = ErrorKind::from_str(&kind)
+            .map_err(|e| DeriveFromStr::new(kind, e, line!(), file!().to_string()))?;
+        let code = match m.get("error_code") {
+            Some(_) => Some(map_to_int("error_code", m)? as i32),
+            None => None,
+        };
+        let message = map_to_string("error_message", m)?;
+        let http_status = match m.get("http_status") {
+            Some(_) => Some(map_to_int("http_status", m)? as u16),
+            None => None,
+        };
+        Ok(Some(ErrorDetail::new(kind, code, message, http_status)))
+    }
+
+    /// Reads an event from the formatted message of a line written before the schema version.
+    /// The leading fields are split from the front and the trailing fields from the back, so that
+    /// commas and colons in the path do not shift the remaining fields.
+    fn read_message(m: &serde_json::Map<String, serde_json::Value>) -> Result<Self, BeaErr> {
+        let msg = map_to_string("message", m)?;
+        tracing::trace!("Raw Event: {msg}");
+        let invalid = || Self::invalid(format!("invalid Event: {msg}"));
+        let rest = msg.strip_prefix("id: ").ok_or_else(invalid)?;
+        let (id, rest) = rest.split_once(", length: ").ok_or_else(invalid)?;
+        let (length, rest) = rest.split_once(", mode: ").ok_or_else(invalid)?;
+        let (mode, rest) = rest.split_once(", path: ").ok_or_else(invalid)?;
+        let (rest, time) = rest.rsplit_once(", time: ").ok_or_else(invalid)?;
+        let (path, status) = rest.rsplit_once(", status: ").ok_or_else(invalid)?;
+        let id = Self::id_from_str(id)?;
+        let length = Self::len_from_str(length.trim())?;
+        let mode = mode.trim().to_string();
+        let mode = Mode::from_str(&mode)
+            .map_err(|e| DeriveFromStr::new(mode, e, line!(), file!().to_string()))?;
+        // The path is written in debug format, quoted and escaped like a JSON string.
+        let path = serde_json::from_str::<String>(path)
+            .unwrap_or_else(|_| path.trim().trim_matches('"').to_string());
+        tracing::trace!("Path is {path}");
+        let path = std::path::PathBuf::from(path);
+        let status = ResultStatus::from_str(status.trim())?;
+        let time = Self::time_from_str(time.trim())?;
+        Ok(Self {
+            id,
+            length,
+            mode,
+            path,
+            status: Self::with_id(status, id, length),
+            time,
+        })
+    }
+
+    /// The status read from a log line holds a placeholder id, replaced here by the event id.
+    fn with_id(status: ResultStatus, id: uuid::Uuid, length: Option<u64>) -> ResultStatus {
+        match status {
+            ResultStatus::Success(_, _) => ResultStatus::Success(id, length.unwrap_or(0)),
+            ResultStatus::Error(_, detail) => ResultStatus::Error(id, detail),
+            ResultStatus::Pass(_) => ResultStatus::Pass(id),
+            ResultStatus::Pending | ResultStatus::Abort => status,
+        }
+    }
+
+    fn id_from_str(id: &str) -> Result<uuid::Uuid, BeaErr> {
+        uuid::Uuid::from_str(id.trim())
+            .map_err(|_| Self::invalid(format!("invalid Event id: {id}")))
+    }
+
+    fn time_from_str(time: &str) -> Result<jiff::Timestamp, BeaErr> {
+        jiff::Timestamp::from_str(time).map_err(|source| Jiff::new(time.to_string(), source).into())
+    }
+
+    fn invalid(clue: String) -> BeaErr {
+        let error = KeyMissing::new(clue, line!(), file!().to_string());
+        let error = JsonParseErrorKind::from(error);
+        let error = JsonParseError::from(error);
+        error.into()
     }
 }
 
@@ -0,0 +1,317 @@
+use crate::bea_data;
+use bears_species::{BeaErr, BeaResponse, IoError, KeyMissing, SerdeJson};
+use std::collections::BTreeMap;
+
+/// The `Archive` enum selects how [`App::save`](crate::App::save) names the snapshot kept for
+/// each download when vintage mode is enabled on an [`App`](crate::App).
+///
+/// * **Dated** - Snapshots are named by the date of retrieval, e.g. `2025-03-27.json`.  A second
+///   download on the same day with different contents replaces the snapshot for that day.
+/// * **Content** - Snapshots are named by a hash of their contents, so every distinct release is
+///   kept, including multiple releases on the same day.
+///
+/// The BEA does not report a release date in the response payload, so the date of retrieval
+/// stands in for the release date in both modes.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum Archive {
+    Dated,
+    Content,
+}
+
+/// The `Vintage` struct records a single snapshot of a download target.
+///
+/// * **date** - Date the snapshot was retrieved from the BEA.
+/// * **time** - Timestamp of the retrieval.
+/// * **hash** - Content hash of the snapshot, used to skip storing unchanged releases.
+/// * **length** - Size of the snapshot in bytes.
+/// * **file** - File name of the snapshot within the vintage directory of the target.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Vintage {
+    date: jiff::civil::Date,
+    time: jiff::Timestamp,
+    hash: String,
+    length: u64,
+    file: std::path::PathBuf,
+}
+
+/// The `Vintages` struct is the index of snapshots for a single download target, stored as
+/// `index.json` in the vintage directory of the target.  Vintages are kept sorted by time of
+/// retrieval.
+///
+/// * **target** - The current-release path of the target, as returned by
+///   [`App::destination`](crate::App::destination).
+/// * **vintages** - The snapshots recorded for the target.
+/// * **root** - Data directory holding the archive, or `None` for the `BEA_DATA` directory.  Not
+///   stored in the index.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Vintages {
+    target: std::path::PathBuf,
+    vintages: Vec<Vintage>,
+    #[serde(skip)]
+    #[new(default)]
+    root: Option<std::path::PathBuf>,
+}
+
+impl Vintages {
+    /// Name of the index file within each vintage directory.
+    pub const INDEX: &str = "index.json";
+
+    /// The `directory` method returns the vintage directory for the current-release path
+    /// `target`.  The directory mirrors the layout of `BEA_DATA/data` under `BEA_DATA/vintage`,
+    /// using the file stem of the target as the final component, e.g. the target
+    /// `data/NIPA/NIPA_T10101.json` keeps its vintages in `vintage/NIPA/NIPA_T10101/`.
+    pub fn directory(target: &std::path::Path) -> Result<std::path::PathBuf, BeaErr> {
+        Ok(Self::directory_in(&bea_data()?, target))
+    }
+
+    /// The `directory_in` method returns the vintage directory for `target` in the data
+    /// directory `root`, laid out as in [`Self::directory`].
+    pub fn directory_in(root: &std::path::Path, target: &std::path::Path) -> std::path::PathBuf {
+        // Targets outside of `BEA_DATA/data` are keyed by file name alone.
+        let relative = match target.strip_prefix(root.join("data")) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => target.file_name().map(Into::into).unwrap_or_default(),
+        };
+        root.join("vintage").join(relative.with_extension(""))
+    }
+
+    /// The vintage directory of the target, in `root` if set.
+    fn dir(&self) -> Result<std::path::PathBuf, BeaErr> {
+        match &self.root {
+            Some(root) => Ok(Self::directory_in(root, &self.target)),
+            None => Self::directory(&self.target),
+        }
+    }
+
+    /// Reads the vintage index for `target`.  Returns an empty index if no vintages have been
+    /// recorded for the target.
+    #[tracing::instrument(skip_all)]
+    pub fn from_target(target: &std::path::Path) -> Result<Self, BeaErr> {
+        let path = Self::directory(target)?.join(Self::INDEX);
+        if path.exists() {
+            Self::try_from(&path)
+        } else {
+            Ok(Self::new(target.to_path_buf(), Vec::new()))
+        }
+    }
+
+    /// Reads the vintage index for `target` from the archive in the data directory `root`,
+    /// rather than the `BEA_DATA` directory.  Snapshots recorded with the index are written to
+    /// the same archive.
+    #[tracing::instrument(skip_all)]
+    pub fn from_target_in(
+        root: &std::path::Path,
+        target: &std::path::Path,
+    ) -> Result<Self, BeaErr> {
+        let path = Self::directory_in(root, target).join(Self::INDEX);
+        let mut index = if path.exists() {
+            Self::try_from(&path)?
+        } else {
+            Self::new(target.to_path_buf(), Vec::new())
+        };
+        index.root = Some(root.to_path_buf());
+        Ok(index)
+    }
+
+    /// Writes the index to the vintage directory of the target, creating the directory if
+    /// necessary.
+    #[tracing::instrument(skip_all)]
+    pub fn save(&self) -> Result<(), BeaErr> {
+        let dir = self.dir()?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+        let path = dir.join(Self::INDEX);
+        let contents = serde_json::to_vec_pretty(self)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+        std::fs::write(&path, contents)
+            .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+        Ok(())
+    }
+
+    /// The `latest` method returns the most recently retrieved vintage, if any.
+    pub fn latest(&self) -> Option<&Vintage> {
+        self.vintages.last()
+    }
+
+    /// The `as_of` method returns the vintage that was current on `date`, meaning the most
+    /// recent snapshot retrieved on or before `date`.  Returns `None` if the first snapshot of the
+    /// target postdates `date`.
+    pub fn as_of(&self, date: jiff::civil::Date) -> Option<&Vintage> {
+        self.vintages.iter().rev().find(|v| v.date <= date)
+    }
+
+    /// The `path` method returns the full path to the snapshot file of `vintage`.
+    pub fn path(&self, vintage: &Vintage) -> Result<std::path::PathBuf, BeaErr> {
+        Ok(self.dir()?.join(&vintage.file))
+    }
+
+    /// The `record` method writes `contents` as a new snapshot of the target using the naming
+    /// scheme of `archive`, and updates the index on disk.  If the contents match the latest
+    /// snapshot, no new vintage is stored and the method returns `None`.
+    #[tracing::instrument(skip(self, contents))]
+    pub fn record(&mut self, contents: &[u8], archive: Archive) -> Result<Option<Vintage>, BeaErr> {
+        let hash = content_hash(contents);
+        if let Some(latest) = self.latest()
+            && latest.hash == hash
+        {
+            tracing::info!("No change since vintage {}.", latest.date);
+            return Ok(None);
+        }
+        let time = jiff::Timestamp::now();
+        let date = time.to_zoned(jiff::tz::TimeZone::system()).date();
+        let file = match archive {
+            Archive::Dated => std::path::PathBuf::from(format!("{date}.json")),
+            Archive::Content => std::path::PathBuf::from(format!("{hash}.json")),
+        };
+        let dir = self.dir()?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+        let path = dir.join(&file);
+        std::fs::write(&path, contents)
+            .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+        let vintage = Vintage::new(date, time, hash, contents.len() as u64, file);
+        // A dated snapshot replaces any earlier snapshot from the same day.
+        if archive == Archive::Dated {
+            self.vintages.retain(|v| v.file != vintage.file);
+        }
+        self.vintages.push(vintage.clone());
+        self.save()?;
+        tracing::info!("Vintage {} saved.", vintage.file.display());
+        Ok(Some(vintage))
+    }
+
+    /// The `load` method reads the snapshot `vintage` of the target into a [`BeaResponse`].
+    pub fn load(&self, vintage: &Vintage) -> Result<BeaResponse, BeaErr> {
+        let path = self.path(vintage)?;
+        read_response(&path)
+    }
+
+    /// The `load_as_of` method reads the snapshot of the target that was current on `date`.
+    /// Returns a [`KeyMissing`] error if there is no vintage on or before `date`.
+    pub fn load_as_of(&self, date: jiff::civil::Date) -> Result<BeaResponse, BeaErr> {
+        match self.as_of(date) {
+            Some(vintage) => self.load(vintage),
+            None => {
+                let clue = format!("no vintage of {:?} as of {date}", self.target);
+                Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+            }
+        }
+    }
+}
+
+impl TryFrom<&std::path::PathBuf> for Vintages {
+    type Error = BeaErr;
+
+    fn try_from(value: &std::path::PathBuf) -> Result<Self, Self::Error> {
+        let file = std::fs::File::open(value)
+            .map_err(|e| IoError::new(value.clone(), e, line!(), file!().into()))?;
+        let rdr = std::io::BufReader::new(file);
+        let mut index: Self = serde_json::from_reader(rdr)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+        index.vintages.sort_by_key(|v| v.time);
+        Ok(index)
+    }
+}
+
+/// The `vintages` function reads every vintage index under `BEA_DATA/vintage`, keyed by the
+/// current-release path of the target.
+#[tracing::instrument]
+pub fn vintages() -> Result<BTreeMap<std::path::PathBuf, Vintages>, BeaErr> {
+    let root = bea_data()?.join("vintage");
+    let mut indices = BTreeMap::new();
+    if !root.exists() {
+        return Ok(indices);
+    }
+    let mut dirs = vec![root];
+    while let Some(dir) = dirs.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.file_name() == Some(std::ffi::OsStr::new(Vintages::INDEX)) {
+                let index = Vintages::try_from(&path)?;
+                indices.insert(index.target.clone(), index);
+            }
+        }
+    }
+    tracing::info!("{} vintage indices read.", indices.len());
+    Ok(indices)
+}
+
+/// The `load_as_of` function reads the vintage of each archived target that was current on
+/// `date`, keyed by the current-release path of the target.  Targets without a vintage on or
+/// before `date` are omitted.  Used to reconstruct the data available at a past date for
+/// real-time evaluation.
+#[tracing::instrument]
+pub fn load_as_of(
+    date: jiff::civil::Date,
+) -> Result<BTreeMap<std::path::PathBuf, BeaResponse>, BeaErr> {
+    let mut responses = BTreeMap::new();
+    for (target, index) in vintages()? {
+        if let Some(vintage) = index.as_of(date) {
+            responses.insert(target, index.load(vintage)?);
+        }
+    }
+    tracing::info!("{} targets loaded as of {date}.", responses.len());
+    Ok(responses)
+}
+
+/// Reads a [`BeaResponse`] from the JSON file at `path`.
+pub fn read_response(path: &std::path::Path) -> Result<BeaResponse, BeaErr> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| IoError::new(path.to_path_buf(), e, line!(), file!().into()))?;
+    let rdr = std::io::BufReader::new(file);
+    let json: serde_json::Value = serde_json::from_reader(rdr)
+        .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+    BeaResponse::try_from(&json)
+}
+
+/// 64-bit FNV-1a hash of `contents` as a hex string.  The hash identifies releases for
+/// deduplication and naming, and is stable across platforms and compiler versions, unlike
+/// [`std::hash::DefaultHasher`].
+fn content_hash(contents: &[u8]) -> String {
+    let hash = contents.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
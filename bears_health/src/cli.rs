@@ -0,0 +1,218 @@
+use bears::{Cli, Command, ValuesCommand};
+use bears_ecology::{Archive, ErrorKind};
+use bears_species::{BeaErr, Dataset, KeyMissing, ParameterName};
+use clap::{CommandFactory, Parser};
+
+/// Parses the arguments `args` of the `bears` binary, reporting a parse failure as an error.
+fn parse(args: &[&str]) -> Result<Cli, BeaErr> {
+    Cli::try_parse_from(std::iter::once("bears").chain(args.iter().copied())).map_err(|e| {
+        let clue = format!("valid arguments {args:?}: {e}");
+        KeyMissing::new(clue, line!(), file!().to_string()).into()
+    })
+}
+
+/// Checks the command line of the `bears` binary.  Runs the clap consistency checks on the
+/// [`Cli`] definition, parses each subcommand, checks which subcommands require the `BEA_DATA`
+/// directory, and that conflicting or out of range arguments are rejected.  Does not require the
+/// `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_cli() -> Result<(), BeaErr> {
+    <Cli as CommandFactory>::command().debug_assert();
+
+    let cases = [
+        (vec!["datasets"], Command::Datasets),
+        (
+            vec!["params", "NIPA"],
+            Command::Params {
+                dataset: Dataset::Nipa,
+            },
+        ),
+        (
+            vec!["download", "ITA"],
+            Command::Download {
+                dataset: Dataset::Ita,
+                archive: None,
+            },
+        ),
+        (
+            vec!["download", "NIPA", "--archive"],
+            Command::Download {
+                dataset: Dataset::Nipa,
+                archive: Some(Archive::Dated),
+            },
+        ),
+        (
+            vec!["download", "NIPA", "--archive", "Content"],
+            Command::Download {
+                dataset: Dataset::Nipa,
+                archive: Some(Archive::Content),
+            },
+        ),
+        (
+            vec!["load", "GDPbyIndustry"],
+            Command::Load {
+                dataset: Dataset::GDPbyIndustry,
+            },
+        ),
+        (
+            vec!["status", "--dataset", "MNE", "--stale-days", "30"],
+            Command::Status {
+                dataset: Some(Dataset::Mne),
+                stale_days: 30,
+            },
+        ),
+        (vec!["migrate"], Command::Migrate),
+        (
+            vec!["generate", "--source", ".."],
+            Command::Generate {
+                source: Some("..".into()),
+            },
+        ),
+        (
+            vec!["completions", "bash"],
+            Command::Completions {
+                shell: clap_complete::Shell::Bash,
+            },
+        ),
+    ];
+    for (args, expected) in cases {
+        let cli = parse(&args)?;
+        if *cli.command() != expected || *cli.json() {
+            let clue = format!("{expected:?} from {args:?}, found {:?}", cli.command());
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    let cli = parse(&["--json", "values", "NIPA", "TableName"])?;
+    let Command::Values(values) = cli.command() else {
+        let clue = format!("values command, found {:?}", cli.command());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    };
+    if !*cli.json()
+        || *values.dataset() != Some(Dataset::Nipa)
+        || *values.param() != Some(ParameterName::TableName)
+        || values.refresh().is_some()
+    {
+        let clue = format!("NIPA table names as JSON, found {values:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let cli = parse(&["values", "refresh", "--max-age-days", "7", "--force"])?;
+    let expected = Some(ValuesCommand::Refresh {
+        max_age_days: Some(7),
+        interval_ms: 1000,
+        force: true,
+    });
+    if !matches!(cli.command(), Command::Values(values) if *values.refresh() == expected) {
+        let clue = format!("{expected:?}, found {:?}", cli.command());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let cli = parse(&[
+        "get",
+        "NIPA",
+        "-t",
+        "T10101",
+        "-y",
+        "2020,2021",
+        "-f",
+        "Quarterly",
+    ])?;
+    let Command::Get(get) = cli.command() else {
+        let clue = format!("get command, found {:?}", cli.command());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    };
+    if *get.dataset() != Dataset::Nipa
+        || get.table().as_deref() != Some("T10101")
+        || *get.year() != ["2020", "2021"]
+        || get.frequency().len() != 1
+        || *get.save()
+    {
+        let clue = format!("quarterly T10101 for two years, found {get:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let cli = parse(&["export", "-d", "NIPA", "--from", "2000", "--to", "2010"])?;
+    let Command::Export(export) = cli.command() else {
+        let clue = format!("export command, found {:?}", cli.command());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    };
+    if *export.dataset() != Some(Dataset::Nipa)
+        || *export.from() != Some(2000)
+        || *export.to() != Some(2010)
+        || export.output().is_some()
+    {
+        let clue = format!("NIPA series from 2000 to 2010, found {export:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let cli = parse(&[
+        "retry",
+        "NIPA",
+        "--download",
+        "--kind",
+        "Api",
+        "--rounds",
+        "2",
+    ])?;
+    let Command::Retry(retry) = cli.command() else {
+        let clue = format!("retry command, found {:?}", cli.command());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    };
+    if *retry.dataset() != Dataset::Nipa
+        || !*retry.download()
+        || *retry.kind() != Some(ErrorKind::Api)
+        || *retry.rounds() != 2
+        || *retry.backoff_secs() != 60
+        || *retry.invalid_after() != 3
+    {
+        let clue = format!("two rounds of NIPA API errors, found {retry:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let offline = ["datasets", "params NIPA", "generate", "completions zsh"];
+    let online = [
+        "download NIPA",
+        "load NIPA",
+        "status",
+        "retry NIPA",
+        "migrate",
+    ];
+    for (args, requires_data) in offline
+        .iter()
+        .map(|args| (args, false))
+        .chain(online.iter().map(|args| (args, true)))
+    {
+        let args = args.split(' ').collect::<Vec<&str>>();
+        if parse(&args)?.command().requires_data() != requires_data {
+            let clue = format!("{args:?} requiring BEA_DATA: {requires_data}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    let invalid = [
+        vec!["download", "Nowhere"],
+        vec!["download", "NIPA", "--archive", "Weekly"],
+        vec!["values", "NIPA"],
+        vec!["status", "--stale-days", "9999999999999999"],
+        vec!["values", "refresh", "--max-age-days", "-1"],
+        vec!["retry", "NIPA", "--first", "--download"],
+        vec!["retry", "NIPA", "--kind", "Api"],
+        vec![
+            "retry",
+            "NIPA",
+            "--download",
+            "--max-age-days",
+            "99999999999",
+        ],
+        vec!["get", "NIPA", "-p", "TableName"],
+        vec!["-c", "load", "-d", "NIPA"],
+    ];
+    for args in invalid {
+        if parse(&args).is_ok() {
+            let clue = format!("rejection of {args:?}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    tracing::info!("Command line checks passed.");
+    Ok(())
+}
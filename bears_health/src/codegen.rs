@@ -0,0 +1,79 @@
+use bears_ecology::bea_data;
+use bears_species::{
+    BeaErr, KeyGenerator, KeyMissing, KeyTarget, NaicsCategory, NaicsIndustry, NaicsSector,
+    NaicsSubcategory, NaicsSubsector,
+};
+use strum::IntoEnumIterator;
+
+/// Returns a `KeyMissing` error naming the variant `variant` of `target` if `round_trip` is
+/// false.
+fn ensure_round_trip(target: KeyTarget, variant: String, round_trip: bool) -> Result<(), BeaErr> {
+    if round_trip {
+        Ok(())
+    } else {
+        let clue = format!("{target}::{variant} does not round trip through its code");
+        Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+    }
+}
+
+/// Logs a warning if the generated region of the parameter value enum `target` does not match
+/// the output of `bears generate` for the parameter values cached in `parameter_values`, or if
+/// the generator cannot read them.  Whether the drift adds unknown keys is left to the
+/// [`CheckReport`](crate::CheckReport) of the enum.
+pub(crate) fn warn_if_stale(target: KeyTarget, parameter_values: &std::path::Path) {
+    let generator = KeyGenerator::new("..".into(), Some(parameter_values.to_path_buf()));
+    match generator.is_current(target) {
+        Ok(true) => {}
+        Ok(false) => tracing::warn!("{target} is out of date, run `bears generate`."),
+        Err(source) => tracing::warn!("Could not regenerate {target}: {source}"),
+    }
+}
+
+/// Checks that the generated region of each [`KeyTarget`] matches the output of
+/// `bears generate`, so that the lookup tables have not drifted from their sources.  Enums
+/// without a generated region are still maintained by hand and are skipped.  Enums of parameter
+/// values are only checked when the `BEA_DATA` directory is available.
+///
+/// Also checks that each variant of the NAICS enums round trips through its code.
+#[tracing::instrument]
+pub fn check_generated_keys() -> Result<(), BeaErr> {
+    let parameter_values = bea_data().ok().map(|path| path.join("parameter_values"));
+    let available = parameter_values.is_some();
+    let generator = KeyGenerator::new("..".into(), parameter_values);
+    for target in KeyTarget::iter() {
+        if !generator.is_generated(target)? {
+            tracing::trace!("{target} is maintained by hand.");
+            continue;
+        }
+        if target.parameter_values().is_some() && !available {
+            tracing::trace!("Skipping {target}, BEA_DATA is not set.");
+            continue;
+        }
+        if !generator.is_current(target)? {
+            let clue = format!("{target} is out of date, run `bears generate`");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    for naics in NaicsSector::iter() {
+        let round_trip = NaicsSector::from_code(&naics.code().to_string()) == Some(naics);
+        ensure_round_trip(KeyTarget::NaicsSector, naics.to_string(), round_trip)?;
+    }
+    for naics in NaicsSubsector::iter() {
+        let round_trip = NaicsSubsector::from_code(&naics.code().to_string()) == Some(naics);
+        ensure_round_trip(KeyTarget::NaicsSubsector, naics.to_string(), round_trip)?;
+    }
+    for naics in NaicsCategory::iter() {
+        let round_trip = NaicsCategory::from_code(&naics.code().to_string()) == Some(naics);
+        ensure_round_trip(KeyTarget::NaicsCategory, naics.to_string(), round_trip)?;
+    }
+    for naics in NaicsSubcategory::iter() {
+        let round_trip = NaicsSubcategory::from_code(&naics.code().to_string()) == Some(naics);
+        ensure_round_trip(KeyTarget::NaicsSubcategory, naics.to_string(), round_trip)?;
+    }
+    for naics in NaicsIndustry::iter() {
+        let round_trip = NaicsIndustry::from_code(&naics.code().to_string()) == Some(naics);
+        ensure_round_trip(KeyTarget::NaicsIndustry, naics.to_string(), round_trip)?;
+    }
+    Ok(())
+}
@@ -136,7 +136,7 @@ pub async fn datasets_download_initial() -> Result<(), BeaErr> {
     ];
     // let datasets = vec![Dataset::Ita];
     for dataset in datasets {
-        initial_download(dataset).await?;
+        initial_download(dataset, None).await?;
     }
     Ok(())
 }
@@ -243,13 +243,21 @@ pub fn next_mne_error() -> Result<(), BeaErr> {
     trace_init()?;
     let mut queue = init_queue(Dataset::Mne)?;
     tracing::info!("Queue length: {}", queue.len());
-    let history = History::try_from((Dataset::Mne, Mode::Load))?;
+    let history = History::try_from((Dataset::Mne, Mode::Download))?;
     queue.errors(&history, Scope::History)?;
     if let Some(req) = queue.first() {
-        tracing::info!("Loading first MNE error.");
-        req.load()?;
+        match history
+            .get(&req.destination(false)?)
+            .and_then(|event| event.status().detail())
+        {
+            Some(detail) => tracing::info!("Recorded error: {detail}"),
+            None => {
+                tracing::info!("No error recorded, loading first MNE error.");
+                req.load()?;
+                tracing::info!("MNE file successfully loaded.");
+            }
+        }
     }
-    tracing::info!("MNE file successfully loaded.");
     Ok(())
 }
 
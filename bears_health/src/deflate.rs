@@ -0,0 +1,207 @@
+use crate::naics::ensure;
+use crate::transforms::approx;
+use bears_ecology::{bea_data, trace_init};
+use bears_species::{
+    BeaErr, Data, Dataset, Frequency, KeyMissing, NipaData, NipaTableName, Period, PriceIndex,
+    Quantity, Series,
+};
+
+/// Checks [`Series::deflate`] and [`PriceIndex::rebase`] against hand-computed values on a
+/// synthetic quarterly price index with reference year 2017, and the price tables matched to
+/// current-dollar tables by [`NipaTableName::price_index`].  Does not require the `BEA_DATA`
+/// directory.
+#[tracing::instrument]
+pub fn check_deflate() -> Result<(), BeaErr> {
+    let quarter =
+        |year, month| Period::new(jiff::civil::date(year, month, 1), Frequency::Quarterly);
+    let annual = |year| Period::new(jiff::civil::date(year, 1, 1), Frequency::Annual);
+    let series = |id: &str, frequency, observations: Vec<(Period, f64)>| {
+        Series::new(
+            id.to_owned(),
+            id.to_owned(),
+            frequency,
+            "Level".to_owned(),
+            Some(6),
+            observations
+                .into_iter()
+                .map(|(period, value)| (period, Quantity::Value(value)))
+                .collect(),
+        )
+    };
+    let prices = [97.0, 97.5, 98.0, 98.5, 99.0, 99.5, 100.5, 101.0];
+    let periods = [2016, 2017]
+        .into_iter()
+        .flat_map(|year| [1, 4, 7, 10].map(|month| quarter(year, month)))
+        .collect::<Vec<Period>>();
+    let prices = series(
+        "price",
+        Frequency::Quarterly,
+        periods.iter().copied().zip(prices).collect(),
+    );
+
+    if PriceIndex::new(NipaTableName::T10105, prices.clone()).is_ok() {
+        let clue = "Table 1.1.5 accepted as a price table".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let index = PriceIndex::new(NipaTableName::T10104, prices)?;
+    if *index.reference() != 2017 {
+        let clue = format!("reference year {}", index.reference());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Annual values are deflated by the mean of the quarterly index.
+    let nominal = series(
+        "nominal",
+        Frequency::Annual,
+        vec![(annual(2016), 1000.0), (annual(2017), 1100.0)],
+    );
+    let real = nominal.deflate(&index)?;
+    approx(
+        real.series().get(&annual(2016)),
+        Some(1000.0 / 97.75 * 100.0),
+        1e-9,
+        "real value in 2016",
+    )?;
+    approx(
+        real.series().get(&annual(2017)),
+        Some(1100.0),
+        1e-9,
+        "real value in the reference year",
+    )?;
+    let provenance = real.provenance();
+    if real.series().unit() != "Chained (2017) dollars"
+        || *real.series().unit_mult() != Some(6)
+        || provenance.nominal() != "nominal"
+        || provenance.index() != "price"
+        || *provenance.table() != NipaTableName::T10104
+        || *provenance.frequency() != Frequency::Annual
+    {
+        let clue = format!("provenance of real series: {provenance}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Quarterly values are deflated period by period, and unmatched periods are missing.
+    let nominal = series(
+        "nominal",
+        Frequency::Quarterly,
+        vec![(quarter(2017, 7), 201.0), (quarter(2018, 1), 205.0)],
+    );
+    let real = nominal.deflate(&index)?;
+    approx(
+        real.series().get(&quarter(2017, 7)),
+        Some(200.0),
+        1e-9,
+        "real value in 2017Q3",
+    )?;
+    if real.series().get(&quarter(2018, 1)).is_some() {
+        let clue = "real value without a price index".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let rebased = index.rebase(2016)?;
+    approx(
+        rebased.series().get(&quarter(2016, 1)),
+        Some(97.0 / 97.75 * 100.0),
+        1e-9,
+        "rebased index",
+    )?;
+    let real =
+        series("nominal", Frequency::Annual, vec![(annual(2016), 1000.0)]).deflate(&rebased)?;
+    approx(
+        real.series().get(&annual(2016)),
+        Some(1000.0),
+        1e-9,
+        "real value in the new reference year",
+    )?;
+
+    // An annual index cannot deflate a quarterly series.
+    let annual_index = PriceIndex::new(
+        NipaTableName::T10104,
+        index.at_frequency(Frequency::Annual)?,
+    )?;
+    if nominal.deflate(&annual_index).is_ok() || index.rebase(2018).is_ok() {
+        let clue = "deflation at a higher frequency than the index".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Current-dollar tables map to the price table of the same section.
+    for (nominal, price) in [
+        (NipaTableName::T10105, Some(NipaTableName::T10104)),
+        (NipaTableName::T10505, Some(NipaTableName::T10504)),
+        (NipaTableName::T20305, Some(NipaTableName::T20304)),
+        (NipaTableName::T50305, Some(NipaTableName::T50304)),
+        (NipaTableName::T10106, None),
+        (NipaTableName::T10104, None),
+    ] {
+        ensure(
+            nominal.price_index() == price && price.is_none_or(|price| price.is_price_index()),
+            &format!("price table of {nominal}: {:?}", nominal.price_index()),
+        )?;
+    }
+    Ok(())
+}
+
+/// Deflates nominal GDP from NIPA Table 1.1.5 by the price index at the same line of its price
+/// table, Table 1.1.4, and compares the result with real GDP in chained dollars from Table 1.1.6.
+///
+/// Chained-dollar GDP is computed from the Fisher quantity index rather than by deflation, so the
+/// two agree only to within rounding of the published index, about one twentieth of a percent.
+#[tracing::instrument]
+pub fn check_real_gdp() -> Result<(), BeaErr> {
+    trace_init()?;
+    let path = bea_data()?.join("data").join(Dataset::Nipa.to_string());
+    // Line 1 of each table is gross domestic product.
+    ensure(
+        NipaTableName::T10105.price_index() == Some(NipaTableName::T10104),
+        "price table of Table 1.1.5",
+    )?;
+    let gdp = |table: NipaTableName| -> Result<String, BeaErr> {
+        let data = NipaData::try_from(&path.join(format!("NIPA_{table}.json")))?;
+        match data.iter().find(|d| *d.line_number() == 1) {
+            Some(datum) => Ok(datum.series_code().to_owned()),
+            None => {
+                let clue = format!("GDP line in {table}");
+                Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+            }
+        }
+    };
+    let nominal = NipaData::try_from(&path.join("NIPA_T10105.json"))?;
+    let nominal = Data::from(nominal).series();
+    let published = NipaData::try_from(&path.join("NIPA_T10106.json"))?;
+    let published = Data::from(published).series();
+    let (price_code, nominal_code, real_code) = (
+        gdp(NipaTableName::T10104)?,
+        gdp(NipaTableName::T10105)?,
+        gdp(NipaTableName::T10106)?,
+    );
+    let mut checked = 0;
+    for frequency in [Frequency::Annual, Frequency::Quarterly] {
+        let (Some(nominal), Some(published)) = (
+            nominal.series(&nominal_code, frequency),
+            published.series(&real_code, frequency),
+        ) else {
+            continue;
+        };
+        let index =
+            PriceIndex::from_nominal(&path, NipaTableName::T10105, &nominal_code, frequency)?;
+        ensure(
+            *index.table() == NipaTableName::T10104 && index.series().id() == &price_code,
+            &format!("GDP price index, found {}", index.series().id()),
+        )?;
+        let real = nominal.deflate(&index)?;
+        tracing::info!("{}", real.provenance());
+        for (period, value) in published.values() {
+            if let Some(computed) = real.series().get(&period) {
+                approx(
+                    Some(computed / value),
+                    Some(1.0),
+                    5e-4,
+                    &format!("real GDP in {period}"),
+                )?;
+                checked += 1;
+            }
+        }
+    }
+    tracing::info!("{checked} real GDP values match Table 1.1.6.");
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use bears_ecology::{App, EstimateBasis, Options, SizeModel};
+use bears_species::{BeaErr, KeyMissing, UrlParseError};
+
+/// Checks the [`SizeModel`] on synthetic requests, confirming that each request is estimated
+/// from the most similar targets available: its nearest countries, its table family, or its
+/// dataset.  Nearest countries are ranked by MNE country code, or by region for ITA areas.  Does
+/// not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_size_estimates() -> Result<(), BeaErr> {
+    let url = "https://apps.bea.gov/api/data";
+    let url = url::Url::parse(url)
+        .map_err(|e| UrlParseError::new(url.to_string(), e, line!(), file!().into()))?;
+    let app = |params: &[(&str, &str)]| {
+        let mut app = App::new("key".to_string(), Options::default(), url.clone());
+        app.with_params(
+            params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        );
+        app
+    };
+    let mne = |country: &str| {
+        app(&[
+            ("DatasetName", "MNE"),
+            ("Country", country),
+            ("Classification", "Country"),
+            ("DirectionOfInvestment", "Outward"),
+        ])
+    };
+    let nipa = |table: &str| app(&[("DatasetName", "NIPA"), ("TableName", table)]);
+
+    let mut model = SizeModel::default();
+    for (country, size) in [("308", 100), ("309", 110), ("310", 120), ("650", 9_000)] {
+        model.observe(&mne(country), size);
+    }
+    model.observe(&nipa("T10101"), 2_000);
+    model.observe(&nipa("T10102"), 3_000);
+    model.observe(&nipa("T20100"), 50_000);
+
+    let mut known = nipa("T30100");
+    known.with_size_hint(Some(7));
+    let cases = [
+        // Outward MNE for country 311 looks like its neighbours 308 to 310, not 650.
+        (mne("311"), Some((110, 120, EstimateBasis::Neighbours, 3))),
+        (mne("308"), Some((100, 100, EstimateBasis::Known, 1))),
+        (
+            nipa("T10105"),
+            Some((2_000, 3_000, EstimateBasis::Family, 2)),
+        ),
+        (
+            nipa("U70205"),
+            Some((3_000, 50_000, EstimateBasis::Dataset, 3)),
+        ),
+        (known, Some((7, 7, EstimateBasis::Known, 1))),
+        (app(&[("DatasetName", "ITA")]), None),
+    ];
+    for (i, (app, expected)) in cases.into_iter().enumerate() {
+        let found = model.estimate(&app).map(|estimate| {
+            (
+                *estimate.size(),
+                *estimate.upper(),
+                *estimate.basis(),
+                *estimate.samples(),
+            )
+        });
+        if found != expected {
+            let clue = format!("estimate {i}: {found:?}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    // Neighbours are trusted at their median, a guess from the dataset reserves the upper size.
+    let neighbours = model.estimate(&mne("311"));
+    let dataset = model.estimate(&nipa("U70205"));
+    if neighbours.map(|estimate| estimate.limit()) != Some(110)
+        || dataset.map(|estimate| estimate.limit()) != Some(50_000)
+    {
+        let clue = format!("limits of {neighbours:?} and {dataset:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    // ITA areas in the same region as Italy are nearer than areas in Asia.
+    let ita = |area: &str| {
+        app(&[
+            ("DatasetName", "ITA"),
+            ("Indicator", "BalGds"),
+            ("AreaOrCountry", area),
+            ("Frequency", "A"),
+        ])
+    };
+    let mut model = SizeModel::default();
+    for (area, size) in [
+        ("Japan", 9_000),
+        ("France", 100),
+        ("China", 9_500),
+        ("Germany", 110),
+        ("Spain", 120),
+    ] {
+        model.observe(&ita(area), size);
+    }
+    let cases = [
+        (ita("Italy"), (110, 120, EstimateBasis::Neighbours, 3)),
+        (
+            ita("SouthKorea"),
+            (9_000, 9_500, EstimateBasis::Neighbours, 3),
+        ),
+        // International organizations have no location, so no neighbours.
+        (
+            ita("IntOrgAndUnalloc"),
+            (120, 9_500, EstimateBasis::Dataset, 5),
+        ),
+    ];
+    for (app, expected) in cases {
+        let found = model.estimate(&app).map(|estimate| {
+            (
+                *estimate.size(),
+                *estimate.upper(),
+                *estimate.basis(),
+                *estimate.samples(),
+            )
+        });
+        if found != Some(expected) {
+            let clue = format!(
+                "estimate for {:?}: {found:?}",
+                app.query().get("AreaOrCountry")
+            );
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    tracing::info!("Size estimation checks passed.");
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use crate::naics::ensure;
+use bears_species::{AocSta, AreaOrCountry, BeaErr, StateKind};
+use std::collections::BTreeSet;
+use strum::IntoEnumIterator;
+
+/// Checks the geographic metadata of [`AreaOrCountry`], [`AocSta`] and [`StateKind`]: ISO and
+/// FIPS codes round trip and are unique, continents partition `AllCountries`, residual
+/// aggregates expand to their region less the listed countries, and BEA regions hold disjoint
+/// sets of states.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_geography() -> Result<(), BeaErr> {
+    let mut alpha2 = BTreeSet::new();
+    for area in AreaOrCountry::iter().filter(|v| *v != AreaOrCountry::EastTimor) {
+        if let Some((two, three)) = area.iso() {
+            ensure(alpha2.insert(two), &format!("{two} is unique"))?;
+            ensure(
+                AreaOrCountry::from_iso(two) == Some(area)
+                    && AreaOrCountry::from_iso(&three.to_lowercase()) == Some(area),
+                &format!("{area} round trips through {two} and {three}"),
+            )?;
+        }
+    }
+    ensure(
+        AreaOrCountry::from_iso("TLS") == Some(AreaOrCountry::TimorLeste),
+        "TL parses to TimorLeste",
+    )?;
+
+    let all = AreaOrCountry::AllCountries.expand()?;
+    ensure(
+        !all.is_empty() && all.iter().all(|area| !area.is_aggregate()),
+        "AllCountries expands to countries",
+    )?;
+    let missing = AreaOrCountry::iter()
+        .filter(|area| !area.is_aggregate() && !all.contains(area))
+        .collect::<Vec<AreaOrCountry>>();
+    ensure(
+        missing
+            == vec![
+                AreaOrCountry::EastTimor,
+                AreaOrCountry::UkIslandsCarib,
+                AreaOrCountry::UnitedStates,
+            ],
+        &format!("countries missing from AllCountries: {missing:?}"),
+    )?;
+    let mut continents = 0;
+    for area in AreaOrCountry::AllCountries.members().unwrap_or_default() {
+        continents += area.expand()?.len();
+    }
+    ensure(continents == all.len(), "continents are disjoint")?;
+    let eu = AreaOrCountry::EU.expand()?;
+    let euro = AreaOrCountry::EuroArea.expand()?;
+    let cafta = AreaOrCountry::CaftaDrCountries.expand()?;
+    let latam = AreaOrCountry::LatAmAndOthWestHem.expand()?;
+    ensure(
+        eu.len() == 27 && euro.is_subset(&eu) && cafta.is_subset(&latam),
+        "EU, Euro Area and CAFTA-DR membership",
+    )?;
+    ensure(
+        AreaOrCountry::OthEuropeExcl32.expand().is_err()
+            && AreaOrCountry::AfricaOthGdsNsaDetail.expand().is_err()
+            && AreaOrCountry::Canada.expand()? == BTreeSet::from([AreaOrCountry::Canada]),
+        "residual aggregates do not expand",
+    )?;
+    // Residual aggregates expand to their region less the areas listed in the table.
+    for area in AreaOrCountry::iter() {
+        if let Some((region, _)) = area.residual_region() {
+            ensure(
+                area.is_aggregate() && region.expand().is_ok(),
+                &format!("{area} has region {region}"),
+            )?;
+        }
+    }
+    let listed = BTreeSet::from([
+        AreaOrCountry::Egypt,
+        AreaOrCountry::Nigeria,
+        AreaOrCountry::SouthAfrica,
+        AreaOrCountry::Canada,
+        AreaOrCountry::Africa,
+    ]);
+    let africa = AreaOrCountry::Africa.expand()?;
+    let other = AreaOrCountry::AfricaOthGdsNsaDetail.expand_residual(&listed)?;
+    ensure(
+        other.len() == africa.len() - 3
+            && other.is_subset(&africa)
+            && !other.contains(&AreaOrCountry::Nigeria)
+            && other.contains(&AreaOrCountry::Kenya)
+            && AreaOrCountry::OthAfricaExcl3.expand_residual(&listed)? == other
+            && AreaOrCountry::OthAfricaExcl1
+                .expand_residual(&BTreeSet::from([AreaOrCountry::SouthAfrica]))?
+                == AreaOrCountry::OthAfricaExcl1.expand()?
+            && AreaOrCountry::OthEuropeExcl7
+                .expand_residual(&listed)
+                .is_err()
+            && AreaOrCountry::Canada.expand_residual(&listed)?
+                == BTreeSet::from([AreaOrCountry::Canada]),
+        &format!("residual of Africa: {other:?}"),
+    )?;
+
+    let mut fips = BTreeSet::new();
+    for state in StateKind::iter() {
+        if let (Some(code), Some(postal)) = (state.fips(), state.postal()) {
+            ensure(fips.insert(code), &format!("FIPS {code} is unique"))?;
+            ensure(
+                StateKind::from_fips(code) == Some(state)
+                    && StateKind::from_postal(&postal.to_lowercase()) == Some(state),
+                &format!("{state} round trips through {code} and {postal}"),
+            )?;
+        }
+    }
+    let regions = StateKind::iter().filter_map(|state| state.region()).count();
+    ensure(regions == 45, &format!("{regions} states in BEA regions"))?;
+
+    let unmapped = AocSta::iter()
+        .filter(|area| area.area_or_country().is_none())
+        .collect::<Vec<AocSta>>();
+    ensure(
+        unmapped.len() == 6 && unmapped.contains(&AocSta::AfricaMidEastAndAsiaAndPac),
+        &format!("unmapped AocSta variants: {unmapped:?}"),
+    )?;
+    ensure(
+        AocSta::UnitedArabEm.iso_alpha3() == Some("ARE")
+            && AocSta::CenAm.expand()? == AreaOrCountry::CentralAmerica.expand()?,
+        "AocSta delegates to AreaOrCountry",
+    )?;
+    Ok(())
+}
@@ -1,5 +1,8 @@
-use bears_ecology::{History, Mode, trace_init};
-use bears_species::{BeaErr, Dataset};
+use bears_ecology::{
+    App, AttemptHistory, EVENT_SCHEMA, ErrorDetail, ErrorKind, Event, History, HistorySink, Mode,
+    Options, ResultStatus, RetryFilter, RetryPolicy, SkipPolicy, TelemetryConfig, trace_init,
+};
+use bears_species::{BeaErr, Dataset, IoError, Jiff, KeyMissing, Method, UrlParseError};
 
 #[tracing::instrument]
 /// Prints summary data from the Load [`History`] of the currently implemented datasets.
@@ -22,3 +25,569 @@ pub fn download_summary() -> Result<(), BeaErr> {
 
     Ok(())
 }
+
+/// Checks that [`Event`] values survive the history log: lines in the structured format are read
+/// back unchanged, lines in the older message format are read with their id, and paths containing
+/// commas and colons are not split.  Migrates a log in a temporary directory and confirms that
+/// unreadable lines are moved aside, the migrated log reads as a [`History`] and a second
+/// migration keeps the backup of the original log.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_event_log() -> Result<(), BeaErr> {
+    let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let path = "/data/NIPA, 2024/T10105:A.json";
+    let legacy = serde_json::json!({
+        "timestamp": "2024-01-02T03:04:05Z",
+        "level": "INFO",
+        "fields": {
+            "message": format!(
+                "id: {id}, length: 42, mode: Download, path: {path:?}, status: Success, time: 2024-01-02T03:04:05Z"
+            ),
+        },
+        "target": "download_history",
+    });
+    let event = Event::try_from(&legacy)?;
+    let expected = (
+        id.to_string(),
+        Some(42),
+        std::path::PathBuf::from(path),
+        "2024-01-02T03:04:05Z".to_string(),
+    );
+    let found = (
+        event.id().to_string(),
+        *event.length(),
+        event.path().clone(),
+        event.time().to_string(),
+    );
+    if found != expected || *event.status() != ResultStatus::Success(*event.id(), 42) {
+        let clue = format!("legacy event read as {event:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // The structured format reads back to the same event.
+    let json = event.to_json();
+    if json["fields"]["schema"] != EVENT_SCHEMA || Event::try_from(&json)? != event {
+        let clue = format!("structured event read from {json}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let mut newer = json.clone();
+    newer["fields"]["schema"] = (EVENT_SCHEMA + 1).into();
+    if Event::try_from(&newer).is_ok() {
+        let clue = "event with a newer schema".to_string();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Migration rewrites the legacy line and moves the unreadable one to the rejected lines.
+    let dir = std::env::temp_dir().join("bears_event_log");
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    }
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    let log = dir.join("history.log");
+    let contents = format!("{legacy}\nnot an event\n");
+    std::fs::write(&log, contents)
+        .map_err(|e| IoError::new(log.clone(), e, line!(), file!().into()))?;
+    let count = History::migrate(&log)?;
+    let migrated = std::fs::read_to_string(&log)
+        .map_err(|e| IoError::new(log.clone(), e, line!(), file!().into()))?;
+    let lines = migrated.lines().collect::<Vec<&str>>();
+    let reject = log.with_extension("log.rejected");
+    let rejected = std::fs::read_to_string(&reject)
+        .map_err(|e| IoError::new(reject, e, line!(), file!().into()))?;
+    let history = History::try_from(&log)?;
+
+    // A second migration keeps the backup of the original log.
+    let backup = log.with_extension("log.bak");
+    let original = std::fs::read_to_string(&backup)
+        .map_err(|e| IoError::new(backup.clone(), e, line!(), file!().into()))?;
+    History::migrate(&log)?;
+    let kept = std::fs::read_to_string(&backup)
+        .map_err(|e| IoError::new(backup.clone(), e, line!(), file!().into()))?;
+    if original != format!("{legacy}\nnot an event\n") || kept != original {
+        let clue = format!("backup of the original log, found {kept}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    std::fs::remove_dir_all(&dir)
+        .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    if count != 1
+        || lines.len() != 1
+        || serde_json::from_str::<serde_json::Value>(lines[0]).ok() != Some(json)
+        || rejected != "not an event\n"
+        || history.len() != 1
+    {
+        let clue = format!("migrated {count} events to {migrated}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Event log checks passed.");
+    Ok(())
+}
+
+/// Checks the statistics of [`Attempts`](bears_ecology::Attempts) and the [`SkipPolicy`] on
+/// synthetic events for three targets: one that failed repeatedly before succeeding, one that
+/// always failed, and one that failed long ago.  Also checks that load events mixed into the
+/// history do not change which download targets are skipped.  Does not require the `BEA_DATA`
+/// directory.
+#[tracing::instrument]
+pub fn check_attempts() -> Result<(), BeaErr> {
+    let count = std::cell::Cell::new(0);
+    let event = |path: &str, status: &str, day: i8| -> Result<Event, BeaErr> {
+        count.set(count.get() + 1);
+        let mut fields = serde_json::json!({
+            "schema": EVENT_SCHEMA,
+            "id": format!("00000000-0000-4000-8000-{:012}", count.get()),
+            "mode": "Download",
+            "path": path,
+            "status": status,
+            "time": format!("2024-06-{day:02}T00:00:00Z"),
+        });
+        if status == "Success" {
+            fields["length"] = 100.into();
+        }
+        Event::try_from(&serde_json::json!({ "fields": fields }))
+    };
+    let mut events = Vec::new();
+    // Fails three times, then succeeds.
+    for day in 1..=3 {
+        events.push(event("recovered.json", "Error", day)?);
+    }
+    events.push(event("recovered.json", "Success", 4)?);
+    // Fails four times.
+    for day in 1..=4 {
+        events.push(event("failing.json", "Error", day)?);
+    }
+    // Fails three times in January, skipped on a pass in June.
+    for day in 1..=3 {
+        let mut stale = event("stale.json", "Error", day)?.to_json();
+        stale["fields"]["time"] = format!("2024-01-{day:02}T00:00:00Z").into();
+        events.push(Event::try_from(&stale)?);
+    }
+    events.push(event("stale.json", "Pass", 5)?);
+    // Logged out of order, and the same event read twice from two logs.
+    events.reverse();
+    events.push(events[0].clone());
+    let history = AttemptHistory::from(events);
+
+    let now = jiff::civil::date(2024, 6, 5)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .map(|zoned| zoned.timestamp())
+        .map_err(|e| Jiff::new("2024-06-05".to_string(), e))?;
+    let policy = SkipPolicy::default();
+    let stats = |path: &str| {
+        history.get(std::path::Path::new(path)).map(|attempts| {
+            (
+                attempts.len(),
+                attempts.failures(),
+                attempts.failure_streak(jiff::Timestamp::MIN),
+                attempts
+                    .first_success()
+                    .map(|event| event.time().to_string()),
+                attempts.flakiness(),
+                policy.skip(attempts, now),
+            )
+        })
+    };
+    let cases = [
+        (
+            "recovered.json",
+            (
+                4,
+                3,
+                0,
+                Some("2024-06-04T00:00:00Z".to_string()),
+                1.0 / 3.0,
+                false,
+            ),
+        ),
+        ("failing.json", (4, 4, 4, None, 0.0, true)),
+        ("stale.json", (4, 3, 3, None, 0.0, false)),
+    ];
+    for (path, expected) in cases {
+        let found = stats(path);
+        if found != Some(expected.clone()) {
+            let clue = format!("attempts at {path}: {found:?}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    let order = history
+        .get(std::path::Path::new("recovered.json"))
+        .map(|attempts| {
+            attempts
+                .windows(2)
+                .all(|pair| pair[0].time() <= pair[1].time())
+        });
+    if order != Some(true) {
+        let clue = "attempts out of order".to_string();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Loads of the same targets neither extend nor reset the download failure streak.
+    let load = |path: &str, status: &str, day: i8| -> Result<Event, BeaErr> {
+        let mut json = event(path, status, day)?.to_json();
+        json["fields"]["mode"] = "Load".into();
+        Event::try_from(&json)
+    };
+    let mut events = Vec::new();
+    for day in 1..=3 {
+        events.push(event("downloads_failing.json", "Error", day)?);
+        events.push(event("loads_failing.json", "Success", day)?);
+        events.push(load("loads_failing.json", "Error", day)?);
+    }
+    events.push(load("downloads_failing.json", "Success", 4)?);
+    let mixed = AttemptHistory::from(events);
+    let downloads = mixed.with_mode(Mode::Download);
+    let skipped = |history: &AttemptHistory, path: &str| {
+        history
+            .get(std::path::Path::new(path))
+            .is_some_and(|attempts| policy.skip(attempts, now))
+    };
+    let found = [
+        skipped(&downloads, "downloads_failing.json"),
+        skipped(&downloads, "loads_failing.json"),
+        skipped(&mixed, "downloads_failing.json"),
+    ];
+    if found != [true, false, false] {
+        let clue = format!("skips with mixed download and load attempts: {found:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Attempt history checks passed.");
+    Ok(())
+}
+
+/// Checks that the [`ErrorDetail`] of error events survives the history log, and that
+/// [`History::errors_by_reason`] separates errors reported by the BEA server from transient
+/// failures.  Also checks that the [`SkipPolicy`] leaves out the targets that the
+/// [`RetryPolicy`] marks as permanently invalid.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_error_reasons() -> Result<(), BeaErr> {
+    let event = |n: usize, detail: Option<serde_json::Value>| -> Result<Event, BeaErr> {
+        let mut fields = serde_json::json!({
+            "schema": EVENT_SCHEMA,
+            "id": format!("00000000-0000-4000-8000-{n:012}"),
+            "mode": "Download",
+            "path": format!("target_{n}.json"),
+            "status": "Error",
+            "time": "2024-06-01T00:00:00Z",
+        });
+        if let Some(serde_json::Value::Object(detail)) = detail {
+            detail
+                .into_iter()
+                .map(|(key, value)| fields[key] = value)
+                .for_each(drop);
+        }
+        Event::try_from(&serde_json::json!({ "fields": fields }))
+    };
+    let unavailable = serde_json::json!({
+        "error_kind": "Api",
+        "error_code": 101,
+        "error_message": "Dataset not available",
+        "http_status": 200,
+    });
+    let events = vec![
+        event(1, Some(unavailable.clone()))?,
+        event(2, Some(unavailable))?,
+        event(
+            3,
+            Some(serde_json::json!({
+                "error_kind": "Transport",
+                "error_message": "timed out: target_3",
+            })),
+        )?,
+        event(
+            4,
+            Some(serde_json::json!({
+                "error_kind": "Transport",
+                "error_message": "timed out: target_4",
+            })),
+        )?,
+        // An error logged before details were recorded.
+        event(5, None)?,
+    ];
+
+    let expected = ErrorDetail::new(
+        ErrorKind::Api,
+        Some(101),
+        "Dataset not available".to_string(),
+        Some(200),
+    );
+    if events[0].status().detail() != Some(&expected)
+        || Event::try_from(&events[0].to_json())? != events[0]
+        || events[4].status().detail().is_some()
+        || !matches!(events[4].status(), ResultStatus::Error(_, None))
+    {
+        let clue = format!("error detail of {:?}", events[0]);
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let history = History::from(events);
+    let reasons = history
+        .errors_by_reason()
+        .into_iter()
+        .map(|(reason, paths)| (reason, paths.len()))
+        .collect::<Vec<(String, usize)>>();
+    let expected = vec![
+        ("Api 101: Dataset not available".to_string(), 2),
+        ("Transport".to_string(), 2),
+        ("Unrecorded".to_string(), 1),
+    ];
+    if reasons != expected {
+        let clue = format!("errors by reason: {reasons:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Error reason checks passed.");
+    Ok(())
+}
+
+/// Checks that the API key of the user never reaches the history log through a transport error.
+/// Sends a request to a closed local port with a synthetic key, and checks that neither the
+/// [`ErrorDetail`] of the failure nor the logged event contains the key, including events logged
+/// by older versions that wrote the request parameters into the message.  Does not require the
+/// `BEA_DATA` directory.
+#[tracing::instrument]
+pub async fn check_transport_redaction() -> Result<(), BeaErr> {
+    let key = "SYNTHETIC-API-KEY-0123456789";
+    let url = "http://127.0.0.1:9/api/data";
+    let url = url::Url::parse(url)
+        .map_err(|e| UrlParseError::new(url.to_string(), e, line!(), file!().into()))?;
+    let mut options = Options::default();
+    options
+        .with_dataset(Dataset::Nipa)
+        .with_method(Method::GetData);
+    let app = App::new(key.to_string(), options, url);
+    let event = Event::new("T10101.json", Mode::Download);
+    let status = app.download(*event.id()).await?;
+    let Some(detail) = status.detail() else {
+        let clue = format!("transport error detail, found {status}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    };
+    if *detail.kind() != ErrorKind::Transport
+        || detail.message().contains(key)
+        || !detail.message().contains("127.0.0.1")
+    {
+        let clue = format!("redacted transport error, found {detail:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let leaked = format!(
+        "error in get request to http://127.0.0.1:9/api/data?UserID={key} with body \
+         [(\"USERID\", \"{key}\")] at line 1 in file app.rs: error sending request for url \
+         (http://127.0.0.1:9/api/data?UserID={key})"
+    );
+    let fields = serde_json::json!({
+        "schema": EVENT_SCHEMA,
+        "id": "00000000-0000-4000-8000-000000000001",
+        "mode": "Download",
+        "path": "T10101.json",
+        "status": "Error",
+        "time": "2024-06-01T00:00:00Z",
+        "error_kind": "Transport",
+        "error_message": leaked,
+    });
+    let old = Event::try_from(&serde_json::json!({ "fields": fields }))?;
+    let line = old.to_json().to_string();
+    if line.contains(key) || !line.contains("http://127.0.0.1:9/api/data") {
+        let clue = format!("redacted history line, found {line}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Transport redaction checks passed.");
+    Ok(())
+}
+
+/// Checks which synthetic failed targets a [`RetryFilter`] selects by kind, reason and age, which
+/// targets the default [`RetryPolicy`] marks as permanently invalid after identical API errors,
+/// and that the backoff doubles between rounds.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_retry() -> Result<(), BeaErr> {
+    let count = std::cell::Cell::new(0);
+    let event = |path: &str, day: i8, detail: Option<(&str, i32)>| -> Result<Event, BeaErr> {
+        count.set(count.get() + 1);
+        let mut fields = serde_json::json!({
+            "schema": EVENT_SCHEMA,
+            "id": format!("00000000-0000-4000-8000-{:012}", count.get()),
+            "mode": "Download",
+            "path": path,
+            "status": "Error",
+            "time": format!("2024-06-{day:02}T00:00:00Z"),
+        });
+        match detail {
+            Some((kind, code)) => {
+                fields["error_kind"] = kind.into();
+                if kind == "Api" {
+                    fields["error_code"] = code.into();
+                }
+                fields["error_message"] = "Invalid parameter".into();
+            }
+            None => {
+                fields["status"] = "Success".into();
+                fields["length"] = 100.into();
+            }
+        }
+        Event::try_from(&serde_json::json!({ "fields": fields }))
+    };
+    let mut events = Vec::new();
+    // Rejected three times with the same API error.
+    for day in 1..=3 {
+        events.push(event("rejected.json", day, Some(("Api", 1)))?);
+    }
+    // Rejected three times, but with different codes.
+    for (day, code) in [(1, 1), (2, 2), (3, 2)] {
+        events.push(event("changing.json", day, Some(("Api", code)))?);
+    }
+    // Timed out, and an older timeout before a success.
+    events.push(event("timeout.json", 1, Some(("Transport", 0)))?);
+    events.push(event("timeout.json", 2, None)?);
+    events.push(event("timeout.json", 4, Some(("Transport", 0)))?);
+    // Failed once, long ago.
+    events.push(event("old.json", 1, Some(("Api", 1)))?);
+    events.push(event("recovered.json", 3, Some(("Api", 1)))?);
+    events.push(event("recovered.json", 4, None)?);
+    let history = AttemptHistory::from(events);
+
+    let now = jiff::civil::date(2024, 6, 5)
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .map(|zoned| zoned.timestamp())
+        .map_err(|e| Jiff::new("2024-06-05".to_string(), e))?;
+    let policy = RetryPolicy::default();
+    // Skips only on identical API errors, so that the failure streak rule does not hide them.
+    let window = *SkipPolicy::from(&policy).window();
+    let skip = SkipPolicy::new(usize::MAX, window, *policy.invalid_after());
+    let mut recent = RetryFilter::default();
+    recent.with_max_age(Some(jiff::SignedDuration::from_hours(24 * 3)));
+    let mut transport = RetryFilter::default();
+    transport.with_kind(Some(ErrorKind::Transport));
+    let mut code = RetryFilter::default();
+    code.with_reason(Some("Api 1:".to_string()));
+    let filters = [RetryFilter::default(), recent, transport, code];
+    // (path, identical errors, invalid, [any, recent, transport, code 1])
+    let cases = [
+        ("rejected.json", 3, true, [true, true, false, true]),
+        ("changing.json", 2, false, [true, true, false, false]),
+        ("timeout.json", 0, false, [true, true, true, false]),
+        ("old.json", 1, false, [true, false, false, true]),
+        ("recovered.json", 0, false, [false, false, false, false]),
+    ];
+    for (path, identical, invalid, selected) in cases {
+        let Some(attempts) = history.get(std::path::Path::new(path)) else {
+            let clue = format!("attempts for {path}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        };
+        let found = (
+            attempts.identical_errors(),
+            policy.is_invalid(attempts),
+            skip.skip(attempts, now),
+            filters
+                .iter()
+                .map(|filter| filter.matches(attempts, now))
+                .collect::<Vec<bool>>(),
+        );
+        if found != (identical, invalid, invalid, selected.to_vec()) {
+            let clue = format!("retry of {path} found {found:?}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    let delays = (0..4)
+        .map(|round| policy.delay(round).as_secs())
+        .collect::<Vec<u64>>();
+    if delays != [0, 60, 120, 240] {
+        let clue = format!("backoff delays {delays:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Retry checks passed.");
+    Ok(())
+}
+
+/// Checks that a [`TelemetryConfig`] routes download and load events to the logs of its
+/// [`HistorySink`] in a temporary directory, where [`AttemptHistory`] reads them back, that the
+/// dataset sink routes each event to the log of its dataset, and that library mode builds no
+/// layers.  Uses a scoped subscriber, so it does not require the
+/// `BEA_DATA` directory or replace the global subscriber.
+#[tracing::instrument]
+pub fn check_history_sinks() -> Result<(), BeaErr> {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let library = TelemetryConfig::library();
+    if !library.is_library() || !library.layers()?.is_empty() || library.init().is_err() {
+        let clue = "library mode installing layers".to_string();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let dir = std::env::temp_dir().join("bears_history_sinks");
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    }
+    let shared = dir.join("shared.log");
+    let download = dir.join("split").join("download.log");
+    let load = dir.join("split").join("load.log");
+    let datasets = dir.join("datasets");
+    let sinks = [
+        HistorySink::File(shared.clone()),
+        HistorySink::Split {
+            download: download.clone(),
+            load: load.clone(),
+        },
+        HistorySink::Dataset(datasets.clone()),
+    ];
+    let mut layers = Vec::new();
+    for sink in sinks {
+        let mut config = TelemetryConfig::library();
+        config.with_history(sink);
+        layers.extend(config.layers()?);
+    }
+    let subscriber = tracing_subscriber::registry().with(layers);
+    tracing::subscriber::with_default(subscriber, || {
+        Event::new("downloaded.json", Mode::Download).log(Mode::Download);
+        Event::new("loaded.json", Mode::Load).log(Mode::Load);
+        Event::new("data/NIPA/T10101.json", Mode::Download).log(Mode::Download);
+        Event::new("data/ITA/BalCurrAcct.json", Mode::Load).log(Mode::Load);
+        tracing::info!("Application log, not history.");
+    });
+
+    // Events of each dataset are routed to the log of that dataset and mode.
+    let dataset_log = |dataset, mode| datasets.join(History::file_name(dataset, mode));
+    let cases = [
+        (
+            shared,
+            vec![
+                "data/ITA/BalCurrAcct.json",
+                "data/NIPA/T10101.json",
+                "downloaded.json",
+                "loaded.json",
+            ],
+        ),
+        (download, vec!["data/NIPA/T10101.json", "downloaded.json"]),
+        (load, vec!["data/ITA/BalCurrAcct.json", "loaded.json"]),
+        (
+            dataset_log(Dataset::Nipa, Mode::Download),
+            vec!["data/NIPA/T10101.json"],
+        ),
+        (
+            dataset_log(Dataset::Ita, Mode::Load),
+            vec!["data/ITA/BalCurrAcct.json"],
+        ),
+        (
+            datasets.join("history.log"),
+            vec!["downloaded.json", "loaded.json"],
+        ),
+    ];
+    for (log, expected) in cases {
+        let history = AttemptHistory::try_from(&log)?;
+        let paths = history
+            .keys()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<String>>();
+        if paths != expected {
+            let clue = format!("{expected:?} in {}, found {paths:?}", log.display());
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    let unused = dataset_log(Dataset::Nipa, Mode::Load);
+    if unused.exists() {
+        let clue = format!("no log at {} without events", unused.display());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    std::fs::remove_dir_all(&dir).map_err(|e| IoError::new(dir, e, line!(), file!().into()))?;
+    tracing::info!("History sink checks passed.");
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::table_view::{TABLE_1_1_5, table_1_1_5};
+use crate::write_json;
+use bears_ecology::{bea_data, initial_load, trace_init};
+use bears_species::{BeaErr, Dataset, IipData, IipDatum, ItaData, ItaDatum, KeyMissing, Violation};
+
+/// Rounding tolerance for identities between values reported in whole millions of dollars.
+const TOLERANCE: f64 = 2.0;
+
+/// Returns an error unless `violations` match `expected`, given as the description of each
+/// identity and whether its total could be computed.
+fn expect(violations: &[Violation], expected: &[(&str, bool)], clue: &str) -> Result<(), BeaErr> {
+    let found = violations
+        .iter()
+        .map(|v| (v.identity().as_str(), v.computed().is_some()))
+        .collect::<Vec<(&str, bool)>>();
+    if found != expected {
+        violations
+            .iter()
+            .map(|v| tracing::info!("{v}"))
+            .for_each(drop);
+        let clue = format!("{clue}: {found:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    Ok(())
+}
+
+/// Checks the accounting identities of [`NipaData`](bears_species::NipaData), [`ItaData`] and
+/// [`IipData`] on synthetic data, confirming that consistent data passes and that imbalances and
+/// missing terms are reported.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_identities() -> Result<(), BeaErr> {
+    // NIPA Table 1.1.5 sums exactly.
+    let nipa = table_1_1_5(&TABLE_1_1_5)?;
+    expect(&nipa.violations(TOLERANCE), &[], "consistent Table 1.1.5")?;
+
+    // GDP overstated by 5 million in 2024.
+    let mut lines = TABLE_1_1_5;
+    lines[0].3 += 5;
+    let nipa = table_1_1_5(&lines)?;
+    let violations = nipa.violations(TOLERANCE);
+    expect(
+        &violations,
+        &[("GDP = C + I + NX + G", true)],
+        "overstated GDP",
+    )?;
+    if violations[0].references().first().map(String::as_str) != Some("line 1 (S1)")
+        || *violations[0].period() != jiff::civil::date(2024, 1, 1)
+    {
+        let clue = format!("reference of {}", violations[0]);
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Imports of services missing, as in a partial download.
+    let mut nipa = table_1_1_5(&TABLE_1_1_5)?;
+    nipa.retain(|datum| *datum.line_number() != 21);
+    let violations = nipa.violations(TOLERANCE);
+    let expected = [("Imports = goods + services", false); 2];
+    expect(&violations, &expected, "missing imports of services")?;
+
+    // ITA balances, with the balance on services off by 5 and primary income suppressed.
+    let ita = [
+        ("BalGds", "-50"),
+        ("ExpGds", "100"),
+        ("ImpGds", "150"),
+        ("BalServ", "10"),
+        ("ExpServ", "30"),
+        ("ImpServ", "25"),
+        ("BalPrimInc", "7"),
+        ("PrimIncRec", ""),
+        ("PrimIncPay", "3"),
+    ]
+    .into_iter()
+    .map(|(indicator, value)| {
+        let json = serde_json::json!({
+            "AreaOrCountry": "AllCountries",
+            "CL_UNIT": "USD",
+            "DataValue": value,
+            "Frequency": "A",
+            "Indicator": indicator,
+            "TimePeriod": "2023",
+            "TimeSeriesDescription": indicator,
+            "TimeSeriesId": indicator,
+            "UNIT_MULT": "6",
+            "Year": "2023",
+        });
+        ItaDatum::try_from(json)
+    })
+    .collect::<Result<Vec<ItaDatum>, BeaErr>>()?;
+    let violations = ItaData::from(ita).violations(TOLERANCE);
+    let expected = [("Balance on services = exports - imports", true)];
+    expect(&violations, &expected, "ITA balances")?;
+
+    // IIP changes in position, with the 2024 position inconsistent with its change and the 2024
+    // change not broken down.
+    let iip = [
+        ("2022", "Pos", "100"),
+        ("2023", "Pos", "120"),
+        ("2023", "ChgPos", "20"),
+        ("2023", "ChgPosTrans", "15"),
+        ("2023", "ChgPosOth", "5"),
+        ("2023", "ChgPosPrice", "2"),
+        ("2023", "ChgPosXRate", "1"),
+        ("2023", "ChgPosNie", "2"),
+        ("2024", "Pos", "150"),
+        ("2024", "ChgPos", "20"),
+    ]
+    .into_iter()
+    .map(|(year, component, value)| {
+        let json = serde_json::json!({
+            "CL_UNIT": "Usd",
+            "Component": component,
+            "DataValue": value,
+            "Frequency": "A",
+            "NoteRef": "",
+            "TimePeriod": year,
+            "TimeSeriesDescription": component,
+            "TimeSeriesId": format!("{component}_CurrAndDepAssets"),
+            "TypeOfInvestment": "CurrAndDepAssets",
+            "UNIT_MULT": "6",
+            "Year": year,
+        });
+        IipDatum::try_from(json)
+    })
+    .collect::<Result<Vec<IipDatum>, BeaErr>>()?;
+    let violations = IipData::from(iip).violations(TOLERANCE);
+    let expected = [
+        ("Change in position = transactions + other changes", false),
+        ("Position = prior position + change in position", true),
+    ];
+    expect(&violations, &expected, "IIP positions")?;
+    tracing::info!("Accounting identity checks passed.");
+    Ok(())
+}
+
+/// Loads the NIPA, ITA and IIP files in the download [`History`](bears_ecology::History) and
+/// checks their accounting identities, logging each violation and writing all violations to
+/// `identity_violations.json` in the `BEA_DATA` directory.
+#[tracing::instrument]
+pub async fn identity_violations() -> Result<(), BeaErr> {
+    trace_init()?;
+    let mut violations = Vec::new();
+    for dataset in [Dataset::Nipa, Dataset::Ita, Dataset::Iip] {
+        let data = initial_load(dataset, None).await?;
+        let found = data
+            .iter()
+            .flat_map(|data| data.violations(TOLERANCE))
+            .collect::<Vec<Violation>>();
+        tracing::info!("{dataset}: {} identity violations.", found.len());
+        violations.extend(found);
+    }
+    violations
+        .iter()
+        .map(|violation| tracing::warn!("{violation}"))
+        .for_each(drop);
+    let path = bea_data()?.join("identity_violations.json");
+    write_json(&violations, path)?;
+    Ok(())
+}
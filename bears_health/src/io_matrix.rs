@@ -0,0 +1,157 @@
+use bears_species::{
+    BeaErr, InputOutputCode, InputOutputData, InputOutputDatum, InputOutputTable, IoMatrix,
+    KeyMissing, Naics, NaicsSector,
+};
+use std::collections::BTreeMap;
+
+/// Cells of a synthetic Use table with three industries in two sectors, as (row, column, value).
+/// Farms and forestry belong to agriculture, and utilities is its own sector.
+const USE_TABLE: [(&str, &str, f64); 16] = [
+    ("111CA", "111CA", 20.0),
+    ("111CA", "113FF", 10.0),
+    ("111CA", "22", 0.0),
+    ("111CA", "F010", 70.0),
+    ("113FF", "111CA", 5.0),
+    ("113FF", "113FF", 10.0),
+    ("113FF", "22", 10.0),
+    ("113FF", "F010", 25.0),
+    ("22", "111CA", 10.0),
+    ("22", "113FF", 5.0),
+    ("22", "22", 20.0),
+    ("22", "F010", 65.0),
+    ("T018", "111CA", 100.0),
+    ("T018", "113FF", 50.0),
+    ("T018", "22", 100.0),
+    ("T018", "F010", 0.0),
+];
+
+/// Returns a `KeyMissing` error with the clue `clue` if `left` and `right` differ by more than
+/// `1e-9`, or if either value is missing.
+fn approx(left: Option<f64>, right: f64, clue: &str) -> Result<(), BeaErr> {
+    match left {
+        Some(left) if (left - right).abs() <= 1e-9 => Ok(()),
+        _ => {
+            let clue = format!("{clue}: {left:?} != {right}");
+            Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+        }
+    }
+}
+
+/// Builds an [`IoMatrix`] for the Use table in 2023 from `cells`, as (row, column, value).
+fn use_table(cells: &[(&str, &str, f64)]) -> Result<IoMatrix, BeaErr> {
+    let mut data = Vec::new();
+    for (row, column, value) in cells {
+        let json = serde_json::json!({
+            "ColCode": column,
+            "ColDescr": column,
+            "ColType": "Industry",
+            "DataValue": value.to_string(),
+            "NoteRef": "",
+            "RowCode": row,
+            "RowDescr": row,
+            "RowType": "Commodity",
+            "TableID": "259",
+            "Year": "2023",
+        });
+        let json = json.as_object().cloned().unwrap_or_default();
+        data.push(InputOutputDatum::read_json(&json)?);
+    }
+    Ok(IoMatrix::new(
+        &InputOutputData::from(data),
+        InputOutputTable::CommodityUseSummary,
+        jiff::civil::date(2023, 1, 1),
+    ))
+}
+
+/// Builds an [`IoMatrix`] from a synthetic Use table and checks the direct requirements, the
+/// Leontief inverse, the output and employment multipliers and the aggregation to sectors against
+/// hand-computed values.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_io_matrix() -> Result<(), BeaErr> {
+    let matrix = use_table(&USE_TABLE)?;
+    let code = |code: &str| InputOutputCode::from_value(code);
+
+    // The intermediate block excludes final demand and totals.
+    let direct = matrix.direct_requirements()?;
+    if direct.rows().len() != 3 || !direct.is_square() {
+        let clue = format!("intermediate block of {:?}", direct.rows());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    approx(
+        direct.get(&code("113FF")?, &code("22")?),
+        0.1,
+        "direct requirement of utilities for forestry",
+    )?;
+    // The Leontief inverse satisfies (I - A) L = I.
+    let inverse = direct.leontief_inverse()?;
+    let n = direct.rows().len();
+    for i in 0..n {
+        for j in 0..n {
+            let product = (0..n)
+                .map(|k| {
+                    let identity = if i == k { 1.0 } else { 0.0 };
+                    (identity - direct.values()[i * n + k]) * inverse.values()[k * n + j]
+                })
+                .sum::<f64>();
+            let expected = if i == j { 1.0 } else { 0.0 };
+            approx(Some(product), expected, &format!("(I - A) L at ({i}, {j})"))?;
+        }
+    }
+
+    // Aggregated to sectors, A = [[0.3, 0.1], [0.1, 0.2]], with inverse
+    // [[0.8, 0.1], [0.1, 0.7]] / 0.55.
+    let sectors = matrix.by_sector();
+    let agriculture =
+        InputOutputCode::from(Naics::from(NaicsSector::AgricultureForestryFishingHunting));
+    let utilities = InputOutputCode::from(Naics::from(NaicsSector::Utilities));
+    approx(
+        sectors.get(&agriculture, &agriculture),
+        45.0,
+        "agriculture inputs to agriculture",
+    )?;
+    let inverse = sectors.direct_requirements()?.leontief_inverse()?;
+    approx(
+        inverse.get(&agriculture, &utilities),
+        0.1 / 0.55,
+        "total requirement of agriculture for utilities",
+    )?;
+    let multipliers = inverse.output_multipliers();
+    approx(
+        multipliers.get(&agriculture).copied(),
+        0.9 / 0.55,
+        "agriculture output multiplier",
+    )?;
+    approx(
+        multipliers.get(&utilities).copied(),
+        0.8 / 0.55,
+        "utilities output multiplier",
+    )?;
+    let intensity = BTreeMap::from([(agriculture, 2.0), (utilities, 1.0)]);
+    let multipliers = inverse.employment_multipliers(&intensity);
+    approx(
+        multipliers.get(&agriculture).copied(),
+        1.7 / 0.55,
+        "agriculture employment multiplier",
+    )?;
+    // One dollar of final demand for utilities.
+    let impact = inverse.impact(&BTreeMap::from([(utilities, 1.0)]));
+    approx(
+        impact.get(&utilities).copied(),
+        0.7 / 0.55,
+        "impact on utilities",
+    )?;
+
+    // A matrix without the total output row has no direct requirements, and I - A is singular
+    // when A is the identity.
+    let intermediate = matrix.intermediate();
+    if intermediate.direct_requirements().is_ok() {
+        let clue = "direct requirements without total output".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let identity = use_table(&[("11", "11", 1.0)])?;
+    if identity.leontief_inverse().is_ok() {
+        let clue = "Leontief inverse of a singular matrix".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    Ok(())
+}
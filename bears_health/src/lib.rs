@@ -46,6 +46,11 @@
 //!
 //! The `check` module is divided into a series of private submodules, documented here:
 //!
+//! ### Command Line
+//!
+//! * [`check_cli`] runs the clap consistency checks on the command line of the `bears` binary and
+//!   parses each subcommand, including arguments it must reject.
+//!
 //! ### Datasets
 //!
 //! The parameter values for datasets indicate the valid range of dataset names.
@@ -94,6 +99,8 @@
 //!   the `parameter_values` folder of the `BEA_DATA` directory.
 //! * [`parameter_values_from_file`] deserializes the JSON files in the `parameter_values` folder
 //!   of the `BEA_DATA` directory into the [`BeaResponse`](crate::BeaResponse) type.
+//! * [`check_manifest`] reads back the manifest that records when each file of the
+//!   `parameter_values` folder was fetched, and checks which files a refresh would fetch again.
 //!
 //! ### Values
 //!
@@ -132,8 +139,9 @@
 //! * [`datasets_download_initial`] downloads data for each dataset into the `data` folder of the
 //!   `BEA_DATA` directory.  Tries every permutation of parameter values, including combinations that
 //!   are not implemented.  An exploratory download used to discover new datasets.  The sizes of
-//!   downloads in this method are unknown, so requests may exceed the 100MB per minute rate limit
-//!   set by the BEA server.  At present the program will abort upon receiving a
+//!   new downloads are estimated from similar targets in any earlier download history, but
+//!   estimates may fall short, so requests may exceed the 100MB per minute rate limit set by the
+//!   BEA server.  At present the program will abort upon receiving a
 //!   [`Results::RequestsExceeded`](crate::Results::RequestsExceeded) status.
 //! * [`datasets_download_with_history`] downloads data for each dataset into the `data` folder of
 //!   the `BEA_DATA` directory, using the download [`History`](crate::History) to select the subset
@@ -166,25 +174,161 @@
 //!
 //! * [`download_summary`] prints summary statistics from the download history for each dataset.
 //!   Used to generate the numbers for the progress report in the `readme.md` file.
+//! * [`check_event_log`] reads history log lines in the structured and older message formats,
+//!   and migrates a log in a temporary directory.  Does not require the `BEA_DATA` directory.
+//! * [`check_attempts`] checks the failure streak, first success and flakiness of synthetic
+//!   attempts, and which targets the default skip policy leaves out of a run.
+//! * [`check_error_reasons`] reads the details of error events from the history log and groups
+//!   errors by reason, separating errors reported by the BEA server from transient failures.
+//! * [`check_transport_redaction`] checks that transport errors written to the history log
+//!   never contain the API key of the user.
+//! * [`check_retry`] checks which failed targets a retry selects by error kind, reason and age,
+//!   and which targets it gives up on after repeated identical API errors.
+//! * [`check_history_sinks`] writes events through the history sinks of a telemetry configuration
+//!   and reads them back, including events routed to the log of their dataset, and checks that
+//!   library mode installs nothing.
+//!
+//! ### Size Estimation
+//!
+//! * [`check_size_estimates`] estimates the payload size of synthetic requests from similar
+//!   targets, checking that nearer countries and table families take precedence over the dataset.
+//!
+//! ### Vintages
+//!
+//! * [`check_vintages`] records synthetic releases of a target in a vintage archive in a temporary
+//!   directory and reads back the release current on each date.
+//!
+//! ### Metrics
+//!
+//! * [`check_metrics`] counts synthetic download and load outcomes and reads them back from a
+//!   local `/metrics` endpoint.  Requires the `metrics` feature.
+//!
+//! ### Status
+//!
+//! * [`check_status`] tallies synthetic targets in a temporary directory against download and
+//!   load histories, checking the counts of present, missing, failing, untried and stale files.
+//!
+//! ### Series
+//!
+//! * [`check_series`] extracts the time series from each loaded dataset and confirms that every
+//!   record maps to a distinct observation, detecting collisions between series identifiers.
+//!
+//! ### Transforms
+//!
+//! * [`check_transforms`] checks growth rates, shares and contributions against hand-computed
+//!   values on a synthetic series.
+//! * [`check_table_1_1_1`] reproduces every published percent change in NIPA Table 1.1.1 from the
+//!   levels in Tables 1.1.5 and 1.1.6.
+//!
+//! ### Deflation
+//!
+//! * [`check_deflate`] deflates synthetic nominal series by a synthetic quarterly price index and
+//!   rebases the index, checking the results against hand-computed values.
+//! * [`check_real_gdp`] deflates nominal GDP in NIPA Table 1.1.5 by the price index in Table 1.1.4
+//!   and compares the result with real GDP in Table 1.1.6.
+//!
+//! ### Frequency Conversion
+//!
+//! * [`check_resample`] aggregates synthetic monthly series to quarters and years by sum, average
+//!   and end of period, and checks that Denton disaggregation reproduces annual benchmarks.
+//!
+//! ### Accounting Identities
+//!
+//! * [`check_identities`] checks the identities of NIPA Table 1.1.5, the ITA balances and the IIP
+//!   changes in position on synthetic data, including imbalances and missing lines.
+//! * [`identity_violations`] checks the identities of the downloaded NIPA, ITA and IIP files and
+//!   writes the violations to `identity_violations.json`.
+//!
+//! ### Suppressed Values
+//!
+//! * [`check_suppression`] checks the value and bounds of suppressed and size-class MNE cells, and
+//!   that totals over suppressed cells, including MNE records aggregated across countries, are
+//!   flagged rather than counted as zero.
+//!
+//! ### Queries
+//!
+//! * [`check_query`] extracts series keys from synthetic NIPA and ITA data and checks the filters
+//!   of a [`Query`](bears_species::Query) against them.
+//! * [`check_index`] builds or updates the [`Index`](bears_ecology::Index) of the `BEA_DATA`
+//!   directory and loads annual GDP from it, opening a single file.
+//!
+//! ### Tables
+//!
+//! * [`check_table_view`] pivots a synthetic copy of NIPA Table 1.1.5 into a
+//!   [`NipaTableView`](bears_species::NipaTableView) and confirms the recovered indentation,
+//!   parent lines and sums of child lines.
+//!
+//! ### Input-Output
+//!
+//! * [`check_io_matrix`] builds an [`IoMatrix`](bears_species::IoMatrix) from a synthetic Use
+//!   table and checks the Leontief inverse, multipliers and sector aggregation against
+//!   hand-computed values.
+//!
+//! ### NAICS Hierarchy
+//!
+//! * [`check_naics_hierarchy`] checks parents, ancestors and descendants of NAICS codes, including
+//!   the BEA combined sectors and input-output codes, and rolls up synthetic records to sectors.
+//! * [`check_naics_concordance`] loads synthetic crosswalks between NAICS vintages and restates
+//!   synthetic records for NAICS 2017 subsectors onto NAICS 2022.
+//!
+//! ### Generated Keys
+//!
+//! * [`check_generated_keys`] confirms that the generated lookup tables of the key enums match the
+//!   output of `bears generate`, and that NAICS variants round trip through their codes.
+//!
+//! ### Health Report
+//!
+//! * [`health_report`] runs every key check against the `BEA_DATA` directory, and the NAICS
+//!   checks against the `data` directory of the workspace, and collects all drift, new codes
+//!   without variants, variants no longer returned and changed descriptions, in a
+//!   [`HealthReport`] written to `health_report.json` and logged as text.
+//! * [`check_health_report`] runs the [`HealthReport`] against a synthetic data directory with
+//!   known drift.
+//!
+//! ### Geography
+//!
+//! * [`check_geography`] checks the ISO 3166 and FIPS codes of the geographic keys, and that the
+//!   expansion of `AllCountries` into continents and of BEA regions into states is disjoint.
+//!   Residual aggregates expand to their region less the countries listed in a table.
 mod aocs;
+mod cli;
+mod codegen;
 mod components;
 mod data;
 mod datasets;
+mod deflate;
+mod estimate;
 mod fa_codes;
+mod geography;
 mod histories;
+mod identity;
 mod iip;
 mod indicators;
 mod industry_codes;
 mod investments;
 mod io_codes;
+mod io_matrix;
 mod json;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod naics;
 mod parameter_values;
 mod parameters;
+mod query;
 mod queues;
+mod report;
+mod resample;
+mod series;
+mod status;
+mod suppression;
+mod table_view;
+mod transforms;
 mod values;
+mod vintages;
 
 pub use aocs::check_aoc_sta;
+pub use cli::check_cli;
+pub use codegen::check_generated_keys;
 pub use components::check_components;
 pub use data::{
     data_from_json, data_to_json, datasets_download_initial, datasets_download_mne_initial,
@@ -192,25 +336,44 @@ pub use data::{
     datasets_retry_load, debug_gdpbyindustry, download_history, next_mne_error,
 };
 pub use datasets::{check_datasets, datasets_from_file, datasets_to_json};
+pub use deflate::{check_deflate, check_real_gdp};
+pub use estimate::check_size_estimates;
 pub use fa_codes::{check_fa_codes, check_fa_keys, fa_codes, fa_keys, get_fa_codes};
-pub use histories::download_summary;
+pub use geography::check_geography;
+pub use histories::{
+    check_attempts, check_error_reasons, check_event_log, check_history_sinks, check_retry,
+    check_transport_redaction, download_summary,
+};
+pub use identity::{check_identities, identity_violations};
 pub use iip::iip_codes;
 pub use indicators::check_indicators;
 pub use industry_codes::{check_gdp_codes, gdp_codes};
 pub use investments::check_investments;
 pub use io_codes::{check_io_codes, check_io_keys, io_codes, io_keys};
+pub use io_matrix::check_io_matrix;
 pub use json::write_json;
+#[cfg(feature = "metrics")]
+pub use metrics::check_metrics;
 pub use naics::{
-    check_naics_category, check_naics_industry, check_naics_sectors, check_naics_subcategory,
-    check_naics_subsectors,
+    check_naics_category, check_naics_concordance, check_naics_hierarchy, check_naics_industry,
+    check_naics_sectors, check_naics_subcategory, check_naics_subsectors,
 };
 pub use parameter_values::{
-    parameter_value_filtered, parameter_value_from_json, parameter_values_from_file,
-    parameter_values_to_json,
+    check_manifest, parameter_value_filtered, parameter_value_from_json,
+    parameter_values_from_file, parameter_values_to_json,
 };
 pub use parameters::{parameter_names, parameters_from_file, parameters_to_json};
+pub use query::{check_index, check_query};
 pub use queues::inspect_queues;
+pub use report::{CheckReport, Drift, DriftKind, HealthReport, check_health_report, health_report};
+pub use resample::check_resample;
+pub use series::check_series;
+pub use status::check_status;
+pub use suppression::check_suppression;
+pub use table_view::check_table_view;
+pub use transforms::{check_table_1_1_1, check_transforms};
 pub use values::{
     api_error, requests_exceeded, values_filtered, values_filtered_subset, values_gdp_filtered,
     values_ugdp_filtered,
 };
+pub use vintages::check_vintages;
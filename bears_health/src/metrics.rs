@@ -0,0 +1,81 @@
+use bears_ecology::{ErrorDetail, ErrorKind, Event, Metrics, Mode, ResultStatus};
+use bears_species::{BeaErr, Dataset, IoError, KeyMissing};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// Checks that [`Metrics`] count synthetic download and load outcomes by dataset and error kind,
+/// track the queue depth and requests in flight, and serve the counts in the OpenMetrics text
+/// format on a local `/metrics` endpoint.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub async fn check_metrics() -> Result<(), BeaErr> {
+    let metrics: &'static Metrics = Box::leak(Box::new(Metrics::new()));
+    let id = *Event::new("metrics.json", Mode::Download).id();
+    let rejected = ErrorDetail::new(ErrorKind::Api, Some(1), "Invalid".to_string(), Some(200));
+    let unreadable = ErrorDetail::new(ErrorKind::Parse, None, "Not Data".to_string(), None);
+    metrics.queue_depth(Mode::Download, 3);
+    for _ in 0..3 {
+        metrics.dequeued(Mode::Download);
+        metrics.dispatched(Mode::Download);
+    }
+    metrics.received(Mode::Download);
+    let nipa = Some(Dataset::Nipa);
+    metrics.record(nipa, Mode::Download, &ResultStatus::Success(id, 100));
+    metrics.record(nipa, Mode::Download, &ResultStatus::Success(id, 50));
+    metrics.record(
+        nipa,
+        Mode::Download,
+        &ResultStatus::Error(id, Some(rejected)),
+    );
+    metrics.record(nipa, Mode::Download, &ResultStatus::Pass(id));
+    metrics.record(nipa, Mode::Load, &ResultStatus::Error(id, Some(unreadable)));
+    // A single wait spanning two pauses.
+    metrics.rate_limit_wait();
+    metrics.rate_limit_pause(std::time::Duration::from_millis(1000));
+    metrics.rate_limit_pause(std::time::Duration::from_millis(500));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .map_err(|e| IoError::new("127.0.0.1:0".into(), e, line!(), file!().into()))?;
+    let addr = listener
+        .local_addr()
+        .map_err(|e| IoError::new("127.0.0.1:0".into(), e, line!(), file!().into()))?;
+    let server = tokio::spawn(metrics.serve(listener));
+    let get = |path: &'static str| async move {
+        let io = |e| IoError::new(addr.to_string().into(), e, line!(), file!().into());
+        let mut stream = tokio::net::TcpStream::connect(addr).await.map_err(io)?;
+        let request = format!("GET {path} HTTP/1.1\r\nHost: {addr}\r\n\r\n");
+        stream.write_all(request.as_bytes()).await.map_err(io)?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.map_err(io)?;
+        Ok::<String, BeaErr>(response)
+    };
+    let response = get("/metrics").await?;
+    let missing = get("/other").await?;
+    server.abort();
+
+    let expected = [
+        "HTTP/1.1 200 OK",
+        "application/openmetrics-text",
+        r#"bears_requests_total{dataset="NIPA",mode="Download"} 3"#,
+        r#"bears_bytes_total{dataset="NIPA",mode="Download"} 150"#,
+        r#"bears_errors_total{dataset="NIPA",mode="Download",kind="Api"} 1"#,
+        r#"bears_errors_total{dataset="NIPA",mode="Load",kind="Parse"} 1"#,
+        r#"bears_load_parse_failures_total{dataset="NIPA"} 1"#,
+        "bears_rate_limit_waits_total 1",
+        "bears_rate_limit_wait_seconds_total 1.5",
+        r#"bears_queue_depth{mode="Download"} 0"#,
+        r#"bears_in_flight{mode="Download"} 2"#,
+        "# EOF",
+    ];
+    for line in expected {
+        if !response.contains(line) {
+            let clue = format!("{line} in metrics response {response}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    if !missing.starts_with("HTTP/1.1 404") {
+        let clue = format!("404 status in response {missing}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Metrics checks passed.");
+    Ok(())
+}
@@ -1,352 +1,333 @@
-use bears_ecology::trace_init;
+use crate::CheckReport;
 use bears_species::{
-    BeaErr, KeyMissing, NaicsCategory, NaicsIndustry, NaicsItems, NaicsSector, NaicsSubcategory,
-    NaicsSubsector,
+    BeaErr, GdpData, GdpDatum, InputOutputData, InputOutputDatum, IoError, KeyMissing, Naics,
+    NaicsConcordance, NaicsLevel, NaicsRelation, NaicsSector, NaicsVintage,
 };
-use std::str::FromStr;
-use strum::IntoEnumIterator;
 
-/// Checks that each Naics sector code matches a variant of
-/// [`NaicsSector`].  The sector code is the leading two digits in the industry code.
-/// Returns an error if a value does not match a known variant.
-///
-/// Does not test that all variants in `NaicsSector` are in active use.
+/// Directory of the Census list of NAICS codes and the legacy codes, the same files read by
+/// `bears generate`, relative to the `bears_health` crate.
+pub(crate) const NAICS_DATA: &str = "../data";
+
+/// Checks the NAICS sector codes in the `naics_codes.csv` file of the workspace `data`
+/// directory against the variants of [`NaicsSector`], matching by name, description and code.
+/// Returns an error summarizing every difference.  See [`CheckReport::naics`].  Does not require
+/// the `BEA_DATA` directory.
 #[tracing::instrument]
 pub fn check_naics_sectors() -> Result<(), BeaErr> {
-    trace_init()?;
-    let path = "cave/naics_sector.csv";
-    let naics = NaicsItems::from_csv(path)?;
-    // Create vector of variants.
-    let sets: Vec<String> = NaicsSector::iter()
-        .map(|d| d.to_string())
-        // .map(|d| d.to_string().to_lowercase())
-        .collect();
-    tracing::trace!("Sets: {:#?}", sets);
-
-    for item in naics.iter() {
-        let name = item.name();
-        if !sets.contains(name) {
-            tracing::warn!("{} not a variant of NaicsSector.", name);
-            let error = KeyMissing::new(name.to_owned(), line!(), file!().to_string());
-            return Err(error.into());
-        } else {
-            // Ok to unwrap because name is in sets
-            let variant = NaicsSector::from_str(name).unwrap();
-            // check that the description matches the record for this variant
-            let desc = variant.description();
-            if desc != item.title().trim() {
-                tracing::warn!(
-                    "{desc} not the description of {variant}, expected {}.",
-                    item.title()
-                );
-                let error = KeyMissing::new(
-                    variant.description().to_owned(),
-                    line!(),
-                    file!().to_string(),
-                );
-                return Err(error.into());
-            }
-            // check that the code matches the record for this variant
-            let code = variant.code();
-            if code != *item.code() {
-                tracing::warn!(
-                    "{code} not the code of {variant}, expected {}.",
-                    item.code()
-                );
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-            if let Some(comp) = NaicsSector::from_code(&code.to_string()) {
-                if variant != comp {
-                    tracing::error!("Code {code} parses to variant {comp}, expected {variant}.");
-                    let error = KeyMissing::new(variant.to_string(), line!(), file!().to_string());
-                    return Err(error.into());
-                }
-            } else {
-                tracing::error!("Code {code} fails to parse to variant {variant}.");
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-        }
-    }
-    // all variants accounted for
-    Ok(())
+    CheckReport::naics(std::path::Path::new(NAICS_DATA), NaicsLevel::Sector).into_result()
 }
 
-/// Checks that each Naics subsector code matches a variant of
-/// [`NaicsSubsector`]. The subsector code is the leading three digits in the naics industry code.
-/// Returns an error if a value does not match a known variant.
-///
-/// Does not test that all variants in `NaicsSubsector` are in active use.
+/// Checks the NAICS subsector codes in the `naics_codes.csv` file of the workspace `data`
+/// directory against the variants of [`NaicsSubsector`], matching by name, description and code.
+/// Returns an error summarizing every difference.  See [`CheckReport::naics`].  Does not require
+/// the `BEA_DATA` directory.
 #[tracing::instrument]
 pub fn check_naics_subsectors() -> Result<(), BeaErr> {
-    trace_init()?;
-    let path = "cave/naics_subsector.csv";
-    let naics = NaicsItems::from_csv(path)?;
-    // Create vector of variants.
-    let sets: Vec<String> = NaicsSubsector::iter()
-        .map(|d| d.to_string())
-        // .map(|d| d.to_string().to_lowercase())
-        .collect();
-    tracing::trace!("Sets: {:#?}", sets);
-
-    for (index, item) in naics.iter().enumerate() {
-        let name = item.name();
-        if !sets.contains(name) {
-            tracing::warn!("Row {index}: {} not a variant of NaicsSubsector.", name);
-            let error = KeyMissing::new(name.to_owned(), line!(), file!().to_string());
-            return Err(error.into());
-        } else {
-            // Ok to unwrap because name is in sets
-            let variant = NaicsSubsector::from_str(name).unwrap();
-            // check that the description matches the record for this variant
-            let desc = variant.description();
-            if desc != item.title().trim() {
-                tracing::warn!(
-                    "{desc} not the description of {variant}, expected {}.",
-                    item.title()
-                );
-                let error = KeyMissing::new(
-                    variant.description().to_owned(),
-                    line!(),
-                    file!().to_string(),
-                );
-                return Err(error.into());
-            }
-            // check that the code matches the record for this variant
-            let code = variant.code();
-            if code != *item.code() {
-                tracing::warn!(
-                    "{code} not the code of {variant}, expected {}.",
-                    item.code()
-                );
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-            if let Some(comp) = NaicsSubsector::from_code(&code.to_string()) {
-                if variant != comp {
-                    tracing::error!("Code {code} parses to variant {comp}, expected {variant}.");
-                    let error = KeyMissing::new(variant.to_string(), line!(), file!().to_string());
-                    return Err(error.into());
-                }
-            } else {
-                tracing::error!("Code {code} fails to parse to variant {variant}.");
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-        }
-    }
-    // all variants accounted for
-    Ok(())
+    CheckReport::naics(std::path::Path::new(NAICS_DATA), NaicsLevel::Subsector).into_result()
 }
 
-/// Checks that each Naics category code matches a variant of
-/// [`NaicsCategory`]. The category code is the leading four digits in the naics industry code.
-/// Returns an error if a value does not match a known variant.
-///
-/// Does not test that all variants in `NaicsCategory` are in active use.
+/// Checks the NAICS category codes in the `naics_codes.csv` file of the workspace `data`
+/// directory against the variants of [`NaicsCategory`], matching by name, description and code.
+/// Returns an error summarizing every difference.  See [`CheckReport::naics`].  Does not require
+/// the `BEA_DATA` directory.
 #[tracing::instrument]
 pub fn check_naics_category() -> Result<(), BeaErr> {
-    trace_init()?;
-    let path = "cave/naics_category.csv";
-    let naics = NaicsItems::from_csv(path)?;
-    // Create vector of variants.
-    let sets: Vec<String> = NaicsCategory::iter()
-        .map(|d| d.to_string())
-        // .map(|d| d.to_string().to_lowercase())
-        .collect();
-    tracing::trace!("Sets: {:#?}", sets);
+    CheckReport::naics(std::path::Path::new(NAICS_DATA), NaicsLevel::Category).into_result()
+}
+
+/// Checks the NAICS subcategory codes in the `naics_codes.csv` file of the workspace `data`
+/// directory against the variants of [`NaicsSubcategory`], matching by name, description and code.
+/// Returns an error summarizing every difference.  See [`CheckReport::naics`].  Does not require
+/// the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_naics_subcategory() -> Result<(), BeaErr> {
+    CheckReport::naics(std::path::Path::new(NAICS_DATA), NaicsLevel::Subcategory).into_result()
+}
 
-    for (index, item) in naics.iter().enumerate() {
-        let name = item.name();
-        if !sets.contains(name) {
-            tracing::warn!("Row {index}: {} not a variant of NaicsCategory.", name);
-            let error = KeyMissing::new(name.to_owned(), line!(), file!().to_string());
-            return Err(error.into());
-        } else {
-            // Ok to unwrap because name is in sets
-            let variant = NaicsCategory::from_str(name).unwrap();
-            // check that the description matches the record for this variant
-            let desc = variant.description();
-            if desc != item.title().trim() {
-                tracing::warn!(
-                    "{desc} not the description of {variant}, expected {}.",
-                    item.title()
-                );
-                let error = KeyMissing::new(
-                    variant.description().to_owned(),
-                    line!(),
-                    file!().to_string(),
-                );
-                return Err(error.into());
-            }
-            // check that the code matches the record for this variant
-            let code = variant.code();
-            if code != *item.code() {
-                tracing::warn!(
-                    "{code} not the code of {variant}, expected {}.",
-                    item.code()
-                );
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-            if let Some(comp) = NaicsCategory::from_code(&code.to_string()) {
-                if variant != comp {
-                    tracing::error!("Code {code} parses to variant {comp}, expected {variant}.");
-                    let error = KeyMissing::new(variant.to_string(), line!(), file!().to_string());
-                    return Err(error.into());
-                }
-            } else {
-                tracing::error!("Code {code} fails to parse to variant {variant}.");
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-        }
+/// Checks the NAICS industry codes in the `naics_codes.csv` file of the workspace `data`
+/// directory against the variants of [`NaicsIndustry`], matching by name, description and code.
+/// Returns an error summarizing every difference.  See [`CheckReport::naics`].  Does not require
+/// the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_naics_industry() -> Result<(), BeaErr> {
+    CheckReport::naics(std::path::Path::new(NAICS_DATA), NaicsLevel::Industry).into_result()
+}
+
+/// Returns a `KeyMissing` error with the clue `clue` unless `condition` holds.
+pub(crate) fn ensure(condition: bool, clue: &str) -> Result<(), BeaErr> {
+    if condition {
+        Ok(())
+    } else {
+        Err(KeyMissing::new(clue.to_owned(), line!(), file!().to_string()).into())
     }
-    // all variants accounted for
-    Ok(())
 }
 
-/// Checks that each Naics subcategory code matches a variant of
-/// [`NaicsSubcategory`]. The subcategory code is the leading five digits in the naics industry code.
-/// Returns an error if a value does not match a known variant.
-///
-/// Does not test that all variants in `NaicsSubcategory` are in active use.
+/// Checks parent, ancestor and descendant relations of the NAICS hierarchy against the published
+/// classification and the layout of the BEA industry accounts, then rolls up synthetic
+/// GDPbyIndustry and InputOutput records to the sector level.  Does not require the `BEA_DATA`
+/// directory.
 #[tracing::instrument]
-pub fn check_naics_subcategory() -> Result<(), BeaErr> {
-    trace_init()?;
-    let path = "cave/naics_subcategory.csv";
-    let naics = NaicsItems::from_csv(path)?;
-    // Create vector of variants.
-    let sets: Vec<String> = NaicsSubcategory::iter()
-        .map(|d| d.to_string())
-        // .map(|d| d.to_string().to_lowercase())
-        .collect();
-    tracing::trace!("Sets: {:#?}", sets);
+pub fn check_naics_hierarchy() -> Result<(), BeaErr> {
+    let code = |code: &str| {
+        Naics::from_code(code)
+            .ok_or_else(|| BeaErr::from(KeyMissing::new(code.to_owned(), line!(), file!().into())))
+    };
+    let manufacturing = Naics::from(NaicsSector::Manufacturing);
+
+    // Industry 336111 rolls up through the published levels to the sector 31-33.
+    let ancestors = code("336111")?
+        .ancestors()
+        .iter()
+        .map(|naics| naics.code())
+        .collect::<Vec<String>>();
+    ensure(
+        ancestors == ["33611", "3361", "336", "31", "PGOOD", "PVT", "II"],
+        &format!("ancestors of 336111: {ancestors:?}"),
+    )?;
+    ensure(
+        code("336111")?.sector() == Some(NaicsSector::Manufacturing),
+        "sector of 336111",
+    )?;
+    ensure(
+        code("336")?.is_within(&manufacturing) && !code("336")?.is_within(&code("44")?),
+        "subsector 336 within manufacturing",
+    )?;
+    ensure(
+        manufacturing.children().contains(&code("336")?)
+            && manufacturing.children().contains(&code("31G")?),
+        "children of manufacturing",
+    )?;
+    let descendants = code("11")?.descendants();
+    ensure(
+        descendants.contains(&code("111CA")?)
+            && descendants.contains(&code("1111")?)
+            && descendants
+                .iter()
+                .all(|d| d.sector() == Some(NaicsSector::AgricultureForestryFishingHunting)),
+        "descendants of agriculture",
+    )?;
 
-    for (index, item) in naics.iter().enumerate() {
-        let name = item.name();
-        if !sets.contains(name) {
-            tracing::warn!("Row {index}: {} not a variant of NaicsSubcategory.", name);
-            let error = KeyMissing::new(name.to_owned(), line!(), file!().to_string());
-            return Err(error.into());
-        } else {
-            // Ok to unwrap because name is in sets
-            let variant = NaicsSubcategory::from_str(name).unwrap();
-            // check that the description matches the record for this variant
-            let desc = variant.description();
-            if desc.trim() != item.title().trim() {
-                tracing::warn!(
-                    "{desc} not the description of {variant}, expected {}.",
-                    item.title().trim()
-                );
-                let error = KeyMissing::new(
-                    variant.description().to_owned(),
-                    line!(),
-                    file!().to_string(),
-                );
-                return Err(error.into());
-            }
-            // check that the code matches the record for this variant
-            let code = variant.code();
-            if code != *item.code() {
-                tracing::warn!(
-                    "{code} not the code of {variant}, expected {}.",
-                    item.code()
-                );
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-            if let Some(comp) = NaicsSubcategory::from_code(&code.to_string()) {
-                if variant != comp {
-                    tracing::error!("Code {code} parses to variant {comp}, expected {variant}.");
-                    let error = KeyMissing::new(variant.to_string(), line!(), file!().to_string());
-                    return Err(error.into());
-                }
-            } else {
-                tracing::error!("Code {code} fails to parse to variant {variant}.");
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-        }
+    // BEA combined sectors and input-output codes.
+    ensure(
+        code("52")?.parent() == Some(code("FIRE")?) && code("61")?.parent() == Some(code("6")?),
+        "combined sectors",
+    )?;
+    ensure(
+        code("GFGD")?.is_within(&code("G")?)
+            && code("GFGD")?.sector() == Some(NaicsSector::PublicAdministration),
+        "government codes",
+    )?;
+    ensure(
+        code("3361MV")?.parent() == Some(code("336")?)
+            && code("311FT")?.parent() == Some(manufacturing)
+            && code("HSO")?.sector() == Some(NaicsSector::RealEstateRentalLeasing),
+        "input-output industry codes",
+    )?;
+    ensure(
+        code("F02E")?.parent() == Some(code("F020")?) && code("V001")?.parent().is_none(),
+        "final demand and value added codes",
+    )?;
+    // Supplemental codes of the international surveys pad NAICS prefixes with zeros.
+    ensure(
+        code("3110")?.parent() == Some(code("311")?)
+            && code("3242")?.parent() == Some(code("3240")?),
+        "supplemental codes",
+    )?;
+    // Every code reaches the top of the hierarchy.
+    for naics in Naics::variants() {
+        ensure(
+            naics.ancestors().len() < 12,
+            &format!("ancestors of {}", naics.code()),
+        )?;
     }
-    // all variants accounted for
+
+    // Published aggregates take precedence over their components.
+    let gdp = [
+        ("11", 15.0),
+        ("111CA", 10.0),
+        ("113FF", 5.0),
+        ("211", 7.0),
+        ("212", 3.0),
+        ("GDP", 40.0),
+    ]
+    .iter()
+    .map(|(industry, value)| {
+        let json = serde_json::json!({
+            "DataValue": value.to_string(),
+            "Frequency": "A",
+            "IndustrYDescription": industry,
+            "Industry": industry,
+            "NoteRef": "",
+            "Quarter": "2023",
+            "TableID": "1",
+            "Year": "2023",
+        });
+        GdpDatum::read_json(json.as_object().unwrap_or(&serde_json::Map::new()))
+    })
+    .collect::<Result<Vec<GdpDatum>, BeaErr>>()?;
+    let sectors = GdpData::from(gdp).roll_up(NaicsLevel::Sector);
+    let value = |industry: Naics| {
+        sectors
+            .iter()
+            .find(|d| *d.industry() == industry)
+            .map(|d| *d.data_value())
+    };
+    ensure(
+        value(code("11")?) == Some(15.0)
+            && value(code("21")?) == Some(10.0)
+            && value(code("GDP")?) == Some(40.0)
+            && sectors.len() == 3,
+        &format!("sector roll-up of GDP by industry: {sectors:?}"),
+    )?;
+
+    let io = [
+        ("111CA", "111CA", 20.0),
+        ("113FF", "111CA", 5.0),
+        ("111CA", "F010", 70.0),
+        ("113FF", "F010", 25.0),
+    ]
+    .iter()
+    .map(|(row, column, value)| {
+        let json = serde_json::json!({
+            "ColCode": column,
+            "ColDescr": column,
+            "ColType": "Industry",
+            "DataValue": value.to_string(),
+            "NoteRef": "",
+            "RowCode": row,
+            "RowDescr": row,
+            "RowType": "Commodity",
+            "TableID": "259",
+            "Year": "2023",
+        });
+        InputOutputDatum::read_json(json.as_object().unwrap_or(&serde_json::Map::new()))
+    })
+    .collect::<Result<Vec<InputOutputDatum>, BeaErr>>()?;
+    let sectors = InputOutputData::from(io).roll_up(NaicsLevel::Sector);
+    let agriculture = code("11")?;
+    let value = |column: Naics| {
+        sectors
+            .iter()
+            .find(|d| **d.row_code() == agriculture && **d.column_code() == column)
+            .map(|d| *d.data_value())
+    };
+    ensure(
+        value(agriculture) == Some(25.0)
+            && value(code("F010")?) == Some(95.0)
+            && sectors.len() == 2,
+        &format!("sector roll-up of input-output data: {sectors:?}"),
+    )?;
     Ok(())
 }
 
-/// Checks that each Naics industry code matches a variant of
-/// [`NaicsIndustry`]. The industry code is the full six-digit naics industry code.
-/// Returns an error if a value does not match a known variant.
-///
-/// Does not test that all variants in `NaicsIndustry` are in active use.
+/// Crosswalk from NAICS 2012 to NAICS 2017 for electronic shopping, in the layout expected by
+/// [`NaicsConcordance::from_csv`].
+const CROSSWALK_2012: &str = "\
+source_code,source_title,target_code,target_title
+454111,Electronic Shopping,454110,Electronic Shopping and Mail-Order Houses
+454112,Electronic Auctions,454110,Electronic Shopping and Mail-Order Houses
+454113,Mail-Order Houses,454110,Electronic Shopping and Mail-Order Houses
+";
+
+/// Crosswalk from NAICS 2017 to NAICS 2022 for nonstore retailers and pharmacies, with synthetic
+/// allocation weights for electronic shopping and a note at the foot of the file.
+const CROSSWALK_2017: &str = "\
+source_code,source_title,target_code,target_title,weight
+454110,Electronic Shopping and Mail-Order Houses,455110,Department Stores,0.6
+454110,Electronic Shopping and Mail-Order Houses,458110,Clothing and Clothing Accessories Retailers,0.4
+454210,Vending Machine Operators,445132,Vending Machine Operators,
+454310,Fuel Dealers,457210,Fuel Dealers,
+446110,Pharmacies and Drug Stores,456110,Pharmacies and Drug Retailers,
+446120,\"Cosmetics, Beauty Supplies, and Perfume Stores\",456110,Pharmacies and Drug Retailers,
+Note: weights are synthetic,,,,
+";
+
+/// Loads synthetic Census crosswalks between NAICS vintages, checks the relationships between
+/// codes and the composition of crosswalks, and restates synthetic GDPbyIndustry records for
+/// NAICS 2017 subsectors onto NAICS 2022.  Does not require the `BEA_DATA` directory.
 #[tracing::instrument]
-pub fn check_naics_industry() -> Result<(), BeaErr> {
-    trace_init()?;
-    let path = "cave/naics_industry.csv";
-    let naics = NaicsItems::from_csv(path)?;
-    // Create vector of variants.
-    let sets: Vec<String> = NaicsIndustry::iter()
-        .map(|d| d.to_string())
-        // .map(|d| d.to_string().to_lowercase())
-        .collect();
-    tracing::trace!("Sets: {:#?}", sets);
+pub fn check_naics_concordance() -> Result<(), BeaErr> {
+    let dir = std::env::temp_dir();
+    let mut concordances = Vec::new();
+    for (name, contents, source, target) in [
+        (
+            "bears_naics_2012_2017.csv",
+            CROSSWALK_2012,
+            NaicsVintage::Naics2012,
+            NaicsVintage::Naics2017,
+        ),
+        (
+            "bears_naics_2017_2022.csv",
+            CROSSWALK_2017,
+            NaicsVintage::Naics2017,
+            NaicsVintage::Naics2022,
+        ),
+    ] {
+        let path = dir.join(name);
+        std::fs::write(&path, contents)
+            .map_err(|e| IoError::new(path.clone(), e, line!(), file!().to_string()))?;
+        concordances.push(NaicsConcordance::from_csv(&path, source, target)?);
+    }
+    let (first, second) = (&concordances[0], &concordances[1]);
+    ensure(second.links().len() == 6, "note row dropped from crosswalk")?;
+    ensure(
+        first.relation(454111) == Some(NaicsRelation::Merge)
+            && second.relation(454110) == Some(NaicsRelation::Split)
+            && second.relation(446110) == Some(NaicsRelation::Merge)
+            && second.relation(454210) == Some(NaicsRelation::OneToOne)
+            && second.relation(999999).is_none(),
+        "relations between vintages",
+    )?;
+    let composed = first.then(second)?;
+    let targets = composed.targets(454112);
+    ensure(
+        targets.len() == 2
+            && (targets[&455110] - 0.6).abs() < 1e-12
+            && (targets[&458110] - 0.4).abs() < 1e-12
+            && *composed.target() == NaicsVintage::CURRENT,
+        &format!("composed crosswalk from 2012 to 2022: {targets:?}"),
+    )?;
+    ensure(second.then(first).is_err(), "crosswalks out of order")?;
 
-    for (index, item) in naics.iter().enumerate() {
-        let name = item.name();
-        if !sets.contains(name) {
-            tracing::warn!("Row {index}: {} not a variant of NaicsIndustry.", name);
-            let error = KeyMissing::new(name.to_owned(), line!(), file!().to_string());
-            return Err(error.into());
-        } else {
-            // Ok to unwrap because name is in sets
-            let variant = NaicsIndustry::from_str(name).unwrap();
-            // check that the description matches the record for this variant
-            let desc = variant.description();
-            if desc.trim() != item.title().trim() {
-                tracing::warn!(
-                    "{desc} not the description of {variant}, expected {}.",
-                    item.title().trim()
-                );
-                let error = KeyMissing::new(
-                    variant.description().to_owned(),
-                    line!(),
-                    file!().to_string(),
-                );
-                return Err(error.into());
-            }
-            // check that the code matches the record for this variant
-            let code = variant.code();
-            if code != *item.code() {
-                tracing::warn!(
-                    "{code} not the code of {variant}, expected {}.",
-                    item.code()
-                );
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-            if let Some(comp) = NaicsIndustry::from_code(&code.to_string()) {
-                if variant != comp {
-                    tracing::error!("Code {code} parses to variant {comp}, expected {variant}.");
-                    let error = KeyMissing::new(variant.to_string(), line!(), file!().to_string());
-                    return Err(error.into());
-                }
-            } else {
-                tracing::error!("Code {code} fails to parse to variant {variant}.");
-                let error =
-                    KeyMissing::new(variant.code().to_string(), line!(), file!().to_string());
-                return Err(error.into());
-            }
-        }
+    // Subsector 454 is split across four subsectors in NAICS 2022, weighting each of its three
+    // national industries equally.
+    let gdp = [("454", 90.0), ("446", 40.0), ("44RT", 500.0)]
+        .iter()
+        .map(|(industry, value)| {
+            let json = serde_json::json!({
+                "DataValue": value.to_string(),
+                "Frequency": "A",
+                "IndustrYDescription": industry,
+                "Industry": industry,
+                "NoteRef": "",
+                "Quarter": "2016",
+                "TableID": "1",
+                "Year": "2016",
+            });
+            GdpDatum::read_json(json.as_object().unwrap_or(&serde_json::Map::new()))
+        })
+        .collect::<Result<Vec<GdpDatum>, BeaErr>>()?;
+    let restated = GdpData::from(gdp).restate(second);
+    let value = |code: &str| {
+        restated
+            .iter()
+            .find(|d| d.industry().code() == code)
+            .map(|d| *d.data_value())
+            .unwrap_or_default()
+    };
+    for (code, expected) in [
+        ("455", 18.0),
+        ("458", 12.0),
+        ("445", 30.0),
+        ("457", 30.0),
+        ("456", 40.0),
+        ("44RT", 500.0),
+    ] {
+        ensure(
+            (value(code) - expected).abs() < 1e-9,
+            &format!("restated value of {code}: {}", value(code)),
+        )?;
     }
-    // all variants accounted for
+    ensure(restated.len() == 6, "restated industries")?;
     Ok(())
 }
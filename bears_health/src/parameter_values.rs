@@ -1,6 +1,8 @@
-use bears_ecology::{Request, bea_data, parameter_values, trace_init};
+use bears_ecology::{
+    Manifest, ManifestEntry, RefreshStep, Request, bea_data, parameter_values, trace_init,
+};
 use bears_species::{
-    BeaErr, BeaResponse, Dataset, IoError, ParameterName, ReqwestError, SerdeJson,
+    BeaErr, BeaResponse, Dataset, IoError, Jiff, KeyMissing, ParameterName, ReqwestError, SerdeJson,
 };
 use strum::IntoEnumIterator;
 
@@ -90,3 +92,64 @@ pub async fn parameter_value_filtered() -> Result<(), BeaErr> {
     }
     Ok(())
 }
+
+/// Checks that the [`Manifest`] of the parameter value cache survives a round trip through a
+/// temporary directory, and that [`Manifest::is_current`] skips fresh files and rejected requests
+/// while refetching stale or deleted files.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_manifest() -> Result<(), BeaErr> {
+    let dir = std::env::temp_dir().join("bears_manifest");
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    }
+    let folder = dir.join("parameter_values");
+    std::fs::create_dir_all(&folder)
+        .map_err(|e| IoError::new(folder.clone(), e, line!(), file!().into()))?;
+    let timestamp = |date: jiff::civil::Date| {
+        date.to_zoned(jiff::tz::TimeZone::UTC)
+            .map(|zoned| zoned.timestamp())
+            .map_err(|e| Jiff::new(date.to_string(), e))
+    };
+    let june = timestamp(jiff::civil::date(2024, 6, 1))?;
+    let january = timestamp(jiff::civil::date(2024, 1, 1))?;
+    let since = Some(timestamp(jiff::civil::date(2024, 3, 1))?);
+
+    let path = |name: &str| std::path::PathBuf::from(format!("parameter_values/{name}.json"));
+    for name in ["fresh", "stale"] {
+        let file = dir.join(path(name));
+        std::fs::write(&file, "{}").map_err(|e| IoError::new(file, e, line!(), file!().into()))?;
+    }
+    let step = RefreshStep::ParameterValues;
+    let mut manifest = Manifest::default();
+    manifest.insert(path("fresh"), ManifestEntry::new(step, june, 2, None));
+    manifest.insert(path("stale"), ManifestEntry::new(step, january, 2, None));
+    manifest.insert(path("deleted"), ManifestEntry::new(step, june, 2, None));
+    let error = Some("Invalid parameter".to_owned());
+    manifest.insert(path("rejected"), ManifestEntry::new(step, june, 0, error));
+    let file = Manifest::path(&dir);
+    manifest.save(&file)?;
+    let read = Manifest::try_from(&file)?;
+    if read != manifest {
+        let clue = format!("manifest read back as {read:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let cases = [
+        ("fresh", since, true),
+        ("stale", since, false),
+        ("stale", None, true),
+        ("deleted", None, false),
+        ("rejected", since, true),
+        ("missing", None, false),
+    ];
+    for (name, since, expected) in cases {
+        if read.is_current(&dir, &path(name), since) != expected {
+            let clue = format!("{name} file current since {since:?} is not {expected}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    std::fs::remove_dir_all(&dir).map_err(|e| IoError::new(dir, e, line!(), file!().into()))?;
+    tracing::info!("Manifest checks passed.");
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::table_view::{TABLE_1_1_5, table_1_1_5};
+use bears_ecology::{Index, trace_init};
+use bears_species::{
+    AreaOrCountry, BeaErr, Data, Dataset, Frequency, ItaData, ItaDatum, KeyMissing, Naics,
+    NaicsSector, NaicsSubsector, Query, SeriesKey,
+};
+
+/// Checks the [`SeriesKey`] values extracted from synthetic NIPA and ITA data, and the filters of
+/// [`Query`] against them.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_query() -> Result<(), BeaErr> {
+    let nipa = Data::from(table_1_1_5(&TABLE_1_1_5)?).keys();
+    if nipa.len() != TABLE_1_1_5.len() {
+        let clue = format!("{} keys in Table 1.1.5", nipa.len());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let count = |keys: &[SeriesKey], dataset, query: &Query| {
+        keys.iter()
+            .filter(|key| query.matches(dataset, key))
+            .count()
+    };
+    let year = |year| Some(jiff::civil::date(year, 1, 1));
+    let cases = [
+        (Query::default(), 26),
+        (Query::default().with_table("T10105"), 26),
+        (Query::default().with_table("T10106"), 0),
+        (Query::default().with_series("S1"), 1),
+        (Query::default().with_frequency(Frequency::Quarterly), 0),
+        (Query::default().with_range(year(2024), None), 26),
+        (Query::default().with_range(year(2025), None), 0),
+        (Query::default().with_range(None, year(2022)), 0),
+        (Query::default().with_dataset(Dataset::Ita), 0),
+    ];
+    for (i, (query, expected)) in cases.iter().enumerate() {
+        let found = count(&nipa, Dataset::Nipa, query);
+        if found != *expected {
+            let clue = format!("NIPA query {i} matched {found}, expected {expected}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    // Two areas of the same indicator, the second quarterly only.
+    let ita = [("AllCountries", "A", "2023"), ("Canada", "QSA", "2023Q1")]
+        .into_iter()
+        .map(|(area, frequency, period)| {
+            let json = serde_json::json!({
+                "AreaOrCountry": area,
+                "CL_UNIT": "USD",
+                "DataValue": "100",
+                "Frequency": frequency,
+                "Indicator": "ExpGds",
+                "TimePeriod": period,
+                "TimeSeriesDescription": "Exports of goods",
+                "TimeSeriesId": format!("ExpGds_{area}"),
+                "UNIT_MULT": "6",
+                "Year": "2023",
+            });
+            ItaDatum::try_from(json)
+        })
+        .collect::<Result<Vec<ItaDatum>, BeaErr>>()?;
+    let ita = Data::from(ItaData::from(ita)).keys();
+    let cases = [
+        (Query::default().with_area(AreaOrCountry::Canada), 1),
+        (
+            Query::default()
+                .with_area(AreaOrCountry::Canada)
+                .with_frequency(Frequency::Annual),
+            0,
+        ),
+        (Query::default().with_table("T10105"), 0),
+    ];
+    for (i, (query, expected)) in cases.iter().enumerate() {
+        let found = count(&ita, Dataset::Ita, query);
+        if found != *expected {
+            let clue = format!("ITA query {i} matched {found}, expected {expected}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    // An industry filter matches the industries within it.
+    let key = |industry: Naics| {
+        let date = jiff::civil::date(2023, 1, 1);
+        SeriesKey::new(
+            "synthetic".to_owned(),
+            Frequency::Annual,
+            None,
+            Some(industry),
+            None,
+            date,
+            date,
+        )
+    };
+    let subsector = key(Naics::from(NaicsSubsector::ChemicalManufacturing));
+    let manufacturing = Query::default().with_industry(Naics::from(NaicsSector::Manufacturing));
+    let mining =
+        Query::default().with_industry(Naics::from(NaicsSector::MiningQuarryingOilGasExtraction));
+    if !manufacturing.matches(Dataset::GDPbyIndustry, &subsector)
+        || mining.matches(Dataset::GDPbyIndustry, &subsector)
+    {
+        let clue = "industry filter on a subsector".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Query checks passed.");
+    Ok(())
+}
+
+/// Builds or updates the [`Index`] of the `BEA_DATA` directory and queries it for annual GDP in
+/// NIPA Table 1.1.5, confirming that the query opens a single file.
+#[tracing::instrument]
+pub fn check_index() -> Result<(), BeaErr> {
+    trace_init()?;
+    let index = Index::from_env()?;
+    let query = Query::default()
+        .with_dataset(Dataset::Nipa)
+        .with_table("T10105")
+        .with_frequency(Frequency::Annual);
+    let files = index.search(&query);
+    if files.len() != 1 {
+        let clue = format!("{} files hold Table 1.1.5", files.len());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let series = index.query(&query)?;
+    if series.is_empty() {
+        let clue = "annual series of Table 1.1.5".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("{} series loaded from the index.", series.len());
+    Ok(())
+}
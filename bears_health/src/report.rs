@@ -0,0 +1,604 @@
+use crate::naics::{NAICS_DATA, ensure};
+use crate::write_json;
+use bears_ecology::{bea_data, trace_init};
+use bears_species::{
+    AocSta, BeaErr, BeaResponse, Component, FixedAssetTable, Indicator, Investment, IoError,
+    KeyMissing, NaicsCategory, NaicsIndustry, NaicsItems, NaicsLevel, NaicsSector,
+    NaicsSubcategory, NaicsSubsector, SerdeJson,
+};
+use strum::IntoEnumIterator;
+
+/// The `DriftKind` enum classifies a difference between a source of codes and the variants of a
+/// key enum.
+///
+/// * **Added** - The source reports a code without a matching variant.
+/// * **Retired** - A variant has no matching code in the source.
+/// * **Description** - The description of the code differs from the description of the variant.
+/// * **Code** - The NAICS code in the source parses to a different variant, or to none.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum DriftKind {
+    Added,
+    Retired,
+    Description,
+    Code,
+}
+
+/// The `Drift` struct records a single difference between a source of codes and a key enum.
+///
+/// * **kind** - The kind of difference.
+/// * **key** - Key in the source, or the variant name for retired variants.
+/// * **expected** - Value held by the enum, if any.
+/// * **found** - Value reported by the source, if any.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Drift {
+    kind: DriftKind,
+    key: String,
+    expected: Option<String>,
+    found: Option<String>,
+}
+
+impl std::fmt::Display for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = self.kind.to_string().to_lowercase();
+        write!(f, "{kind:<12} {}", self.key)?;
+        match (&self.expected, &self.found) {
+            (Some(expected), Some(found)) => write!(f, ": {expected:?} -> {found:?}"),
+            (Some(value), None) | (None, Some(value)) => write!(f, ": {value:?}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// A code reported by the source of a check.
+struct Reported {
+    key: String,
+    description: String,
+    code: Option<i64>,
+}
+
+/// The `CheckReport` struct holds the drift between one key enum and its source.
+///
+/// * **check** - Name of the key enum checked.
+/// * **source** - Path of the source file.
+/// * **drift** - Differences between the source and the enum, sorted by kind and key.
+/// * **error** - Error reading the source, if any.  Drift is empty when the source is unreadable.
+#[derive(
+    Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct CheckReport {
+    check: String,
+    source: std::path::PathBuf,
+    drift: Vec<Drift>,
+    error: Option<String>,
+}
+
+impl CheckReport {
+    /// Compares the NAICS enum at `level` with the `naics_codes.csv` file in `data_dir`, adding
+    /// the `naics_legacy_codes.csv` file when present.  Variants are matched by name.
+    #[tracing::instrument]
+    pub fn naics(data_dir: &std::path::Path, level: NaicsLevel) -> Self {
+        let check = match level {
+            NaicsLevel::Sector => "NaicsSector",
+            NaicsLevel::Subsector => "NaicsSubsector",
+            NaicsLevel::Category => "NaicsCategory",
+            NaicsLevel::Subcategory => "NaicsSubcategory",
+            NaicsLevel::Industry => "NaicsIndustry",
+        };
+        let source = data_dir.join("naics_codes.csv");
+        let read = || -> Result<Vec<Reported>, BeaErr> {
+            let mut items = NaicsItems::from_csv(&source)?;
+            let legacy = data_dir.join("naics_legacy_codes.csv");
+            if legacy.exists() {
+                items.extend(NaicsItems::from_csv(legacy)?.to_vec());
+            }
+            Ok(items
+                .iter()
+                .filter(|item| item.code().to_string().len() == level.digits())
+                .map(|item| Reported {
+                    key: item.name().to_owned(),
+                    description: item.title().to_owned(),
+                    code: Some(*item.code()),
+                })
+                .collect())
+        };
+        let known = match level {
+            NaicsLevel::Sector => variants(NaicsSector::iter(), NaicsSector::description),
+            NaicsLevel::Subsector => variants(NaicsSubsector::iter(), NaicsSubsector::description),
+            NaicsLevel::Category => variants(NaicsCategory::iter(), NaicsCategory::description),
+            NaicsLevel::Subcategory => {
+                variants(NaicsSubcategory::iter(), NaicsSubcategory::description)
+            }
+            NaicsLevel::Industry => variants(NaicsIndustry::iter(), NaicsIndustry::description),
+        };
+        let from_code = |code: i64| -> Option<String> {
+            let code = code.to_string();
+            match level {
+                NaicsLevel::Sector => NaicsSector::from_code(&code).map(|v| v.to_string()),
+                NaicsLevel::Subsector => NaicsSubsector::from_code(&code).map(|v| v.to_string()),
+                NaicsLevel::Category => NaicsCategory::from_code(&code).map(|v| v.to_string()),
+                NaicsLevel::Subcategory => {
+                    NaicsSubcategory::from_code(&code).map(|v| v.to_string())
+                }
+                NaicsLevel::Industry => NaicsIndustry::from_code(&code).map(|v| v.to_string()),
+            }
+        };
+        let resolve = |key: &str| {
+            known
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|v| v.0.clone())
+        };
+        Self::compare(check, source.clone(), read(), &known, resolve, from_code)
+    }
+
+    /// Compares the variants of the `Indicator` enum with the ITA "Indicator" parameter values.
+    pub fn indicators(parameter_values: &std::path::Path) -> Self {
+        let known = variants(Indicator::iter(), Indicator::description);
+        Self::parameter(
+            "Indicator",
+            parameter_values,
+            "ITA_Indicator_values.json",
+            known,
+        )
+    }
+
+    /// Compares the variants of the `Investment` enum with the IIP "TypeOfInvestment" parameter
+    /// values.
+    pub fn investments(parameter_values: &std::path::Path) -> Self {
+        let known = variants(Investment::iter(), Investment::description);
+        let file = "IIP_TypeOfInvestment_values.json";
+        Self::parameter("Investment", parameter_values, file, known)
+    }
+
+    /// Compares the variants of the `Component` enum with the IIP "Component" parameter values.
+    pub fn components(parameter_values: &std::path::Path) -> Self {
+        let known = variants(Component::iter(), Component::description);
+        Self::parameter(
+            "Component",
+            parameter_values,
+            "IIP_Component_values.json",
+            known,
+        )
+    }
+
+    /// Compares the variants of the `FixedAssetTable` enum with the FixedAssets "TableName"
+    /// parameter values.
+    pub fn fixed_asset_tables(parameter_values: &std::path::Path) -> Self {
+        let known = variants(FixedAssetTable::iter(), FixedAssetTable::description);
+        let file = "FixedAssets_TableName_values.json";
+        Self::parameter("FixedAssetTable", parameter_values, file, known)
+    }
+
+    /// Compares the variants of the `AocSta` enum with the IntlServSTA "AreaOrCountry" parameter
+    /// values.  Keys are matched with [`AocSta::from_key`], which ignores whitespace.
+    pub fn aoc_sta(parameter_values: &std::path::Path) -> Self {
+        let known = variants(AocSta::iter(), AocSta::description);
+        let source = parameter_values.join("IntlServSTA_AreaOrCountry_values.json");
+        let resolve = |key: &str| AocSta::from_key(key).ok().map(|v| v.to_string());
+        let reported = read_parameter_values(&source);
+        Self::compare("AocSta", source, reported, &known, resolve, |_| None)
+    }
+
+    /// Compares `known` variants with the parameter values in the file `file` of the
+    /// `parameter_values` directory.  Keys match variant names without regard to case.
+    fn parameter(
+        check: &str,
+        parameter_values: &std::path::Path,
+        file: &str,
+        known: Vec<(String, String)>,
+    ) -> Self {
+        let source = parameter_values.join(file);
+        let resolve = |key: &str| {
+            known
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|v| v.0.clone())
+        };
+        let reported = read_parameter_values(&source);
+        Self::compare(check, source.clone(), reported, &known, resolve, |_| None)
+    }
+
+    /// Collects the drift between the `reported` codes and the `known` variants, as pairs of
+    /// name and description.  `resolve` maps a reported key to the name of its variant, and
+    /// `from_code` maps a reported code to the name of the variant it parses to.
+    fn compare(
+        check: &str,
+        source: std::path::PathBuf,
+        reported: Result<Vec<Reported>, BeaErr>,
+        known: &[(String, String)],
+        resolve: impl Fn(&str) -> Option<String>,
+        from_code: impl Fn(i64) -> Option<String>,
+    ) -> Self {
+        let reported = match reported {
+            Ok(reported) => reported,
+            Err(e) => {
+                return Self {
+                    check: check.to_owned(),
+                    source,
+                    drift: Vec::new(),
+                    error: Some(e.to_string()),
+                };
+            }
+        };
+        let mut drift = std::collections::BTreeSet::new();
+        let mut matched = std::collections::BTreeSet::new();
+        for item in &reported {
+            let found = item.description.trim().to_owned();
+            let Some(name) = resolve(&item.key) else {
+                drift.insert(Drift::new(
+                    DriftKind::Added,
+                    item.key.clone(),
+                    None,
+                    Some(found),
+                ));
+                continue;
+            };
+            matched.insert(name.clone());
+            if let Some((_, expected)) = known.iter().find(|(known, _)| *known == name)
+                && expected.trim() != found
+            {
+                drift.insert(Drift::new(
+                    DriftKind::Description,
+                    item.key.clone(),
+                    Some(expected.to_owned()),
+                    Some(found),
+                ));
+            }
+            if let Some(code) = item.code {
+                let parsed = from_code(code);
+                if parsed.as_ref() != Some(&name) {
+                    drift.insert(Drift::new(
+                        DriftKind::Code,
+                        code.to_string(),
+                        Some(name),
+                        parsed,
+                    ));
+                }
+            }
+        }
+        known
+            .iter()
+            .filter(|(name, _)| !matched.contains(name))
+            .map(|(name, description)| {
+                drift.insert(Drift::new(
+                    DriftKind::Retired,
+                    name.to_owned(),
+                    Some(description.to_owned()),
+                    None,
+                ))
+            })
+            .for_each(drop);
+        Self {
+            check: check.to_owned(),
+            source,
+            drift: drift.into_iter().collect(),
+            error: None,
+        }
+    }
+
+    /// Whether the source was read without error and matches the enum.
+    pub fn is_clean(&self) -> bool {
+        self.error.is_none() && self.drift.is_empty()
+    }
+
+    /// Returns a [`KeyMissing`] error summarizing every difference if the report is not clean.
+    pub fn into_result(self) -> Result<(), BeaErr> {
+        if self.is_clean() {
+            return Ok(());
+        }
+        for drift in &self.drift {
+            tracing::warn!("{}: {drift}", self.check);
+        }
+        let clue = match &self.error {
+            Some(error) => format!("{} could not be checked: {error}", self.check),
+            None => format!("{} has drifted in {} places", self.check, self.drift.len()),
+        };
+        Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+    }
+
+    /// Returns a [`KeyMissing`] error if the source could not be read, or if it reports keys
+    /// without a matching variant.  Retired variants and changed descriptions do not prevent the
+    /// enum from reading the source, so they are logged as warnings instead.
+    pub fn into_known_result(self) -> Result<(), BeaErr> {
+        for drift in &self.drift {
+            tracing::warn!("{}: {drift}", self.check);
+        }
+        if let Some(error) = &self.error {
+            let clue = format!("{} could not be checked: {error}", self.check);
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+        let unknown = self
+            .drift
+            .iter()
+            .filter(|drift| drift.kind == DriftKind::Added)
+            .map(|drift| drift.key.as_str())
+            .collect::<Vec<&str>>();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            let clue = format!("{} is missing keys {}", self.check, unknown.join(", "));
+            Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+        }
+    }
+}
+
+/// Pairs the name and description of each variant in `iter`.
+fn variants<T: std::fmt::Display>(
+    iter: impl Iterator<Item = T>,
+    description: impl Fn(&T) -> &'static str,
+) -> Vec<(String, String)> {
+    iter.map(|v| (v.to_string(), description(&v).to_owned()))
+        .collect()
+}
+
+/// Reads the keys and descriptions of a cached parameter values file.
+fn read_parameter_values(path: &std::path::Path) -> Result<Vec<Reported>, BeaErr> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| IoError::new(path.to_owned(), e, line!(), file!().into()))?;
+    let rdr = std::io::BufReader::new(file);
+    let json: serde_json::Value = serde_json::from_reader(rdr)
+        .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+    let data = BeaResponse::try_from(&json)?;
+    let mut reported = Vec::new();
+    if let Some(values) = data.parameter_values() {
+        values
+            .iter()
+            .filter_map(|value| value.parameter_fields())
+            .map(|fields| {
+                reported.push(Reported {
+                    key: fields.key().to_owned(),
+                    description: fields.desc().to_owned(),
+                    code: None,
+                })
+            })
+            .for_each(drop);
+    }
+    Ok(reported)
+}
+
+/// The `HealthReport` struct runs every key check against a data directory and collects all
+/// drift, rather than stopping at the first mismatch, so that a single run after a BEA release
+/// lists every new code, retired variant and changed description.
+///
+/// * **data_dir** - Directory holding the `parameter_values` directory, usually `BEA_DATA`.
+/// * **naics_dir** - Directory holding `naics_codes.csv`, usually the `data` directory of the
+///   workspace.
+/// * **checks** - Report of each check.
+#[derive(
+    Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct HealthReport {
+    data_dir: std::path::PathBuf,
+    naics_dir: std::path::PathBuf,
+    checks: Vec<CheckReport>,
+}
+
+impl HealthReport {
+    /// Runs the NAICS checks against `naics_dir` and the parameter value checks against
+    /// `data_dir`.
+    #[tracing::instrument]
+    pub fn new(data_dir: std::path::PathBuf, naics_dir: std::path::PathBuf) -> Self {
+        let parameter_values = data_dir.join("parameter_values");
+        let mut checks = NaicsLevel::iter()
+            .map(|level| CheckReport::naics(&naics_dir, level))
+            .collect::<Vec<CheckReport>>();
+        checks.push(CheckReport::indicators(&parameter_values));
+        checks.push(CheckReport::investments(&parameter_values));
+        checks.push(CheckReport::components(&parameter_values));
+        checks.push(CheckReport::aoc_sta(&parameter_values));
+        checks.push(CheckReport::fixed_asset_tables(&parameter_values));
+        Self {
+            data_dir,
+            naics_dir,
+            checks,
+        }
+    }
+
+    /// Whether every check ran without error and found no drift.
+    pub fn is_clean(&self) -> bool {
+        self.checks.iter().all(|check| check.is_clean())
+    }
+
+    /// Total number of differences across all checks.
+    pub fn drift_count(&self) -> usize {
+        self.checks.iter().map(|check| check.drift.len()).sum()
+    }
+
+    /// Writes the report as JSON to `path`.
+    pub fn to_json<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), BeaErr> {
+        write_json(self, path)
+    }
+
+    /// Renders the report as text, with a line for each check followed by its drift.
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "Health report for {}: {} differences\n",
+            self.data_dir.display(),
+            self.drift_count()
+        );
+        for check in &self.checks {
+            let status = match &check.error {
+                Some(error) => format!("error: {error}"),
+                None if check.drift.is_empty() => "ok".to_owned(),
+                None => format!("{} differences", check.drift.len()),
+            };
+            text.push_str(&format!(
+                "{} ({}): {status}\n",
+                check.check,
+                check.source.display()
+            ));
+            for drift in &check.drift {
+                text.push_str(&format!("    {drift}\n"));
+            }
+        }
+        text
+    }
+}
+
+impl std::fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+/// Runs the [`HealthReport`] against the `BEA_DATA` directory and the NAICS codes in the `data`
+/// directory of the workspace, writes it to `health_report.json` in the `BEA_DATA` directory and
+/// prints the text version to the console.
+#[tracing::instrument]
+pub fn health_report() -> Result<HealthReport, BeaErr> {
+    trace_init()?;
+    let path = bea_data()?;
+    let report = HealthReport::new(path.clone(), NAICS_DATA.into());
+    report.to_json(path.join("health_report.json"))?;
+    tracing::info!("{report}");
+    Ok(report)
+}
+
+/// Builds a data directory from the NAICS code lists in the `data` directory and synthetic ITA
+/// "Indicator" parameter values, each with one code removed, one description changed and one new
+/// code, and checks that the [`HealthReport`] collects exactly that drift.  Does not require the
+/// `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_health_report() -> Result<(), BeaErr> {
+    let data_dir = std::env::temp_dir().join("bears_health_report");
+    let parameter_values = data_dir.join("parameter_values");
+    std::fs::create_dir_all(&parameter_values)
+        .map_err(|e| IoError::new(parameter_values.clone(), e, line!(), file!().into()))?;
+    let write = |path: std::path::PathBuf, contents: String| {
+        std::fs::write(&path, contents).map_err(|e| IoError::new(path, e, line!(), file!().into()))
+    };
+
+    // Drop the first sector, retitle the second and report a new one.
+    let path = std::path::PathBuf::from("../data/naics_codes.csv");
+    let codes = std::fs::read_to_string(&path)
+        .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+    let mut lines = codes
+        .lines()
+        .map(|line| line.to_owned())
+        .collect::<Vec<String>>();
+    let retired = lines.remove(1);
+    lines[1] = lines[1].replacen("Mining", "Mines", 1);
+    lines.push("98,Synthetic Sector,SyntheticSector".to_owned());
+    write(data_dir.join("naics_codes.csv"), lines.join("\n"))?;
+    let path = std::path::PathBuf::from("../data/naics_legacy_codes.csv");
+    let legacy = std::fs::read_to_string(&path)
+        .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+    write(data_dir.join("naics_legacy_codes.csv"), legacy)?;
+
+    // Likewise for the indicators.
+    let mut values = Indicator::iter()
+        .skip(1)
+        .map(|v| serde_json::json!({"Key": v.to_string(), "Desc": v.description()}))
+        .collect::<Vec<serde_json::Value>>();
+    values[0]["Desc"] = serde_json::json!("Synthetic description");
+    values.push(serde_json::json!({"Key": "SyntheticIndicator", "Desc": "Synthetic indicator"}));
+    let json = serde_json::json!({"BEAAPI": {
+        "Request": {"RequestParam": [
+            {"ParameterName": "USERID", "ParameterValue": "synthetic"},
+            {"ParameterName": "METHOD", "ParameterValue": "GETPARAMETERVALUES"},
+            {"ParameterName": "DATASETNAME", "ParameterValue": "ITA"},
+            {"ParameterName": "PARAMETERNAME", "ParameterValue": "Indicator"},
+            {"ParameterName": "RESULTFORMAT", "ParameterValue": "JSON"},
+        ]},
+        "Results": {"ParamValue": values},
+    }});
+    write(
+        parameter_values.join("ITA_Indicator_values.json"),
+        json.to_string(),
+    )?;
+
+    let report = HealthReport::new(data_dir.clone(), data_dir.clone());
+    tracing::info!("{report}");
+    let sectors = &report.checks()[0];
+    let retired = retired.rsplit(',').next().unwrap_or_default().to_owned();
+    let kinds = sectors
+        .drift()
+        .iter()
+        .map(|d| (*d.kind(), d.key().to_owned()))
+        .collect::<Vec<(DriftKind, String)>>();
+    ensure(
+        sectors.error().is_none()
+            && kinds
+                == vec![
+                    (DriftKind::Added, "SyntheticSector".to_owned()),
+                    (DriftKind::Retired, retired),
+                    (
+                        DriftKind::Description,
+                        "MiningQuarryingOilGasExtraction".to_owned(),
+                    ),
+                ],
+        &format!("sector drift: {kinds:?}"),
+    )?;
+    ensure(
+        report.checks()[1..5].iter().all(|check| check.is_clean()),
+        "NAICS code lists match the generated keys",
+    )?;
+    let indicators = &report.checks()[5];
+    let mut kinds = indicators
+        .drift()
+        .iter()
+        .map(|d| *d.kind())
+        .collect::<Vec<DriftKind>>();
+    kinds.sort();
+    ensure(
+        indicators.error().is_none()
+            && kinds == vec![DriftKind::Added, DriftKind::Retired, DriftKind::Description],
+        &format!("indicator drift: {:?}", indicators.drift()),
+    )?;
+    // Only the new indicator fails the check, the retired and retitled indicators are warnings.
+    ensure(
+        indicators.clone().into_known_result().is_err(),
+        "new indicator fails the check",
+    )?;
+    let mut known = indicators.clone();
+    known.drift.retain(|drift| drift.kind != DriftKind::Added);
+    ensure(
+        known.into_known_result().is_ok(),
+        "retired and retitled indicators are warnings",
+    )?;
+    // Parameter values missing from the directory are reported as errors, not drift.
+    ensure(
+        report.checks()[6..]
+            .iter()
+            .all(|check| check.error().is_some() && check.drift().is_empty()),
+        "missing parameter values",
+    )?;
+    ensure(!report.is_clean(), "report is not clean")?;
+    ensure(report.drift_count() == 6, "drift count")?;
+    let path = data_dir.join("health_report.json");
+    report.to_json(&path)?;
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+    let round_trip: HealthReport =
+        serde_json::from_str(&text).map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+    ensure(round_trip == report, "JSON round trip")?;
+    ensure(
+        report.to_text().contains("retired      "),
+        "text report lists drift",
+    )?;
+    Ok(())
+}
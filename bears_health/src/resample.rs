@@ -0,0 +1,147 @@
+use crate::transforms::approx;
+use bears_species::{
+    Aggregation, BeaErr, BeaErrorKind, Dataset, Frequency, KeyMissing, Period, Quantity, Series,
+};
+
+/// Checks [`Series::aggregate`], [`Series::disaggregate`] and [`Series::interpolate`] against
+/// hand-computed values on synthetic monthly, quarterly and annual series.  Does not require the
+/// `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_resample() -> Result<(), BeaErr> {
+    let period = |year, month, frequency| Period::new(jiff::civil::date(year, month, 1), frequency);
+    let series = |frequency, observations: Vec<(Period, f64)>| {
+        Series::new(
+            "synthetic".to_owned(),
+            "synthetic".to_owned(),
+            frequency,
+            "Level".to_owned(),
+            None,
+            observations
+                .into_iter()
+                .map(|(period, value)| (period, Quantity::Value(value)))
+                .collect(),
+        )
+    };
+    let quarters = |years: &[i16]| {
+        years
+            .iter()
+            .flat_map(|year| [1, 4, 7, 10].map(|month| period(*year, month, Frequency::Quarterly)))
+            .collect::<Vec<Period>>()
+    };
+    let annual = |year| period(year, 1, Frequency::Annual);
+
+    if Aggregation::for_dataset(Dataset::Iip) != Some(Aggregation::EndOfPeriod)
+        || Aggregation::for_dataset(Dataset::Ita) != Some(Aggregation::Sum)
+        || Aggregation::for_dataset(Dataset::Nipa) != Some(Aggregation::Average)
+    {
+        let clue = "default aggregation of datasets".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Months of 2020 valued 1 to 12, and October 2021 alone.
+    let mut months = (1..=12)
+        .map(|month| (period(2020, month, Frequency::Monthly), month as f64))
+        .collect::<Vec<(Period, f64)>>();
+    months.push((period(2021, 10, Frequency::Monthly), 10.0));
+    let monthly = series(Frequency::Monthly, months);
+
+    let q1 = period(2020, 1, Frequency::Quarterly);
+    let q4 = period(2020, 10, Frequency::Quarterly);
+    let sum = monthly.aggregate(Frequency::Quarterly, Aggregation::Sum)?;
+    approx(sum.get(&q1), Some(6.0), 1e-9, "sum of first quarter")?;
+    approx(sum.get(&q4), Some(33.0), 1e-9, "sum of fourth quarter")?;
+    if sum.get(&period(2021, 10, Frequency::Quarterly)).is_some() {
+        let clue = "sum of incomplete quarter".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let average = monthly.aggregate(Frequency::Quarterly, Aggregation::Average)?;
+    approx(
+        average.get(&q4),
+        Some(11.0),
+        1e-9,
+        "average of fourth quarter",
+    )?;
+    let end = monthly.aggregate(Frequency::Quarterly, Aggregation::EndOfPeriod)?;
+    approx(end.get(&q1), Some(3.0), 1e-9, "end of first quarter")?;
+    let year = monthly.aggregate(Frequency::Annual, Aggregation::Sum)?;
+    approx(year.get(&annual(2020)), Some(78.0), 1e-9, "sum of year")?;
+    match sum.aggregate(Frequency::Monthly, Aggregation::Sum) {
+        Err(e) if matches!(**e, BeaErrorKind::Check(_)) && e.to_string().contains("Monthly") => {}
+        other => {
+            let clue = format!("aggregation to a higher frequency: {other:?}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    // Benchmarks twice the annual sums of the indicator distribute as twice the indicator.
+    let periods = quarters(&[2020, 2021]);
+    let levels = [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0];
+    let indicator = series(
+        Frequency::Quarterly,
+        periods.iter().copied().zip(levels).collect(),
+    );
+    let benchmarks = series(
+        Frequency::Annual,
+        vec![(annual(2020), 20.0), (annual(2021), 40.0)],
+    );
+    let result = benchmarks.disaggregate(&indicator, Aggregation::Sum)?;
+    for (period, level) in periods.iter().zip(levels) {
+        approx(
+            result.get(period),
+            Some(2.0 * level),
+            1e-6,
+            &format!("proportional result in {period}"),
+        )?;
+    }
+
+    // Inconsistent benchmarks are reproduced exactly by the result.
+    let benchmarks = series(
+        Frequency::Annual,
+        vec![(annual(2020), 100.0), (annual(2021), 120.0)],
+    );
+    for aggregation in [
+        Aggregation::Sum,
+        Aggregation::Average,
+        Aggregation::EndOfPeriod,
+    ] {
+        let result = benchmarks.disaggregate(&indicator, aggregation)?;
+        let restored = result.aggregate(Frequency::Annual, aggregation)?;
+        for (year, value) in [(2020, 100.0), (2021, 120.0)] {
+            approx(
+                restored.get(&annual(year)),
+                Some(value),
+                1e-6,
+                &format!("{aggregation} benchmark in {year}"),
+            )?;
+        }
+    }
+
+    // A flat series interpolates to a flat series.
+    let flat = series(
+        Frequency::Annual,
+        vec![(annual(2020), 100.0), (annual(2021), 100.0)],
+    );
+    let result = flat.interpolate(Frequency::Quarterly, Aggregation::Average)?;
+    for period in &periods {
+        approx(
+            result.get(period),
+            Some(100.0),
+            1e-6,
+            &format!("interpolated {period}"),
+        )?;
+    }
+
+    let gap = series(
+        Frequency::Annual,
+        vec![(annual(2019), 100.0), (annual(2021), 100.0)],
+    );
+    if gap
+        .interpolate(Frequency::Quarterly, Aggregation::Average)
+        .is_ok()
+    {
+        let clue = "interpolation across a gap".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    tracing::info!("Frequency conversion checks passed.");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use bears_ecology::{initial_load, trace_init};
+use bears_species::{BeaErr, Data, Dataset, KeyMissing};
+
+/// Loads the downloaded files for each dataset with an available download history and extracts
+/// the time series from each response.  Confirms that every record maps to a distinct observation,
+/// so that no two records share a series id and period.
+#[tracing::instrument(skip_all)]
+pub async fn check_series() -> Result<(), BeaErr> {
+    trace_init()?;
+    let datasets = vec![
+        Dataset::Nipa,
+        Dataset::FixedAssets,
+        Dataset::Ita,
+        Dataset::Iip,
+        Dataset::GDPbyIndustry,
+    ];
+    for dataset in datasets {
+        let data = initial_load(dataset, None).await?;
+        for datum in data {
+            let records = match &datum {
+                Data::Nipa(data) => data.len(),
+                Data::FixedAssets(data) => data.len(),
+                Data::MneDi(data) => data.len(),
+                Data::Gdp(data) => data.len(),
+                Data::UnderlyingGdp(data) => data.len(),
+                Data::ItaData(data) => data.len(),
+                Data::Iip(data) => data.len(),
+                Data::InputOutput(data) => data.len(),
+            };
+            let series = datum.series();
+            let observations = series.values().map(|s| s.len()).sum::<usize>();
+            if records != observations {
+                let clue = format!(
+                    "{dataset}: {records} records map to {observations} series observations"
+                );
+                return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+            }
+        }
+        tracing::info!("Series checked for {dataset}.");
+    }
+    Ok(())
+}
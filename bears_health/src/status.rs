@@ -0,0 +1,89 @@
+use bears_ecology::{DatasetStatus, EVENT_SCHEMA, Event, History};
+use bears_species::{BeaErr, Dataset, IoError, Jiff, KeyMissing};
+
+/// Checks the coverage counts of a [`DatasetStatus`] for synthetic targets in a temporary
+/// directory: files present, deleted after download, failing, never tried and stale.  Does not
+/// require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_status() -> Result<(), BeaErr> {
+    let dir = std::env::temp_dir().join("bears_status");
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    }
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| IoError::new(dir.clone(), e, line!(), file!().into()))?;
+    let timestamp = |date: jiff::civil::Date| {
+        date.to_zoned(jiff::tz::TimeZone::UTC)
+            .map(|zoned| zoned.timestamp())
+            .map_err(|e| Jiff::new(date.to_string(), e))
+    };
+    let old = timestamp(jiff::civil::date(2020, 1, 1))?;
+    let stale_before = timestamp(jiff::civil::date(2024, 1, 1))?;
+
+    let paths = ["fresh", "old", "deleted", "failing", "untried"]
+        .map(|name| dir.join(format!("{name}.json")));
+    for (path, contents) in paths.iter().zip(["[1, 2, 3]", "[1]"]) {
+        std::fs::write(path, contents)
+            .map_err(|e| IoError::new(path.clone(), e, line!(), file!().into()))?;
+    }
+    let file = std::fs::File::options()
+        .write(true)
+        .open(&paths[1])
+        .map_err(|e| IoError::new(paths[1].clone(), e, line!(), file!().into()))?;
+    file.set_modified(old.into())
+        .map_err(|e| IoError::new(paths[1].clone(), e, line!(), file!().into()))?;
+
+    let count = std::cell::Cell::new(0);
+    let event = |path: &std::path::Path, mode: &str, status: &str| -> Result<Event, BeaErr> {
+        count.set(count.get() + 1);
+        let mut fields = serde_json::json!({
+            "schema": EVENT_SCHEMA,
+            "id": format!("00000000-0000-4000-8000-{:012}", count.get()),
+            "mode": mode,
+            "path": path,
+            "status": status,
+            "time": "2024-06-01T00:00:00Z",
+        });
+        if status == "Success" {
+            fields["length"] = 100.into();
+        }
+        Event::try_from(&serde_json::json!({ "fields": fields }))
+    };
+    let downloads = History::from(vec![
+        event(&paths[0], "Download", "Success")?,
+        event(&paths[1], "Download", "Success")?,
+        event(&paths[2], "Download", "Success")?,
+        event(&paths[3], "Download", "Error")?,
+    ]);
+    let loads = History::from(vec![
+        event(&paths[0], "Load", "Success")?,
+        event(&paths[1], "Load", "Error")?,
+    ]);
+    let status = DatasetStatus::from_paths(Dataset::Nipa, &paths, &downloads, &loads, stale_before);
+
+    let counts = [
+        *status.targets(),
+        *status.present(),
+        *status.missing(),
+        *status.failing(),
+        *status.untried(),
+        *status.loaded(),
+        *status.load_failing(),
+    ];
+    if counts != [5, 2, 1, 1, 1, 1, 1] {
+        let clue = format!("status counts {counts:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    if *status.bytes() != 12 || *status.stale() != [paths[1].clone()] {
+        let clue = format!("{} bytes, stale {:?}", status.bytes(), status.stale());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    if *status.oldest() != Some(old) || status.newest().is_none_or(|newest| newest <= old) {
+        let clue = format!("oldest {:?}, newest {:?}", status.oldest(), status.newest());
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    std::fs::remove_dir_all(&dir).map_err(|e| IoError::new(dir, e, line!(), file!().into()))?;
+    tracing::info!("Status checks passed.");
+    Ok(())
+}
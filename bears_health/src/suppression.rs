@@ -0,0 +1,124 @@
+use bears_species::{
+    AnnotatedInteger, BeaErr, Estimate, KeyMissing, MneDiData, MneDiDatum, NaicsItems,
+};
+use std::cmp::Ordering;
+
+/// Checks the value, bounds and suppression flag of annotated MNE cells, and that sums and
+/// comparisons of [`Estimate`] values propagate suppression, including totals across countries
+/// from [`MneDiData::aggregate`].  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_suppression() -> Result<(), BeaErr> {
+    let estimate = |value: &str| -> Result<Estimate, BeaErr> {
+        Ok(AnnotatedInteger::from_value(value)?.estimate())
+    };
+    let cells = [
+        ("123", Some(123.0), Some(123.0), Some(123.0), false),
+        ("(D)", None, None, None, true),
+        ("(*)", Some(0.0), Some(-0.5), Some(0.5), false),
+        ("G", None, Some(1_000.0), Some(2_499.0), true),
+        ("M", None, Some(100_000.0), None, true),
+        ("n.a.", None, None, None, false),
+    ];
+    for (cell, value, lower, upper, suppressed) in cells {
+        let found = estimate(cell)?;
+        if (
+            *found.value(),
+            *found.lower(),
+            *found.upper(),
+            *found.suppressed(),
+        ) != (value, lower, upper, suppressed)
+        {
+            let clue = format!("estimate of {cell}: {found:?}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+
+    // A total over a suppressed cell has bounds but no value.
+    let total = ["123", "G", "(*)"]
+        .into_iter()
+        .map(estimate)
+        .sum::<Result<Estimate, BeaErr>>()?;
+    if total.value().is_some()
+        || *total.lower() != Some(1_122.5)
+        || *total.upper() != Some(2_622.5)
+        || !total.suppressed()
+    {
+        let clue = format!("total over a suppressed cell: {total:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let total = ["1", "2"]
+        .into_iter()
+        .map(estimate)
+        .sum::<Result<Estimate, BeaErr>>()?;
+    if total != Estimate::exact(3.0) {
+        let clue = format!("total over reported cells: {total:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    // Aggregating MNE records across countries keeps the bounds of suppressed cells.
+    let datum = |year: &str, code: i64, row: &str, value: &str| -> Result<MneDiDatum, BeaErr> {
+        let json = serde_json::json!({
+            "Column": "Employment",
+            "ColumnCode": "0",
+            "ColumnGParent": "",
+            "ColumnGParentCode": "0",
+            "ColumnParent": "",
+            "ColumnParentCode": "0",
+            "DataValue": value,
+            "DataValueUnformatted": value,
+            "Row": row,
+            "RowCode": code.to_string(),
+            "SeriesID": "4",
+            "SeriesName": "Employment",
+            "TableColumnDisplayOrder": "1",
+            "TableRowDisplayOrder": code.to_string(),
+            "TableScale": "Thousands of employees",
+            "Year": year,
+        });
+        let serde_json::Value::Object(map) = json else {
+            let clue = "synthetic MNE record".to_string();
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        };
+        MneDiDatum::read_json(&map, &NaicsItems::from(Vec::new()))
+    };
+    let data = MneDiData::from(vec![
+        datum("2022", 200, "Argentina", "123")?,
+        datum("2022", 201, "Bolivia", "G")?,
+        datum("2022", 202, "Brazil", "(*)")?,
+        datum("2023", 200, "Argentina", "1")?,
+        datum("2023", 201, "Bolivia", "2")?,
+    ]);
+    let totals = data.aggregate(|datum| (*datum.series_id(), datum.year().year()));
+    let suppressed = totals.get(&(4, 2022));
+    let exact = totals.get(&(4, 2023));
+    if totals.len() != 2
+        || suppressed.map(|total| {
+            (
+                *total.value(),
+                *total.lower(),
+                *total.upper(),
+                *total.suppressed(),
+            )
+        }) != Some((None, Some(1_122.5), Some(2_622.5), true))
+        || exact != Some(&Estimate::exact(3.0))
+    {
+        let clue = format!("aggregate of MNE records: {totals:?}");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+
+    let comparisons = [
+        ("G", "5000", Some(Ordering::Less)),
+        ("G", "2000", None),
+        ("M", "G", Some(Ordering::Greater)),
+        ("(D)", "10", None),
+        ("10", "10", Some(Ordering::Equal)),
+    ];
+    for (left, right, expected) in comparisons {
+        if estimate(left)?.compare(&estimate(right)?) != expected {
+            let clue = format!("comparison of {left} and {right}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    tracing::info!("Suppression checks passed.");
+    Ok(())
+}
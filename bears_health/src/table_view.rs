@@ -0,0 +1,135 @@
+use bears_species::{BeaErr, Frequency, KeyMissing, NipaData, NipaTableView};
+
+/// Lines of NIPA Table 1.1.5 with synthetic levels for two years, as (line, description, 2023
+/// level, 2024 level).
+pub(crate) const TABLE_1_1_5: [(i64, &str, i64, i64); 26] = [
+    (1, "Gross domestic product", 100, 107),
+    (2, "Personal consumption expenditures", 60, 64),
+    (3, "Goods", 25, 27),
+    (4, "Durable goods", 10, 11),
+    (5, "Nondurable goods", 15, 16),
+    (6, "Services", 35, 37),
+    (7, "Gross private domestic investment", 20, 21),
+    (8, "Fixed investment", 18, 20),
+    (9, "Nonresidential", 14, 17),
+    (10, "Structures", 3, 4),
+    (11, "Equipment", 6, 7),
+    (12, "Intellectual property products", 5, 6),
+    (13, "Residential", 4, 3),
+    (14, "Change in private inventories", 2, 1),
+    (15, "Net exports of goods and services", -5, -5),
+    (16, "Exports", 10, 12),
+    (17, "Goods", 6, 7),
+    (18, "Services", 4, 5),
+    (19, "Imports", 15, 17),
+    (20, "Goods", 12, 13),
+    (21, "Services", 3, 4),
+    (
+        22,
+        "Government consumption expenditures and gross investment",
+        25,
+        27,
+    ),
+    (23, "Federal", 13, 14),
+    (24, "National defense", 8, 9),
+    (25, "Nondefense", 5, 5),
+    (26, "State and local", 12, 13),
+];
+
+/// Builds a synthetic copy of NIPA Table 1.1.5 in millions of dollars for 2023 and 2024 from
+/// `lines`, given in the format of [`TABLE_1_1_5`].
+pub(crate) fn table_1_1_5(lines: &[(i64, &str, i64, i64)]) -> Result<NipaData, BeaErr> {
+    let mut data = Vec::new();
+    for (line, description, first, second) in lines.iter().copied() {
+        for (year, level) in [("2023", first), ("2024", second)] {
+            let json = serde_json::json!({
+                "CL_UNIT": "Level",
+                "DataValue": (level * 1000).to_string(),
+                "LineDescription": description,
+                "LineNumber": line.to_string(),
+                "METRIC_NAME": "Current Dollars",
+                "NoteRef": "T10105",
+                "SeriesCode": format!("S{line}"),
+                "TableName": "T10105",
+                "TimePeriod": year,
+                "UNIT_MULT": "6",
+            });
+            data.push(bears_species::NipaDatum::try_from(json)?);
+        }
+    }
+    Ok(NipaData::from(data))
+}
+
+/// Builds a [`NipaTableView`] from a synthetic copy of NIPA Table 1.1.5 and confirms that the
+/// recovered indentation and parent lines match the published table, and that the values of each
+/// line with children equal the sum of its children, with imports subtracted.  Renders the view as
+/// text and writes it to a CSV file in the temporary directory.  Does not require the `BEA_DATA`
+/// directory.
+#[tracing::instrument]
+pub fn check_table_view() -> Result<(), BeaErr> {
+    let view = NipaTableView::new(&table_1_1_5(&TABLE_1_1_5)?, Frequency::Annual);
+    tracing::info!("\n{view}");
+    if view.rows().len() != TABLE_1_1_5.len() || view.periods().len() != 2 {
+        let clue = format!(
+            "{} rows and {} periods in the view",
+            view.rows().len(),
+            view.periods().len()
+        );
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let depths = [
+        0, 1, 2, 3, 3, 2, 1, 2, 3, 4, 4, 4, 3, 2, 1, 2, 3, 3, 2, 3, 3, 1, 2, 3, 3, 2,
+    ];
+    // Parent line of each line, with zero for top-level lines.
+    let parents = [
+        0, 1, 2, 3, 3, 2, 1, 7, 8, 9, 9, 9, 8, 7, 1, 15, 16, 16, 15, 19, 19, 1, 22, 23, 23, 22,
+    ];
+    for ((row, depth), parent) in view.rows().iter().zip(depths).zip(parents) {
+        let parent = (parent != 0).then_some(parent);
+        if *row.depth() != depth || *row.parent() != parent {
+            let clue = format!(
+                "line {} at depth {} under {:?}, expected {depth} under {parent:?}",
+                row.line_number(),
+                row.depth(),
+                row.parent()
+            );
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+    }
+    // Each aggregate equals the sum of its children in every period.
+    let mut aggregates = 0;
+    for row in view.rows() {
+        let children = view.children(*row.line_number());
+        if children.is_empty() {
+            continue;
+        }
+        for (period, value) in row.values() {
+            let sum = children
+                .iter()
+                .map(|child| {
+                    let sign = if child.line_description().starts_with("Imports") {
+                        -1.0
+                    } else {
+                        1.0
+                    };
+                    child.values().get(period).map(|v| sign * v)
+                })
+                .sum::<Option<f64>>();
+            if sum.is_none_or(|sum| (sum - value).abs() > 1e-9) {
+                let clue = format!(
+                    "children of line {} in {period} sum to {sum:?}, expected {value}",
+                    row.line_number()
+                );
+                return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+            }
+        }
+        aggregates += 1;
+    }
+    if aggregates != 11 {
+        let clue = format!("{aggregates} lines with children, expected 11");
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let path = std::env::temp_dir().join("bears_table_view.csv");
+    view.to_csv(&path)?;
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use bears_ecology::{bea_data, trace_init};
+use bears_species::{
+    BeaErr, Change, Data, Dataset, Frequency, KeyMissing, NipaData, NipaTableName, Period,
+    PriceIndex, Quantity, Series, SeriesSet,
+};
+use std::collections::BTreeMap;
+
+/// Returns a `KeyMissing` error with the clue `clue` if `left` and `right` differ by more than
+/// `tolerance`, or if either value is missing.
+pub(crate) fn approx(
+    left: Option<f64>,
+    right: Option<f64>,
+    tolerance: f64,
+    clue: &str,
+) -> Result<(), BeaErr> {
+    match (left, right) {
+        (Some(left), Some(right)) if (left - right).abs() <= tolerance => Ok(()),
+        _ => {
+            let clue = format!("{clue}: {left:?} != {right:?}");
+            Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+        }
+    }
+}
+
+/// Checks the transforms in the [`Change`] enum against hand-computed values on a synthetic
+/// quarterly series with a gap.  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_transforms() -> Result<(), BeaErr> {
+    let quarter =
+        |year, month| Period::new(jiff::civil::date(year, month, 1), Frequency::Quarterly);
+    let levels = |id: &str, values: &[Option<f64>]| {
+        let observations = [
+            quarter(2023, 1),
+            quarter(2023, 4),
+            quarter(2023, 7),
+            quarter(2023, 10),
+            quarter(2024, 1),
+            quarter(2024, 4),
+        ]
+        .into_iter()
+        .zip(values.iter().map(|v| match v {
+            Some(v) => Quantity::Value(*v),
+            None => Quantity::Missing,
+        }))
+        .collect();
+        Series::new(
+            id.to_owned(),
+            id.to_owned(),
+            Frequency::Quarterly,
+            "Level".to_owned(),
+            None,
+            observations,
+        )
+    };
+    let total = levels(
+        "total",
+        &[
+            Some(100.0),
+            Some(101.0),
+            Some(102.0),
+            Some(103.0),
+            Some(104.0),
+            None,
+        ],
+    );
+
+    let change = total.change(Change::Level);
+    approx(
+        change.get(&quarter(2023, 4)),
+        Some(1.0),
+        1e-9,
+        "level change",
+    )?;
+    let change = total.change(Change::Percent);
+    approx(
+        change.get(&quarter(2023, 4)),
+        Some(1.0),
+        1e-9,
+        "percent change",
+    )?;
+    let change = total.change(Change::Annualized);
+    approx(
+        change.get(&quarter(2023, 4)),
+        Some((1.01_f64.powi(4) - 1.0) * 100.0),
+        1e-9,
+        "annualized change",
+    )?;
+    // The first period has no predecessor, and the last period is missing.
+    if change.get(&quarter(2023, 1)).is_some() || change.get(&quarter(2024, 4)).is_some() {
+        let clue = "change across a gap".to_owned();
+        return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+    }
+    let change = total.change(Change::YearOverYear);
+    approx(
+        change.get(&quarter(2024, 1)),
+        Some(4.0),
+        1e-9,
+        "year over year change",
+    )?;
+
+    let mut set = SeriesSet::default();
+    let first = levels(
+        "first",
+        &[
+            Some(60.0),
+            Some(60.5),
+            Some(61.0),
+            Some(62.0),
+            Some(62.0),
+            None,
+        ],
+    );
+    let second = levels(
+        "second",
+        &[
+            Some(40.0),
+            Some(40.5),
+            Some(41.0),
+            Some(41.0),
+            Some(42.0),
+            None,
+        ],
+    );
+    for series in [total, first, second] {
+        set.insert((series.id().to_owned(), Frequency::Quarterly), series);
+    }
+    let shares = set.shares("total");
+    approx(
+        shares
+            .series("first", Frequency::Quarterly)
+            .and_then(|s| s.get(&quarter(2023, 1))),
+        Some(60.0),
+        1e-9,
+        "share",
+    )?;
+    // Contributions of the components sum to the annualized change in the total.
+    let contributions = set.contributions("total");
+    let growth = set
+        .series("total", Frequency::Quarterly)
+        .map(|s| s.change(Change::Annualized));
+    for period in [
+        quarter(2023, 4),
+        quarter(2023, 7),
+        quarter(2023, 10),
+        quarter(2024, 1),
+    ] {
+        let sum = ["first", "second"]
+            .iter()
+            .map(|id| {
+                contributions
+                    .series(id, Frequency::Quarterly)
+                    .and_then(|s| s.get(&period))
+            })
+            .sum::<Option<f64>>();
+        let growth = growth.as_ref().and_then(|s| s.get(&period));
+        approx(sum, growth, 1e-9, &format!("contributions in {period}"))?;
+    }
+    Ok(())
+}
+
+/// Reproduces every percent change published in NIPA Table 1.1.1 from the chained-dollar levels
+/// at the same line of Table 1.1.6, using [`Change::Annualized`].  Lines without chained-dollar
+/// levels are computed from the current-dollar levels of Table 1.1.5, deflated by their price
+/// index with [`PriceIndex::from_nominal`].
+///
+/// The published changes are rounded, so computed changes may differ from the published changes
+/// by up to one tenth of a percentage point.
+#[tracing::instrument]
+pub fn check_table_1_1_1() -> Result<(), BeaErr> {
+    trace_init()?;
+    let path = bea_data()?.join("data").join(Dataset::Nipa.to_string());
+    // Series codes by line number, since the tables share their line layout.
+    let lines = |table: NipaTableName| -> Result<(BTreeMap<i64, String>, SeriesSet), BeaErr> {
+        let data = NipaData::try_from(&path.join(format!("NIPA_{table}.json")))?;
+        let lines = data
+            .iter()
+            .map(|d| (*d.line_number(), d.series_code().to_owned()))
+            .collect::<BTreeMap<i64, String>>();
+        Ok((lines, Data::from(data).series()))
+    };
+    let (published_lines, published) = lines(NipaTableName::T10101)?;
+    let (real_lines, real) = lines(NipaTableName::T10106)?;
+    let (nominal_lines, nominal) = lines(NipaTableName::T10105)?;
+    let real = real.change(Change::Annualized);
+    let mut checked = 0;
+    for (line, published_code) in &published_lines {
+        let mut line_checked = 0;
+        for frequency in [Frequency::Annual, Frequency::Quarterly] {
+            let Some(published) = published.series(published_code, frequency) else {
+                continue;
+            };
+            let nominal_series = nominal_lines
+                .get(line)
+                .and_then(|code| nominal.series(code, frequency));
+            let computed = match (real_lines.get(line), nominal_series) {
+                (Some(code), _) => real.series(code, frequency).cloned(),
+                (None, Some(series)) => {
+                    let index = PriceIndex::from_nominal(
+                        &path,
+                        NipaTableName::T10105,
+                        series.id(),
+                        frequency,
+                    )?;
+                    Some(series.deflate(&index)?.series().change(Change::Annualized))
+                }
+                (None, None) => None,
+            };
+            let Some(computed) = computed else {
+                continue;
+            };
+            for (period, value) in published.values() {
+                if let Some(value_computed) = computed.get(&period) {
+                    approx(
+                        Some(value),
+                        Some(value_computed),
+                        0.11,
+                        &format!("Table 1.1.1 line {line} in {period}"),
+                    )?;
+                    line_checked += 1;
+                }
+            }
+        }
+        let has_values = [Frequency::Annual, Frequency::Quarterly]
+            .iter()
+            .filter_map(|frequency| published.series(published_code, *frequency))
+            .any(|series| !series.values().is_empty());
+        if has_values && line_checked == 0 {
+            let clue = format!("levels for Table 1.1.1 line {line}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+        checked += line_checked;
+    }
+    tracing::info!(
+        "{checked} percent changes on {} lines match Table 1.1.1.",
+        published_lines.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use crate::naics::ensure;
+use bears_ecology::{App, Archive, Options, Queue, Vintages};
+use bears_species::{BeaErr, BeaResponse, IoError, Jiff, UrlParseError};
+
+/// A synthetic NIPA "TableName" parameter values response holding the single table `T10101`
+/// described as `description`.
+fn release(description: &str) -> serde_json::Value {
+    serde_json::json!({"BEAAPI": {
+        "Request": {"RequestParam": [
+            {"ParameterName": "USERID", "ParameterValue": "synthetic"},
+            {"ParameterName": "METHOD", "ParameterValue": "GETPARAMETERVALUES"},
+            {"ParameterName": "DATASETNAME", "ParameterValue": "NIPA"},
+            {"ParameterName": "PARAMETERNAME", "ParameterValue": "TableName"},
+            {"ParameterName": "RESULTFORMAT", "ParameterValue": "JSON"},
+        ]},
+        "Results": {"ParamValue": [{"Key": "T10101", "Desc": description}]},
+    }})
+}
+
+/// Records synthetic releases of a target with [`Vintages::record`] in an archive in a temporary
+/// directory, and reads them back with [`Vintages::load_as_of`].  Checks that unchanged releases
+/// are skipped, that content archives keep every release while dated archives keep one per day,
+/// and that the index survives a round trip through the archive.  Also checks that archive mode
+/// reaches every member of a download [`Queue`].  Does not require the `BEA_DATA` directory.
+#[tracing::instrument]
+pub fn check_vintages() -> Result<(), BeaErr> {
+    let root = std::env::temp_dir().join("bears_vintages");
+    if root.exists() {
+        std::fs::remove_dir_all(&root)
+            .map_err(|e| IoError::new(root.clone(), e, line!(), file!().into()))?;
+    }
+    let today = jiff::Zoned::now().date();
+    let yesterday = today
+        .yesterday()
+        .map_err(|e| Jiff::new(today.to_string(), e))?;
+    let first = release("Table 1.1.1. Percent Change From Preceding Period");
+    let second = release("Table 1.1.1. Percent Change From Preceding Period, Revised");
+    let bytes = |json: &serde_json::Value| json.to_string().into_bytes();
+
+    // A content archive keeps each distinct release and skips unchanged ones.
+    let target = root.join("data").join("NIPA").join("NIPA_T10101.json");
+    let mut index = Vintages::from_target_in(&root, &target)?;
+    ensure(
+        index.record(&bytes(&first), Archive::Content)?.is_some(),
+        "first release recorded",
+    )?;
+    ensure(
+        index.record(&bytes(&first), Archive::Content)?.is_none(),
+        "unchanged release skipped",
+    )?;
+    ensure(
+        index.record(&bytes(&second), Archive::Content)?.is_some(),
+        "revised release recorded",
+    )?;
+    let dir = Vintages::directory_in(&root, &target);
+    ensure(
+        dir == root.join("vintage").join("NIPA").join("NIPA_T10101")
+            && index.vintages().len() == 2
+            && index.vintages().iter().all(|v| dir.join(v.file()).exists()),
+        &format!("content vintages in {}", dir.display()),
+    )?;
+
+    // The index reads back from the archive, and loads the release current on each date.
+    let read = Vintages::from_target_in(&root, &target)?;
+    ensure(read == index, "vintage index round trip")?;
+    let loaded = read.load_as_of(today)?;
+    ensure(
+        loaded == BeaResponse::try_from(&second)?,
+        "latest release as of today",
+    )?;
+    ensure(
+        read.load_as_of(yesterday).is_err(),
+        "no release before the first retrieval",
+    )?;
+
+    // A dated archive keeps a single snapshot per day.
+    let target = root.join("data").join("NIPA").join("NIPA_T10105.json");
+    let mut index = Vintages::from_target_in(&root, &target)?;
+    index.record(&bytes(&first), Archive::Dated)?;
+    index.record(&bytes(&second), Archive::Dated)?;
+    let read = Vintages::from_target_in(&root, &target)?;
+    ensure(
+        read.vintages().len() == 1
+            && read.latest().map(|v| v.file().display().to_string())
+                == Some(format!("{today}.json"))
+            && read.load_as_of(today)? == BeaResponse::try_from(&second)?,
+        &format!("dated vintages: {:?}", read.vintages()),
+    )?;
+
+    // Archive mode set on a queue reaches each of its downloads.
+    let url = "https://apps.bea.gov/api/data";
+    let url = url::Url::parse(url)
+        .map_err(|e| UrlParseError::new(url.to_string(), e, line!(), file!().into()))?;
+    let app = App::new("key".to_string(), Options::default(), url);
+    let mut queue = Queue::new(vec![app.clone(), app]);
+    queue.with_archive(Archive::Content);
+    ensure(
+        queue
+            .iter()
+            .all(|app| *app.archive() == Some(Archive::Content)),
+        "archive mode of queued downloads",
+    )?;
+
+    std::fs::remove_dir_all(&root).map_err(|e| IoError::new(root, e, line!(), file!().into()))?;
+    tracing::info!("Vintage checks passed.");
+    Ok(())
+}
@@ -265,3 +265,178 @@ async fn iip_codes() -> anyhow::Result<()> {
     bears_health::iip_codes().await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn check_series() -> anyhow::Result<()> {
+    bears_health::check_series().await?;
+    Ok(())
+}
+
+#[test]
+fn check_transforms() -> anyhow::Result<()> {
+    bears_health::check_transforms()?;
+    Ok(())
+}
+
+#[test]
+fn check_table_1_1_1() -> anyhow::Result<()> {
+    bears_health::check_table_1_1_1()?;
+    Ok(())
+}
+
+#[test]
+fn check_table_view() -> anyhow::Result<()> {
+    bears_health::check_table_view()?;
+    Ok(())
+}
+
+#[test]
+fn check_io_matrix() -> anyhow::Result<()> {
+    bears_health::check_io_matrix()?;
+    Ok(())
+}
+
+#[test]
+fn check_naics_hierarchy() -> anyhow::Result<()> {
+    bears_health::check_naics_hierarchy()?;
+    Ok(())
+}
+
+#[test]
+fn check_naics_concordance() -> anyhow::Result<()> {
+    bears_health::check_naics_concordance()?;
+    Ok(())
+}
+
+#[test]
+fn check_generated_keys() -> anyhow::Result<()> {
+    bears_health::check_generated_keys()?;
+    Ok(())
+}
+
+#[test]
+fn check_vintages() -> anyhow::Result<()> {
+    bears_health::check_vintages()?;
+    Ok(())
+}
+
+#[test]
+fn check_health_report() -> anyhow::Result<()> {
+    bears_health::check_health_report()?;
+    Ok(())
+}
+
+#[test]
+fn check_geography() -> anyhow::Result<()> {
+    bears_health::check_geography()?;
+    Ok(())
+}
+
+#[test]
+fn check_deflate() -> anyhow::Result<()> {
+    bears_health::check_deflate()?;
+    Ok(())
+}
+
+#[test]
+fn check_real_gdp() -> anyhow::Result<()> {
+    bears_health::check_real_gdp()?;
+    Ok(())
+}
+
+#[test]
+fn check_resample() -> anyhow::Result<()> {
+    bears_health::check_resample()?;
+    Ok(())
+}
+
+#[test]
+fn check_identities() -> anyhow::Result<()> {
+    bears_health::check_identities()?;
+    Ok(())
+}
+
+#[test]
+fn check_suppression() -> anyhow::Result<()> {
+    bears_health::check_suppression()?;
+    Ok(())
+}
+
+#[test]
+fn check_query() -> anyhow::Result<()> {
+    bears_health::check_query()?;
+    Ok(())
+}
+
+#[test]
+fn check_index() -> anyhow::Result<()> {
+    bears_health::check_index()?;
+    Ok(())
+}
+
+#[test]
+fn check_event_log() -> anyhow::Result<()> {
+    bears_health::check_event_log()?;
+    Ok(())
+}
+
+#[test]
+fn check_attempts() -> anyhow::Result<()> {
+    bears_health::check_attempts()?;
+    Ok(())
+}
+
+#[test]
+fn check_error_reasons() -> anyhow::Result<()> {
+    bears_health::check_error_reasons()?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn check_transport_redaction() -> anyhow::Result<()> {
+    bears_health::check_transport_redaction().await?;
+    Ok(())
+}
+
+#[test]
+fn check_size_estimates() -> anyhow::Result<()> {
+    bears_health::check_size_estimates()?;
+    Ok(())
+}
+
+#[test]
+fn check_status() -> anyhow::Result<()> {
+    bears_health::check_status()?;
+    Ok(())
+}
+
+#[test]
+fn check_manifest() -> anyhow::Result<()> {
+    bears_health::check_manifest()?;
+    Ok(())
+}
+
+#[test]
+fn check_retry() -> anyhow::Result<()> {
+    bears_health::check_retry()?;
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(feature = "metrics")]
+async fn check_metrics() -> anyhow::Result<()> {
+    bears_health::check_metrics().await?;
+    Ok(())
+}
+
+#[test]
+fn check_history_sinks() -> anyhow::Result<()> {
+    bears_health::check_history_sinks()?;
+    Ok(())
+}
+
+#[test]
+fn check_cli() -> anyhow::Result<()> {
+    bears_health::check_cli()?;
+    Ok(())
+}
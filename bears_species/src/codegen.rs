@@ -0,0 +1,487 @@
+use crate::{
+    BeaErr, BeaResponse, IoError, KeyMissing, NaicsItems, NaicsLevel, SerdeJson, from_csv,
+};
+use convert_case::Casing;
+
+/// Marks the start of the generated region of a source file.
+const BEGIN: &str = "// @generated begin";
+/// Marks the end of the generated region of a source file.
+const END: &str = "// @generated end";
+/// Census list of NAICS codes, relative to the workspace root.
+const NAICS_CODES: &str = "data/naics_codes.csv";
+/// Codes from earlier NAICS vintages still reported by BEA datasets, relative to the workspace
+/// root.
+const NAICS_LEGACY_CODES: &str = "data/naics_legacy_codes.csv";
+/// Row codes of MNE rows reported without a code, by title, relative to the workspace root.
+const MNE_ROW_TITLES: &str = "data/mne_row_titles.csv";
+/// Derives shared by every generated enum.
+const DERIVES: [&str; 13] = [
+    "Debug",
+    "Copy",
+    "Clone",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "serde::Serialize",
+    "serde::Deserialize",
+    "derive_more::Display",
+    "derive_more::FromStr",
+    "strum::EnumIter",
+];
+
+/// The `KeyTarget` enum lists the key enums with lookup tables generated from cached BEA
+/// responses, the Census list of NAICS codes, or the row titles of MNE tables.
+///
+/// The generator only writes to the region of the source file between the `// @generated begin`
+/// and `// @generated end` markers, so hand-written impls outside the region are kept.  Files
+/// without the markers are still maintained by hand.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::FromStr,
+    strum::EnumIter,
+)]
+pub enum KeyTarget {
+    NaicsSector,
+    NaicsSubsector,
+    NaicsCategory,
+    NaicsSubcategory,
+    NaicsIndustry,
+    Indicator,
+    Investment,
+    NipaTableName,
+    AreaOrCountry,
+    RowCode,
+}
+
+impl KeyTarget {
+    /// Path of the source file defining the enum, relative to the workspace root.
+    pub fn path(&self) -> &'static str {
+        match self {
+            Self::NaicsSector => "bears_species/src/keys/naics/sector.rs",
+            Self::NaicsSubsector => "bears_species/src/keys/naics/subsector.rs",
+            Self::NaicsCategory => "bears_species/src/keys/naics/category.rs",
+            Self::NaicsSubcategory => "bears_species/src/keys/naics/subcategory.rs",
+            Self::NaicsIndustry => "bears_species/src/keys/naics/industry.rs",
+            Self::Indicator => "bears_species/src/keys/indicator.rs",
+            Self::Investment => "bears_species/src/keys/investment.rs",
+            Self::NipaTableName => "bears_species/src/keys/table_name.rs",
+            Self::AreaOrCountry => "bears_species/src/keys/area_or_country.rs",
+            Self::RowCode => "bears_species/src/keys/row_code.rs",
+        }
+    }
+
+    /// Level of the NAICS codes in the enum, or `None` for enums of parameter values.
+    pub fn level(&self) -> Option<NaicsLevel> {
+        match self {
+            Self::NaicsSector => Some(NaicsLevel::Sector),
+            Self::NaicsSubsector => Some(NaicsLevel::Subsector),
+            Self::NaicsCategory => Some(NaicsLevel::Category),
+            Self::NaicsSubcategory => Some(NaicsLevel::Subcategory),
+            Self::NaicsIndustry => Some(NaicsLevel::Industry),
+            _ => None,
+        }
+    }
+
+    /// Name of the cached parameter values file in the `parameter_values` directory of
+    /// `BEA_DATA`, or `None` for targets read from the `data` directory of the workspace.
+    pub fn parameter_values(&self) -> Option<&'static str> {
+        match self {
+            Self::Indicator => Some("ITA_Indicator_values.json"),
+            Self::Investment => Some("IIP_TypeOfInvestment_values.json"),
+            Self::NipaTableName => Some("NIPA_TableName_values.json"),
+            Self::AreaOrCountry => Some("ITA_AreaOrCountry_values.json"),
+            _ => None,
+        }
+    }
+
+    /// Whether the first variant is the default value of the enum.
+    fn has_default(&self) -> bool {
+        matches!(self, Self::NaicsSector | Self::Investment)
+    }
+}
+
+/// The `KeyVariant` struct holds a single variant of a generated enum, or a single entry of the
+/// [`RowCode`](crate::RowCode) title table.
+///
+/// * **name** - Name of the variant.  For row titles, the variant of the row code.
+/// * **key** - Key of the parameter value in the BEA response, or the row title.  Same as the
+///   name for NAICS codes.
+/// * **description** - Title of the code, or description of the parameter value.  For row
+///   titles, the value held by the row code.
+/// * **codes** - NAICS codes of the variant, empty for parameter values.  Sectors that span
+///   several two-digit codes, such as manufacturing in 31-33, have more than one.
+#[derive(
+    Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct KeyVariant {
+    name: String,
+    key: String,
+    description: String,
+    codes: Vec<i64>,
+}
+
+/// A row of the `mne_row_titles.csv` file: the title of an MNE row reported without a code, the
+/// variant of [`RowCode`](crate::RowCode) it belongs to, and the NAICS code, area or label held
+/// by the variant.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RowTitle {
+    title: String,
+    variant: String,
+    value: String,
+}
+
+/// The `KeyTable` struct holds the variants of a generated enum, and renders the enum with its
+/// lookup tables as Rust source.
+///
+/// * **target** - The enum to generate.
+/// * **source** - Files the variants are read from, noted in the generated region.
+/// * **variants** - Variants in the order of the enum.
+#[derive(
+    Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct KeyTable {
+    target: KeyTarget,
+    source: String,
+    variants: Vec<KeyVariant>,
+}
+
+impl KeyTable {
+    /// Creates the table of the NAICS enum `target` from the codes in `items` at the level of
+    /// `target`.  Codes sharing a name form a single variant, and variants are sorted by code.
+    pub fn from_naics(target: KeyTarget, items: &NaicsItems, source: &str) -> Self {
+        let digits = target.level().map(|level| level.digits()).unwrap_or(0);
+        let mut items = items
+            .iter()
+            .filter(|item| item.code().to_string().len() == digits)
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| *item.code());
+        let mut variants: Vec<KeyVariant> = Vec::new();
+        for item in items {
+            match variants.iter_mut().find(|v| v.name == *item.name()) {
+                Some(variant) => variant.codes.push(*item.code()),
+                None => variants.push(KeyVariant {
+                    name: item.name().to_owned(),
+                    key: item.name().to_owned(),
+                    description: item.title().trim().to_owned(),
+                    codes: vec![*item.code()],
+                }),
+            }
+        }
+        Self {
+            target,
+            source: source.to_owned(),
+            variants,
+        }
+    }
+
+    /// Creates the table of the parameter value enum `target` from the cached parameter values
+    /// in `response`, keeping the order of the response.  Returns a [`KeyMissing`] error if the
+    /// response does not contain parameter values.
+    pub fn from_parameter_values(
+        target: KeyTarget,
+        response: &BeaResponse,
+        source: &str,
+    ) -> Result<Self, KeyMissing> {
+        let Some(values) = response.parameter_values() else {
+            let clue = format!("parameter values in {source}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()));
+        };
+        let mut variants: Vec<KeyVariant> = Vec::new();
+        for fields in values.iter().filter_map(|value| value.parameter_fields()) {
+            let name = variant_name(fields.key());
+            if !variants.iter().any(|v| v.name == name) {
+                variants.push(KeyVariant {
+                    name,
+                    key: fields.key().to_owned(),
+                    description: fields.desc().trim().to_owned(),
+                    codes: Vec::new(),
+                });
+            }
+        }
+        Ok(Self {
+            target,
+            source: source.to_owned(),
+            variants,
+        })
+    }
+
+    /// Creates the title table of [`RowCode`](crate::RowCode) from the rows of `titles`, keeping
+    /// the order of the file.  Returns a [`KeyMissing`] error for a row naming an unknown variant.
+    fn from_row_titles(titles: Vec<RowTitle>, source: &str) -> Result<Self, KeyMissing> {
+        let mut variants = Vec::new();
+        for row in titles {
+            if !["Naics", "Parent", "Region", "Addendum"].contains(&row.variant.as_str()) {
+                let clue = format!("RowCode variant {} in {source}", row.variant);
+                return Err(KeyMissing::new(clue, line!(), file!().to_string()));
+            }
+            variants.push(KeyVariant {
+                name: row.variant,
+                key: row.title,
+                description: row.value,
+                codes: Vec::new(),
+            });
+        }
+        Ok(Self {
+            target: KeyTarget::RowCode,
+            source: source.to_owned(),
+            variants,
+        })
+    }
+
+    /// Renders the generated region, including the markers: the enum with a doc comment on each
+    /// variant, and the `description` method.  Variants of parameter values whose key is not a
+    /// valid variant name display as the key.  NAICS enums also receive the `code` and
+    /// `from_code` methods, and the [`RowCode`](crate::RowCode) target receives the `from_title`
+    /// method in place of an enum.  Items are marked `#[rustfmt::skip]` so that each match arm
+    /// stays on a single line.
+    pub fn render(&self) -> String {
+        let name = self.target.to_string();
+        let mut text = format!(
+            "{BEGIN} by `bears generate` from {}, do not edit.\n",
+            self.source
+        );
+        if self.target == KeyTarget::RowCode {
+            text.push_str(&self.render_titles());
+            text.push_str(&format!("{END}\n"));
+            return text;
+        }
+        text.push_str("#[rustfmt::skip]\n#[derive(\n");
+        let mut derives = DERIVES.to_vec();
+        if self.target.has_default() {
+            derives.insert(1, "Default");
+        }
+        for derive in derives {
+            text.push_str(&format!("    {derive},\n"));
+        }
+        text.push_str(&format!(")]\npub enum {name} {{\n"));
+        for (i, variant) in self.variants.iter().enumerate() {
+            text.push_str(&format!("    /// {}\n", variant.description));
+            if i == 0 && self.target.has_default() {
+                text.push_str("    #[default]\n");
+            }
+            if variant.key != variant.name {
+                text.push_str(&format!("    #[display({:?})]\n", variant.key));
+            }
+            text.push_str(&format!("    {},\n", variant.name));
+        }
+        text.push_str(&format!("}}\n\n#[rustfmt::skip]\nimpl {name} {{\n"));
+        text.push_str("    /// Returns the description of the variant.\n");
+        text.push_str("    pub fn description(&self) -> &'static str {\n        match self {\n");
+        for variant in &self.variants {
+            text.push_str(&format!(
+                "            Self::{} => {:?},\n",
+                variant.name, variant.description
+            ));
+        }
+        text.push_str("        }\n    }\n");
+        if self.target.level().is_some() {
+            text.push_str("\n    /// Returns the NAICS code of the variant.  Variants spanning several codes return the\n");
+            text.push_str("    /// first.\n");
+            text.push_str("    pub fn code(&self) -> i64 {\n        match self {\n");
+            for variant in &self.variants {
+                let code = variant.codes.first().copied().unwrap_or_default();
+                text.push_str(&format!("            Self::{} => {code},\n", variant.name));
+            }
+            text.push_str("        }\n    }\n\n");
+            text.push_str("    /// Returns the variant with the NAICS code `code`, or `None` if the code is unknown.\n");
+            text.push_str("    pub fn from_code(code: &str) -> Option<Self> {\n");
+            text.push_str("        let code = code.parse::<i64>().ok()?;\n");
+            text.push_str("        let result = match code {\n");
+            for variant in &self.variants {
+                let codes = patterns(&variant.codes);
+                text.push_str(&format!("            {codes} => Self::{},\n", variant.name));
+            }
+            text.push_str(
+                "            _ => return None,\n        };\n        Some(result)\n    }\n",
+            );
+        }
+        text.push_str(&format!("}}\n{END}\n"));
+        text
+    }
+
+    /// Renders the `from_title` method of [`RowCode`](crate::RowCode), matching each row title
+    /// to its row code.
+    fn render_titles(&self) -> String {
+        let mut text = "#[rustfmt::skip]\nimpl RowCode {\n".to_string();
+        text.push_str("    /// Returns the row code of an MNE row reported without a code, from its title.  Returns\n");
+        text.push_str(
+            "    /// `None` if the title is unknown, or if its NAICS code is not recognized.\n",
+        );
+        text.push_str("    pub fn from_title(title: &str) -> Option<Self> {\n");
+        text.push_str("        let result = match title {\n");
+        for variant in &self.variants {
+            let value = match variant.name.as_str() {
+                "Naics" | "Parent" => format!("Naics::from_code({:?})?", variant.description),
+                "Region" => format!("AreaOrCountry::{}", variant.description),
+                _ => format!("{:?}.to_string()", variant.description),
+            };
+            text.push_str(&format!(
+                "            {:?} => Self::{}({value}),\n",
+                variant.key, variant.name
+            ));
+        }
+        text.push_str(
+            "            _ => return None,\n        };\n        Some(result)\n    }\n}\n",
+        );
+        text
+    }
+
+    /// Returns `contents` with its generated region replaced by [`Self::render`].  Returns a
+    /// [`KeyMissing`] error if `contents` does not have a generated region.
+    pub fn splice(&self, contents: &str) -> Result<String, KeyMissing> {
+        let Some((start, end)) = region(contents) else {
+            let clue = format!("generated region in {}", self.target.path());
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()));
+        };
+        Ok(format!(
+            "{}{}{}",
+            &contents[..start],
+            self.render(),
+            &contents[end..]
+        ))
+    }
+}
+
+/// Joins `codes` into a match pattern, writing runs of three or more consecutive codes as a
+/// range, e.g. "31..=33" for manufacturing.
+fn patterns(codes: &[i64]) -> String {
+    let mut runs: Vec<(i64, i64)> = Vec::new();
+    for code in codes {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == *code => *end = *code,
+            _ => runs.push((*code, *code)),
+        }
+    }
+    runs.iter()
+        .flat_map(|(start, end)| {
+            if end - start >= 2 {
+                vec![format!("{start}..={end}")]
+            } else {
+                (*start..=*end).map(|code| code.to_string()).collect()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" | ")
+}
+
+/// Returns the byte range of the generated region of `contents`, from the start of the opening
+/// marker to the end of the line holding the closing marker.
+fn region(contents: &str) -> Option<(usize, usize)> {
+    let start = contents.find(BEGIN)?;
+    let end = start + contents[start..].find(END)?;
+    let end = contents[end..]
+        .find('\n')
+        .map(|i| end + i + 1)
+        .unwrap_or(contents.len());
+    Some((start, end))
+}
+
+/// Converts the parameter value `key` into a variant name.  Keys that are already valid variant
+/// names, such as "BalCapAcct" or "T10101", are kept as is.
+fn variant_name(key: &str) -> String {
+    let valid = key.starts_with(|c: char| c.is_ascii_uppercase())
+        && key.chars().all(|c| c.is_ascii_alphanumeric());
+    if valid {
+        return key.to_owned();
+    }
+    let words = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect::<String>();
+    let name = words.to_case(convert_case::Case::Pascal);
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Key{name}")
+    } else {
+        name
+    }
+}
+
+/// The `KeyGenerator` struct reads the sources of the [`KeyTarget`] enums and writes their
+/// generated regions.
+///
+/// * **root** - Path to the workspace root.
+/// * **parameter_values** - Path to the `parameter_values` directory of `BEA_DATA`, if available.
+///   Required for the enums of parameter values.
+#[derive(Debug, Clone, PartialEq, Eq, derive_getters::Getters, derive_new::new)]
+pub struct KeyGenerator {
+    root: std::path::PathBuf,
+    parameter_values: Option<std::path::PathBuf>,
+}
+
+impl KeyGenerator {
+    /// Reads the table of `target` from its source.  NAICS enums read the Census code list and
+    /// the legacy codes in the `data` directory, and [`RowCode`](crate::RowCode) reads the row
+    /// titles in the `data` directory.  Returns a [`KeyMissing`] error if `target` is an enum of
+    /// parameter values and `parameter_values` is `None`.
+    #[tracing::instrument(skip(self))]
+    pub fn table(&self, target: KeyTarget) -> Result<KeyTable, BeaErr> {
+        if target == KeyTarget::RowCode {
+            let titles = from_csv::<RowTitle, _>(self.root.join(MNE_ROW_TITLES))?;
+            return Ok(KeyTable::from_row_titles(titles, MNE_ROW_TITLES)?);
+        }
+        if target.level().is_some() {
+            let mut items = NaicsItems::from_csv(self.root.join(NAICS_CODES))?;
+            items.extend(NaicsItems::from_csv(self.root.join(NAICS_LEGACY_CODES))?.to_vec());
+            let source = format!("{NAICS_CODES} and {NAICS_LEGACY_CODES}");
+            return Ok(KeyTable::from_naics(target, &items, &source));
+        }
+        let (Some(dir), Some(name)) = (&self.parameter_values, target.parameter_values()) else {
+            let clue = format!("parameter values for {target}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        };
+        let path = dir.join(name);
+        let file = std::fs::File::open(&path)
+            .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+        let rdr = std::io::BufReader::new(file);
+        let json: serde_json::Value = serde_json::from_reader(rdr)
+            .map_err(|e| SerdeJson::new(e, line!(), file!().to_string()))?;
+        let response = BeaResponse::try_from(&json)?;
+        let source = format!("parameter_values/{name}");
+        Ok(KeyTable::from_parameter_values(target, &response, &source)?)
+    }
+
+    /// Reads the current source file of `target`.
+    pub fn contents(&self, target: KeyTarget) -> Result<String, IoError> {
+        let path = self.root.join(target.path());
+        std::fs::read_to_string(&path).map_err(|e| IoError::new(path, e, line!(), file!().into()))
+    }
+
+    /// Whether the source file of `target` has a generated region.
+    pub fn is_generated(&self, target: KeyTarget) -> Result<bool, IoError> {
+        Ok(region(&self.contents(target)?).is_some())
+    }
+
+    /// Whether the generated region of `target` matches its source.
+    #[tracing::instrument(skip(self))]
+    pub fn is_current(&self, target: KeyTarget) -> Result<bool, BeaErr> {
+        let contents = self.contents(target)?;
+        let generated = self.table(target)?.splice(&contents)?;
+        Ok(generated == contents)
+    }
+
+    /// Regenerates the region of `target` in its source file.  Returns `true` if the file changed.
+    #[tracing::instrument(skip(self))]
+    pub fn write(&self, target: KeyTarget) -> Result<bool, BeaErr> {
+        let contents = self.contents(target)?;
+        let generated = self.table(target)?.splice(&contents)?;
+        if generated == contents {
+            return Ok(false);
+        }
+        let path = self.root.join(target.path());
+        std::fs::write(&path, generated)
+            .map_err(|e| IoError::new(path, e, line!(), file!().into()))?;
+        Ok(true)
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
-    AnnotatedInteger, BeaErr, BeaResponse, DatasetMissing, FixedAssetData, GdpData, IipData,
-    InputOutputData, IoError, ItaData, KeyMissing, NaicsItems, NipaData, NotArray, NotObject,
-    RowCode, SerdeJson, UnderlyingGdpData, VariantMissing, map_to_float, map_to_int, map_to_string,
-    parse_year,
+    AnnotatedInteger, BeaErr, BeaResponse, DatasetMissing, Estimate, FixedAssetData, GdpData,
+    IipData, InputOutputData, IoError, ItaData, KeyMissing, NaicsItems, NipaData, NotArray,
+    NotObject, RowCode, SerdeJson, UnderlyingGdpData, VariantMissing, map_to_float, map_to_int,
+    map_to_string, parse_year,
 };
 
 #[derive(
@@ -74,7 +74,16 @@ pub fn result_to_data(result: &serde_json::Value) -> Result<&serde_json::Value,
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+    derive_getters::Getters,
+)]
 pub struct MneDiDatum {
     column: String,
     column_code: i64,
@@ -166,6 +175,26 @@ impl MneDiDatum {
 #[from(Vec<MneDiDatum>)]
 pub struct MneDiData(Vec<MneDiDatum>);
 
+impl MneDiData {
+    /// Sums the values of the records in self grouped by `key`, such as the series and year across
+    /// countries or industries.  Each total is an [`Estimate`] that carries the bounds implied by
+    /// annotated cells and whether any suppressed cell contributed, rather than counting
+    /// suppressed cells as zero.
+    pub fn aggregate<K: Ord>(
+        &self,
+        key: impl Fn(&MneDiDatum) -> K,
+    ) -> std::collections::BTreeMap<K, Estimate> {
+        let mut totals = std::collections::BTreeMap::new();
+        self.iter()
+            .map(|datum| {
+                let total = totals.entry(key(datum)).or_insert(Estimate::exact(0.0));
+                *total = *total + datum.data_value_unformatted().estimate();
+            })
+            .for_each(drop);
+        totals
+    }
+}
+
 impl TryFrom<&std::path::PathBuf> for MneDiData {
     type Error = BeaErr;
 
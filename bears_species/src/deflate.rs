@@ -0,0 +1,279 @@
+use crate::{
+    Aggregation, BeaErr, Data, Frequency, KeyMissing, NipaData, NipaDatum, NipaTableName, Period,
+    Quantity, Series,
+};
+use std::collections::BTreeMap;
+
+/// The `PriceIndex` struct holds a chain-type price index or implicit price deflator from a NIPA
+/// price table, used to convert current-dollar series to real values.
+///
+/// * **table** - The NIPA table reporting the index.
+/// * **series** - The index, equal to 100 on average in the reference year.
+/// * **reference** - The reference year of the index.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct PriceIndex {
+    table: NipaTableName,
+    series: Series,
+    reference: i16,
+}
+
+impl PriceIndex {
+    /// Creates a price index from the series `series` of the table `table`, detecting the
+    /// reference year as the year in which the index averages 100.  Returns an error if `table`
+    /// is not a price table or no year averages 100.
+    pub fn new(table: NipaTableName, series: Series) -> Result<Self, BeaErr> {
+        if !table.is_price_index() {
+            let clue = format!("{table} is not a price index table");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+        let reference = annual_means(&series)
+            .into_iter()
+            .map(|(year, mean)| (year, (mean - 100.0).abs()))
+            .filter(|(_, distance)| *distance < 0.01)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(year, _)| year);
+        match reference {
+            Some(reference) => Ok(Self {
+                table,
+                series,
+                reference,
+            }),
+            None => {
+                let clue = format!("reference year of {} in {table}", series.id());
+                Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+            }
+        }
+    }
+
+    /// Loads the series with code `series_code` at frequency `frequency` from the file
+    /// `NIPA_{table}.json` in the NIPA data directory `path`, usually `BEA_DATA/data/NIPA`.
+    #[tracing::instrument]
+    pub fn from_store(
+        path: &std::path::Path,
+        table: NipaTableName,
+        series_code: &str,
+        frequency: Frequency,
+    ) -> Result<Self, BeaErr> {
+        let path = path.join(format!("NIPA_{table}.json"));
+        let data = Data::from(NipaData::try_from(&path)?);
+        match data.series().series(series_code, frequency) {
+            Some(series) => Self::new(table, series.clone()),
+            None => {
+                let clue = format!("{series_code} ({frequency}) in {}", path.display());
+                Err(KeyMissing::new(clue, line!(), file!().to_string()).into())
+            }
+        }
+    }
+
+    /// Loads the price index for the current-dollar series with code `series_code` in the table
+    /// `nominal`, from the NIPA data directory `path`.  The index is read from the price table
+    /// of `nominal` given by [`NipaTableName::price_index`], at the line number of the series in
+    /// `nominal`.  Returns a [`KeyMissing`] error if `nominal` has no price table, or if either
+    /// table lacks the line.
+    #[tracing::instrument]
+    pub fn from_nominal(
+        path: &std::path::Path,
+        nominal: NipaTableName,
+        series_code: &str,
+        frequency: Frequency,
+    ) -> Result<Self, BeaErr> {
+        let Some(table) = nominal.price_index() else {
+            let clue = format!("price table for {nominal}");
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        };
+        let line = |table: NipaTableName, find: &dyn Fn(&NipaDatum) -> bool| {
+            let data = NipaData::try_from(&path.join(format!("NIPA_{table}.json")))?;
+            match data.iter().find(|datum| find(datum)) {
+                Some(datum) => Ok((*datum.line_number(), datum.series_code().to_owned())),
+                None => {
+                    let clue = format!("{series_code} line in {table}");
+                    Err(BeaErr::from(KeyMissing::new(
+                        clue,
+                        line!(),
+                        file!().to_string(),
+                    )))
+                }
+            }
+        };
+        let (number, _) = line(nominal, &|datum| datum.series_code() == series_code)?;
+        let (_, price_code) = line(table, &|datum| *datum.line_number() == number)?;
+        tracing::trace!("{series_code} in {nominal} deflated by {price_code} in {table}.");
+        Self::from_store(path, table, &price_code, frequency)
+    }
+
+    /// Rebases the index so that it averages 100 in the year `year`.  Returns an error if the
+    /// index does not cover every period of `year`.
+    pub fn rebase(&self, year: i16) -> Result<Self, BeaErr> {
+        let Some(mean) = annual_means(&self.series).get(&year).copied() else {
+            let clue = format!("{} does not cover {year}", self.series.id());
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        };
+        let observations = self
+            .series
+            .observations()
+            .iter()
+            .map(|(period, quantity)| match quantity.value() {
+                Some(value) => (*period, Quantity::Value(value / mean * 100.0)),
+                None => (*period, Quantity::Missing),
+            })
+            .collect();
+        let series = Series::new(
+            self.series.id().to_owned(),
+            self.series.description().to_owned(),
+            *self.series.frequency(),
+            format!("Index {year}=100"),
+            None,
+            observations,
+        );
+        Ok(Self {
+            table: self.table,
+            series,
+            reference: year,
+        })
+    }
+
+    /// The index at frequency `frequency`.  Higher frequencies are averaged to `frequency`,
+    /// following the BEA convention that an annual index is the mean of its quarters.  Returns
+    /// an error if `frequency` is higher than the frequency of the index.
+    pub fn at_frequency(&self, frequency: Frequency) -> Result<Series, BeaErr> {
+        if *self.series.frequency() == frequency {
+            return Ok(self.series.clone());
+        }
+        self.series.aggregate(frequency, Aggregation::Average)
+    }
+}
+
+/// The `Provenance` struct records how a real series was derived from a nominal series.
+///
+/// * **nominal** - Identifier of the nominal series.
+/// * **index** - Identifier of the price index.
+/// * **table** - The NIPA table reporting the price index.
+/// * **reference** - Reference year of the price index, and the year of the real dollars.
+/// * **frequency** - Frequency at which the index was applied.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Provenance {
+    nominal: String,
+    index: String,
+    table: NipaTableName,
+    reference: i16,
+    frequency: Frequency,
+}
+
+impl std::fmt::Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in {} dollars, deflated by {} ({}=100) from {}",
+            self.nominal,
+            self.reference,
+            self.index,
+            self.reference,
+            self.table.description()
+        )
+    }
+}
+
+/// The `RealSeries` struct holds a series deflated by [`Series::deflate`] together with its
+/// [`Provenance`].
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct RealSeries {
+    series: Series,
+    provenance: Provenance,
+}
+
+impl Series {
+    /// The `deflate` method converts self from current dollars to dollars of the reference year
+    /// of `index`, dividing each value by the index and multiplying by 100.
+    ///
+    /// The index is averaged to the frequency of self if it is reported more often.  Periods
+    /// without an index value are marked missing.  Returns an error if the index is reported less
+    /// often than self, or if no period of self has an index value.
+    pub fn deflate(&self, index: &PriceIndex) -> Result<RealSeries, BeaErr> {
+        let aligned = index.at_frequency(*self.frequency())?;
+        let provenance = Provenance::new(
+            self.id().to_owned(),
+            index.series().id().to_owned(),
+            *index.table(),
+            *index.reference(),
+            *self.frequency(),
+        );
+        let observations = self
+            .observations()
+            .iter()
+            .map(|(period, quantity)| {
+                let value = quantity
+                    .value()
+                    .zip(aligned.get(period))
+                    .filter(|(_, price)| *price != 0.0)
+                    .map(|(value, price)| value / price * 100.0);
+                match value {
+                    Some(value) => (*period, Quantity::Value(value)),
+                    None => (*period, Quantity::Missing),
+                }
+            })
+            .collect::<Vec<(Period, Quantity)>>();
+        if observations
+            .iter()
+            .all(|(_, quantity)| *quantity == Quantity::Missing)
+        {
+            let clue = format!(
+                "{} has no periods in common with {}",
+                self.id(),
+                aligned.id()
+            );
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+        let series = Self::new(
+            self.id().to_owned(),
+            format!("{}, real", self.description()),
+            *self.frequency(),
+            format!("Chained ({}) dollars", index.reference()),
+            *self.unit_mult(),
+            observations,
+        );
+        Ok(RealSeries { series, provenance })
+    }
+}
+
+/// The mean of each year fully covered by the observations of `series`.
+fn annual_means(series: &Series) -> BTreeMap<i16, f64> {
+    let mut years = BTreeMap::<i16, Vec<f64>>::new();
+    series
+        .values()
+        .into_iter()
+        .map(|(period, value)| years.entry(period.year()).or_default().push(value))
+        .for_each(drop);
+    years
+        .into_iter()
+        .filter(|(_, values)| values.len() as i64 == series.frequency().per_year())
+        .map(|(year, values)| (year, values.iter().sum::<f64>() / values.len() as f64))
+        .collect()
+}
@@ -58,6 +58,7 @@ impl_bea_err!(
     IoError,
     Jiff,
     JsonParseError,
+    MatrixInvalid,
     Nom,
     OwnershipInvalid,
     ParameterValueTableVariant,
@@ -120,6 +121,8 @@ pub enum BeaErrorKind {
     Jiff(Jiff),
     #[from(JsonParseError)]
     JsonParse(JsonParseError),
+    #[from(MatrixInvalid)]
+    MatrixInvalid(MatrixInvalid),
     #[from(Nom)]
     Nom(Nom),
     #[from(OwnershipInvalid)]
@@ -190,6 +193,9 @@ impl std::fmt::Display for BeaErrorKind {
             Self::JsonParse(e) => {
                 write!(f, "{e}")
             }
+            Self::MatrixInvalid(e) => {
+                write!(f, "{e}")
+            }
             Self::Nom(e) => {
                 write!(f, "{e}")
             }
@@ -249,6 +255,7 @@ impl std::error::Error for BeaErrorKind {
             Self::Io(e) => Some(e.source()),
             Self::Jiff(e) => e.source(),
             Self::JsonParse(e) => e.source(),
+            Self::MatrixInvalid(e) => e.source(),
             Self::Nom(e) => e.source(),
             Self::OwnershipInvalid(e) => e.source(),
             Self::ParameterValueTableVariant(e) => e.source(),
@@ -729,3 +736,19 @@ impl std::error::Error for RateLimit {
         None
     }
 }
+
+/// The `MatrixInvalid` struct reports a matrix operation that cannot be performed, such as
+/// inverting a singular or non-square matrix.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_new::new)]
+#[display("matrix invalid: {clue} at line {line} in {file}")]
+pub struct MatrixInvalid {
+    clue: String,
+    line: u32,
+    file: String,
+}
+
+impl std::error::Error for MatrixInvalid {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
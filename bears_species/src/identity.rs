@@ -0,0 +1,402 @@
+use crate::{
+    Component, Data, Dataset, Frequency, IipData, Indicator, Investment, ItaData, ItaFrequency,
+    NipaData,
+};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// The `Identity` struct describes an accounting identity between the lines of a table, where the
+/// value of the `total` line equals the sum of the `terms`, each multiplied by its sign.
+struct Identity<K: 'static> {
+    name: &'static str,
+    total: K,
+    terms: &'static [(K, f64)],
+}
+
+/// Identities between the lines of NIPA Table 1.1.5, Gross Domestic Product.
+const TABLE_1_1_5: [Identity<i64>; 11] = [
+    Identity {
+        name: "GDP = C + I + NX + G",
+        total: 1,
+        terms: &[(2, 1.0), (7, 1.0), (15, 1.0), (22, 1.0)],
+    },
+    Identity {
+        name: "PCE = goods + services",
+        total: 2,
+        terms: &[(3, 1.0), (6, 1.0)],
+    },
+    Identity {
+        name: "Goods = durable + nondurable",
+        total: 3,
+        terms: &[(4, 1.0), (5, 1.0)],
+    },
+    Identity {
+        name: "Gross private domestic investment = fixed investment + change in inventories",
+        total: 7,
+        terms: &[(8, 1.0), (14, 1.0)],
+    },
+    Identity {
+        name: "Fixed investment = nonresidential + residential",
+        total: 8,
+        terms: &[(9, 1.0), (13, 1.0)],
+    },
+    Identity {
+        name: "Nonresidential = structures + equipment + intellectual property",
+        total: 9,
+        terms: &[(10, 1.0), (11, 1.0), (12, 1.0)],
+    },
+    Identity {
+        name: "Net exports = exports - imports",
+        total: 15,
+        terms: &[(16, 1.0), (19, -1.0)],
+    },
+    Identity {
+        name: "Exports = goods + services",
+        total: 16,
+        terms: &[(17, 1.0), (18, 1.0)],
+    },
+    Identity {
+        name: "Imports = goods + services",
+        total: 19,
+        terms: &[(20, 1.0), (21, 1.0)],
+    },
+    Identity {
+        name: "Government = federal + state and local",
+        total: 22,
+        terms: &[(23, 1.0), (26, 1.0)],
+    },
+    Identity {
+        name: "Federal = national defense + nondefense",
+        total: 23,
+        terms: &[(24, 1.0), (25, 1.0)],
+    },
+];
+
+/// Balances of the ITA current and capital accounts, equal to credits minus debits.
+const ITA_BALANCES: [Identity<Indicator>; 7] = [
+    Identity {
+        name: "Balance on goods = exports - imports",
+        total: Indicator::BalGds,
+        terms: &[(Indicator::ExpGds, 1.0), (Indicator::ImpGds, -1.0)],
+    },
+    Identity {
+        name: "Balance on services = exports - imports",
+        total: Indicator::BalServ,
+        terms: &[(Indicator::ExpServ, 1.0), (Indicator::ImpServ, -1.0)],
+    },
+    Identity {
+        name: "Balance on goods and services = exports - imports",
+        total: Indicator::BalGdsServ,
+        terms: &[(Indicator::ExpGdsServ, 1.0), (Indicator::ImpGdsServ, -1.0)],
+    },
+    Identity {
+        name: "Balance on primary income = receipts - payments",
+        total: Indicator::BalPrimInc,
+        terms: &[(Indicator::PrimIncRec, 1.0), (Indicator::PrimIncPay, -1.0)],
+    },
+    Identity {
+        name: "Balance on secondary income = receipts - payments",
+        total: Indicator::BalSecInc,
+        terms: &[(Indicator::SecIncRec, 1.0), (Indicator::SecIncPay, -1.0)],
+    },
+    Identity {
+        name: "Current account balance = credits - debits",
+        total: Indicator::BalCurrAcct,
+        terms: &[
+            (Indicator::ExpGdsServIncRec, 1.0),
+            (Indicator::ImpGdsServIncPay, -1.0),
+        ],
+    },
+    Identity {
+        name: "Capital account balance = credits - debits",
+        total: Indicator::BalCapAcct,
+        terms: &[
+            (Indicator::CapTransRecAndOthCred, 1.0),
+            (Indicator::CapTransPayAndOthDeb, -1.0),
+        ],
+    },
+];
+
+/// Decomposition of the change in an IIP position into financial transactions and other changes.
+const IIP_CHANGES: [Identity<Component>; 2] = [
+    Identity {
+        name: "Change in position = transactions + other changes",
+        total: Component::ChgPos,
+        terms: &[(Component::ChgPosTrans, 1.0), (Component::ChgPosOth, 1.0)],
+    },
+    Identity {
+        name: "Other changes = price + exchange-rate + other volume changes",
+        total: Component::ChgPosOth,
+        terms: &[
+            (Component::ChgPosPrice, 1.0),
+            (Component::ChgPosXRate, 1.0),
+            (Component::ChgPosNie, 1.0),
+        ],
+    },
+];
+
+/// Values of the lines of one table, area or investment type in one period, each paired with a
+/// reference to the line or series reporting it.  Suppressed values are `None`.
+type Lines<K> = BTreeMap<K, (Option<f64>, String)>;
+
+/// The `Violation` struct records an accounting identity that does not hold in loaded data.
+///
+/// * **dataset** - The dataset of the data.
+/// * **identity** - Description of the identity.
+/// * **context** - The table, area or investment type and the frequency of the data.
+/// * **period** - The time period of the data.
+/// * **reported** - The reported value of the total.
+/// * **computed** - The total computed from its terms, or `None` if a term is missing from the
+///   data, as in a partial download.
+/// * **references** - The line numbers or series identifiers of the total and its terms.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Violation {
+    dataset: Dataset,
+    identity: String,
+    context: String,
+    period: jiff::civil::Date,
+    reported: f64,
+    computed: Option<f64>,
+    references: Vec<String>,
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let computed = match self.computed {
+            Some(value) => value.to_string(),
+            None => "incomplete".to_owned(),
+        };
+        write!(
+            f,
+            "{} {} {}: {} fails, reported {} and computed {} from {}",
+            self.dataset,
+            self.context,
+            self.period,
+            self.identity,
+            self.reported,
+            computed,
+            self.references.join(", ")
+        )
+    }
+}
+
+/// Checks each of `identities` against `lines`, returning a [`Violation`] for each identity whose
+/// total differs from the sum of its terms by more than `tolerance`, or whose total is reported
+/// while a term is missing.  Identities with a missing or suppressed total, or a suppressed term,
+/// cannot be checked and are skipped.
+fn check<K: Ord>(
+    identities: &[Identity<K>],
+    lines: &Lines<K>,
+    tolerance: f64,
+    dataset: Dataset,
+    context: &str,
+    period: jiff::civil::Date,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for identity in identities {
+        let Some((Some(reported), reference)) = lines.get(&identity.total) else {
+            continue;
+        };
+        let mut references = vec![reference.to_owned()];
+        let mut computed = Some(0.0);
+        let mut suppressed = false;
+        for (key, sign) in identity.terms {
+            match lines.get(key) {
+                Some((Some(value), reference)) => {
+                    computed = computed.map(|sum| sum + sign * value);
+                    references.push(reference.to_owned());
+                }
+                Some((None, _)) => suppressed = true,
+                None => computed = None,
+            }
+        }
+        if suppressed {
+            continue;
+        }
+        if computed.is_none_or(|computed| (computed - reported).abs() > tolerance) {
+            violations.push(Violation::new(
+                dataset,
+                identity.name.to_owned(),
+                context.to_owned(),
+                period,
+                *reported,
+                computed,
+                references,
+            ));
+        }
+    }
+    violations
+}
+
+impl NipaData {
+    /// Checks the accounting identities between the lines of the NIPA tables in self, returning
+    /// the identities that do not hold within `tolerance`, in the units of the reported values.
+    /// Identities are defined for Table 1.1.5, Gross Domestic Product.
+    pub fn violations(&self, tolerance: f64) -> Vec<Violation> {
+        let mut tables = BTreeMap::<(String, Frequency, jiff::civil::Date), Lines<i64>>::new();
+        self.iter()
+            .map(|datum| {
+                let reference = format!("line {} ({})", datum.line_number(), datum.series_code());
+                tables
+                    .entry((
+                        datum.table_name().to_owned(),
+                        *datum.frequency(),
+                        *datum.time_period(),
+                    ))
+                    .or_default()
+                    .insert(*datum.line_number(), (Some(*datum.data_value()), reference))
+            })
+            .for_each(drop);
+        tables
+            .iter()
+            .flat_map(|((table, frequency, period), lines)| {
+                let identities: &[Identity<i64>] = match table.as_str() {
+                    "T10105" => &TABLE_1_1_5,
+                    _ => &[],
+                };
+                let context = format!("{table} ({frequency})");
+                check(
+                    identities,
+                    lines,
+                    tolerance,
+                    Dataset::Nipa,
+                    &context,
+                    *period,
+                )
+            })
+            .collect()
+    }
+}
+
+impl ItaData {
+    /// Checks that the current and capital account balances in self equal credits minus debits
+    /// for each area and period, returning the balances that do not hold within `tolerance`.
+    /// Records with an indicator not recognized by [`Indicator`] are ignored.
+    pub fn violations(&self, tolerance: f64) -> Vec<Violation> {
+        let mut areas = BTreeMap::new();
+        self.iter()
+            .filter_map(|datum| {
+                let indicator = Indicator::from_str(datum.indicator()).ok()?;
+                let value = datum.data_value().map(|value| value as f64);
+                areas
+                    .entry((
+                        *datum.area_or_country(),
+                        *datum.frequency(),
+                        *datum.time_period(),
+                    ))
+                    .or_insert_with(Lines::new)
+                    .insert(indicator, (value, datum.time_series_id().to_owned()))
+            })
+            .for_each(drop);
+        areas
+            .iter()
+            .flat_map(|((area, frequency, period), lines)| {
+                let context = format!("{area} ({frequency})");
+                check(
+                    &ITA_BALANCES,
+                    lines,
+                    tolerance,
+                    Dataset::Ita,
+                    &context,
+                    *period,
+                )
+            })
+            .collect()
+    }
+}
+
+impl IipData {
+    /// Checks for each type of investment and period in self that the change in position equals
+    /// financial transactions plus price, exchange-rate and other changes, and that the change in
+    /// position equals the difference from the position in the prior period.  Returns the
+    /// identities that do not hold within `tolerance`.
+    pub fn violations(&self, tolerance: f64) -> Vec<Violation> {
+        let mut investments =
+            BTreeMap::<(Investment, ItaFrequency, jiff::civil::Date), Lines<Component>>::new();
+        self.iter()
+            .map(|datum| {
+                let value = datum.data_value().map(|value| value as f64);
+                investments
+                    .entry((
+                        datum.type_of_investment().to_owned(),
+                        *datum.frequency(),
+                        *datum.time_period(),
+                    ))
+                    .or_default()
+                    .insert(
+                        *datum.component(),
+                        (value, datum.time_series_id().to_owned()),
+                    )
+            })
+            .for_each(drop);
+        let mut violations = Vec::new();
+        for ((investment, frequency, period), lines) in &investments {
+            let context = format!("{investment} ({frequency})");
+            violations.extend(check(
+                &IIP_CHANGES,
+                lines,
+                tolerance,
+                Dataset::Iip,
+                &context,
+                *period,
+            ));
+            let months = match frequency {
+                ItaFrequency::Annual => 12,
+                ItaFrequency::Qnsa | ItaFrequency::Qsa => 3,
+            };
+            let Ok(prior) = period.checked_sub(jiff::Span::new().months(months)) else {
+                continue;
+            };
+            let (Some((Some(position), reference)), Some((Some(change), change_reference))) =
+                (lines.get(&Component::Pos), lines.get(&Component::ChgPos))
+            else {
+                continue;
+            };
+            let Some((Some(previous), previous_reference)) = investments
+                .get(&(investment.to_owned(), *frequency, prior))
+                .and_then(|lines| lines.get(&Component::Pos))
+            else {
+                continue;
+            };
+            let computed = previous + change;
+            if (computed - position).abs() > tolerance {
+                violations.push(Violation::new(
+                    Dataset::Iip,
+                    "Position = prior position + change in position".to_owned(),
+                    context,
+                    *period,
+                    *position,
+                    Some(computed),
+                    vec![
+                        format!("{reference} ({period})"),
+                        format!("{previous_reference} ({prior})"),
+                        format!("{change_reference} ({period})"),
+                    ],
+                ));
+            }
+        }
+        violations
+    }
+}
+
+impl Data {
+    /// Checks the accounting identities of NIPA, ITA and IIP data, returning the identities that
+    /// do not hold within `tolerance`.  Returns no violations for other datasets.
+    pub fn violations(&self, tolerance: f64) -> Vec<Violation> {
+        match self {
+            Self::Nipa(data) => data.violations(tolerance),
+            Self::ItaData(data) => data.violations(tolerance),
+            Self::Iip(data) => data.violations(tolerance),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{
+    BeaErr, InputOutputCode, InputOutputData, InputOutputTable, KeyMissing, MatrixInvalid, Naics,
+    NaicsInputOutput,
+};
+use std::collections::BTreeMap;
+
+/// Pivots smaller than this value in absolute terms are treated as zero when inverting a matrix.
+const PIVOT_TOLERANCE: f64 = 1e-12;
+
+/// The `IoMatrix` struct holds a single table and year of the InputOutput dataset as a dense
+/// matrix, with rows and columns indexed by [`InputOutputCode`].
+///
+/// * **table_id** - The input-output table of the matrix.
+/// * **year** - The year of the matrix.
+/// * **rows** - Row codes, in the order they first appear in the source data.
+/// * **columns** - Column codes, in the order they first appear in the source data.
+/// * **values** - Cell values in row-major order.  Cells absent from the source data are zero.
+///
+/// For the Use tables, rows are commodities and columns are industries, with value added and
+/// total rows below the commodities and final demand and total columns after the industries.
+/// The total requirements tables are already Leontief inverses, and can be used directly with
+/// [`IoMatrix::output_multipliers`] and [`IoMatrix::impact`].
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct IoMatrix {
+    table_id: InputOutputTable,
+    year: jiff::civil::Date,
+    rows: Vec<InputOutputCode>,
+    columns: Vec<InputOutputCode>,
+    values: Vec<f64>,
+}
+
+impl IoMatrix {
+    /// Creates the matrix of the records in `data` for the table `table_id` in the year `year`.
+    /// Records from other tables and years are ignored.
+    #[tracing::instrument(skip(data))]
+    pub fn new(
+        data: &InputOutputData,
+        table_id: InputOutputTable,
+        year: jiff::civil::Date,
+    ) -> Self {
+        let records = data
+            .iter()
+            .filter(|d| *d.table_id() == table_id && *d.year() == year)
+            .collect::<Vec<_>>();
+        let mut rows = Vec::new();
+        let mut columns = Vec::new();
+        records
+            .iter()
+            .map(|d| {
+                if !rows.contains(d.row_code()) {
+                    rows.push(*d.row_code());
+                }
+                if !columns.contains(d.column_code()) {
+                    columns.push(*d.column_code());
+                }
+            })
+            .for_each(drop);
+        let mut matrix = Self::zeros(table_id, year, rows, columns);
+        records
+            .iter()
+            .map(|d| {
+                if let Some(index) = matrix.index(d.row_code(), d.column_code()) {
+                    matrix.values[index] += *d.data_value();
+                }
+            })
+            .for_each(drop);
+        tracing::info!(
+            "{} by {} matrix for table {table_id} in {}.",
+            matrix.rows.len(),
+            matrix.columns.len(),
+            year.year()
+        );
+        matrix
+    }
+
+    /// Creates a matrix of zeros with the metadata `table_id` and `year`.
+    fn zeros(
+        table_id: InputOutputTable,
+        year: jiff::civil::Date,
+        rows: Vec<InputOutputCode>,
+        columns: Vec<InputOutputCode>,
+    ) -> Self {
+        let values = vec![0.0; rows.len() * columns.len()];
+        Self {
+            table_id,
+            year,
+            rows,
+            columns,
+            values,
+        }
+    }
+
+    /// Index into `values` of the cell at `row` and `column`.
+    fn index(&self, row: &InputOutputCode, column: &InputOutputCode) -> Option<usize> {
+        let i = self.rows.iter().position(|r| r == row)?;
+        let j = self.columns.iter().position(|c| c == column)?;
+        Some(i * self.columns.len() + j)
+    }
+
+    /// The `get` method returns the value of the cell at `row` and `column`, or `None` if either
+    /// code is not in the matrix.
+    pub fn get(&self, row: &InputOutputCode, column: &InputOutputCode) -> Option<f64> {
+        self.index(row, column).map(|index| self.values[index])
+    }
+
+    /// The `is_square` method returns `true` if the rows and columns have the same codes in the
+    /// same order.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    /// The `submatrix` method returns the matrix restricted to `rows` and `columns`, in the
+    /// order given.  Codes not present in self produce rows or columns of zeros.
+    pub fn submatrix(&self, rows: &[InputOutputCode], columns: &[InputOutputCode]) -> Self {
+        let mut matrix = Self::zeros(self.table_id, self.year, rows.to_vec(), columns.to_vec());
+        for (i, row) in rows.iter().enumerate() {
+            for (j, column) in columns.iter().enumerate() {
+                if let Some(value) = self.get(row, column) {
+                    matrix.values[i * columns.len() + j] = value;
+                }
+            }
+        }
+        matrix
+    }
+
+    /// The `intermediate` method returns the square block of codes that appear as both rows
+    /// and columns, excluding table totals.  In the Use tables this is the block of intermediate
+    /// inputs, where commodities and industries share codes.
+    pub fn intermediate(&self) -> Self {
+        let codes = self
+            .rows
+            .iter()
+            .filter(|code| self.columns.contains(code) && !is_total(code))
+            .copied()
+            .collect::<Vec<InputOutputCode>>();
+        self.submatrix(&codes, &codes)
+    }
+
+    /// The `direct_requirements` method computes the direct requirements coefficients of a Use
+    /// table, dividing each column of the [`IoMatrix::intermediate`] block by the total output of
+    /// the industry in that column.  The coefficient in row `i` and column `j` is the input from
+    /// `i` required to produce one dollar of output in `j`.
+    ///
+    /// Total output is read from the row of total industry output (code "T018").  Returns a
+    /// [`KeyMissing`] error if the matrix has no such row.  Industries with zero output have zero
+    /// coefficients.
+    pub fn direct_requirements(&self) -> Result<Self, BeaErr> {
+        let total = InputOutputCode::from(Naics::from(
+            NaicsInputOutput::TotalIndustryOutputBasicPrices,
+        ));
+        if !self.rows.contains(&total) {
+            let clue = format!("total industry output row in table {}", self.table_id);
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+        let mut matrix = self.intermediate();
+        let n = matrix.columns.len();
+        let outputs = matrix
+            .columns
+            .iter()
+            .map(|column| self.get(&total, column).unwrap_or_default())
+            .collect::<Vec<f64>>();
+        for (j, output) in outputs.into_iter().enumerate() {
+            for i in 0..matrix.rows.len() {
+                let value = &mut matrix.values[i * n + j];
+                *value = if output == 0.0 { 0.0 } else { *value / output };
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// The `leontief_inverse` method computes the total requirements matrix `(I - A)^-1` from a
+    /// square matrix of direct requirements coefficients `A`.  The entry in row `i` and column
+    /// `j` is the output of `i` required, directly and indirectly, to deliver one dollar of `j`
+    /// to final demand.
+    ///
+    /// Returns a [`MatrixInvalid`] error if self is not square or `I - A` is singular.
+    #[tracing::instrument(skip_all)]
+    pub fn leontief_inverse(&self) -> Result<Self, BeaErr> {
+        if !self.is_square() {
+            let clue = format!(
+                "Leontief inverse of a {} by {} matrix",
+                self.rows.len(),
+                self.columns.len()
+            );
+            return Err(MatrixInvalid::new(clue, line!(), file!().to_string()).into());
+        }
+        let n = self.rows.len();
+        let mut identity_less = self.values.iter().map(|v| -v).collect::<Vec<f64>>();
+        for i in 0..n {
+            identity_less[i * n + i] += 1.0;
+        }
+        match invert(n, identity_less) {
+            Some(values) => Ok(Self {
+                values,
+                ..self.clone()
+            }),
+            None => {
+                let clue = format!("I - A is singular for table {}", self.table_id);
+                Err(MatrixInvalid::new(clue, line!(), file!().to_string()).into())
+            }
+        }
+    }
+
+    /// The `output_multipliers` method returns the column sums of a total requirements matrix,
+    /// keyed by column code.  The multiplier of an industry is the total output across all
+    /// industries generated by one dollar of final demand for that industry.
+    pub fn output_multipliers(&self) -> BTreeMap<InputOutputCode, f64> {
+        let n = self.columns.len();
+        let mut multipliers = BTreeMap::new();
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(j, column)| {
+                let sum = (0..self.rows.len()).map(|i| self.values[i * n + j]).sum();
+                multipliers.insert(*column, sum)
+            })
+            .for_each(drop);
+        multipliers
+    }
+
+    /// The `employment_multipliers` method weights each row of a total requirements matrix by
+    /// `intensity`, the employment per dollar of output of the row industry, and returns the
+    /// column sums keyed by column code.  The multiplier of an industry is the employment across
+    /// all industries supported by one dollar of final demand for that industry.
+    ///
+    /// The InputOutput dataset does not report employment, so the caller supplies intensities
+    /// from another source.  Rows missing from `intensity` contribute no employment.
+    pub fn employment_multipliers(
+        &self,
+        intensity: &BTreeMap<InputOutputCode, f64>,
+    ) -> BTreeMap<InputOutputCode, f64> {
+        let n = self.columns.len();
+        let mut multipliers = BTreeMap::new();
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(j, column)| {
+                let sum = self
+                    .rows
+                    .iter()
+                    .enumerate()
+                    .map(|(i, row)| intensity.get(row).unwrap_or(&0.0) * self.values[i * n + j])
+                    .sum();
+                multipliers.insert(*column, sum)
+            })
+            .for_each(drop);
+        multipliers
+    }
+
+    /// The `impact` method multiplies a total requirements matrix by the change in final demand
+    /// `demand`, keyed by column code, and returns the resulting change in output keyed by row
+    /// code.  Codes in `demand` that are not columns of the matrix are ignored.
+    pub fn impact(
+        &self,
+        demand: &BTreeMap<InputOutputCode, f64>,
+    ) -> BTreeMap<InputOutputCode, f64> {
+        let n = self.columns.len();
+        let mut output = BTreeMap::new();
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let sum = self
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(j, column)| demand.get(column).unwrap_or(&0.0) * self.values[i * n + j])
+                    .sum();
+                output.insert(*row, sum)
+            })
+            .for_each(drop);
+        output
+    }
+
+    /// The `aggregate` method sums the rows and columns of self that `f` maps to the same code.
+    /// Aggregated codes appear in the order of their first member.
+    ///
+    /// Aggregate a matrix of flows, such as a Use table, and derive coefficients from the result.
+    /// Sums of coefficients or of total requirements are not meaningful.
+    pub fn aggregate<F: Fn(&InputOutputCode) -> InputOutputCode>(&self, f: F) -> Self {
+        let group = |codes: &[InputOutputCode]| {
+            let mut groups = Vec::new();
+            let indices = codes
+                .iter()
+                .map(|code| {
+                    let code = f(code);
+                    match groups.iter().position(|g| *g == code) {
+                        Some(index) => index,
+                        None => {
+                            groups.push(code);
+                            groups.len() - 1
+                        }
+                    }
+                })
+                .collect::<Vec<usize>>();
+            (groups, indices)
+        };
+        let (rows, row_indices) = group(&self.rows);
+        let (columns, column_indices) = group(&self.columns);
+        let mut matrix = Self::zeros(self.table_id, self.year, rows, columns);
+        let n = matrix.columns.len();
+        for (i, row) in row_indices.iter().enumerate() {
+            for (j, column) in column_indices.iter().enumerate() {
+                matrix.values[row * n + column] += self.values[i * self.columns.len() + j];
+            }
+        }
+        matrix
+    }
+
+    /// The `by_sector` method aggregates the rows and columns of self to NAICS sectors using
+    /// [`Naics::sector`].  Codes without a sector, such as value added, final demand and totals,
+    /// are kept as they are.
+    pub fn by_sector(&self) -> Self {
+        self.aggregate(|code| match code.sector() {
+            Some(sector) => InputOutputCode::from(Naics::from(sector)),
+            None => *code,
+        })
+    }
+}
+
+/// Returns `true` for the total rows and columns of the input-output tables, with codes of the
+/// form "T" followed by digits.
+fn is_total(code: &InputOutputCode) -> bool {
+    let code = code.code();
+    let mut chars = code.chars();
+    chars.next() == Some('T') && chars.next().is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Inverts the `n` by `n` row-major matrix `values` by Gauss-Jordan elimination with partial
+/// pivoting.  Returns `None` if the matrix is singular.
+pub(crate) fn invert(n: usize, mut values: Vec<f64>) -> Option<Vec<f64>> {
+    let mut inverse = vec![0.0; n * n];
+    for i in 0..n {
+        inverse[i * n + i] = 1.0;
+    }
+    for col in 0..n {
+        let pivot = (col..n).max_by(|a, b| {
+            values[a * n + col]
+                .abs()
+                .total_cmp(&values[b * n + col].abs())
+        })?;
+        if values[pivot * n + col].abs() < PIVOT_TOLERANCE {
+            return None;
+        }
+        if pivot != col {
+            for k in 0..n {
+                values.swap(pivot * n + k, col * n + k);
+                inverse.swap(pivot * n + k, col * n + k);
+            }
+        }
+        let scale = values[col * n + col];
+        for k in 0..n {
+            values[col * n + k] /= scale;
+            inverse[col * n + k] /= scale;
+        }
+        for row in 0..n {
+            let factor = values[row * n + col];
+            if row != col && factor != 0.0 {
+                for k in 0..n {
+                    values[row * n + k] -= factor * values[col * n + k];
+                    inverse[row * n + k] -= factor * inverse[col * n + k];
+                }
+            }
+        }
+    }
+    Some(inverse)
+}
@@ -1,8 +1,9 @@
 use crate::{
     BeaErr, BeaResponse, Data, Dataset, DatasetMissing, Frequencies, Frequency, Integer, IoError,
-    JsonParseError, KeyMissing, Naics, NotArray, NotObject, ParameterFields, ParameterName,
-    ParameterValueTable, SelectionKind, SerdeJson, Set, VariantMissing, Year, data::result_to_data,
-    map_to_float, map_to_int, map_to_string, parse_year, roman_numeral_quarter,
+    JsonParseError, KeyMissing, Naics, NaicsConcordance, NaicsLevel, NotArray, NotObject,
+    ParameterFields, ParameterName, ParameterValueTable, SelectionKind, SerdeJson, Set,
+    VariantMissing, Year, data::result_to_data, map_to_float, map_to_int, map_to_string,
+    parse_year, roman_numeral_quarter,
 };
 
 #[derive(
@@ -553,6 +554,98 @@ impl GdpData {
             .for_each(drop);
         set
     }
+
+    /// The `roll_up` method sums the values of each table, frequency and period to the industries
+    /// at `level` of the NAICS hierarchy, using [`NaicsLevel::groups`] so that published
+    /// aggregates and their components are not counted twice.  Industries without an ancestor at
+    /// `level` are kept unchanged.
+    ///
+    /// Sums are only meaningful for tables in current dollars.  Percent changes, price and
+    /// quantity indexes and chained-dollar values are not additive.
+    #[tracing::instrument(skip(self))]
+    pub fn roll_up(&self, level: NaicsLevel) -> Self {
+        let mut slices = std::collections::BTreeMap::new();
+        self.iter()
+            .map(|d| {
+                slices
+                    .entry((d.table_id, d.frequency, d.year, d.quarter))
+                    .or_insert_with(Vec::new)
+                    .push(d)
+            })
+            .for_each(drop);
+        let mut data = Vec::new();
+        for records in slices.values() {
+            let groups = level.groups(records.iter().map(|d| &d.industry));
+            let mut sums = std::collections::BTreeMap::new();
+            records
+                .iter()
+                .filter_map(|d| Some((*groups.get(&d.industry)?, d)))
+                .map(|(group, d)| {
+                    sums.entry(group)
+                        .or_insert_with(|| {
+                            let industry_description = if group == d.industry {
+                                d.industry_description.clone()
+                            } else {
+                                group.description().to_owned()
+                            };
+                            GdpDatum {
+                                data_value: 0.0,
+                                industry_description,
+                                industry: group,
+                                note_ref: String::new(),
+                                ..(*d).clone()
+                            }
+                        })
+                        .data_value += d.data_value
+                })
+                .for_each(drop);
+            data.extend(sums.into_values());
+        }
+        Self(data)
+    }
+
+    /// The `restate` method maps the industries of self onto the target vintage of
+    /// `concordance`, allocating each value across target industries by the shares returned by
+    /// [`NaicsConcordance::restate`] and summing values that map to the same industry.  Numeric
+    /// codes above the six-digit level are mapped with [`NaicsConcordance::at_digits`].
+    /// Industries outside the crosswalk are kept unchanged.
+    ///
+    /// As with [`GdpData::roll_up`], restated values are only meaningful for tables in current
+    /// dollars.
+    #[tracing::instrument(skip_all)]
+    pub fn restate(&self, concordance: &NaicsConcordance) -> Self {
+        let levels = (2..=6)
+            .map(|digits| (digits, concordance.at_digits(digits)))
+            .collect::<std::collections::BTreeMap<usize, NaicsConcordance>>();
+        let mut sums = std::collections::BTreeMap::new();
+        self.iter()
+            .map(|d| {
+                let length = d.industry.digits().map(|code| code.len()).unwrap_or(0);
+                let targets = match levels.get(&length) {
+                    Some(level) => level.restate(&d.industry),
+                    None => vec![(d.industry, 1.0)],
+                };
+                for (industry, weight) in targets {
+                    sums.entry((d.table_id, d.frequency, d.year, d.quarter, industry))
+                        .or_insert_with(|| {
+                            let industry_description = if industry == d.industry {
+                                d.industry_description.clone()
+                            } else {
+                                industry.description().to_owned()
+                            };
+                            GdpDatum {
+                                data_value: 0.0,
+                                industry_description,
+                                industry,
+                                ..d.clone()
+                            }
+                        })
+                        .data_value += d.data_value * weight;
+                }
+            })
+            .for_each(drop);
+        Self(sums.into_values().collect())
+    }
 }
 
 impl TryFrom<&std::path::PathBuf> for GdpData {
@@ -1,6 +1,7 @@
 use crate::{
-    BeaErr, BeaResponse, Dataset, InputOutputCode, InputOutputTable, IoError, Naics, NotArray,
-    NotObject, ParameterName, SerdeJson, Set, Year, map_to_float, map_to_string, parse_year,
+    BeaErr, BeaResponse, Dataset, InputOutputCode, InputOutputTable, IoError, Naics, NaicsLevel,
+    NotArray, NotObject, ParameterName, SerdeJson, Set, Year, map_to_float, map_to_string,
+    parse_year,
 };
 
 #[derive(
@@ -284,6 +285,65 @@ impl InputOutputData {
             .for_each(drop);
         set
     }
+
+    /// The `roll_up` method sums the values of each table and year to the industries and
+    /// commodities at `level` of the NAICS hierarchy, grouping row and column codes separately with
+    /// [`NaicsLevel::groups`] so that aggregates and their components are not counted twice.
+    /// Codes without an ancestor at `level`, such as final demand, value added and totals, are
+    /// kept unchanged.
+    ///
+    /// Sums are meaningful for the Use and Supply tables.  Requirements coefficients are not
+    /// additive, so roll up a Use table and derive coefficients from the result instead.
+    #[tracing::instrument(skip(self))]
+    pub fn roll_up(&self, level: NaicsLevel) -> Self {
+        let mut slices = std::collections::BTreeMap::new();
+        self.iter()
+            .map(|d| {
+                slices
+                    .entry((d.table_id, d.year))
+                    .or_insert_with(Vec::new)
+                    .push(d)
+            })
+            .for_each(drop);
+        let mut data = Vec::new();
+        for records in slices.values() {
+            let rows = level.groups(records.iter().map(|d| &*d.row_code));
+            let columns = level.groups(records.iter().map(|d| &*d.column_code));
+            let mut sums = std::collections::BTreeMap::new();
+            records
+                .iter()
+                .filter_map(|d| Some((*rows.get(&d.row_code)?, *columns.get(&d.column_code)?, d)))
+                .map(|(row, column, d)| {
+                    sums.entry((row, column))
+                        .or_insert_with(|| {
+                            let description = |group: Naics, code: &InputOutputCode, text: &str| {
+                                if group == **code {
+                                    text.to_owned()
+                                } else {
+                                    group.description().to_owned()
+                                }
+                            };
+                            InputOutputDatum {
+                                column_code: column.into(),
+                                column_description: description(
+                                    column,
+                                    &d.column_code,
+                                    &d.column_description,
+                                ),
+                                data_value: 0.0,
+                                note_ref: None,
+                                row_code: row.into(),
+                                row_description: description(row, &d.row_code, &d.row_description),
+                                ..(*d).clone()
+                            }
+                        })
+                        .data_value += d.data_value
+                })
+                .for_each(drop);
+            data.extend(sums.into_values());
+        }
+        Self(data)
+    }
 }
 
 impl TryFrom<&serde_json::Value> for InputOutputData {
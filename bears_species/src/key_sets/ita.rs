@@ -269,6 +269,24 @@ impl ItaDatum {
     }
 }
 
+impl TryFrom<serde_json::Value> for ItaDatum {
+    type Error = BeaErr;
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        tracing::trace!("Reading ItaDatum.");
+        match value {
+            serde_json::Value::Object(m) => {
+                let data = Self::read_json(&m)?;
+                Ok(data)
+            }
+            _ => {
+                tracing::trace!("Invalid Value: {value:#?}");
+                let error = NotObject::new(line!(), file!().to_string());
+                Err(error.into())
+            }
+        }
+    }
+}
+
 #[derive(
     Clone,
     Debug,
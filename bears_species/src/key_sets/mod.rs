@@ -10,16 +10,17 @@ mod mne;
 mod nipa;
 mod regional;
 
-pub use fixed_assets::{FixedAssetCodes, FixedAssetData, FixedAssets};
+pub use fixed_assets::{FixedAssetCodes, FixedAssetData, FixedAssetDatum, FixedAssets};
 pub use gdp_by_industry::{
     GdpByIndustry, GdpCodes, GdpData, GdpDatum, UnderlyingGdpByIndustry, UnderlyingGdpData,
+    UnderlyingGdpDatum,
 };
-pub use iip::{Iip, IipData};
-pub use input_output::{InputOutput, InputOutputData, IoCodes};
+pub use iip::{Iip, IipData, IipDatum};
+pub use input_output::{InputOutput, InputOutputData, InputOutputDatum, IoCodes};
 pub use intl_serv_sta::IntlServSta;
 pub use intl_serv_trade::IntlServTrade;
 pub use ita::{Ita, ItaData, ItaDatum};
 pub use metadata::ApiMetadata;
 pub use mne::Mne;
-pub use nipa::{NiUnderlyingDetail, Nipa, NipaData, NipaIterator};
+pub use nipa::{NiUnderlyingDetail, Nipa, NipaData, NipaDatum, NipaIterator};
 pub use regional::Regional;
@@ -654,10 +654,20 @@ impl Iterator for NiUnderlyingDetailIterator<'_> {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+    derive_getters::Getters,
+)]
 pub struct NipaDatum {
     cl_unit: String,
     data_value: f64,
+    frequency: Frequency,
     line_description: String,
     line_number: i64,
     metric_name: String,
@@ -687,6 +697,7 @@ impl NipaDatum {
         let table_name = map_to_string("TableName", m)?;
         tracing::trace!("table_name is {table_name}.");
         let time_period = map_to_string("TimePeriod", m)?;
+        let frequency = Frequency::from_period(&time_period);
         let time_period = date_by_period(&time_period)?;
         tracing::trace!("time_period is {time_period}.");
         let unit_mult = map_to_int("UNIT_MULT", m)?;
@@ -698,6 +709,7 @@ impl NipaDatum {
         Ok(Self {
             cl_unit,
             data_value,
+            frequency,
             line_description,
             line_number,
             metric_name,
@@ -109,6 +109,31 @@ impl Annotation {
         };
         anno.to_string()
     }
+
+    /// The range of employment indicated by a size-class letter, as (lower, upper) bounds in
+    /// employees, following the ranges published by BEA for suppressed employment in the MNE
+    /// tables.  The upper bound of class M is open.  Returns `None` for other annotations.
+    pub fn employment_range(&self) -> Option<(i64, Option<i64>)> {
+        let range = match self {
+            Self::A => (1, Some(499)),
+            Self::F => (500, Some(999)),
+            Self::G => (1_000, Some(2_499)),
+            Self::H => (2_500, Some(4_999)),
+            Self::I => (5_000, Some(9_999)),
+            Self::J | Self::Note => (10_000, Some(24_999)),
+            Self::K => (25_000, Some(49_999)),
+            Self::L => (50_000, Some(99_999)),
+            Self::M => (100_000, None),
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    /// Whether the annotation replaces a value withheld to avoid disclosing the data of individual
+    /// companies, either `(D)` or a size-class letter.
+    pub fn is_suppressed(&self) -> bool {
+        matches!(self, Self::D) || self.employment_range().is_some()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_new::new)]
@@ -1,5 +1,9 @@
 use crate::ParameterName;
 
+/// Valid values for the `AreaOrCountry` parameter of the ITA dataset, also used for the area
+/// and country codes of MNE rows.
+// @generated begin by `bears generate` from parameter_values/ITA_AreaOrCountry_values.json, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Copy,
@@ -16,314 +20,586 @@ use crate::ParameterName;
     strum::EnumIter,
 )]
 pub enum AreaOrCountry {
+    /// Afghanistan
     Afghanistan,
+    /// Africa
     Africa,
+    /// Africa; other countries (those not listed in table 2.3)
     AfricaOthGdsNsaDetail,
+    /// Albania
     Albania,
+    /// Algeria
     Algeria,
+    /// All countries
     AllCountries,
+    /// All other countries (those not listed separately in tables 2.2 and 3.2)
     AllOthSeas,
+    /// All countries other than Canada
     AllOthThanCanada,
+    /// Andorra
     Andorra,
+    /// Angola
     Angola,
+    /// Anguilla
     Anguilla,
+    /// Antigua and Barbuda
     AntiguaBarbuda,
+    /// Argentina
     Argentina,
+    /// Armenia
     Armenia,
+    /// Aruba
     Aruba,
+    /// Asia and Pacific
     AsiaAndPac,
+    /// Asia and Pacific; other countries (those not listed in table 2.3)
     AsiaAndPacOthNsaDetail,
+    /// Australia
     Australia,
+    /// Austria
     Austria,
+    /// Azerbaijan
     Azerbaijan,
+    /// Bahamas
     Bahamas,
+    /// Bahrain
     Bahrain,
+    /// Bangladesh
     Bangladesh,
+    /// Barbados
     Barbados,
+    /// Belarus
     Belarus,
+    /// Belgium
     Belgium,
+    /// Belize
     Belize,
+    /// Benin
     Benin,
+    /// Bermuda
     Bermuda,
+    /// Bhutan
     Bhutan,
+    /// Bolivia
     Bolivia,
+    /// Bosnia and Herzegovina
     BosniaHerzegovina,
+    /// Botswana
     Botswana,
+    /// Brazil
     Brazil,
+    /// Brunei
     Brunei,
+    /// Bulgaria
     Bulgaria,
+    /// Burkina Faso
     BurkinaFaso,
+    /// Burma
     Burma,
+    /// Burundi
     Burundi,
+    /// Cabo Verde
     CaboVerde,
+    /// CAFTA-DR countries
     CaftaDrCountries,
+    /// Cambodia
     Cambodia,
+    /// Cameroon
     Cameroon,
+    /// Canada
     Canada,
+    /// Central America
     CentralAmerica,
+    /// Central African Republic
     CentralAfricanRepublic,
+    /// Chad
     Chad,
+    /// Chile
     Chile,
+    /// China
     China,
+    /// Cook Islands
     CookIslands,
+    /// Colombia
     Colombia,
+    /// Comoros
     Comoros,
+    /// Congo (Brazzaville)
     CongoBrazzaville,
+    /// Congo (Kinshasa)
     CongoKinshasa,
+    /// Costa Rica
     CostaRica,
+    /// Cote D'Ivoire
     CoteDIvoire,
+    /// Croatia
     Croatia,
+    /// Cuba
     Cuba,
+    /// Curacao
     Curacao,
+    /// Cyprus
     Cyprus,
+    /// Czech Republic
     CzechRep,
+    /// Denmark
     Denmark,
+    /// Djibouti
     Djibouti,
+    /// Dominica
     Dominica,
+    /// Dominican Republic
     DominicanRep,
+    /// Eastern Europe
     EasternEurope,
+    /// East Timor
     EastTimor,
+    /// Ecuador
     Ecuador,
+    /// Egypt
     Egypt,
+    /// El Salvador
     ElSalvador,
+    /// Equatorial Guinea
     EquatorialGuinea,
+    /// Eritrea
     Eritrea,
+    /// Estonia
     Estonia,
+    /// Eswatini
     Eswatini,
+    /// Ethiopia
     Ethiopia,
+    /// European Union
     EU,
+    /// Euro Area
     EuroArea,
+    /// Europe
     Europe,
+    /// Europe; other countries (those not listed in table 2.3)
     EuropeOthNsaDetail,
+    /// Far East
     FarEast,
+    /// Far West
     FarWest,
+    /// Fiji
     Fiji,
+    /// Finland
     Finland,
+    /// France
     France,
+    /// French Guiana
     FrenchGuiana,
+    /// French Islands, Caribbean
     FrenchIslandsCaribbean,
+    /// French Islands, Indian Ocean
     FrenchIslandsIndianOcean,
+    /// French Islands, Pacific
     FrenchIslandsPacific,
+    /// Gabon
     Gabon,
+    /// Gambia
     Gambia,
+    /// Georgia
     Georgia,
+    /// Germany
     Germany,
+    /// Ghana
     Ghana,
+    /// Gibraltar
     Gibraltar,
+    /// Great Lakes
     GreatLakes,
+    /// Greece
     Greece,
+    /// Greenland
     Greenland,
+    /// Grenada
     Grenada,
+    /// Guatemala
     Guatemala,
+    /// Guinea
     Guinea,
+    /// Guinea-Bissau
     GuineaBissau,
+    /// Guyana
     Guyana,
+    /// Haiti
     Haiti,
+    /// Honduras
     Honduras,
+    /// Hong Kong
     HongKong,
+    /// Hungary
     Hungary,
+    /// Iceland
     Iceland,
+    /// India
     India,
+    /// Indonesia
     Indonesia,
+    /// International organizations and unallocated
     IntOrgAndUnalloc,
+    /// Iran
     Iran,
+    /// Iraq
     Iraq,
+    /// Ireland
     Ireland,
+    /// Israel
     Israel,
+    /// Italy
     Italy,
+    /// Jamaica
     Jamaica,
+    /// Japan
     Japan,
+    /// Jordan
     Jordan,
+    /// Kazakhstan
     Kazakhstan,
+    /// Kenya
     Kenya,
+    /// Kiribati
     Kiribati,
+    /// Kosovo
     Kosovo,
+    /// Kuwait
     Kuwait,
+    /// Kyrgyzstan
     Kyrgyzstan,
+    /// Laos
     Laos,
+    /// Latin America and Other Western Hemisphere
     LatAmAndOthWestHem,
+    /// Latvia
     Latvia,
+    /// Lebanon
     Lebanon,
+    /// Lesotho
     Lesotho,
+    /// Liberia
     Liberia,
+    /// Libya
     Libya,
+    /// Liechtenstein
     Liechtenstein,
+    /// Lithuania
     Lithuania,
+    /// Luxembourg
     Luxembourg,
+    /// Macau
     Macau,
+    /// Madagascar
     Madagascar,
+    /// Malawi
     Malawi,
+    /// Malaysia
     Malaysia,
+    /// Maldives
     Maldives,
+    /// Mali
     Mali,
+    /// Malta
     Malta,
+    /// Marshall Islands
     MarshallIslands,
+    /// Mauritania
     Mauritania,
+    /// Mauritius
     Mauritius,
+    /// Mexico
     Mexico,
+    /// Micronesia
     Micronesia,
+    /// Middle East
     MiddleEast,
+    /// Middle East; other countries (those not listed in table 2.3)
     MiddleEastOthGdsNsaDetail,
+    /// Moldova
     Moldova,
+    /// Monaco
     Monaco,
+    /// Mongolia
     Mongolia,
+    /// Montenegro
     Montenegro,
+    /// Morocco
     Morocco,
+    /// Mozambique
     Mozambique,
+    /// Namibia
     Namibia,
+    /// Nauru
     Nauru,
+    /// Nepal
     Nepal,
+    /// Netherlands
     Netherlands,
+    /// Netherlands Antilles
     NetherlandsAntilles,
+    /// Netherlands Islands, Caribbean
     NetherlandsIslandsCaribbean,
+    /// New England
     NewEngland,
+    /// New Zealand
     NewZealand,
+    /// Nicaragua
     Nicaragua,
+    /// Niger
     Niger,
+    /// Nigeria
     Nigeria,
+    /// Niue
     Niue,
+    /// North Korea
     NorthKorea,
+    /// North Macedonia
     NorthMacedonia,
+    /// Norway
     Norway,
+    /// Oman
     Oman,
+    /// OPEC
     OPEC,
+    /// Other Africa (excluding 1 country, South Africa)
     OthAfricaExcl1,
+    /// Other Africa (excluding 3 countries)
     OthAfricaExcl3,
+    /// Africa, other (excluding 3 countries; outward direct investment)
     OthAfricaExcl3DiOutward,
+    /// Other Africa (excluding 3 countries)
     OthAfricaIst,
+    /// Asia and Pacific, other (excluding 10 countries; inward direct investment)
     OthAsiaAndPacExcl10DiInward,
+    /// Asia and Pacific, other (excluding 13 countries; outward direct investment)
     OthAsiaAndPacExcl13DiOutward,
+    /// Other Asia and Pacific (excluding 15 countries)
     OthAsiaAndPacExcl15,
+    /// Other Asia and Pacific (excluding 8 countries)
     OthAsiaAndPacExcl8,
+    /// Other Asia and Pacific (excluding 15 countries)
     OthAsiaAndPacIst,
+    /// Other-Asia and Pacific
     OtherAsiaPacific,
+    /// Central America, other (excluding 4 countries; outward direct investment)
     OthCenAmExcl4DiOutward,
+    /// Europe, other (excluding 17 countries; inward direct investment)
     OthEuropeExcl17DiInward,
+    /// Europe, other (excluding 22 countries; outward direct investment)
     OthEuropeExcl22DiOutward,
+    /// Other Europe (excluding 32 countries)
     OthEuropeExcl32,
+    /// Other Europe (excluding 7 countries)
     OthEuropeExcl7,
+    /// Other Europe (excluding 32 countries)
     OthEuropeIst,
+    /// Middle East, other (excluding 3 countries; outward direct investment)
     OthMiddleEastExc3DiOutward,
+    /// Middle East, other (excluding 5 countries; inward direct investment)
     OthMiddleEastExc5DiInward,
+    /// Other Middle East (excluding 5 countries)
     OthMiddleEastExcl5,
+    /// Other Middle East (excluding 5 countries)
     OthMiddleEastIst,
+    /// South America, other (excluding 7 countries; outward direct investment)
     OthSouthAmExcl7DiOutward,
+    /// Other South And Central America (excluding 13 countries)
     OthSouthAndCenAmExcl13,
+    /// Other South and Central America (excluding 4 countries)
     OthSouthAndCenAmExcl4,
+    /// South and Central America, other (excluding 4 countries; inward direct investment)
     OthSouthAndCenAmExcl4DiInward,
+    /// Other South and Central America (excluding 13 countries)
     OthSouthAndCenAmIst,
+    /// Other Western Hemisphere
     OthWestHem,
+    /// Other Western Hemisphere, Other (excluding 3 countries)
     OthWestHemOthExcl3,
+    /// Other Western Hemisphere, other (excluding 4 countries; inward direct investment)
     OthWestHemOthExcl4DiInward,
+    /// Other Western Hemisphere, other (excluding 4 countries; outward direct investment)
     OthWestHemOthExcl4DiOutward,
+    /// Other Western Hemisphere, Other (excluding 3 countries)
     OthWestHemOthIst,
+    /// Pakistan
     Pakistan,
+    /// Palau
     Palau,
+    /// Panama
     Panama,
+    /// Papua New Ginea
     PapuaNewGinea,
+    /// Paraguay
     Paraguay,
+    /// Peru
     Peru,
+    /// Philippines
     Philippines,
+    /// Plains
     Plains,
+    /// Poland
     Poland,
+    /// Portugal
     Portugal,
+    /// Qatar
     Qatar,
+    /// Residual between the seasonally adjusted total based on service type or commodity and the sum of the seasonally adjusted individual countries and the "all other countries" aggregate
     ResidualSeas,
+    /// Rocky Mountains
     RockyMountains,
+    /// Romania
     Romania,
+    /// Russia
     Russia,
+    /// Rwanda
     Rwanda,
+    /// Samoa
     Samoa,
+    /// San Marino
     SanMarino,
+    /// Sao Tome and Principe
     SaoTomePrincipe,
+    /// Saudi Arabia
     SaudiArabia,
+    /// Senegal
     Senegal,
+    /// Serbia
     Serbia,
+    /// Seychelles
     Seychelles,
+    /// Sierra Leone
     SierraLeone,
+    /// Singapore
     Singapore,
+    /// Sint Maarten
     SintMaarten,
+    /// Slovakia
     Slovakia,
+    /// Slovenia
     Slovenia,
+    /// Solomon Islands
     SolomonIslands,
+    /// Somalia
     Somalia,
+    /// South Africa
     SouthAfrica,
+    /// South America
     SouthAm,
+    /// South and Central America
     SouthAndCenAm,
+    /// South and Central America; other countries (those not listed in table 2.3)
     SouthAndCenAmOthNsaDetail,
+    /// Southeast
     Southeast,
+    /// South Korea
     SouthKorea,
+    /// South Sudan
     SouthSudan,
+    /// Southwest
     Southwest,
+    /// Spain
     Spain,
+    /// Sri Lanka
     SriLanka,
+    /// St. Kitts and Nevis
     StKittsNevis,
+    /// St. Lucia
     StLucia,
+    /// St. Pierre and Miquelon
     StPierreMiquelon,
+    /// St. Vincent and the Grenadines
     StVincentGrenadines,
+    /// Sudan
     Sudan,
+    /// Suriname
     Suriname,
+    /// Switzerland
     Switzerland,
+    /// Sweden
     Sweden,
+    /// Syria
     Syria,
+    /// Taiwan
     Taiwan,
+    /// Tajikistan
     Tajikistan,
+    /// Tanzania
     Tanzania,
+    /// Thailand
     Thailand,
+    /// Timor-Leste
     TimorLeste,
+    /// Togo
     Togo,
+    /// Tonga
     Tonga,
+    /// Trinidad and Tobago
     TrinidadTobago,
+    /// Tunisia
     Tunisia,
+    /// Turkey
     Turkey,
+    /// Turkmenistan
     Turkmenistan,
+    /// Tuvalu
     Tuvalu,
+    /// United Arab Emirates
     Uae,
+    /// Uganda
     Uganda,
+    /// United Kingdom Islands, Caribbean
     UkIslandsCarib,
+    /// Ukraine
     Ukraine,
+    /// United Kingdom
     UnitedKingdom,
+    /// United Kingdom Islands, Atlantic (Africa)
     UnitedKingdomIslandsAtlanticAfrica,
+    /// United Kingdom Islands, Atlantic (OWH)
     UnitedKingdomIslandsAtlanticOWH,
+    /// United Kingdom Islands, Caribbean
     UnitedKingdomIslandsCaribbean,
+    /// United Kingdom Islands, Indian Ocean
     UnitedKingdomIslandsIndianOcean,
+    /// United Kingdom Islands, Pacific
     UnitedKingdomIslandsPacific,
+    /// United States
     UnitedStates,
+    /// Uruguay
     Uruguay,
+    /// Uzbekistan
     Uzbekistan,
+    /// Vanuatu
     Vanuatu,
+    /// Vatican City
     VaticanCity,
+    /// Venezuela
     Venezuela,
+    /// Vietnam
     Vietnam,
+    /// Western Sahara
     WesternSahara,
+    /// Yemen
     Yemen,
+    /// Zambia
     Zambia,
+    /// Zimbabwe
     Zimbabwe,
 }
 
+#[rustfmt::skip]
 impl AreaOrCountry {
-    /// Format `self` for insertion into a request BTreeMap(key, value).
-    /// The key is the parameter name.  The value is the parameter value.
-    pub fn params(&self) -> (String, String) {
-        let key = ParameterName::AreaOrCountry.to_string();
-        let value = self.to_string();
-        (key, value)
-    }
-
-    /// The description field associated with the variant in the BEA response.
-    pub fn description(&self) -> String {
-        let desc = match self {
+    /// Returns the description of the variant.
+    pub fn description(&self) -> &'static str {
+        match self {
             Self::Afghanistan => "Afghanistan",
             Self::Africa => "Africa",
-            Self::AfricaOthGdsNsaDetail => {
-                "Africa; other countries (those not listed in table 2.3)"
-            }
+            Self::AfricaOthGdsNsaDetail => "Africa; other countries (those not listed in table 2.3)",
             Self::Albania => "Albania",
             Self::Algeria => "Algeria",
             Self::AllCountries => "All countries",
-            Self::AllOthSeas => {
-                "All other countries (those not listed separately in tables 2.2 and 3.2)"
-            }
+            Self::AllOthSeas => "All other countries (those not listed separately in tables 2.2 and 3.2)",
             Self::AllOthThanCanada => "All countries other than Canada",
             Self::Andorra => "Andorra",
             Self::Angola => "Angola",
@@ -333,9 +609,7 @@ impl AreaOrCountry {
             Self::Armenia => "Armenia",
             Self::Aruba => "Aruba",
             Self::AsiaAndPac => "Asia and Pacific",
-            Self::AsiaAndPacOthNsaDetail => {
-                "Asia and Pacific; other countries (those not listed in table 2.3)"
-            }
+            Self::AsiaAndPacOthNsaDetail => "Asia and Pacific; other countries (those not listed in table 2.3)",
             Self::Australia => "Australia",
             Self::Austria => "Austria",
             Self::Azerbaijan => "Azerbaijan",
@@ -368,8 +642,8 @@ impl AreaOrCountry {
             Self::Chad => "Chad",
             Self::Chile => "Chile",
             Self::China => "China",
-            Self::Colombia => "Colombia",
             Self::CookIslands => "Cook Islands",
+            Self::Colombia => "Colombia",
             Self::Comoros => "Comoros",
             Self::CongoBrazzaville => "Congo (Brazzaville)",
             Self::CongoKinshasa => "Congo (Kinshasa)",
@@ -466,9 +740,7 @@ impl AreaOrCountry {
             Self::Mexico => "Mexico",
             Self::Micronesia => "Micronesia",
             Self::MiddleEast => "Middle East",
-            Self::MiddleEastOthGdsNsaDetail => {
-                "Middle East; other countries (those not listed in table 2.3)"
-            }
+            Self::MiddleEastOthGdsNsaDetail => "Middle East; other countries (those not listed in table 2.3)",
             Self::Moldova => "Moldova",
             Self::Monaco => "Monaco",
             Self::Mongolia => "Mongolia",
@@ -494,61 +766,33 @@ impl AreaOrCountry {
             Self::OPEC => "OPEC",
             Self::OthAfricaExcl1 => "Other Africa (excluding 1 country, South Africa)",
             Self::OthAfricaExcl3 => "Other Africa (excluding 3 countries)",
-            Self::OthAfricaExcl3DiOutward => {
-                "Africa, other (excluding 3 countries; outward direct investment)"
-            }
+            Self::OthAfricaExcl3DiOutward => "Africa, other (excluding 3 countries; outward direct investment)",
             Self::OthAfricaIst => "Other Africa (excluding 3 countries)",
-            Self::OthAsiaAndPacExcl10DiInward => {
-                "Asia and Pacific, other (excluding 10 countries; inward direct investment)"
-            }
-            Self::OthAsiaAndPacExcl13DiOutward => {
-                "Asia and Pacific, other (excluding 13 countries; outward direct investment)"
-            }
+            Self::OthAsiaAndPacExcl10DiInward => "Asia and Pacific, other (excluding 10 countries; inward direct investment)",
+            Self::OthAsiaAndPacExcl13DiOutward => "Asia and Pacific, other (excluding 13 countries; outward direct investment)",
             Self::OthAsiaAndPacExcl15 => "Other Asia and Pacific (excluding 15 countries)",
             Self::OthAsiaAndPacExcl8 => "Other Asia and Pacific (excluding 8 countries)",
             Self::OthAsiaAndPacIst => "Other Asia and Pacific (excluding 15 countries)",
             Self::OtherAsiaPacific => "Other-Asia and Pacific",
-            Self::OthCenAmExcl4DiOutward => {
-                "Central America, other (excluding 4 countries; outward direct investment)"
-            }
-            Self::OthEuropeExcl17DiInward => {
-                "Europe, other (excluding 17 countries; inward direct investment)"
-            }
-            Self::OthEuropeExcl22DiOutward => {
-                "Europe, other (excluding 22 countries; outward direct investment)"
-            }
+            Self::OthCenAmExcl4DiOutward => "Central America, other (excluding 4 countries; outward direct investment)",
+            Self::OthEuropeExcl17DiInward => "Europe, other (excluding 17 countries; inward direct investment)",
+            Self::OthEuropeExcl22DiOutward => "Europe, other (excluding 22 countries; outward direct investment)",
             Self::OthEuropeExcl32 => "Other Europe (excluding 32 countries)",
             Self::OthEuropeExcl7 => "Other Europe (excluding 7 countries)",
             Self::OthEuropeIst => "Other Europe (excluding 32 countries)",
-            Self::OthMiddleEastExc3DiOutward => {
-                "Middle East, other (excluding 3 countries; outward direct investment)"
-            }
-            Self::OthMiddleEastExc5DiInward => {
-                "Middle East, other (excluding 5 countries; inward direct investment)"
-            }
+            Self::OthMiddleEastExc3DiOutward => "Middle East, other (excluding 3 countries; outward direct investment)",
+            Self::OthMiddleEastExc5DiInward => "Middle East, other (excluding 5 countries; inward direct investment)",
             Self::OthMiddleEastExcl5 => "Other Middle East (excluding 5 countries)",
             Self::OthMiddleEastIst => "Other Middle East (excluding 5 countries)",
-            Self::OthSouthAmExcl7DiOutward => {
-                "South America, other (excluding 7 countries; outward direct investment)"
-            }
-            Self::OthSouthAndCenAmExcl13 => {
-                "Other South And Central America (excluding 13 countries)"
-            }
-            Self::OthSouthAndCenAmExcl4 => {
-                "Other South and Central America (excluding 4 countries)"
-            }
-            Self::OthSouthAndCenAmExcl4DiInward => {
-                "South and Central America, other (excluding 4 countries; inward direct investment)"
-            }
+            Self::OthSouthAmExcl7DiOutward => "South America, other (excluding 7 countries; outward direct investment)",
+            Self::OthSouthAndCenAmExcl13 => "Other South And Central America (excluding 13 countries)",
+            Self::OthSouthAndCenAmExcl4 => "Other South and Central America (excluding 4 countries)",
+            Self::OthSouthAndCenAmExcl4DiInward => "South and Central America, other (excluding 4 countries; inward direct investment)",
             Self::OthSouthAndCenAmIst => "Other South and Central America (excluding 13 countries)",
             Self::OthWestHem => "Other Western Hemisphere",
             Self::OthWestHemOthExcl3 => "Other Western Hemisphere, Other (excluding 3 countries)",
-            Self::OthWestHemOthExcl4DiInward => {
-                "Other Western Hemisphere, other (excluding 4 countries; inward direct investment)"
-            }
-            Self::OthWestHemOthExcl4DiOutward => {
-                "Other Western Hemisphere, other (excluding 4 countries; outward direct investment)"
-            }
+            Self::OthWestHemOthExcl4DiInward => "Other Western Hemisphere, other (excluding 4 countries; inward direct investment)",
+            Self::OthWestHemOthExcl4DiOutward => "Other Western Hemisphere, other (excluding 4 countries; outward direct investment)",
             Self::OthWestHemOthIst => "Other Western Hemisphere, Other (excluding 3 countries)",
             Self::Pakistan => "Pakistan",
             Self::Palau => "Palau",
@@ -561,9 +805,7 @@ impl AreaOrCountry {
             Self::Poland => "Poland",
             Self::Portugal => "Portugal",
             Self::Qatar => "Qatar",
-            Self::ResidualSeas => {
-                "Residual between the seasonally adjusted total based on service type or commodity and the sum of the seasonally adjusted individual countries and the \"all other countries\" aggregate"
-            }
+            Self::ResidualSeas => "Residual between the seasonally adjusted total based on service type or commodity and the sum of the seasonally adjusted individual countries and the \"all other countries\" aggregate",
             Self::RockyMountains => "Rocky Mountains",
             Self::Romania => "Romania",
             Self::Russia => "Russia",
@@ -585,9 +827,7 @@ impl AreaOrCountry {
             Self::SouthAfrica => "South Africa",
             Self::SouthAm => "South America",
             Self::SouthAndCenAm => "South and Central America",
-            Self::SouthAndCenAmOthNsaDetail => {
-                "South and Central America; other countries (those not listed in table 2.3)"
-            }
+            Self::SouthAndCenAmOthNsaDetail => "South and Central America; other countries (those not listed in table 2.3)",
             Self::Southeast => "Southeast",
             Self::SouthKorea => "South Korea",
             Self::SouthSudan => "South Sudan",
@@ -600,8 +840,8 @@ impl AreaOrCountry {
             Self::StVincentGrenadines => "St. Vincent and the Grenadines",
             Self::Sudan => "Sudan",
             Self::Suriname => "Suriname",
-            Self::Sweden => "Sweden",
             Self::Switzerland => "Switzerland",
+            Self::Sweden => "Sweden",
             Self::Syria => "Syria",
             Self::Taiwan => "Taiwan",
             Self::Tajikistan => "Tajikistan",
@@ -636,8 +876,18 @@ impl AreaOrCountry {
             Self::Yemen => "Yemen",
             Self::Zambia => "Zambia",
             Self::Zimbabwe => "Zimbabwe",
-        };
-        desc.to_owned()
+        }
+    }
+}
+// @generated end
+
+impl AreaOrCountry {
+    /// Format `self` for insertion into a request BTreeMap(key, value).
+    /// The key is the parameter name.  The value is the parameter value.
+    pub fn params(&self) -> (String, String) {
+        let key = ParameterName::AreaOrCountry.to_string();
+        let value = self.to_string();
+        (key, value)
     }
 
     pub fn from_code(code: i64) -> Option<Self> {
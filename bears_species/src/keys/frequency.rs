@@ -39,6 +39,15 @@ impl Frequency {
         s.to_string()
     }
 
+    /// Number of periods of this frequency in a year.
+    pub fn per_year(&self) -> i64 {
+        match self {
+            Self::Annual => 1,
+            Self::Quarterly => 4,
+            Self::Monthly => 12,
+        }
+    }
+
     /// Canonical method for parsing from a BEA parameter value into a variant of `Self`.
     pub fn from_value(value: &str) -> Result<Self, JsonParseError> {
         let frequency = match value {
@@ -60,6 +69,28 @@ impl Frequency {
         let value = self.value();
         (key, value)
     }
+
+    /// Infers the frequency of a BEA `TimePeriod` value from its format, e.g. "2020Q1" is
+    /// quarterly, "2020M01" is monthly and "2020" is annual.
+    pub fn from_period(period: &str) -> Self {
+        if period.contains('Q') {
+            Self::Quarterly
+        } else if period.contains('M') {
+            Self::Monthly
+        } else {
+            Self::Annual
+        }
+    }
+}
+
+impl From<ItaFrequency> for Frequency {
+    /// Both seasonally adjusted and unadjusted quarterly series map to `Quarterly`.
+    fn from(value: ItaFrequency) -> Self {
+        match value {
+            ItaFrequency::Annual => Self::Annual,
+            ItaFrequency::Qnsa | ItaFrequency::Qsa => Self::Quarterly,
+        }
+    }
 }
 
 impl TryFrom<&NipaFrequency> for Frequency {
@@ -0,0 +1,1037 @@
+//! Geographic metadata for the [`AreaOrCountry`], [`AocSta`] and [`StateKind`] keys, used to join
+//! ITA, MNE and Regional data with sources keyed by ISO 3166 or FIPS codes.
+//!
+//! Area membership follows the BEA area codes of [`AreaOrCountry::from_code`]: the 200 series
+//! holds South and Central America (with Mexico in Central America), the 250 series the other
+//! Western Hemisphere, the 300 series Europe (including the former Soviet republics), the 400
+//! series Africa, the 500 series the Middle East and the 600 series Asia and Pacific.
+use crate::{AocSta, AreaOrCountry, BeaErr, StateKind, VariantMissing};
+use std::collections::BTreeSet;
+use strum::IntoEnumIterator;
+
+impl AreaOrCountry {
+    /// The ISO 3166-1 alpha-2 and alpha-3 codes of a country or territory.  Aggregates, and
+    /// groups of islands without a single ISO code, return `None`.  Kosovo uses the user-assigned
+    /// codes `XK` and `XKX` common to the World Bank and the European Commission.
+    #[rustfmt::skip]
+    pub fn iso(&self) -> Option<(&'static str, &'static str)> {
+        let iso = match self {
+            Self::Afghanistan => ("AF", "AFG"),
+            Self::Albania => ("AL", "ALB"),
+            Self::Algeria => ("DZ", "DZA"),
+            Self::Andorra => ("AD", "AND"),
+            Self::Angola => ("AO", "AGO"),
+            Self::Anguilla => ("AI", "AIA"),
+            Self::AntiguaBarbuda => ("AG", "ATG"),
+            Self::Argentina => ("AR", "ARG"),
+            Self::Armenia => ("AM", "ARM"),
+            Self::Aruba => ("AW", "ABW"),
+            Self::Australia => ("AU", "AUS"),
+            Self::Austria => ("AT", "AUT"),
+            Self::Azerbaijan => ("AZ", "AZE"),
+            Self::Bahamas => ("BS", "BHS"),
+            Self::Bahrain => ("BH", "BHR"),
+            Self::Bangladesh => ("BD", "BGD"),
+            Self::Barbados => ("BB", "BRB"),
+            Self::Belarus => ("BY", "BLR"),
+            Self::Belgium => ("BE", "BEL"),
+            Self::Belize => ("BZ", "BLZ"),
+            Self::Benin => ("BJ", "BEN"),
+            Self::Bermuda => ("BM", "BMU"),
+            Self::Bhutan => ("BT", "BTN"),
+            Self::Bolivia => ("BO", "BOL"),
+            Self::BosniaHerzegovina => ("BA", "BIH"),
+            Self::Botswana => ("BW", "BWA"),
+            Self::Brazil => ("BR", "BRA"),
+            Self::Brunei => ("BN", "BRN"),
+            Self::Bulgaria => ("BG", "BGR"),
+            Self::BurkinaFaso => ("BF", "BFA"),
+            Self::Burma => ("MM", "MMR"),
+            Self::Burundi => ("BI", "BDI"),
+            Self::CaboVerde => ("CV", "CPV"),
+            Self::Cambodia => ("KH", "KHM"),
+            Self::Cameroon => ("CM", "CMR"),
+            Self::Canada => ("CA", "CAN"),
+            Self::CentralAfricanRepublic => ("CF", "CAF"),
+            Self::Chad => ("TD", "TCD"),
+            Self::Chile => ("CL", "CHL"),
+            Self::China => ("CN", "CHN"),
+            Self::CookIslands => ("CK", "COK"),
+            Self::Colombia => ("CO", "COL"),
+            Self::Comoros => ("KM", "COM"),
+            Self::CongoBrazzaville => ("CG", "COG"),
+            Self::CongoKinshasa => ("CD", "COD"),
+            Self::CostaRica => ("CR", "CRI"),
+            Self::CoteDIvoire => ("CI", "CIV"),
+            Self::Croatia => ("HR", "HRV"),
+            Self::Cuba => ("CU", "CUB"),
+            Self::Curacao => ("CW", "CUW"),
+            Self::Cyprus => ("CY", "CYP"),
+            Self::CzechRep => ("CZ", "CZE"),
+            Self::Denmark => ("DK", "DNK"),
+            Self::Djibouti => ("DJ", "DJI"),
+            Self::Dominica => ("DM", "DMA"),
+            Self::DominicanRep => ("DO", "DOM"),
+            Self::Ecuador => ("EC", "ECU"),
+            Self::Egypt => ("EG", "EGY"),
+            Self::ElSalvador => ("SV", "SLV"),
+            Self::EquatorialGuinea => ("GQ", "GNQ"),
+            Self::Eritrea => ("ER", "ERI"),
+            Self::Estonia => ("EE", "EST"),
+            Self::Eswatini => ("SZ", "SWZ"),
+            Self::Ethiopia => ("ET", "ETH"),
+            Self::Fiji => ("FJ", "FJI"),
+            Self::Finland => ("FI", "FIN"),
+            Self::France => ("FR", "FRA"),
+            Self::FrenchGuiana => ("GF", "GUF"),
+            Self::Gabon => ("GA", "GAB"),
+            Self::Gambia => ("GM", "GMB"),
+            Self::Georgia => ("GE", "GEO"),
+            Self::Germany => ("DE", "DEU"),
+            Self::Ghana => ("GH", "GHA"),
+            Self::Gibraltar => ("GI", "GIB"),
+            Self::Greece => ("GR", "GRC"),
+            Self::Greenland => ("GL", "GRL"),
+            Self::Grenada => ("GD", "GRD"),
+            Self::Guatemala => ("GT", "GTM"),
+            Self::Guinea => ("GN", "GIN"),
+            Self::GuineaBissau => ("GW", "GNB"),
+            Self::Guyana => ("GY", "GUY"),
+            Self::Haiti => ("HT", "HTI"),
+            Self::Honduras => ("HN", "HND"),
+            Self::HongKong => ("HK", "HKG"),
+            Self::Hungary => ("HU", "HUN"),
+            Self::Iceland => ("IS", "ISL"),
+            Self::India => ("IN", "IND"),
+            Self::Indonesia => ("ID", "IDN"),
+            Self::Iran => ("IR", "IRN"),
+            Self::Iraq => ("IQ", "IRQ"),
+            Self::Ireland => ("IE", "IRL"),
+            Self::Israel => ("IL", "ISR"),
+            Self::Italy => ("IT", "ITA"),
+            Self::Jamaica => ("JM", "JAM"),
+            Self::Japan => ("JP", "JPN"),
+            Self::Jordan => ("JO", "JOR"),
+            Self::Kazakhstan => ("KZ", "KAZ"),
+            Self::Kenya => ("KE", "KEN"),
+            Self::Kiribati => ("KI", "KIR"),
+            Self::Kosovo => ("XK", "XKX"),
+            Self::Kuwait => ("KW", "KWT"),
+            Self::Kyrgyzstan => ("KG", "KGZ"),
+            Self::Laos => ("LA", "LAO"),
+            Self::Latvia => ("LV", "LVA"),
+            Self::Lebanon => ("LB", "LBN"),
+            Self::Lesotho => ("LS", "LSO"),
+            Self::Liberia => ("LR", "LBR"),
+            Self::Libya => ("LY", "LBY"),
+            Self::Liechtenstein => ("LI", "LIE"),
+            Self::Lithuania => ("LT", "LTU"),
+            Self::Luxembourg => ("LU", "LUX"),
+            Self::Macau => ("MO", "MAC"),
+            Self::Madagascar => ("MG", "MDG"),
+            Self::Malawi => ("MW", "MWI"),
+            Self::Malaysia => ("MY", "MYS"),
+            Self::Maldives => ("MV", "MDV"),
+            Self::Mali => ("ML", "MLI"),
+            Self::Malta => ("MT", "MLT"),
+            Self::MarshallIslands => ("MH", "MHL"),
+            Self::Mauritania => ("MR", "MRT"),
+            Self::Mauritius => ("MU", "MUS"),
+            Self::Mexico => ("MX", "MEX"),
+            Self::Micronesia => ("FM", "FSM"),
+            Self::Moldova => ("MD", "MDA"),
+            Self::Monaco => ("MC", "MCO"),
+            Self::Mongolia => ("MN", "MNG"),
+            Self::Montenegro => ("ME", "MNE"),
+            Self::Morocco => ("MA", "MAR"),
+            Self::Mozambique => ("MZ", "MOZ"),
+            Self::Namibia => ("NA", "NAM"),
+            Self::Nauru => ("NR", "NRU"),
+            Self::Nepal => ("NP", "NPL"),
+            Self::Netherlands => ("NL", "NLD"),
+            Self::NetherlandsIslandsCaribbean => ("BQ", "BES"),
+            Self::NewZealand => ("NZ", "NZL"),
+            Self::Nicaragua => ("NI", "NIC"),
+            Self::Niger => ("NE", "NER"),
+            Self::Nigeria => ("NG", "NGA"),
+            Self::Niue => ("NU", "NIU"),
+            Self::NorthKorea => ("KP", "PRK"),
+            Self::NorthMacedonia => ("MK", "MKD"),
+            Self::Norway => ("NO", "NOR"),
+            Self::Oman => ("OM", "OMN"),
+            Self::Pakistan => ("PK", "PAK"),
+            Self::Palau => ("PW", "PLW"),
+            Self::Panama => ("PA", "PAN"),
+            Self::PapuaNewGinea => ("PG", "PNG"),
+            Self::Paraguay => ("PY", "PRY"),
+            Self::Peru => ("PE", "PER"),
+            Self::Philippines => ("PH", "PHL"),
+            Self::Poland => ("PL", "POL"),
+            Self::Portugal => ("PT", "PRT"),
+            Self::Qatar => ("QA", "QAT"),
+            Self::Romania => ("RO", "ROU"),
+            Self::Russia => ("RU", "RUS"),
+            Self::Rwanda => ("RW", "RWA"),
+            Self::Samoa => ("WS", "WSM"),
+            Self::SanMarino => ("SM", "SMR"),
+            Self::SaoTomePrincipe => ("ST", "STP"),
+            Self::SaudiArabia => ("SA", "SAU"),
+            Self::Senegal => ("SN", "SEN"),
+            Self::Serbia => ("RS", "SRB"),
+            Self::Seychelles => ("SC", "SYC"),
+            Self::SierraLeone => ("SL", "SLE"),
+            Self::Singapore => ("SG", "SGP"),
+            Self::SintMaarten => ("SX", "SXM"),
+            Self::Slovakia => ("SK", "SVK"),
+            Self::Slovenia => ("SI", "SVN"),
+            Self::SolomonIslands => ("SB", "SLB"),
+            Self::Somalia => ("SO", "SOM"),
+            Self::SouthAfrica => ("ZA", "ZAF"),
+            Self::SouthKorea => ("KR", "KOR"),
+            Self::SouthSudan => ("SS", "SSD"),
+            Self::Spain => ("ES", "ESP"),
+            Self::SriLanka => ("LK", "LKA"),
+            Self::StKittsNevis => ("KN", "KNA"),
+            Self::StLucia => ("LC", "LCA"),
+            Self::StPierreMiquelon => ("PM", "SPM"),
+            Self::StVincentGrenadines => ("VC", "VCT"),
+            Self::Sudan => ("SD", "SDN"),
+            Self::Suriname => ("SR", "SUR"),
+            Self::Switzerland => ("CH", "CHE"),
+            Self::Sweden => ("SE", "SWE"),
+            Self::Syria => ("SY", "SYR"),
+            Self::Taiwan => ("TW", "TWN"),
+            Self::Tajikistan => ("TJ", "TJK"),
+            Self::Tanzania => ("TZ", "TZA"),
+            Self::Thailand => ("TH", "THA"),
+            // `EastTimor` is the older name of the same country.
+            Self::EastTimor | Self::TimorLeste => ("TL", "TLS"),
+            Self::Togo => ("TG", "TGO"),
+            Self::Tonga => ("TO", "TON"),
+            Self::TrinidadTobago => ("TT", "TTO"),
+            Self::Tunisia => ("TN", "TUN"),
+            Self::Turkey => ("TR", "TUR"),
+            Self::Turkmenistan => ("TM", "TKM"),
+            Self::Tuvalu => ("TV", "TUV"),
+            Self::Uae => ("AE", "ARE"),
+            Self::Uganda => ("UG", "UGA"),
+            Self::Ukraine => ("UA", "UKR"),
+            Self::UnitedKingdom => ("GB", "GBR"),
+            Self::UnitedStates => ("US", "USA"),
+            Self::Uruguay => ("UY", "URY"),
+            Self::Uzbekistan => ("UZ", "UZB"),
+            Self::Vanuatu => ("VU", "VUT"),
+            Self::VaticanCity => ("VA", "VAT"),
+            Self::Venezuela => ("VE", "VEN"),
+            Self::Vietnam => ("VN", "VNM"),
+            Self::WesternSahara => ("EH", "ESH"),
+            Self::Yemen => ("YE", "YEM"),
+            Self::Zambia => ("ZM", "ZMB"),
+            Self::Zimbabwe => ("ZW", "ZWE"),
+            _ => return None,
+        };
+        Some(iso)
+    }
+
+    /// The ISO 3166-1 alpha-2 code of a country or territory.
+    pub fn iso_alpha2(&self) -> Option<&'static str> {
+        self.iso().map(|(alpha2, _)| alpha2)
+    }
+
+    /// The ISO 3166-1 alpha-3 code of a country or territory.
+    pub fn iso_alpha3(&self) -> Option<&'static str> {
+        self.iso().map(|(_, alpha3)| alpha3)
+    }
+
+    /// Parses an ISO 3166-1 alpha-2 or alpha-3 code, ignoring case.  The code `TL` parses to
+    /// `TimorLeste` rather than `EastTimor`.
+    pub fn from_iso(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::iter().filter(|v| *v != Self::EastTimor).find(|v| {
+            v.iso().is_some_and(|(alpha2, alpha3)| {
+                alpha2.eq_ignore_ascii_case(code) || alpha3.eq_ignore_ascii_case(code)
+            })
+        })
+    }
+
+    /// The direct members of an aggregate area, which may themselves be aggregates.  Returns
+    /// `None` for countries and territories, for the BEA regions of the United States (see
+    /// [`AreaOrCountry::states`]), and for residual aggregates such as `AfricaOthGdsNsaDetail`
+    /// or `OthEuropeExcl32`, whose members depend on the countries shown separately in a table
+    /// (see [`AreaOrCountry::expand_residual`]).  `OthAfricaExcl1` excludes only South Africa, so
+    /// its members are known.
+    pub fn members(&self) -> Option<Vec<Self>> {
+        let members = match self {
+            Self::AllCountries => vec![
+                Self::Canada,
+                Self::Europe,
+                Self::LatAmAndOthWestHem,
+                Self::Africa,
+                Self::MiddleEast,
+                Self::AsiaAndPac,
+            ],
+            Self::AllOthThanCanada => vec![
+                Self::Europe,
+                Self::LatAmAndOthWestHem,
+                Self::Africa,
+                Self::MiddleEast,
+                Self::AsiaAndPac,
+            ],
+            Self::LatAmAndOthWestHem => vec![Self::SouthAndCenAm, Self::OthWestHem],
+            Self::SouthAndCenAm => vec![
+                Self::SouthAm,
+                Self::CentralAmerica,
+                Self::Cuba,
+                Self::DominicanRep,
+                Self::Haiti,
+            ],
+            Self::SouthAm => vec![
+                Self::Argentina,
+                Self::Bolivia,
+                Self::Brazil,
+                Self::Chile,
+                Self::Colombia,
+                Self::Ecuador,
+                Self::Paraguay,
+                Self::Peru,
+                Self::Uruguay,
+                Self::Venezuela,
+            ],
+            Self::CentralAmerica => vec![
+                Self::CostaRica,
+                Self::ElSalvador,
+                Self::Guatemala,
+                Self::Honduras,
+                Self::Mexico,
+                Self::Nicaragua,
+                Self::Panama,
+            ],
+            Self::CaftaDrCountries => vec![
+                Self::CostaRica,
+                Self::DominicanRep,
+                Self::ElSalvador,
+                Self::Guatemala,
+                Self::Honduras,
+                Self::Nicaragua,
+            ],
+            Self::OthWestHem => vec![
+                Self::Anguilla,
+                Self::AntiguaBarbuda,
+                Self::Aruba,
+                Self::Bahamas,
+                Self::Barbados,
+                Self::Belize,
+                Self::Bermuda,
+                Self::Curacao,
+                Self::Dominica,
+                Self::FrenchGuiana,
+                Self::FrenchIslandsCaribbean,
+                Self::Grenada,
+                Self::Guyana,
+                Self::Jamaica,
+                Self::NetherlandsAntilles,
+                Self::NetherlandsIslandsCaribbean,
+                Self::SintMaarten,
+                Self::StKittsNevis,
+                Self::StLucia,
+                Self::StPierreMiquelon,
+                Self::StVincentGrenadines,
+                Self::Suriname,
+                Self::TrinidadTobago,
+                Self::UnitedKingdomIslandsAtlanticOWH,
+                Self::UnitedKingdomIslandsCaribbean,
+            ],
+            Self::Europe => vec![
+                Self::Andorra,
+                Self::Austria,
+                Self::Belgium,
+                Self::Cyprus,
+                Self::Denmark,
+                Self::Finland,
+                Self::France,
+                Self::Germany,
+                Self::Gibraltar,
+                Self::Greece,
+                Self::Greenland,
+                Self::Iceland,
+                Self::Ireland,
+                Self::Italy,
+                Self::Liechtenstein,
+                Self::Luxembourg,
+                Self::Malta,
+                Self::Monaco,
+                Self::Netherlands,
+                Self::Norway,
+                Self::Portugal,
+                Self::SanMarino,
+                Self::Spain,
+                Self::Sweden,
+                Self::Switzerland,
+                Self::Turkey,
+                Self::UnitedKingdom,
+                Self::VaticanCity,
+                Self::EasternEurope,
+            ],
+            Self::EasternEurope => vec![
+                Self::Albania,
+                Self::Armenia,
+                Self::Azerbaijan,
+                Self::Belarus,
+                Self::BosniaHerzegovina,
+                Self::Bulgaria,
+                Self::Croatia,
+                Self::CzechRep,
+                Self::Estonia,
+                Self::Georgia,
+                Self::Hungary,
+                Self::Kazakhstan,
+                Self::Kosovo,
+                Self::Kyrgyzstan,
+                Self::Latvia,
+                Self::Lithuania,
+                Self::Moldova,
+                Self::Montenegro,
+                Self::NorthMacedonia,
+                Self::Poland,
+                Self::Romania,
+                Self::Russia,
+                Self::Serbia,
+                Self::Slovakia,
+                Self::Slovenia,
+                Self::Tajikistan,
+                Self::Turkmenistan,
+                Self::Ukraine,
+                Self::Uzbekistan,
+            ],
+            // Membership as of 2020, after the withdrawal of the United Kingdom.
+            Self::EU => vec![
+                Self::Austria,
+                Self::Belgium,
+                Self::Bulgaria,
+                Self::Croatia,
+                Self::Cyprus,
+                Self::CzechRep,
+                Self::Denmark,
+                Self::Estonia,
+                Self::Finland,
+                Self::France,
+                Self::Germany,
+                Self::Greece,
+                Self::Hungary,
+                Self::Ireland,
+                Self::Italy,
+                Self::Latvia,
+                Self::Lithuania,
+                Self::Luxembourg,
+                Self::Malta,
+                Self::Netherlands,
+                Self::Poland,
+                Self::Portugal,
+                Self::Romania,
+                Self::Slovakia,
+                Self::Slovenia,
+                Self::Spain,
+                Self::Sweden,
+            ],
+            // Membership as of 2023, after the accession of Croatia.
+            Self::EuroArea => vec![
+                Self::Austria,
+                Self::Belgium,
+                Self::Croatia,
+                Self::Cyprus,
+                Self::Estonia,
+                Self::Finland,
+                Self::France,
+                Self::Germany,
+                Self::Greece,
+                Self::Ireland,
+                Self::Italy,
+                Self::Latvia,
+                Self::Lithuania,
+                Self::Luxembourg,
+                Self::Malta,
+                Self::Netherlands,
+                Self::Portugal,
+                Self::Slovakia,
+                Self::Slovenia,
+                Self::Spain,
+            ],
+            Self::Africa => {
+                let mut members = Self::OthAfricaExcl1.members()?;
+                members.push(Self::SouthAfrica);
+                members
+            }
+            Self::OthAfricaExcl1 => vec![
+                Self::Algeria,
+                Self::Angola,
+                Self::Benin,
+                Self::Botswana,
+                Self::BurkinaFaso,
+                Self::Burundi,
+                Self::CaboVerde,
+                Self::Cameroon,
+                Self::CentralAfricanRepublic,
+                Self::Chad,
+                Self::Comoros,
+                Self::CongoBrazzaville,
+                Self::CongoKinshasa,
+                Self::CoteDIvoire,
+                Self::Djibouti,
+                Self::Egypt,
+                Self::EquatorialGuinea,
+                Self::Eritrea,
+                Self::Eswatini,
+                Self::Ethiopia,
+                Self::Gabon,
+                Self::Gambia,
+                Self::Ghana,
+                Self::Guinea,
+                Self::GuineaBissau,
+                Self::Kenya,
+                Self::Lesotho,
+                Self::Liberia,
+                Self::Libya,
+                Self::Madagascar,
+                Self::Malawi,
+                Self::Mali,
+                Self::Mauritania,
+                Self::Mauritius,
+                Self::Morocco,
+                Self::Mozambique,
+                Self::Namibia,
+                Self::Niger,
+                Self::Nigeria,
+                Self::Rwanda,
+                Self::SaoTomePrincipe,
+                Self::Senegal,
+                Self::Seychelles,
+                Self::SierraLeone,
+                Self::Somalia,
+                Self::SouthSudan,
+                Self::Sudan,
+                Self::Tanzania,
+                Self::Togo,
+                Self::Tunisia,
+                Self::Uganda,
+                Self::UnitedKingdomIslandsAtlanticAfrica,
+                Self::WesternSahara,
+                Self::Zambia,
+                Self::Zimbabwe,
+            ],
+            Self::MiddleEast => vec![
+                Self::Bahrain,
+                Self::Iran,
+                Self::Iraq,
+                Self::Israel,
+                Self::Jordan,
+                Self::Kuwait,
+                Self::Lebanon,
+                Self::Oman,
+                Self::Qatar,
+                Self::SaudiArabia,
+                Self::Syria,
+                Self::Uae,
+                Self::Yemen,
+            ],
+            Self::AsiaAndPac => vec![
+                Self::Afghanistan,
+                Self::Australia,
+                Self::Bangladesh,
+                Self::Bhutan,
+                Self::Brunei,
+                Self::Burma,
+                Self::Cambodia,
+                Self::China,
+                Self::CookIslands,
+                Self::Fiji,
+                Self::FrenchIslandsIndianOcean,
+                Self::FrenchIslandsPacific,
+                Self::HongKong,
+                Self::India,
+                Self::Indonesia,
+                Self::Japan,
+                Self::Kiribati,
+                Self::Laos,
+                Self::Macau,
+                Self::Malaysia,
+                Self::Maldives,
+                Self::MarshallIslands,
+                Self::Micronesia,
+                Self::Mongolia,
+                Self::Nauru,
+                Self::Nepal,
+                Self::NewZealand,
+                Self::Niue,
+                Self::NorthKorea,
+                Self::Pakistan,
+                Self::Palau,
+                Self::PapuaNewGinea,
+                Self::Philippines,
+                Self::Samoa,
+                Self::Singapore,
+                Self::SolomonIslands,
+                Self::SouthKorea,
+                Self::SriLanka,
+                Self::Taiwan,
+                Self::Thailand,
+                Self::TimorLeste,
+                Self::Tonga,
+                Self::Tuvalu,
+                Self::UnitedKingdomIslandsIndianOcean,
+                Self::UnitedKingdomIslandsPacific,
+                Self::Vanuatu,
+                Self::Vietnam,
+            ],
+            // Membership as of 2024, after the withdrawal of Angola.
+            Self::OPEC => vec![
+                Self::Algeria,
+                Self::CongoBrazzaville,
+                Self::EquatorialGuinea,
+                Self::Gabon,
+                Self::Iran,
+                Self::Iraq,
+                Self::Kuwait,
+                Self::Libya,
+                Self::Nigeria,
+                Self::SaudiArabia,
+                Self::Uae,
+                Self::Venezuela,
+            ],
+            _ => return None,
+        };
+        Some(members)
+    }
+
+    /// Expands an aggregate area into the countries and territories it contains, recursing
+    /// through nested aggregates.  A country or territory expands to itself.  Returns a
+    /// [`VariantMissing`] error naming the aggregate if the members of `self` or of a nested
+    /// aggregate are unknown.  Residual aggregates such as `AfricaOthGdsNsaDetail` expand with
+    /// [`AreaOrCountry::expand_residual`] instead, given the countries listed in the table.
+    pub fn expand(&self) -> Result<BTreeSet<Self>, BeaErr> {
+        let mut expanded = BTreeSet::new();
+        match self.members() {
+            Some(members) => {
+                for member in members {
+                    expanded.append(&mut member.expand()?);
+                }
+            }
+            None if self.is_aggregate() => {
+                let clue = match self.residual_region() {
+                    Some(_) => "Members depend on the listed countries, see expand_residual, for",
+                    None => "Members unknown for aggregate area",
+                }
+                .to_string();
+                return Err(
+                    VariantMissing::new(clue, self.to_string(), line!(), file!().into()).into(),
+                );
+            }
+            None => {
+                expanded.insert(*self);
+            }
+        }
+        Ok(expanded)
+    }
+
+    /// The region of a residual aggregate, which covers the countries of the region not shown
+    /// separately in a table, and the number of countries excluded if the description states it.
+    /// Returns `None` for other areas, and for `IntOrgAndUnalloc` and `ResidualSeas`, which are not
+    /// geographic.
+    pub fn residual_region(&self) -> Option<(Self, Option<usize>)> {
+        let residual = match self {
+            Self::AllOthSeas => (Self::AllCountries, None),
+            Self::AfricaOthGdsNsaDetail => (Self::Africa, None),
+            Self::OthAfricaExcl1 => (Self::Africa, Some(1)),
+            Self::OthAfricaExcl3 | Self::OthAfricaExcl3DiOutward | Self::OthAfricaIst => {
+                (Self::Africa, Some(3))
+            }
+            Self::AsiaAndPacOthNsaDetail | Self::OtherAsiaPacific => (Self::AsiaAndPac, None),
+            Self::OthAsiaAndPacExcl8 => (Self::AsiaAndPac, Some(8)),
+            Self::OthAsiaAndPacExcl10DiInward => (Self::AsiaAndPac, Some(10)),
+            Self::OthAsiaAndPacExcl13DiOutward => (Self::AsiaAndPac, Some(13)),
+            Self::OthAsiaAndPacExcl15 | Self::OthAsiaAndPacIst => (Self::AsiaAndPac, Some(15)),
+            Self::OthCenAmExcl4DiOutward => (Self::CentralAmerica, Some(4)),
+            Self::EuropeOthNsaDetail => (Self::Europe, None),
+            Self::OthEuropeExcl7 => (Self::Europe, Some(7)),
+            Self::OthEuropeExcl17DiInward => (Self::Europe, Some(17)),
+            Self::OthEuropeExcl22DiOutward => (Self::Europe, Some(22)),
+            Self::OthEuropeExcl32 | Self::OthEuropeIst => (Self::Europe, Some(32)),
+            Self::MiddleEastOthGdsNsaDetail => (Self::MiddleEast, None),
+            Self::OthMiddleEastExc3DiOutward => (Self::MiddleEast, Some(3)),
+            Self::OthMiddleEastExc5DiInward | Self::OthMiddleEastExcl5 | Self::OthMiddleEastIst => {
+                (Self::MiddleEast, Some(5))
+            }
+            Self::OthSouthAmExcl7DiOutward => (Self::SouthAm, Some(7)),
+            Self::SouthAndCenAmOthNsaDetail => (Self::SouthAndCenAm, None),
+            Self::OthSouthAndCenAmExcl4 | Self::OthSouthAndCenAmExcl4DiInward => {
+                (Self::SouthAndCenAm, Some(4))
+            }
+            Self::OthSouthAndCenAmExcl13 | Self::OthSouthAndCenAmIst => {
+                (Self::SouthAndCenAm, Some(13))
+            }
+            Self::OthWestHemOthExcl3 | Self::OthWestHemOthIst => (Self::OthWestHem, Some(3)),
+            Self::OthWestHemOthExcl4DiInward | Self::OthWestHemOthExcl4DiOutward => {
+                (Self::OthWestHem, Some(4))
+            }
+            _ => return None,
+        };
+        Some(residual)
+    }
+
+    /// Expands a residual aggregate into the countries and territories of its region that are
+    /// not shown separately in a table, where `listed` holds the areas the table shows.  Listed
+    /// aggregates within the region exclude all of their members, but only countries count toward
+    /// the number excluded.  Any other area expands as in
+    /// [`AreaOrCountry::expand`].  Returns a [`VariantMissing`] error if the description of the
+    /// residual states a number of excluded countries that differs from the number listed in
+    /// its region.
+    pub fn expand_residual(&self, listed: &BTreeSet<Self>) -> Result<BTreeSet<Self>, BeaErr> {
+        let Some((region, excluded)) = self.residual_region() else {
+            return self.expand();
+        };
+        let mut expanded = region.expand()?;
+        let mut count = 0;
+        for area in listed.iter().filter(|area| **area != region) {
+            let Ok(members) = area.expand() else {
+                continue;
+            };
+            if members.is_subset(&expanded) {
+                expanded.retain(|member| !members.contains(member));
+                if !area.is_aggregate() {
+                    count += 1;
+                }
+            }
+        }
+        if excluded.is_some_and(|excluded| excluded != count) {
+            let clue = format!("Residual excludes {excluded:?} areas, {count} listed, for");
+            return Err(
+                VariantMissing::new(clue, self.to_string(), line!(), file!().into()).into(),
+            );
+        }
+        Ok(expanded)
+    }
+
+    /// The countries and territories an area covers: the expansion of the area, or the region of
+    /// a residual aggregate.  Returns `None` for areas without known members.
+    fn coverage(&self) -> Option<BTreeSet<Self>> {
+        match self.residual_region() {
+            Some((region, _)) => region.expand().ok(),
+            None => self.expand().ok(),
+        }
+    }
+
+    /// The geographic distance between two areas, measured as the number of countries in the
+    /// smallest aggregate covering both, so that countries of the same subregion are nearer than
+    /// countries of the same continent.  An area is at distance zero from itself.  Returns `None`
+    /// if either area has no known members, as for `IntOrgAndUnalloc`.
+    pub fn distance(&self, other: &Self) -> Option<usize> {
+        static REGIONS: std::sync::OnceLock<Vec<BTreeSet<AreaOrCountry>>> =
+            std::sync::OnceLock::new();
+        if self == other {
+            return Some(0);
+        }
+        let (left, right) = (self.coverage()?, other.coverage()?);
+        let regions = REGIONS.get_or_init(|| {
+            Self::iter()
+                .filter(|area| area.members().is_some())
+                .filter_map(|area| area.expand().ok())
+                .collect()
+        });
+        regions
+            .iter()
+            .filter(|region| left.is_subset(region) && right.is_subset(region))
+            .map(|region| region.len())
+            .min()
+    }
+
+    /// Whether `self` names a group of countries rather than a single country, territory or
+    /// group of islands, including residual aggregates and the BEA regions of the United States.
+    pub fn is_aggregate(&self) -> bool {
+        self.iso().is_none()
+            && !matches!(
+                self,
+                Self::FrenchIslandsCaribbean
+                    | Self::FrenchIslandsIndianOcean
+                    | Self::FrenchIslandsPacific
+                    | Self::NetherlandsAntilles
+                    | Self::UkIslandsCarib
+                    | Self::UnitedKingdomIslandsAtlanticAfrica
+                    | Self::UnitedKingdomIslandsAtlanticOWH
+                    | Self::UnitedKingdomIslandsCaribbean
+                    | Self::UnitedKingdomIslandsIndianOcean
+                    | Self::UnitedKingdomIslandsPacific
+            )
+    }
+
+    /// The states in a BEA region of the United States.  The Mideast region has no variant.
+    pub fn states(&self) -> Option<Vec<StateKind>> {
+        let states = match self {
+            Self::NewEngland => vec![
+                StateKind::Connecticut,
+                StateKind::Maine,
+                StateKind::Massachusetts,
+                StateKind::NewHampshire,
+                StateKind::RhodeIsland,
+                StateKind::Vermont,
+            ],
+            Self::GreatLakes => vec![
+                StateKind::Illinois,
+                StateKind::Indiana,
+                StateKind::Michigan,
+                StateKind::Ohio,
+                StateKind::Wisconsin,
+            ],
+            Self::Plains => vec![
+                StateKind::Iowa,
+                StateKind::Kansas,
+                StateKind::Minnesota,
+                StateKind::Missouri,
+                StateKind::Nebraska,
+                StateKind::NorthDakota,
+                StateKind::SouthDakota,
+            ],
+            Self::Southeast => vec![
+                StateKind::Alabama,
+                StateKind::Arkansas,
+                StateKind::Florida,
+                StateKind::Georgia,
+                StateKind::Kentucky,
+                StateKind::Louisiana,
+                StateKind::Mississippi,
+                StateKind::NorthCarolina,
+                StateKind::SouthCarolina,
+                StateKind::Tennessee,
+                StateKind::Virginia,
+                StateKind::WestVirginia,
+            ],
+            Self::Southwest => vec![
+                StateKind::Arizona,
+                StateKind::NewMexico,
+                StateKind::Oklahoma,
+                StateKind::Texas,
+            ],
+            Self::RockyMountains => vec![
+                StateKind::Colorado,
+                StateKind::Idaho,
+                StateKind::Montana,
+                StateKind::Utah,
+                StateKind::Wyoming,
+            ],
+            Self::FarWest => vec![
+                StateKind::Alaska,
+                StateKind::California,
+                StateKind::Hawaii,
+                StateKind::Nevada,
+                StateKind::Oregon,
+                StateKind::Washington,
+            ],
+            _ => return None,
+        };
+        Some(states)
+    }
+}
+
+impl AocSta {
+    /// The [`AreaOrCountry`] variant naming the same area.  Returns `None` for the residual
+    /// aggregates specific to the IntlServSTA dataset and for `AfricaMidEastAndAsiaAndPac`.
+    pub fn area_or_country(&self) -> Option<AreaOrCountry> {
+        match self {
+            Self::CenAm => Some(AreaOrCountry::CentralAmerica),
+            Self::UnitedArabEm => Some(AreaOrCountry::Uae),
+            _ => self.to_string().parse::<AreaOrCountry>().ok(),
+        }
+    }
+
+    /// The ISO 3166-1 alpha-2 code of a country or territory.
+    pub fn iso_alpha2(&self) -> Option<&'static str> {
+        self.area_or_country()?.iso_alpha2()
+    }
+
+    /// The ISO 3166-1 alpha-3 code of a country or territory.
+    pub fn iso_alpha3(&self) -> Option<&'static str> {
+        self.area_or_country()?.iso_alpha3()
+    }
+
+    /// Expands an aggregate area into the countries and territories it contains.  See
+    /// [`AreaOrCountry::expand`].  Returns a [`VariantMissing`] error for the residual aggregates
+    /// without an [`AreaOrCountry`] variant.
+    pub fn expand(&self) -> Result<BTreeSet<AreaOrCountry>, BeaErr> {
+        match self {
+            Self::AfricaMidEastAndAsiaAndPac => {
+                let mut expanded = AreaOrCountry::Africa.expand()?;
+                expanded.append(&mut AreaOrCountry::MiddleEast.expand()?);
+                expanded.append(&mut AreaOrCountry::AsiaAndPac.expand()?);
+                Ok(expanded)
+            }
+            _ => match self.area_or_country() {
+                Some(area) => area.expand(),
+                None => {
+                    let clue = "Members unknown for aggregate area".to_string();
+                    Err(VariantMissing::new(clue, self.to_string(), line!(), file!().into()).into())
+                }
+            },
+        }
+    }
+}
+
+impl StateKind {
+    /// The two-digit FIPS code of a state, the District of Columbia or an outlying area.  Note
+    /// that [`StateKind::from_code`] reads the BEA code 43 for Puerto Rico, where FIPS uses 72.
+    #[rustfmt::skip]
+    pub fn fips(&self) -> Option<u8> {
+        let fips = match self {
+            Self::Alabama => 1,
+            Self::Alaska => 2,
+            Self::Arizona => 4,
+            Self::Arkansas => 5,
+            Self::California => 6,
+            Self::Colorado => 8,
+            Self::Connecticut => 9,
+            Self::Delaware => 10,
+            Self::DistrictOfColumbia => 11,
+            Self::Florida => 12,
+            Self::Georgia => 13,
+            Self::Hawaii => 15,
+            Self::Idaho => 16,
+            Self::Illinois => 17,
+            Self::Indiana => 18,
+            Self::Iowa => 19,
+            Self::Kansas => 20,
+            Self::Kentucky => 21,
+            Self::Louisiana => 22,
+            Self::Maine => 23,
+            Self::Maryland => 24,
+            Self::Massachusetts => 25,
+            Self::Michigan => 26,
+            Self::Minnesota => 27,
+            Self::Mississippi => 28,
+            Self::Missouri => 29,
+            Self::Montana => 30,
+            Self::Nebraska => 31,
+            Self::Nevada => 32,
+            Self::NewHampshire => 33,
+            Self::NewJersey => 34,
+            Self::NewMexico => 35,
+            Self::NewYork => 36,
+            Self::NorthCarolina => 37,
+            Self::NorthDakota => 38,
+            Self::Ohio => 39,
+            Self::Oklahoma => 40,
+            Self::Oregon => 41,
+            Self::Pennsylvania => 42,
+            Self::RhodeIsland => 44,
+            Self::SouthCarolina => 45,
+            Self::SouthDakota => 46,
+            Self::Tennessee => 47,
+            Self::Texas => 48,
+            Self::Utah => 49,
+            Self::Vermont => 50,
+            Self::Virginia => 51,
+            Self::Washington => 53,
+            Self::WestVirginia => 54,
+            Self::Wisconsin => 55,
+            Self::Wyoming => 56,
+            Self::AmericanSomoa => 60,
+            Self::Guam => 66,
+            Self::NorthernMarianaIslands => 69,
+            Self::PuertoRico => 72,
+            Self::VirginIslands => 78,
+            _ => return None,
+        };
+        Some(fips)
+    }
+
+    /// The USPS postal abbreviation of a state, the District of Columbia or an outlying area.
+    #[rustfmt::skip]
+    pub fn postal(&self) -> Option<&'static str> {
+        let postal = match self {
+            Self::Alabama => "AL",
+            Self::Alaska => "AK",
+            Self::Arizona => "AZ",
+            Self::Arkansas => "AR",
+            Self::California => "CA",
+            Self::Colorado => "CO",
+            Self::Connecticut => "CT",
+            Self::Delaware => "DE",
+            Self::DistrictOfColumbia => "DC",
+            Self::Florida => "FL",
+            Self::Georgia => "GA",
+            Self::Hawaii => "HI",
+            Self::Idaho => "ID",
+            Self::Illinois => "IL",
+            Self::Indiana => "IN",
+            Self::Iowa => "IA",
+            Self::Kansas => "KS",
+            Self::Kentucky => "KY",
+            Self::Louisiana => "LA",
+            Self::Maine => "ME",
+            Self::Maryland => "MD",
+            Self::Massachusetts => "MA",
+            Self::Michigan => "MI",
+            Self::Minnesota => "MN",
+            Self::Mississippi => "MS",
+            Self::Missouri => "MO",
+            Self::Montana => "MT",
+            Self::Nebraska => "NE",
+            Self::Nevada => "NV",
+            Self::NewHampshire => "NH",
+            Self::NewJersey => "NJ",
+            Self::NewMexico => "NM",
+            Self::NewYork => "NY",
+            Self::NorthCarolina => "NC",
+            Self::NorthDakota => "ND",
+            Self::Ohio => "OH",
+            Self::Oklahoma => "OK",
+            Self::Oregon => "OR",
+            Self::Pennsylvania => "PA",
+            Self::RhodeIsland => "RI",
+            Self::SouthCarolina => "SC",
+            Self::SouthDakota => "SD",
+            Self::Tennessee => "TN",
+            Self::Texas => "TX",
+            Self::Utah => "UT",
+            Self::Vermont => "VT",
+            Self::Virginia => "VA",
+            Self::Washington => "WA",
+            Self::WestVirginia => "WV",
+            Self::Wisconsin => "WI",
+            Self::Wyoming => "WY",
+            Self::AmericanSomoa => "AS",
+            Self::Guam => "GU",
+            Self::NorthernMarianaIslands => "MP",
+            Self::PuertoRico => "PR",
+            Self::VirginIslands => "VI",
+            _ => return None,
+        };
+        Some(postal)
+    }
+
+    /// Parses a two-digit FIPS state code.
+    pub fn from_fips(code: u8) -> Option<Self> {
+        Self::iter().find(|v| v.fips() == Some(code))
+    }
+
+    /// Parses a USPS postal abbreviation, ignoring case.
+    pub fn from_postal(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::iter().find(|v| {
+            v.postal()
+                .is_some_and(|postal| postal.eq_ignore_ascii_case(code))
+        })
+    }
+
+    /// The BEA region of the United States containing a state, if it has an
+    /// [`AreaOrCountry`] variant.
+    pub fn region(&self) -> Option<AreaOrCountry> {
+        [
+            AreaOrCountry::NewEngland,
+            AreaOrCountry::GreatLakes,
+            AreaOrCountry::Plains,
+            AreaOrCountry::Southeast,
+            AreaOrCountry::Southwest,
+            AreaOrCountry::RockyMountains,
+            AreaOrCountry::FarWest,
+        ]
+        .into_iter()
+        .find(|region| region.states().is_some_and(|states| states.contains(self)))
+    }
+}
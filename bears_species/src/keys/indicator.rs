@@ -1,20 +1,23 @@
 use crate::{BeaErr, KeyMissing, ParameterFields, ParameterValueTable, ParameterValueTableVariant};
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 /// Valid values for the Indicator parameter.
+// @generated begin by `bears generate` from parameter_values/ITA_Indicator_values.json, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
-    Clone,
     Copy,
+    Clone,
     PartialEq,
     Eq,
     PartialOrd,
     Ord,
     Hash,
-    derive_more::FromStr,
-    derive_more::Display,
     serde::Serialize,
     serde::Deserialize,
+    derive_more::Display,
+    derive_more::FromStr,
     strum::EnumIter,
 )]
 pub enum Indicator {
@@ -1558,83 +1561,83 @@ pub enum Indicator {
     OthReserveAssets,
     /// Net U.S. acquisition of portfolio investment assets
     PfInvAssets,
-    /// Net U.S. acquisition of portfolio investment assets; held by deposit-taking institutions except central bank
+    /// Net U.S. acquisition of portfolio investment assets; deposit-taking institutions
     PfInvAssetsDepTaking,
-    /// Net U.S. acquisition of portfolio investment assets; held by nonfinancial institutions except general government
+    /// Net U.S. acquisition of portfolio investment assets; nonfinancial institutions
     PfInvAssetsNonFin,
-    /// Net U.S. acquisition of portfolio investment assets; held by non-deposit-taking financial institutions
+    /// Net U.S. acquisition of portfolio investment assets; other financial institutions
     PfInvAssetsOthFin,
     /// Portfolio investment income payments
     PfInvIncPay,
-    /// Portfolio investment income payments; deposit-taking institutions except central bank
+    /// Portfolio investment income payments; deposit-taking institutions
     PfInvIncPayDepTaking,
     /// Portfolio investment income payments; general government
     PfInvIncPayGenGovt,
-    /// Portfolio investment income payments; nonfinancial institutions except general government
+    /// Portfolio investment income payments; nonfinancial institutions
     PfInvIncPayNonFin,
-    /// Portfolio investment income payments; non-deposit-taking financial institutions
+    /// Portfolio investment income payments; other financial institutions
     PfInvIncPayOthFin,
     /// Portfolio investment income receipts
     PfInvIncRec,
-    /// Portfolio investment income receipts; deposit-taking institutions except central bank
+    /// Portfolio investment income receipts; deposit-taking institutions
     PfInvIncRecDepTaking,
-    /// Portfolio investment income receipts; nonfinancial institutions except general government
+    /// Portfolio investment income receipts; nonfinancial institutions
     PfInvIncRecNonFin,
-    /// Portfolio investment income receipts; non-deposit-taking financial institutions
+    /// Portfolio investment income receipts; other financial institutions
     PfInvIncRecOthFin,
     /// Net U.S. incurrence of portfolio investment liabilities
     PfInvLiabs,
-    /// Net U.S. incurrence of portfolio investment liabilities; issued by deposit-taking institutions except central bank
+    /// Net U.S. incurrence of portfolio investment liabilities; deposit-taking institutions
     PfInvLiabsDepTaking,
-    /// Net U.S. incurrence of portfolio investment liabilities to foreign official agencies
+    /// Net U.S. incurrence of portfolio investment liabilities; financial account
     PfInvLiabsFoa,
-    /// Net U.S. incurrence of portfolio investment liabilities; issued by general government
+    /// Net U.S. incurrence of portfolio investment liabilities; general government
     PfInvLiabsGenGovt,
-    /// Net U.S. incurrence of portfolio investment liabilities; issued by nonfinancial institutions except general government
+    /// Net U.S. incurrence of portfolio investment liabilities; nonfinancial institutions
     PfInvLiabsNonFin,
-    /// Net U.S. incurrence of portfolio investment liabilities; issued by non-deposit-taking financial institutions
+    /// Net U.S. incurrence of portfolio investment liabilities; other financial institutions
     PfInvLiabsOthFin,
     /// Primary income payments
     PrimIncPay,
     /// Primary income receipts
     PrimIncRec,
-    /// Net U.S. acquisition of reserve assets
+    /// Reserve assets
     ReserveAssets,
-    /// Net U.S. incurrence of other investment liabilities; special drawing rights allocations
+    /// Special drawing rights allocations; liabilities
     SdrAllocLiabs,
-    /// Net U.S. incurrence of other investment liabilities to foreign official agencies; special drawing rights allocations
+    /// Special drawing rights allocations; liabilities; financial account
     SdrAllocLiabsFoa,
-    /// Net U.S. acquisition of reserve assets; special drawing rights
+    /// Special drawing rights; reserve assets
     SdrReserveAssets,
     /// Seasonal adjustment discrepancy
     SeasAdjDisc,
     /// Secondary income (current transfer) payments
     SecIncPay,
-    /// Secondary income (current transfer) payments; private transfers; charitable donations
+    /// Secondary income (current transfer) payments; charitable donations
     SecIncPayCharitableDonations,
-    /// Secondary income (current transfer) payments; private transfers; transfers to foreign students
+    /// Secondary income (current transfer) payments; foreign students
     SecIncPayForeignStudents,
     /// Secondary income (current transfer) payments; general government transfers
     SecIncPayGenGovt,
-    /// Secondary income (current transfer) payments; general government transfers; contributions to international organizations
+    /// Secondary income (current transfer) payments; general government contributions to international organizations
     SecIncPayGenGovtContribIntOrg,
-    /// Secondary income (current transfer) payments; general government transfers; international cooperation
+    /// Secondary income (current transfer) payments; general government international cooperation
     SecIncPayGenGovtIntCoop,
-    /// Secondary income (current transfer) payments; other general government transfers
+    /// Secondary income (current transfer) payments; general government other
     SecIncPayGenGovtOth,
-    /// Secondary income (current transfer) payments; general government transfers; social benefits
+    /// Secondary income (current transfer) payments; general government social benefits
     SecIncPayGenGovtSocialBenefits,
-    /// Secondary income (current transfer) payments; private transfers; insurance-related transfers
+    /// Secondary income (current transfer) payments; insurance-related
     SecIncPayInsuranceRelated,
     /// Secondary income (current transfer) payments; other private transfers
     SecIncPayOthPrivateTransfer,
-    /// Secondary income (current transfer) payments; private transfers; personal transfers
+    /// Secondary income (current transfer) payments; personal
     SecIncPayPersonal,
-    /// Secondary income (current transfer) payments; private transfers
+    /// Secondary income (current transfer) payments; private
     SecIncPayPrivate,
-    /// Secondary income (current transfer) payments; private transfers; fines and penalties
+    /// Secondary income (current transfer) payments; private fines and penalties
     SecIncPayPrivateFinesPenalties,
-    /// Secondary income (current transfer) payments; private transfers; taxes on income, wealth, etc.
+    /// Secondary income (current transfer) payments; taxes on income and wealth
     SecIncPayTaxesIncomeWealth,
     /// Secondary income (current transfer) receipts
     SecIncRec,
@@ -1781,32 +1784,9 @@ pub enum Indicator {
     TsiItaDiInvWithoutCurrCostAdjIncOutward,
 }
 
+#[rustfmt::skip]
 impl Indicator {
-    /// Converts BEA parameter value strings into a variant of `Indicator`, handling special cases
-    /// where the BEA key is not strictly camel cased.
-    pub fn from_value(value: &str) -> Result<Self, KeyMissing> {
-        match Indicator::from_str(value) {
-            Ok(result) => Ok(result),
-            Err(_) => {
-                let result = match value {
-                    "TSI_ItaDiInvIncInward" => Self::TsiItaDiInvIncInward,
-                    "TSI_ItaDiInvIncOutward" => Self::TsiItaDiInvIncOutward,
-                    "TSI_ItaDiInvWithoutCurrCostAdjIncOutward" => {
-                        Self::TsiItaDiInvWithoutCurrCostAdjIncOutward
-                    }
-                    _ => {
-                        return Err(KeyMissing::new(
-                            value.to_owned(),
-                            line!(),
-                            file!().to_owned(),
-                        ));
-                    }
-                };
-                Ok(result)
-            }
-        }
-    }
-
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::BalCapAcct => "Capital account balance",
@@ -1822,21 +1802,13 @@ impl Indicator {
             Self::CompOfEmplRec => "Compensation of employees; receipts",
             Self::CurrAndDepAssets => "Currency and deposits; assets",
             Self::CurrAndDepAssetsCentralBank => "Currency and deposits; assets; central bank",
-            Self::CurrAndDepAssetsDepTaking => {
-                "Currency and deposits; assets; deposit-taking institutions"
-            }
-            Self::CurrAndDepAssetsOthFinNonFin => {
-                "Currency and deposits; assets; other financial and nonfinancial institutions"
-            }
+            Self::CurrAndDepAssetsDepTaking => "Currency and deposits; assets; deposit-taking institutions",
+            Self::CurrAndDepAssetsOthFinNonFin => "Currency and deposits; assets; other financial and nonfinancial institutions",
             Self::CurrAndDepLiabs => "Currency and deposits; liabilities",
             Self::CurrAndDepLiabsCentralBank => "Currency and deposits; liabilities; central bank",
-            Self::CurrAndDepLiabsDepTaking => {
-                "Currency and deposits; liabilities; deposit-taking institutions"
-            }
+            Self::CurrAndDepLiabsDepTaking => "Currency and deposits; liabilities; deposit-taking institutions",
             Self::CurrAndDepLiabsFoa => "Currency and deposits; liabilities; financial account",
-            Self::CurrAndDepLiabsOthFinNonFin => {
-                "Currency and deposits; liabilities; other financial and nonfinancial institutions"
-            }
+            Self::CurrAndDepLiabsOthFinNonFin => "Currency and deposits; liabilities; other financial and nonfinancial institutions",
             Self::CurrAndDepReserveAssets => "Currency and deposits; reserve assets",
             Self::CurrAssets => "Current assets",
             Self::CurrLiabs => "Current liabilities",
@@ -1845,839 +1817,307 @@ impl Indicator {
             Self::DebtSecAssetsDepTaking => "Debt securities; assets; deposit-taking institutions",
             Self::DebtSecAssetsNonFin => "Debt securities; assets; nonfinancial institutions",
             Self::DebtSecAssetsOthFin => "Debt securities; assets; other financial institutions",
-            Self::DebtSecFedSponsorAgencyIncPay => {
-                "Debt securities; federal-sponsored agency income payments"
-            }
-            Self::DebtSecFedSponsorAgencyLiabs => {
-                "Debt securities; federal-sponsored agency liabilities"
-            }
+            Self::DebtSecFedSponsorAgencyIncPay => "Debt securities; federal-sponsored agency income payments",
+            Self::DebtSecFedSponsorAgencyLiabs => "Debt securities; federal-sponsored agency liabilities",
             Self::DebtSecIncPay => "Debt securities; income payments",
-            Self::DebtSecIncPayDepTaking => {
-                "Debt securities; income payments; deposit-taking institutions"
-            }
+            Self::DebtSecIncPayDepTaking => "Debt securities; income payments; deposit-taking institutions",
             Self::DebtSecIncPayGenGovt => "Debt securities; income payments; general government",
-            Self::DebtSecIncPayNonFin => {
-                "Debt securities; income payments; nonfinancial institutions"
-            }
-            Self::DebtSecIncPayOthFin => {
-                "Debt securities; income payments; other financial institutions"
-            }
+            Self::DebtSecIncPayNonFin => "Debt securities; income payments; nonfinancial institutions",
+            Self::DebtSecIncPayOthFin => "Debt securities; income payments; other financial institutions",
             Self::DebtSecIncRec => "Debt securities; income receipts",
-            Self::DebtSecIncRecDepTaking => {
-                "Debt securities; income receipts; deposit-taking institutions"
-            }
-            Self::DebtSecIncRecNonFin => {
-                "Debt securities; income receipts; nonfinancial institutions"
-            }
-            Self::DebtSecIncRecOthFin => {
-                "Debt securities; income receipts; other financial institutions"
-            }
+            Self::DebtSecIncRecDepTaking => "Debt securities; income receipts; deposit-taking institutions",
+            Self::DebtSecIncRecNonFin => "Debt securities; income receipts; nonfinancial institutions",
+            Self::DebtSecIncRecOthFin => "Debt securities; income receipts; other financial institutions",
             Self::DebtSecLiabs => "Debt securities; liabilities",
-            Self::DebtSecLiabsDepTaking => {
-                "Debt securities; liabilities; deposit-taking institutions"
-            }
+            Self::DebtSecLiabsDepTaking => "Debt securities; liabilities; deposit-taking institutions",
             Self::DebtSecLiabsFoa => "Debt securities; liabilities; financial account",
             Self::DebtSecLiabsGenGovt => "Debt securities; liabilities; general government",
             Self::DebtSecLiabsNonFin => "Debt securities; liabilities; nonfinancial institutions",
-            Self::DebtSecLiabsOthFin => {
-                "Debt securities; liabilities; other financial institutions"
-            }
-            Self::DebtSecOthThanFedSponsorAgencyIncPayOthFin => {
-                "Debt securities; other than federal-sponsored agency income payments; other financial institutions"
-            }
-            Self::DebtSecOthThanFedSponsorAgencyLiabsOthFin => {
-                "Debt securities; other than federal-sponsored agency liabilities; other financial institutions"
-            }
+            Self::DebtSecLiabsOthFin => "Debt securities; liabilities; other financial institutions",
+            Self::DebtSecOthThanFedSponsorAgencyIncPayOthFin => "Debt securities; other than federal-sponsored agency income payments; other financial institutions",
+            Self::DebtSecOthThanFedSponsorAgencyLiabsOthFin => "Debt securities; other than federal-sponsored agency liabilities; other financial institutions",
             Self::DebtSecTreasIncPay => "Debt securities; Treasury income payments",
             Self::DebtSecTreasLiabs => "Debt securities; Treasury liabilities",
             Self::DepAssets => "Deposits; assets",
             Self::DepAssetsCentralBank => "Deposits; assets; central bank",
             Self::DepAssetsDepTaking => "Deposits; assets; deposit-taking institutions",
-            Self::DepAssetsOthFinNonFin => {
-                "Deposits; assets; other financial and nonfinancial institutions"
-            }
+            Self::DepAssetsOthFinNonFin => "Deposits; assets; other financial and nonfinancial institutions",
             Self::DepLiabs => "Deposits; liabilities",
             Self::DepLiabsCentralBank => "Deposits; liabilities; central bank",
             Self::DepLiabsDepTaking => "Deposits; liabilities; deposit-taking institutions",
-            Self::DepLiabsOthFinNonFin => {
-                "Deposits; liabilities; other financial and nonfinancial institutions"
-            }
-            Self::DepRepurchaseLiabsDepTaking => {
-                "Deposits; repurchase liabilities; deposit-taking institutions"
-            }
-            Self::DepResaleAssetsDepTaking => {
-                "Deposits; resale assets; deposit-taking institutions"
-            }
+            Self::DepLiabsOthFinNonFin => "Deposits; liabilities; other financial and nonfinancial institutions",
+            Self::DepRepurchaseLiabsDepTaking => "Deposits; repurchase liabilities; deposit-taking institutions",
+            Self::DepResaleAssetsDepTaking => "Deposits; resale assets; deposit-taking institutions",
             Self::DiInvAssets => "Direct investment; assets",
             Self::DiInvAssetsNonSpe => "Direct investment; assets; non-speculative",
             Self::DiInvAssetsSpe => "Direct investment; assets; speculative",
             Self::DiInvCurrCostAdjAssets => "Direct investment; current cost adjustments; assets",
-            Self::DiInvCurrCostAdjIncPay => {
-                "Direct investment; current cost adjustments; income payments"
-            }
-            Self::DiInvCurrCostAdjIncRec => {
-                "Direct investment; current cost adjustments; income receipts"
-            }
-            Self::DiInvCurrCostAdjLiabs => {
-                "Direct investment; current cost adjustments; liabilities"
-            }
+            Self::DiInvCurrCostAdjIncPay => "Direct investment; current cost adjustments; income payments",
+            Self::DiInvCurrCostAdjIncRec => "Direct investment; current cost adjustments; income receipts",
+            Self::DiInvCurrCostAdjLiabs => "Direct investment; current cost adjustments; liabilities",
             Self::DiInvDebtInstAssets => "Direct investment; debt instruments; assets",
-            Self::DiInvDebtInstAssetsNonSpe => {
-                "Direct investment; debt instruments; assets; non-speculative"
-            }
-            Self::DiInvDebtInstAssetsSpe => {
-                "Direct investment; debt instruments; assets; speculative"
-            }
+            Self::DiInvDebtInstAssetsNonSpe => "Direct investment; debt instruments; assets; non-speculative",
+            Self::DiInvDebtInstAssetsSpe => "Direct investment; debt instruments; assets; speculative",
             Self::DiInvDebtInstInward => "Direct investment; debt instruments; inward",
-            Self::DiInvDebtInstInwardByInd => {
-                "Direct investment; debt instruments; inward; by industry"
-            }
-            Self::DiInvDebtInstInwardFinAndIns => {
-                "Direct investment; debt instruments; inward; finance and insurance"
-            }
-            Self::DiInvDebtInstInwardMnfctr => {
-                "Direct investment; debt instruments; inward; manufacturing"
-            }
-            Self::DiInvDebtInstInwardOthInd => {
-                "Direct investment; debt instruments; inward; other industries"
-            }
-            Self::DiInvDebtInstInwardWhlslTrd => {
-                "Direct investment; debt instruments; inward; wholesale trade"
-            }
+            Self::DiInvDebtInstInwardByInd => "Direct investment; debt instruments; inward; by industry",
+            Self::DiInvDebtInstInwardFinAndIns => "Direct investment; debt instruments; inward; finance and insurance",
+            Self::DiInvDebtInstInwardMnfctr => "Direct investment; debt instruments; inward; manufacturing",
+            Self::DiInvDebtInstInwardOthInd => "Direct investment; debt instruments; inward; other industries",
+            Self::DiInvDebtInstInwardWhlslTrd => "Direct investment; debt instruments; inward; wholesale trade",
             Self::DiInvDebtInstLiabs => "Direct investment; debt instruments; liabilities",
-            Self::DiInvDebtInstLiabsNonSpe => {
-                "Direct investment; debt instruments; liabilities; non-speculative"
-            }
-            Self::DiInvDebtInstLiabsSpe => {
-                "Direct investment; debt instruments; liabilities; speculative"
-            }
+            Self::DiInvDebtInstLiabsNonSpe => "Direct investment; debt instruments; liabilities; non-speculative",
+            Self::DiInvDebtInstLiabsSpe => "Direct investment; debt instruments; liabilities; speculative",
             Self::DiInvDebtInstOutward => "Direct investment; debt instruments; outward",
-            Self::DiInvDebtInstOutwardByInd => {
-                "Direct investment; debt instruments; outward; by industry"
-            }
-            Self::DiInvDebtInstOutwardFinAndIns => {
-                "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvDebtInstOutwardHoldExcBank => {
-                "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; holding companies except bank holding companies"
-            }
-            Self::DiInvDebtInstOutwardMnfctr => {
-                "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; manufacturing"
-            }
-            Self::DiInvDebtInstOutwardOthInd => {
-                "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; other industries (those not listed under acquisition of assets in table 6.1)"
-            }
-            Self::DiInvDebtInstOutwardWhlslTrd => {
-                "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; wholesale trade"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaims => {
-                "Financial transactions for direct investment; debt instruments; U.S. affiliates' claims"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsByNonSpe => {
-                "Financial transactions for direct investment; U.S. non-SPE affiliates' debt claims transactions with their foreign parent groups"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsBySpe => {
-                "Financial transactions for direct investment; U.S. SPE affiliates' debt claims transactions with their foreign parent groups"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsNonSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. affiliates' claims; Non-SPEs"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. affiliates' claims; SPEs"
-            }
-            Self::DiInvDebtInstUsAffiliatesLiabs => {
-                "Financial transactions for direct investment; debt instruments; U.S. affiliates' liabilities"
-            }
-            Self::DiInvDebtInstUsAffiliatesLiabsNonSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. affiliates' liabilities; Non-SPEs"
-            }
-            Self::DiInvDebtInstUsAffiliatesLiabsSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. affiliates' liabilities; SPEs"
-            }
-            Self::DiInvDebtInstUsParentsClaims => {
-                "Financial transactions for direct investment; debt instruments; U.S. parents' claims"
-            }
-            Self::DiInvDebtInstUsParentsClaimsNonSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. parents' claims; Non-SPEs"
-            }
-            Self::DiInvDebtInstUsParentsClaimsSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. parents' claims; SPEs"
-            }
-            Self::DiInvDebtInstUsParentsLiabs => {
-                "Financial transactions for direct investment; debt instruments; U.S. parents' liabilities"
-            }
-            Self::DiInvDebtInstUsParentsLiabsNonSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. parents' liabilities; Non-SPEs"
-            }
-            Self::DiInvDebtInstUsParentsLiabsSpe => {
-                "Financial transactions for direct investment; debt instruments; U.S. parents' liabilities; SPEs"
-            }
-            Self::DiInvDebtInstUsParentsLiabsWithNonSpe => {
-                "Financial transactions for direct investment; U.S. parents' debt liabilities transactions with their foreign non-SPE affiliates"
-            }
-            Self::DiInvDebtInstUsParentsLiabsWithSpe => {
-                "Financial transactions for direct investment; U.S. parents' debt liabilities transactions with their foreign SPE affiliates"
-            }
-            Self::DiInvDirectionalBasisAdj => {
-                "Financial transactions for direct investment; adjustments to convert to directional basis"
-            }
-            Self::DiInvDirectionalBasisAdjIncPay => {
-                "Direct investment income payments; adjustments to convert to directional basis"
-            }
-            Self::DiInvDirectionalBasisAdjIncRec => {
-                "Direct investment income receipts; adjustments to convert to directional basis"
-            }
-            Self::DiInvDivWithdrawIncPay => {
-                "Direct investment income on liabilities; dividends and withdrawals"
-            }
-            Self::DiInvDivWithdrawIncPayNonSpe => {
-                "Direct investment income on liabilities; dividends and withdrawals; Non-SPEs"
-            }
-            Self::DiInvDivWithdrawIncPaySpe => {
-                "Direct investment income on liabilities; dividends and withdrawals; SPEs"
-            }
-            Self::DiInvDivWithdrawIncRec => {
-                "Direct investment income on assets; dividends and withdrawals"
-            }
-            Self::DiInvDivWithdrawIncRecNonSpe => {
-                "Direct investment income on assets; dividends and withdrawals; Non-SPEs"
-            }
-            Self::DiInvDivWithdrawIncRecSpe => {
-                "Direct investment income on assets; dividends and withdrawals; SPEs"
-            }
+            Self::DiInvDebtInstOutwardByInd => "Direct investment; debt instruments; outward; by industry",
+            Self::DiInvDebtInstOutwardFinAndIns => "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; finance (including depository institutions) and insurance",
+            Self::DiInvDebtInstOutwardHoldExcBank => "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; holding companies except bank holding companies",
+            Self::DiInvDebtInstOutwardMnfctr => "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; manufacturing",
+            Self::DiInvDebtInstOutwardOthInd => "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; other industries (those not listed under acquisition of assets in table 6.1)",
+            Self::DiInvDebtInstOutwardWhlslTrd => "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis; debt instruments; wholesale trade",
+            Self::DiInvDebtInstUsAffiliatesClaims => "Financial transactions for direct investment; debt instruments; U.S. affiliates' claims",
+            Self::DiInvDebtInstUsAffiliatesClaimsByNonSpe => "Financial transactions for direct investment; U.S. non-SPE affiliates' debt claims transactions with their foreign parent groups",
+            Self::DiInvDebtInstUsAffiliatesClaimsBySpe => "Financial transactions for direct investment; U.S. SPE affiliates' debt claims transactions with their foreign parent groups",
+            Self::DiInvDebtInstUsAffiliatesClaimsNonSpe => "Financial transactions for direct investment; debt instruments; U.S. affiliates' claims; Non-SPEs",
+            Self::DiInvDebtInstUsAffiliatesClaimsSpe => "Financial transactions for direct investment; debt instruments; U.S. affiliates' claims; SPEs",
+            Self::DiInvDebtInstUsAffiliatesLiabs => "Financial transactions for direct investment; debt instruments; U.S. affiliates' liabilities",
+            Self::DiInvDebtInstUsAffiliatesLiabsNonSpe => "Financial transactions for direct investment; debt instruments; U.S. affiliates' liabilities; Non-SPEs",
+            Self::DiInvDebtInstUsAffiliatesLiabsSpe => "Financial transactions for direct investment; debt instruments; U.S. affiliates' liabilities; SPEs",
+            Self::DiInvDebtInstUsParentsClaims => "Financial transactions for direct investment; debt instruments; U.S. parents' claims",
+            Self::DiInvDebtInstUsParentsClaimsNonSpe => "Financial transactions for direct investment; debt instruments; U.S. parents' claims; Non-SPEs",
+            Self::DiInvDebtInstUsParentsClaimsSpe => "Financial transactions for direct investment; debt instruments; U.S. parents' claims; SPEs",
+            Self::DiInvDebtInstUsParentsLiabs => "Financial transactions for direct investment; debt instruments; U.S. parents' liabilities",
+            Self::DiInvDebtInstUsParentsLiabsNonSpe => "Financial transactions for direct investment; debt instruments; U.S. parents' liabilities; Non-SPEs",
+            Self::DiInvDebtInstUsParentsLiabsSpe => "Financial transactions for direct investment; debt instruments; U.S. parents' liabilities; SPEs",
+            Self::DiInvDebtInstUsParentsLiabsWithNonSpe => "Financial transactions for direct investment; U.S. parents' debt liabilities transactions with their foreign non-SPE affiliates",
+            Self::DiInvDebtInstUsParentsLiabsWithSpe => "Financial transactions for direct investment; U.S. parents' debt liabilities transactions with their foreign SPE affiliates",
+            Self::DiInvDirectionalBasisAdj => "Financial transactions for direct investment; adjustments to convert to directional basis",
+            Self::DiInvDirectionalBasisAdjIncPay => "Direct investment income payments; adjustments to convert to directional basis",
+            Self::DiInvDirectionalBasisAdjIncRec => "Direct investment income receipts; adjustments to convert to directional basis",
+            Self::DiInvDivWithdrawIncPay => "Direct investment income on liabilities; dividends and withdrawals",
+            Self::DiInvDivWithdrawIncPayNonSpe => "Direct investment income on liabilities; dividends and withdrawals; Non-SPEs",
+            Self::DiInvDivWithdrawIncPaySpe => "Direct investment income on liabilities; dividends and withdrawals; SPEs",
+            Self::DiInvDivWithdrawIncRec => "Direct investment income on assets; dividends and withdrawals",
+            Self::DiInvDivWithdrawIncRecNonSpe => "Direct investment income on assets; dividends and withdrawals; Non-SPEs",
+            Self::DiInvDivWithdrawIncRecSpe => "Direct investment income on assets; dividends and withdrawals; SPEs",
             Self::DiInvEquityAssets => "Net U.S. acquisition of direct investment assets; equity",
-            Self::DiInvEquityAssetsNonSpe => {
-                "Net U.S. acquisition of direct investment assets; equity; Non-SPEs"
-            }
-            Self::DiInvEquityAssetsSpe => {
-                "Net U.S. acquisition of direct investment assets; equity; SPEs"
-            }
+            Self::DiInvEquityAssetsNonSpe => "Net U.S. acquisition of direct investment assets; equity; Non-SPEs",
+            Self::DiInvEquityAssetsSpe => "Net U.S. acquisition of direct investment assets; equity; SPEs",
             Self::DiInvEquityIncPay => "Direct investment income on liabilities; equity",
-            Self::DiInvEquityIncPayNonSpe => {
-                "Direct investment income on liabilities; equity; Non-SPEs"
-            }
+            Self::DiInvEquityIncPayNonSpe => "Direct investment income on liabilities; equity; Non-SPEs",
             Self::DiInvEquityIncPaySpe => "Direct investment income on liabilities; equity; SPEs",
             Self::DiInvEquityIncRec => "Direct investment income on assets; equity",
             Self::DiInvEquityIncRecNonSpe => "Direct investment income on assets; equity; Non-SPEs",
             Self::DiInvEquityIncRecSpe => "Direct investment income on assets; equity; SPEs",
-            Self::DiInvEquityIntIncRecFinAndIns => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvEquityIntIncRecHoldExcBank => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; holding companies except bank holding companies"
-            }
-            Self::DiInvEquityIntIncRecMnfctr => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; manufacturing"
-            }
-            Self::DiInvEquityIntIncRecOthInd => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; manufacturing; other industries (those not listed under receipts in table 4.2)"
-            }
-            Self::DiInvEquityIntIncRecWhlslTrd => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; wholesale trade"
-            }
-            Self::DiInvEquityLiabs => {
-                "Net U.S. incurrence of direct investment liabilities; equity"
-            }
-            Self::DiInvEquityLiabsNonSpe => {
-                "Net U.S. incurrence of direct investment liabilities; equity; Non-SPEs"
-            }
-            Self::DiInvEquityLiabsSpe => {
-                "Net U.S. incurrence of direct investment liabilities; equity; SPEs"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssets => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsFinAndIns => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsHoldExcBank => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; holding companies except bank holding companies"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsMnfctr => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; manufacturing"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsNonSpe => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; Non-SPEs"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsOthInd => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; other industries (those not listed under acquisition of assets in table 6.1)"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsSpe => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; SPEs"
-            }
-            Self::DiInvEquityOthThanReinvestEarnAssetsWhlslTrd => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; wholesale trade"
-            }
-            Self::DiInvEquityOthThanReinvestEarnDecAssets => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; decreases"
-            }
-            Self::DiInvEquityOthThanReinvestEarnDecLiabs => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; decreases"
-            }
-            Self::DiInvEquityOthThanReinvestEarnIncAssets => {
-                "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; increases"
-            }
-            Self::DiInvEquityOthThanReinvestEarnIncLiabs => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; increases"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabs => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabsFinAndIns => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabsHoldExcBank => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; other industries (those not listed under incurrence of liabilities in table 6.1)"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabsMnfctr => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; manufacturing"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabsNonSpe => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; Non-SPEs"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabsSpe => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; SPEs"
-            }
-            Self::DiInvEquityOthThanReinvestEarnLiabsWhlslTrd => {
-                "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; wholesale trade"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncPay => {
-                "Direct investment income without current cost adjustment on liabilities; equity"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncPayFinAndIns => {
-                "Direct investment income without current cost adjustment on liabilities; equity; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncPayMnfctr => {
-                "Direct investment income without current cost adjustment on liabilities; equity; manufacturing"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncPayOthInd => {
-                "Direct investment income without current cost adjustment on liabilities; equity; other industries (those not listed under receipts in table 4.2)"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncPayWhlslTrd => {
-                "Direct investment income without current cost adjustment on liabilities; equity; wholesale trade"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncRec => {
-                "Direct investment income without current cost adjustment on assets; equity"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncRecFinAndIns => {
-                "Direct investment income without current cost adjustment on assets; equity; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncRecHoldExcBank => {
-                "Direct investment income without current cost adjustment on assets; equity; holding companies except bank holding companies"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncRecMnfctr => {
-                "Direct investment income without current cost adjustment on assets; equity; manufacturing"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncRecOthInd => {
-                "Direct investment income without current cost adjustment on assets; equity; manufacturing; other industries (those not listed under receipts in table 4.2)"
-            }
-            Self::DiInvEquityWithoutCurrCostAdjIncRecWhlslTrd => {
-                "Direct investment income without current cost adjustment on assets; equity; wholesale trade"
-            }
+            Self::DiInvEquityIntIncRecFinAndIns => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; finance (including depository institutions) and insurance",
+            Self::DiInvEquityIntIncRecHoldExcBank => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; holding companies except bank holding companies",
+            Self::DiInvEquityIntIncRecMnfctr => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; manufacturing",
+            Self::DiInvEquityIntIncRecOthInd => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; manufacturing; other industries (those not listed under receipts in table 4.2)",
+            Self::DiInvEquityIntIncRecWhlslTrd => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts; wholesale trade",
+            Self::DiInvEquityLiabs => "Net U.S. incurrence of direct investment liabilities; equity",
+            Self::DiInvEquityLiabsNonSpe => "Net U.S. incurrence of direct investment liabilities; equity; Non-SPEs",
+            Self::DiInvEquityLiabsSpe => "Net U.S. incurrence of direct investment liabilities; equity; SPEs",
+            Self::DiInvEquityOthThanReinvestEarnAssets => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings",
+            Self::DiInvEquityOthThanReinvestEarnAssetsFinAndIns => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; finance (including depository institutions) and insurance",
+            Self::DiInvEquityOthThanReinvestEarnAssetsHoldExcBank => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; holding companies except bank holding companies",
+            Self::DiInvEquityOthThanReinvestEarnAssetsMnfctr => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; manufacturing",
+            Self::DiInvEquityOthThanReinvestEarnAssetsNonSpe => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; Non-SPEs",
+            Self::DiInvEquityOthThanReinvestEarnAssetsOthInd => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; other industries (those not listed under acquisition of assets in table 6.1)",
+            Self::DiInvEquityOthThanReinvestEarnAssetsSpe => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; SPEs",
+            Self::DiInvEquityOthThanReinvestEarnAssetsWhlslTrd => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; wholesale trade",
+            Self::DiInvEquityOthThanReinvestEarnDecAssets => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; decreases",
+            Self::DiInvEquityOthThanReinvestEarnDecLiabs => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; decreases",
+            Self::DiInvEquityOthThanReinvestEarnIncAssets => "Net U.S. acquisition of direct investment assets; equity other than reinvestment of earnings; increases",
+            Self::DiInvEquityOthThanReinvestEarnIncLiabs => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; increases",
+            Self::DiInvEquityOthThanReinvestEarnLiabs => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings",
+            Self::DiInvEquityOthThanReinvestEarnLiabsFinAndIns => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; finance (including depository institutions) and insurance",
+            Self::DiInvEquityOthThanReinvestEarnLiabsHoldExcBank => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; other industries (those not listed under incurrence of liabilities in table 6.1)",
+            Self::DiInvEquityOthThanReinvestEarnLiabsMnfctr => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; manufacturing",
+            Self::DiInvEquityOthThanReinvestEarnLiabsNonSpe => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; Non-SPEs",
+            Self::DiInvEquityOthThanReinvestEarnLiabsSpe => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; SPEs",
+            Self::DiInvEquityOthThanReinvestEarnLiabsWhlslTrd => "Net U.S. incurrence of direct investment liabilities; equity other than reinvestment of earnings; wholesale trade",
+            Self::DiInvEquityWithoutCurrCostAdjIncPay => "Direct investment income without current cost adjustment on liabilities; equity",
+            Self::DiInvEquityWithoutCurrCostAdjIncPayFinAndIns => "Direct investment income without current cost adjustment on liabilities; equity; finance (including depository institutions) and insurance",
+            Self::DiInvEquityWithoutCurrCostAdjIncPayMnfctr => "Direct investment income without current cost adjustment on liabilities; equity; manufacturing",
+            Self::DiInvEquityWithoutCurrCostAdjIncPayOthInd => "Direct investment income without current cost adjustment on liabilities; equity; other industries (those not listed under receipts in table 4.2)",
+            Self::DiInvEquityWithoutCurrCostAdjIncPayWhlslTrd => "Direct investment income without current cost adjustment on liabilities; equity; wholesale trade",
+            Self::DiInvEquityWithoutCurrCostAdjIncRec => "Direct investment income without current cost adjustment on assets; equity",
+            Self::DiInvEquityWithoutCurrCostAdjIncRecFinAndIns => "Direct investment income without current cost adjustment on assets; equity; finance (including depository institutions) and insurance",
+            Self::DiInvEquityWithoutCurrCostAdjIncRecHoldExcBank => "Direct investment income without current cost adjustment on assets; equity; holding companies except bank holding companies",
+            Self::DiInvEquityWithoutCurrCostAdjIncRecMnfctr => "Direct investment income without current cost adjustment on assets; equity; manufacturing",
+            Self::DiInvEquityWithoutCurrCostAdjIncRecOthInd => "Direct investment income without current cost adjustment on assets; equity; manufacturing; other industries (those not listed under receipts in table 4.2)",
+            Self::DiInvEquityWithoutCurrCostAdjIncRecWhlslTrd => "Direct investment income without current cost adjustment on assets; equity; wholesale trade",
             Self::DiInvIncPay => "Direct investment income on liabilities, asset/liability basis",
-            Self::DiInvIncPayNonSpe => {
-                "Direct investment income on liabilities, asset/liability basis; Non-SPEs"
-            }
-            Self::DiInvIncPaySpe => {
-                "Direct investment income on liabilities, asset/liability basis; SPEs"
-            }
+            Self::DiInvIncPayNonSpe => "Direct investment income on liabilities, asset/liability basis; Non-SPEs",
+            Self::DiInvIncPaySpe => "Direct investment income on liabilities, asset/liability basis; SPEs",
             Self::DiInvIncRec => "Direct investment income on assets, asset/liability basis",
-            Self::DiInvIncRecNonSpe => {
-                "Direct investment income on assets, asset/liability basis; Non-SPEs"
-            }
-            Self::DiInvIncRecSpe => {
-                "Direct investment income on assets, asset/liability basis; SPEs"
-            }
-            Self::DiInvIntIncInward => {
-                "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments"
-            }
-            Self::DiInvIntIncInwardFinAndIns => {
-                "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvIntIncInwardMnfctr => {
-                "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; manufacturing"
-            }
-            Self::DiInvIntIncInwardOthInd => {
-                "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; other industries (those not listed under receipts in table 4.2)"
-            }
-            Self::DiInvIntIncInwardWhlslTrd => {
-                "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; wholesale trade"
-            }
-            Self::DiInvIntIncOutward => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts"
-            }
-            Self::DiInvIntIncPay => {
-                "Direct investment income on liabilities, asset/liability basis; interest"
-            }
-            Self::DiInvIntIncPayNonSpe => {
-                "Direct investment income on liabilities, asset/liability basis; interest; Non-SPEs"
-            }
-            Self::DiInvIntIncPaySpe => {
-                "Direct investment income on liabilities, asset/liability basis; interest; SPEs"
-            }
-            Self::DiInvIntIncRec => {
-                "Direct investment income on assets, asset/liability basis; interest"
-            }
-            Self::DiInvIntIncRecNonSpe => {
-                "Direct investment income on assets, asset/liability basis; interest; Non-SPEs"
-            }
-            Self::DiInvIntIncRecSpe => {
-                "Direct investment income on assets, asset/liability basis; interest; SPEs"
-            }
-            Self::DiInvIntUsAffiliatesIncPay => {
-                "Direct investment income; U.S. affiliates' interest payments"
-            }
-            Self::DiInvIntUsAffiliatesIncPayNonSpe => {
-                "Direct investment income; U.S. affiliates' interest payments; Non-SPEs"
-            }
-            Self::DiInvIntUsAffiliatesIncPaySpe => {
-                "Direct investment income; U.S. affiliates' interest payments; SPEs"
-            }
-            Self::DiInvIntUsAffiliatesIncRec => {
-                "Direct investment income; U.S. affiliates' interest receipts"
-            }
-            Self::DiInvIntUsAffiliatesIncRecNonSpe => {
-                "Direct investment income; U.S. affiliates' receipts from their foreign parent groups; Non-SPEs"
-            }
-            Self::DiInvIntUsAffiliatesIncRecOfNonSpe => {
-                "Direct investment income; U.S. non-SPE affiliates' interest receipts from their foreign parent groups"
-            }
-            Self::DiInvIntUsAffiliatesIncRecOfSpe => {
-                "Direct investment income; U.S. non-SPE affiliates' interest receipts from their foreign parent groups"
-            }
-            Self::DiInvIntUsAffiliatesIncRecSpe => {
-                "Direct investment income; U.S. affiliates' receipts from their foreign parent groups; SPEs"
-            }
-            Self::DiInvIntUsParentsIncPay => {
-                "Direct investment income; U.S. parents' interest payments"
-            }
-            Self::DiInvIntUsParentsIncPayNonSpe => {
-                "Direct investment income; U.S. parents' payments to their foreign affiliates; Non-SPEs"
-            }
-            Self::DiInvIntUsParentsIncPaySpe => {
-                "Direct investment income; U.S. parents' payments to their foreign affiliates; SPEs"
-            }
-            Self::DiInvIntUsParentsIncPayToNonSpe => {
-                "Direct investment income; U.S. parents' interest payments to their foreign non-SPE affiliates"
-            }
-            Self::DiInvIntUsParentsIncPayToSpe => {
-                "Direct investment income; U.S. parents' interest payments to their foreign SPE affiliates"
-            }
-            Self::DiInvIntUsParentsIncRec => {
-                "Direct investment income; U.S. parents' interest receipts"
-            }
-            Self::DiInvIntUsParentsIncRecNonSpe => {
-                "Direct investment income; U.S. parents' interest receipts; Non-SPEs"
-            }
-            Self::DiInvIntUsParentsIncRecSpe => {
-                "Direct investment income; U.S. parents' interest receipts; SPEs"
-            }
-            Self::DiInvInwardDirectionalBasis => {
-                "Financial transactions for inward direct investment (foreign direct investment in the United States), directional basis"
-            }
-            Self::DiInvLiabs => {
-                "Net U.S. incurrence of direct investment liabilities, asset/liability basis"
-            }
-            Self::DiInvLiabsNonSpe => {
-                "Net U.S. incurrence of direct investment liabilities, asset/liability basis; Non-SPEs"
-            }
-            Self::DiInvLiabsSpe => {
-                "Net U.S. incurrence of direct investment liabilities, asset/liability basis; SPEs"
-            }
-            Self::DiInvOutward => {
-                "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis"
-            }
-            Self::DiInvReinvestEarnAssets => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings"
-            }
-            Self::DiInvReinvestEarnAssetsNonSpe => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings; Non-SPEs"
-            }
-            Self::DiInvReinvestEarnAssetsSpe => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings; SPEs"
-            }
-            Self::DiInvReinvestEarnIncPay => {
-                "Direct investment income on liabilities; reinvested earnings"
-            }
-            Self::DiInvReinvestEarnIncPayNonSpe => {
-                "Direct investment income on liabilities; reinvested earnings; Non-SPEs"
-            }
-            Self::DiInvReinvestEarnIncPaySpe => {
-                "Direct investment income on liabilities; reinvested earnings; SPEs"
-            }
-            Self::DiInvReinvestEarnIncRec => {
-                "Direct investment income on assets; reinvested earnings"
-            }
-            Self::DiInvReinvestEarnIncRecNonSpe => {
-                "Direct investment income on assets; reinvested earnings; Non-SPEs"
-            }
-            Self::DiInvReinvestEarnIncRecSpe => {
-                "Direct investment income on assets; reinvested earnings; SPEs"
-            }
-            Self::DiInvReinvestEarnLiabs => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings"
-            }
-            Self::DiInvReinvestEarnLiabsNonSpe => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings; Non-SPEs"
-            }
-            Self::DiInvReinvestEarnLiabsSpe => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings; SPEs"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjAssets => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsFinAndIns => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsHoldExcBank => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; holding companies except bank holding companies"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsMnfctr => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; manufacturing"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsOthInd => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; other industries (those not listed under acquisition of assets in table 6.1)"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsWhlslTrd => {
-                "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; wholesale trade"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjIncPay => {
-                "Direct investment income on liabilities; reinvested earnings without current-cost adjustment"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjIncRec => {
-                "Direct investment income on assets; reinvested earnings without current-cost adjustment"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabs => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsFinAndIns => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsMnfctr => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; manufacturing"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsOthInd => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; other industries (those not listed under incurrence of liabilities in table 6.1)"
-            }
-            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsWhlslTrd => {
-                "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; wholesale trade"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInward => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardChem => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; chemicals"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardCompElecProd => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; computers and electronic products"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardDepIns => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; depository institutions "
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardElectrical => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; electrical equipment, appliances, and components"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardFinAndIns => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardFinExclDepInsAndIns => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardFood => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; food"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardInfo => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; information"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardMachinery => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; machinery"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardMnfctr => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardMnfctrOth => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; other manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardOthInd => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; other industries (those not listed under receipts in table 4.2)"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardOthIndExcl8DiInward => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; other industries (excluding 8 industry groups)"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardPrimFabMtls => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; primary and fabricated metals"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardProfSciAndTech => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; professional, scientific, and technical services"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardRealEstRentLeas => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; real estate and rental and leasing"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardRtlTrd => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; retail trade"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardTransEquip => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; transportation equipment"
-            }
-            Self::DiInvWithoutCurrCostAdjIncInwardWhlslTrd => {
-                "Direct investment income without current-cost adjustment on inward investment, directional basis; wholesale trade"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutward => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardChem => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; chemicals"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardCompElecProd => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; computers and electronic products"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardDepIns => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; depository institutions "
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardElectrical => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; electrical equipment, appliances, and components"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardFinAndIns => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardFinExclDepInsAndIns => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardFood => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; food"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardHoldExcBank => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; holding companies except bank holding companies"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardInfo => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; information"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardMachinery => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; machinery"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardMining => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; mining"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardMnfctr => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardMnfctrOth => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; other manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardOthInd => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; manufacturing; other industries (those not listed under receipts in table 4.2)"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardOthIndExcl8DiOutward => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; other industries (excluding 8 industry groups)"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardPrimFabMtls => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; primary and fabricated metals"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardProfSciAndTech => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; professional, scientific, and technical services"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardTransEquip => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; transportation equipment"
-            }
-            Self::DiInvWithoutCurrCostAdjIncOutwardWhlslTrd => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; wholesale trade"
-            }
-            Self::DiInvWithoutCurrCostAdjInward => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardChem => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; chemicals"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardCompElecProd => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; computers and electronic products"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardDepIns => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; depository institutions "
-            }
-            Self::DiInvWithoutCurrCostAdjInwardElectrical => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; electrical equipment, appliances, and components"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardFinAndIns => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardFinExclDepInsAndIns => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardFood => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; food"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardInfo => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; information"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardMachinery => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; machinery"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardMnfctr => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardMnfctrOth => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; other manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardOthInd => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; other industries (those not listed under incurrence of liabilities in table 6.1)"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardOthIndExcl8DiInward => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; other industries (excluding 8 industry groups)"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardPrimFabMtls => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; primary and fabricated metals"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardProfSciAndTech => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; professional, scientific, and technical services"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardRealEstRentLeas => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; real estate and rental and leasing"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardRtlTrd => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; retail trade"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardTransEquip => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; transportation equipment"
-            }
-            Self::DiInvWithoutCurrCostAdjInwardWhlslTrd => {
-                "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; wholesale trade"
-            }
-            Self::DiInvWithoutCurrCostAdjOutward => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardChem => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; chemicals"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardCompElecProd => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; computers and electronic products"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardDepIns => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; depository institutions "
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardElectrical => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; electrical equipment, appliances, and components"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardFinAndIns => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardFinExclDepInsAndIns => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardFood => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; food"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardHoldExcBank => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; holding companies except bank holding companies"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardInfo => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; information"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardMachinery => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; machinery"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardMining => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; mining"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardMnfctr => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardMnfctrOth => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; other manufacturing"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardOthInd => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; other industries (those not listed under acquisition of assets in table 6.1)"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardOthIndExcl8DiOutward => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; other industries (excluding 8 industry groups)"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardPrimFabMtls => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; primary and fabricated metals"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardProfSciAndTech => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; professional, scientific, and technical services"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardTransEquip => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; transportation equipment"
-            }
-            Self::DiInvWithoutCurrCostAdjOutwardWhlslTrd => {
-                "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; wholesale trade"
-            }
-            Self::EquityAndInvFundSharesAssets => {
-                "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesAssetsDepTaking => {
-                "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares; held by deposit-taking institutions except central bank"
-            }
-            Self::EquityAndInvFundSharesAssetsNonFin => {
-                "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares; held by nonfinancial institutions except general government"
-            }
-            Self::EquityAndInvFundSharesAssetsOthFin => {
-                "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares; held by non-deposit-taking financial institutions"
-            }
-            Self::EquityAndInvFundSharesIncPay => {
-                "Portfolio investment income payments; income on equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesIncPayDepTaking => {
-                "Portfolio investment income payments on equity and investment fund shares; deposit-taking institutions except central bank"
-            }
-            Self::EquityAndInvFundSharesIncPayNonFin => {
-                "Portfolio investment income payments on equity and investment fund shares; nonfinancial institutions except general government"
-            }
-            Self::EquityAndInvFundSharesIncPayOthFin => {
-                "Portfolio investment income payments on equity and investment fund shares; non-deposit-taking financial institutions"
-            }
-            Self::EquityAndInvFundSharesIncRec => {
-                "Portfolio investment income receipts; income on equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesIncRecDepTaking => {
-                "Portfolio investment income receipts on equity and investment fund shares; deposit-taking institutions except central bank"
-            }
-            Self::EquityAndInvFundSharesIncRecNonFin => {
-                "Portfolio investment income receipts on equity and investment fund shares; nonfinancial institutions except general government"
-            }
-            Self::EquityAndInvFundSharesIncRecOthFin => {
-                "Portfolio investment income receipts on equity and investment fund shares; non-deposit-taking financial institutions"
-            }
-            Self::EquityAndInvFundSharesLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesLiabsDepTaking => {
-                "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares; issued by deposit-taking institutions except central bank"
-            }
-            Self::EquityAndInvFundSharesLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesLiabsNonFin => {
-                "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares; issued by nonfinancial institutions except general government"
-            }
-            Self::EquityAndInvFundSharesLiabsOthFin => {
-                "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares; issued by non-deposit-taking financial institutions"
-            }
-            Self::EquityOthThanInvFundSharesAssets => {
-                "Net U.S. acquisition of portfolio investment assets; equity other than investment fund shares"
-            }
-            Self::EquityOthThanInvFundSharesIncPay => {
-                "Portfolio investment income payments; dividends on equity other than investment fund shares"
-            }
-            Self::EquityOthThanInvFundSharesIncRec => {
-                "Portfolio investment income receipts; dividends on equity other than investment fund shares"
-            }
-            Self::EquityOthThanInvFundSharesLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; equity other than investment fund shares"
-            }
-            Self::EquityOthThanInvFundSharesLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; equity excluding investment fund shares"
-            }
+            Self::DiInvIncRecNonSpe => "Direct investment income on assets, asset/liability basis; Non-SPEs",
+            Self::DiInvIncRecSpe => "Direct investment income on assets, asset/liability basis; SPEs",
+            Self::DiInvIntIncInward => "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments",
+            Self::DiInvIntIncInwardFinAndIns => "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; finance (including depository institutions) and insurance",
+            Self::DiInvIntIncInwardMnfctr => "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; manufacturing",
+            Self::DiInvIntIncInwardOthInd => "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; other industries (those not listed under receipts in table 4.2)",
+            Self::DiInvIntIncInwardWhlslTrd => "Direct investment income on inward investment (foreign direct investment in the United States), directional basis; interest, net payments; wholesale trade",
+            Self::DiInvIntIncOutward => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis; interest, net receipts",
+            Self::DiInvIntIncPay => "Direct investment income on liabilities, asset/liability basis; interest",
+            Self::DiInvIntIncPayNonSpe => "Direct investment income on liabilities, asset/liability basis; interest; Non-SPEs",
+            Self::DiInvIntIncPaySpe => "Direct investment income on liabilities, asset/liability basis; interest; SPEs",
+            Self::DiInvIntIncRec => "Direct investment income on assets, asset/liability basis; interest",
+            Self::DiInvIntIncRecNonSpe => "Direct investment income on assets, asset/liability basis; interest; Non-SPEs",
+            Self::DiInvIntIncRecSpe => "Direct investment income on assets, asset/liability basis; interest; SPEs",
+            Self::DiInvIntUsAffiliatesIncPay => "Direct investment income; U.S. affiliates' interest payments",
+            Self::DiInvIntUsAffiliatesIncPayNonSpe => "Direct investment income; U.S. affiliates' interest payments; Non-SPEs",
+            Self::DiInvIntUsAffiliatesIncPaySpe => "Direct investment income; U.S. affiliates' interest payments; SPEs",
+            Self::DiInvIntUsAffiliatesIncRec => "Direct investment income; U.S. affiliates' interest receipts",
+            Self::DiInvIntUsAffiliatesIncRecNonSpe => "Direct investment income; U.S. affiliates' receipts from their foreign parent groups; Non-SPEs",
+            Self::DiInvIntUsAffiliatesIncRecOfNonSpe => "Direct investment income; U.S. non-SPE affiliates' interest receipts from their foreign parent groups",
+            Self::DiInvIntUsAffiliatesIncRecOfSpe => "Direct investment income; U.S. non-SPE affiliates' interest receipts from their foreign parent groups",
+            Self::DiInvIntUsAffiliatesIncRecSpe => "Direct investment income; U.S. affiliates' receipts from their foreign parent groups; SPEs",
+            Self::DiInvIntUsParentsIncPay => "Direct investment income; U.S. parents' interest payments",
+            Self::DiInvIntUsParentsIncPayNonSpe => "Direct investment income; U.S. parents' payments to their foreign affiliates; Non-SPEs",
+            Self::DiInvIntUsParentsIncPaySpe => "Direct investment income; U.S. parents' payments to their foreign affiliates; SPEs",
+            Self::DiInvIntUsParentsIncPayToNonSpe => "Direct investment income; U.S. parents' interest payments to their foreign non-SPE affiliates",
+            Self::DiInvIntUsParentsIncPayToSpe => "Direct investment income; U.S. parents' interest payments to their foreign SPE affiliates",
+            Self::DiInvIntUsParentsIncRec => "Direct investment income; U.S. parents' interest receipts",
+            Self::DiInvIntUsParentsIncRecNonSpe => "Direct investment income; U.S. parents' interest receipts; Non-SPEs",
+            Self::DiInvIntUsParentsIncRecSpe => "Direct investment income; U.S. parents' interest receipts; SPEs",
+            Self::DiInvInwardDirectionalBasis => "Financial transactions for inward direct investment (foreign direct investment in the United States), directional basis",
+            Self::DiInvLiabs => "Net U.S. incurrence of direct investment liabilities, asset/liability basis",
+            Self::DiInvLiabsNonSpe => "Net U.S. incurrence of direct investment liabilities, asset/liability basis; Non-SPEs",
+            Self::DiInvLiabsSpe => "Net U.S. incurrence of direct investment liabilities, asset/liability basis; SPEs",
+            Self::DiInvOutward => "Financial transactions for outward direct investment (U.S. direct investment abroad), directional basis",
+            Self::DiInvReinvestEarnAssets => "Net U.S. acquisition of direct investment assets; reinvestment of earnings",
+            Self::DiInvReinvestEarnAssetsNonSpe => "Net U.S. acquisition of direct investment assets; reinvestment of earnings; Non-SPEs",
+            Self::DiInvReinvestEarnAssetsSpe => "Net U.S. acquisition of direct investment assets; reinvestment of earnings; SPEs",
+            Self::DiInvReinvestEarnIncPay => "Direct investment income on liabilities; reinvested earnings",
+            Self::DiInvReinvestEarnIncPayNonSpe => "Direct investment income on liabilities; reinvested earnings; Non-SPEs",
+            Self::DiInvReinvestEarnIncPaySpe => "Direct investment income on liabilities; reinvested earnings; SPEs",
+            Self::DiInvReinvestEarnIncRec => "Direct investment income on assets; reinvested earnings",
+            Self::DiInvReinvestEarnIncRecNonSpe => "Direct investment income on assets; reinvested earnings; Non-SPEs",
+            Self::DiInvReinvestEarnIncRecSpe => "Direct investment income on assets; reinvested earnings; SPEs",
+            Self::DiInvReinvestEarnLiabs => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings",
+            Self::DiInvReinvestEarnLiabsNonSpe => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings; Non-SPEs",
+            Self::DiInvReinvestEarnLiabsSpe => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings; SPEs",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjAssets => "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsFinAndIns => "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; finance (including depository institutions) and insurance",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsHoldExcBank => "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; holding companies except bank holding companies",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsMnfctr => "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; manufacturing",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsOthInd => "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; other industries (those not listed under acquisition of assets in table 6.1)",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjAssetsWhlslTrd => "Net U.S. acquisition of direct investment assets; reinvestment of earnings without current-cost adjustment; wholesale trade",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjIncPay => "Direct investment income on liabilities; reinvested earnings without current-cost adjustment",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjIncRec => "Direct investment income on assets; reinvested earnings without current-cost adjustment",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabs => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsFinAndIns => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; finance (including depository institutions) and insurance",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsMnfctr => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; manufacturing",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsOthInd => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; other industries (those not listed under incurrence of liabilities in table 6.1)",
+            Self::DiInvReinvestEarnWithoutCurrCostAdjLiabsWhlslTrd => "Net U.S. incurrence of direct investment liabilities; reinvestment of earnings without current-cost adjustment; wholesale trade",
+            Self::DiInvWithoutCurrCostAdjIncInward => "Direct investment income without current-cost adjustment on inward investment, directional basis",
+            Self::DiInvWithoutCurrCostAdjIncInwardChem => "Direct investment income without current-cost adjustment on inward investment, directional basis; chemicals",
+            Self::DiInvWithoutCurrCostAdjIncInwardCompElecProd => "Direct investment income without current-cost adjustment on inward investment, directional basis; computers and electronic products",
+            Self::DiInvWithoutCurrCostAdjIncInwardDepIns => "Direct investment income without current-cost adjustment on inward investment, directional basis; depository institutions",
+            Self::DiInvWithoutCurrCostAdjIncInwardElectrical => "Direct investment income without current-cost adjustment on inward investment, directional basis; electrical equipment, appliances, and components",
+            Self::DiInvWithoutCurrCostAdjIncInwardFinAndIns => "Direct investment income without current-cost adjustment on inward investment, directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjIncInwardFinExclDepInsAndIns => "Direct investment income without current-cost adjustment on inward investment, directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjIncInwardFood => "Direct investment income without current-cost adjustment on inward investment, directional basis; food",
+            Self::DiInvWithoutCurrCostAdjIncInwardInfo => "Direct investment income without current-cost adjustment on inward investment, directional basis; information",
+            Self::DiInvWithoutCurrCostAdjIncInwardMachinery => "Direct investment income without current-cost adjustment on inward investment, directional basis; machinery",
+            Self::DiInvWithoutCurrCostAdjIncInwardMnfctr => "Direct investment income without current-cost adjustment on inward investment, directional basis; manufacturing",
+            Self::DiInvWithoutCurrCostAdjIncInwardMnfctrOth => "Direct investment income without current-cost adjustment on inward investment, directional basis; other manufacturing",
+            Self::DiInvWithoutCurrCostAdjIncInwardOthInd => "Direct investment income without current-cost adjustment on inward investment, directional basis; other industries (those not listed under receipts in table 4.2)",
+            Self::DiInvWithoutCurrCostAdjIncInwardOthIndExcl8DiInward => "Direct investment income without current-cost adjustment on inward investment, directional basis; other industries (excluding 8 industry groups)",
+            Self::DiInvWithoutCurrCostAdjIncInwardPrimFabMtls => "Direct investment income without current-cost adjustment on inward investment, directional basis; primary and fabricated metals",
+            Self::DiInvWithoutCurrCostAdjIncInwardProfSciAndTech => "Direct investment income without current-cost adjustment on inward investment, directional basis; professional, scientific, and technical services",
+            Self::DiInvWithoutCurrCostAdjIncInwardRealEstRentLeas => "Direct investment income without current-cost adjustment on inward investment, directional basis; real estate and rental and leasing",
+            Self::DiInvWithoutCurrCostAdjIncInwardRtlTrd => "Direct investment income without current-cost adjustment on inward investment, directional basis; retail trade",
+            Self::DiInvWithoutCurrCostAdjIncInwardTransEquip => "Direct investment income without current-cost adjustment on inward investment, directional basis; transportation equipment",
+            Self::DiInvWithoutCurrCostAdjIncInwardWhlslTrd => "Direct investment income without current-cost adjustment on inward investment, directional basis; wholesale trade",
+            Self::DiInvWithoutCurrCostAdjIncOutward => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis",
+            Self::DiInvWithoutCurrCostAdjIncOutwardChem => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; chemicals",
+            Self::DiInvWithoutCurrCostAdjIncOutwardCompElecProd => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; computers and electronic products",
+            Self::DiInvWithoutCurrCostAdjIncOutwardDepIns => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; depository institutions",
+            Self::DiInvWithoutCurrCostAdjIncOutwardElectrical => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; electrical equipment, appliances, and components",
+            Self::DiInvWithoutCurrCostAdjIncOutwardFinAndIns => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjIncOutwardFinExclDepInsAndIns => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjIncOutwardFood => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; food",
+            Self::DiInvWithoutCurrCostAdjIncOutwardHoldExcBank => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; holding companies except bank holding companies",
+            Self::DiInvWithoutCurrCostAdjIncOutwardInfo => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; information",
+            Self::DiInvWithoutCurrCostAdjIncOutwardMachinery => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; machinery",
+            Self::DiInvWithoutCurrCostAdjIncOutwardMining => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; mining",
+            Self::DiInvWithoutCurrCostAdjIncOutwardMnfctr => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; manufacturing",
+            Self::DiInvWithoutCurrCostAdjIncOutwardMnfctrOth => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; other manufacturing",
+            Self::DiInvWithoutCurrCostAdjIncOutwardOthInd => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; manufacturing; other industries (those not listed under receipts in table 4.2)",
+            Self::DiInvWithoutCurrCostAdjIncOutwardOthIndExcl8DiOutward => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; other industries (excluding 8 industry groups)",
+            Self::DiInvWithoutCurrCostAdjIncOutwardPrimFabMtls => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; primary and fabricated metals",
+            Self::DiInvWithoutCurrCostAdjIncOutwardProfSciAndTech => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; professional, scientific, and technical services",
+            Self::DiInvWithoutCurrCostAdjIncOutwardTransEquip => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; transportation equipment",
+            Self::DiInvWithoutCurrCostAdjIncOutwardWhlslTrd => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis; wholesale trade",
+            Self::DiInvWithoutCurrCostAdjInward => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis",
+            Self::DiInvWithoutCurrCostAdjInwardChem => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; chemicals",
+            Self::DiInvWithoutCurrCostAdjInwardCompElecProd => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; computers and electronic products",
+            Self::DiInvWithoutCurrCostAdjInwardDepIns => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; depository institutions",
+            Self::DiInvWithoutCurrCostAdjInwardElectrical => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; electrical equipment, appliances, and components",
+            Self::DiInvWithoutCurrCostAdjInwardFinAndIns => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjInwardFinExclDepInsAndIns => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjInwardFood => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; food",
+            Self::DiInvWithoutCurrCostAdjInwardInfo => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; information",
+            Self::DiInvWithoutCurrCostAdjInwardMachinery => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; machinery",
+            Self::DiInvWithoutCurrCostAdjInwardMnfctr => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; manufacturing",
+            Self::DiInvWithoutCurrCostAdjInwardMnfctrOth => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; other manufacturing",
+            Self::DiInvWithoutCurrCostAdjInwardOthInd => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; other industries (those not listed under incurrence of liabilities in table 6.1)",
+            Self::DiInvWithoutCurrCostAdjInwardOthIndExcl8DiInward => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; other industries (excluding 8 industry groups)",
+            Self::DiInvWithoutCurrCostAdjInwardPrimFabMtls => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; primary and fabricated metals",
+            Self::DiInvWithoutCurrCostAdjInwardProfSciAndTech => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; professional, scientific, and technical services",
+            Self::DiInvWithoutCurrCostAdjInwardRealEstRentLeas => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; real estate and rental and leasing",
+            Self::DiInvWithoutCurrCostAdjInwardRtlTrd => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; retail trade",
+            Self::DiInvWithoutCurrCostAdjInwardTransEquip => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; transportation equipment",
+            Self::DiInvWithoutCurrCostAdjInwardWhlslTrd => "Financial transactions without current-cost adjustment for inward direct investment (foreign direct investment in the United States), directional basis; wholesale trade",
+            Self::DiInvWithoutCurrCostAdjOutward => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis",
+            Self::DiInvWithoutCurrCostAdjOutwardChem => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; chemicals",
+            Self::DiInvWithoutCurrCostAdjOutwardCompElecProd => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; computers and electronic products",
+            Self::DiInvWithoutCurrCostAdjOutwardDepIns => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; depository institutions",
+            Self::DiInvWithoutCurrCostAdjOutwardElectrical => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; electrical equipment, appliances, and components",
+            Self::DiInvWithoutCurrCostAdjOutwardFinAndIns => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjOutwardFinExclDepInsAndIns => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; finance (including depository institutions) and insurance",
+            Self::DiInvWithoutCurrCostAdjOutwardFood => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; food",
+            Self::DiInvWithoutCurrCostAdjOutwardHoldExcBank => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; holding companies except bank holding companies",
+            Self::DiInvWithoutCurrCostAdjOutwardInfo => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; information",
+            Self::DiInvWithoutCurrCostAdjOutwardMachinery => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; machinery",
+            Self::DiInvWithoutCurrCostAdjOutwardMining => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; mining",
+            Self::DiInvWithoutCurrCostAdjOutwardMnfctr => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; manufacturing",
+            Self::DiInvWithoutCurrCostAdjOutwardMnfctrOth => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; other manufacturing",
+            Self::DiInvWithoutCurrCostAdjOutwardOthInd => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; other industries (those not listed under acquisition of assets in table 6.1)",
+            Self::DiInvWithoutCurrCostAdjOutwardOthIndExcl8DiOutward => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; other industries (excluding 8 industry groups)",
+            Self::DiInvWithoutCurrCostAdjOutwardPrimFabMtls => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; primary and fabricated metals",
+            Self::DiInvWithoutCurrCostAdjOutwardProfSciAndTech => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; professional, scientific, and technical services",
+            Self::DiInvWithoutCurrCostAdjOutwardTransEquip => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; transportation equipment",
+            Self::DiInvWithoutCurrCostAdjOutwardWhlslTrd => "Financial transactions without current-cost adjustment for outward direct investment (U.S. direct investment abroad), directional basis; wholesale trade",
+            Self::EquityAndInvFundSharesAssets => "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares",
+            Self::EquityAndInvFundSharesAssetsDepTaking => "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares; held by deposit-taking institutions except central bank",
+            Self::EquityAndInvFundSharesAssetsNonFin => "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares; held by nonfinancial institutions except general government",
+            Self::EquityAndInvFundSharesAssetsOthFin => "Net U.S. acquisition of portfolio investment assets; equity and investment fund shares; held by non-deposit-taking financial institutions",
+            Self::EquityAndInvFundSharesIncPay => "Portfolio investment income payments; income on equity and investment fund shares",
+            Self::EquityAndInvFundSharesIncPayDepTaking => "Portfolio investment income payments on equity and investment fund shares; deposit-taking institutions except central bank",
+            Self::EquityAndInvFundSharesIncPayNonFin => "Portfolio investment income payments on equity and investment fund shares; nonfinancial institutions except general government",
+            Self::EquityAndInvFundSharesIncPayOthFin => "Portfolio investment income payments on equity and investment fund shares; non-deposit-taking financial institutions",
+            Self::EquityAndInvFundSharesIncRec => "Portfolio investment income receipts; income on equity and investment fund shares",
+            Self::EquityAndInvFundSharesIncRecDepTaking => "Portfolio investment income receipts on equity and investment fund shares; deposit-taking institutions except central bank",
+            Self::EquityAndInvFundSharesIncRecNonFin => "Portfolio investment income receipts on equity and investment fund shares; nonfinancial institutions except general government",
+            Self::EquityAndInvFundSharesIncRecOthFin => "Portfolio investment income receipts on equity and investment fund shares; non-deposit-taking financial institutions",
+            Self::EquityAndInvFundSharesLiabs => "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares",
+            Self::EquityAndInvFundSharesLiabsDepTaking => "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares; issued by deposit-taking institutions except central bank",
+            Self::EquityAndInvFundSharesLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; equity and investment fund shares",
+            Self::EquityAndInvFundSharesLiabsNonFin => "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares; issued by nonfinancial institutions except general government",
+            Self::EquityAndInvFundSharesLiabsOthFin => "Net U.S. incurrence of portfolio investment liabilities; equity and investment fund shares; issued by non-deposit-taking financial institutions",
+            Self::EquityOthThanInvFundSharesAssets => "Net U.S. acquisition of portfolio investment assets; equity other than investment fund shares",
+            Self::EquityOthThanInvFundSharesIncPay => "Portfolio investment income payments; dividends on equity other than investment fund shares",
+            Self::EquityOthThanInvFundSharesIncRec => "Portfolio investment income receipts; dividends on equity other than investment fund shares",
+            Self::EquityOthThanInvFundSharesLiabs => "Net U.S. incurrence of portfolio investment liabilities; equity other than investment fund shares",
+            Self::EquityOthThanInvFundSharesLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; equity excluding investment fund shares",
             Self::ExpGds => "Exports of goods",
-            Self::ExpGdsAgFoodsFeedsAndBevs => {
-                "Exports of agricultural foods, feeds, and beverages"
-            }
+            Self::ExpGdsAgFoodsFeedsAndBevs => "Exports of agricultural foods, feeds, and beverages",
             Self::ExpGdsAgIsm => "Exports of agricultural industrial supplies and materials",
             Self::ExpGdsAppFootAndHouse => "Exports of apparel, footwear, and household goods",
             Self::ExpGdsAutoEngAndEngParts => "Exports of automotive engines and engine parts",
-            Self::ExpGdsAutoVehPartsAndEngines => {
-                "Exports of automotive vehicles, parts, and engines"
-            }
+            Self::ExpGdsAutoVehPartsAndEngines => "Exports of automotive vehicles, parts, and engines",
             Self::ExpGdsBauxAndAlum => "Exports of bauxite and aluminum",
             Self::ExpGdsBopAdj => "Exports of goods; balance of payments adjustments, net",
             Self::ExpGdsBuildMatsExcMetals => "Exports of building materials except metals",
@@ -2688,44 +2128,28 @@ impl Indicator {
             Self::ExpGdsCivAirEngAndParts => "Exports of civilian aircraft, engines, and parts",
             Self::ExpGdsCoalAndRelProds => "Exports of coal and related products",
             Self::ExpGdsComp => "Exports of computers",
-            Self::ExpGdsCompAccPeriAndParts => {
-                "Exports of computer accessories, peripherals, and parts"
-            }
-            Self::ExpGdsConsGoodsExcFoodAndAuto => {
-                "Exports of consumer goods except food and automotive"
-            }
+            Self::ExpGdsCompAccPeriAndParts => "Exports of computer accessories, peripherals, and parts",
+            Self::ExpGdsConsGoodsExcFoodAndAuto => "Exports of consumer goods except food and automotive",
             Self::ExpGdsCopper => "Exports of copper",
             Self::ExpGdsCorn => "Exports of corn",
             Self::ExpGdsCrudePet => "Exports of crude petroleum",
-            Self::ExpGdsDistBevAndOthNonAgFoodsFeedsAndBevs => {
-                "Exports of distilled beverages and other nonagricultural foods, feeds, and beverages"
-            }
+            Self::ExpGdsDistBevAndOthNonAgFoodsFeedsAndBevs => "Exports of distilled beverages and other nonagricultural foods, feeds, and beverages",
             Self::ExpGdsDurCons => "Exports of durable consumer goods",
-            Self::ExpGdsElecGenMachElecAppAndParts => {
-                "Exports of electric-generating machinery, electric apparatus, and parts"
-            }
+            Self::ExpGdsElecGenMachElecAppAndParts => "Exports of electric-generating machinery, electric apparatus, and parts",
             Self::ExpGdsEnergyProd => "Exports of energy products",
-            Self::ExpGdsEngAndPartsForCivAir => {
-                "Exports of engines and parts for civilian aircraft"
-            }
+            Self::ExpGdsEngAndPartsForCivAir => "Exports of engines and parts for civilian aircraft",
             Self::ExpGdsFertPestAndInsect => "Exports of fertilizers, pesticides, and insecticides",
             Self::ExpGdsFishShellfish => "Exports of fish and shellfish",
             Self::ExpGdsFoodsFeedsAndBevs => "Exports of foods, feeds, and beverages",
             Self::ExpGdsFuelOil => "Exports of fuel oil",
-            Self::ExpGdsGdsProcPortsBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; goods procured in U.S. ports by foreign carriers"
-            }
+            Self::ExpGdsGdsProcPortsBopAdj => "Exports of goods; balance of payments adjustments, net; goods procured in U.S. ports by foreign carriers",
             Self::ExpGdsGemDiamAndOthGem => "Exports of gem diamonds and other gemstones",
             Self::ExpGdsGenMerch => "Exports of general merchandise",
             Self::ExpGdsGrainsPreps => "Exports of grains and preparations",
             Self::ExpGdsHidesSkins => "Exports of hides and skins, including furskins",
             Self::ExpGdsHouseAndKitchApp => "Exports of household and kitchen appliances",
-            Self::ExpGdsHouseFurnAndRelProds => {
-                "Exports of household furnishings and related products"
-            }
-            Self::ExpGdsHouseKitchAppAndOthHouse => {
-                "Exports of household and kitchen appliances and other household goods"
-            }
+            Self::ExpGdsHouseFurnAndRelProds => "Exports of household furnishings and related products",
+            Self::ExpGdsHouseKitchAppAndOthHouse => "Exports of household and kitchen appliances and other household goods",
             Self::ExpGdsIndEngPumpsComps => "Exports of industrial engines, pumps, and compressors",
             Self::ExpGdsIndInorgChems => "Exports of industrial inorganic chemicals",
             Self::ExpGdsIndOrgChems => "Exports of industrial organic chemicals",
@@ -2733,88 +2157,52 @@ impl Indicator {
             Self::ExpGdsIsm => "Exports of industrial supplies and materials",
             Self::ExpGdsJewelryAndCollect => "Exports of jewelry and collectibles",
             Self::ExpGdsLiqPetGases => "Exports of liquified petroleum gases",
-            Self::ExpGdsMachAndEquipExcCons => {
-                "Exports of machinery and equipment except consumer-type"
-            }
-            Self::ExpGdsMachToolsMetalworkMach => {
-                "Exports of machine tools and metalworking machinery"
-            }
-            Self::ExpGdsMeasTestControlInst => {
-                "Exports of measuring, testing, and control instruments"
-            }
+            Self::ExpGdsMachAndEquipExcCons => "Exports of machinery and equipment except consumer-type",
+            Self::ExpGdsMachToolsMetalworkMach => "Exports of machine tools and metalworking machinery",
+            Self::ExpGdsMeasTestControlInst => "Exports of measuring, testing, and control instruments",
             Self::ExpGdsMeatProdsPoultry => "Exports of meat products and poultry",
-            Self::ExpGdsMedDentAndPharm => {
-                "Exports of medicinal, dental, and pharmaceutical products"
-            }
-            Self::ExpGdsMerchantingBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; net exports of goods under merchanting"
-            }
+            Self::ExpGdsMedDentAndPharm => "Exports of medicinal, dental, and pharmaceutical products",
+            Self::ExpGdsMerchantingBopAdj => "Exports of goods; balance of payments adjustments, net; net exports of goods under merchanting",
             Self::ExpGdsMerchantingNet => "Net exports of goods under merchanting",
             Self::ExpGdsMetalsAndNonmetProds => "Exports of metals and nonmetallic products",
             Self::ExpGdsNaturalGas => "Exports of natural gas",
-            Self::ExpGdsNonAgFoodsFeedsAndBevs => {
-                "Exports of nonagricultural foods, feeds, and beverages"
-            }
+            Self::ExpGdsNonAgFoodsFeedsAndBevs => "Exports of nonagricultural foods, feeds, and beverages",
             Self::ExpGdsNonAgIsm => "Exports of nonagricultural industrial supplies and materials",
             Self::ExpGdsNondurCons => "Exports of nondurable consumer goods",
             Self::ExpGdsNonferrousMetals => "Exports of nonferrous metals",
             Self::ExpGdsNonmonetaryGold => "Exports of nonmonetary gold",
-            Self::ExpGdsNonmonGoldBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; nonmonetary gold"
-            }
+            Self::ExpGdsNonmonGoldBopAdj => "Exports of goods; balance of payments adjustments, net; nonmonetary gold",
             Self::ExpGdsNuclearFuelAndElecEnergy => "Exports of nuclear fuel and electric energy",
-            Self::ExpGdsOilDrillMiningConstMach => {
-                "Exports of oil-drilling, mining, and construction machinery"
-            }
-            Self::ExpGdsOthAgFoodsFeedsAndBevs => {
-                "Exports of other agricultural foods, feeds, and beverages"
-            }
+            Self::ExpGdsOilDrillMiningConstMach => "Exports of oil-drilling, mining, and construction machinery",
+            Self::ExpGdsOthAgFoodsFeedsAndBevs => "Exports of other agricultural foods, feeds, and beverages",
             Self::ExpGdsOthAgIsm => "Exports of other agricultural industrial supplies",
             Self::ExpGdsOthAutoPartsAndAcc => "Exports of other automotive parts and accessories",
-            Self::ExpGdsOthBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; other adjustments, net"
-            }
+            Self::ExpGdsOthBopAdj => "Exports of goods; balance of payments adjustments, net; other adjustments, net",
             Self::ExpGdsOthChems => "Exports of other chemicals",
             Self::ExpGdsOthDurCons => "Exports of other durable consumer goods",
             Self::ExpGdsOthFeeds => "Exports of other feeds",
             Self::ExpGdsOthGenMerch => "Exports of other general merchandise",
-            Self::ExpGdsOthHouseIncCellPhones => {
-                "Exports of other household goods, including cell phones"
-            }
+            Self::ExpGdsOthHouseIncCellPhones => "Exports of other household goods, including cell phones",
             Self::ExpGdsOthIndMach => "Exports of other industrial machinery",
-            Self::ExpGdsOthMetalsAndNonmetProds => {
-                "Exports of other metals and nonmetallic products"
-            }
+            Self::ExpGdsOthMetalsAndNonmetProds => "Exports of other metals and nonmetallic products",
             Self::ExpGdsOthNondurCons => "Exports of other nondurable consumer goods",
             Self::ExpGdsOthNonferrousMetals => "Exports of other nonferrous metals",
             Self::ExpGdsOthNonmetals => "Exports of other nonmetals",
             Self::ExpGdsOthOfficeAndBusMach => "Exports of other office and business machines",
             Self::ExpGdsOthPetProds => "Exports of other petroleum products",
-            Self::ExpGdsOthServIndAndAgMach => {
-                "Exports of other service-industry and agricultural machinery"
-            }
+            Self::ExpGdsOthServIndAndAgMach => "Exports of other service-industry and agricultural machinery",
             Self::ExpGdsOthTransEquip => "Exports of other transportation equipment",
             Self::ExpGdsPaperAndPaperBaseStocks => "Exports of paper and paper-base stocks",
             Self::ExpGdsPassCars => "Exports of passenger cars, new and used",
             Self::ExpGdsPetAndProds => "Exports of petroleum and products",
             Self::ExpGdsPlasticMaterials => "Exports of plastic materials",
-            Self::ExpGdsPrecMetalsExcNonmonGold => {
-                "Exports of precious metals except nonmonetary gold"
-            }
-            Self::ExpGdsPrivGiftParcelRemitBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; private gift parcel remittances"
-            }
-            Self::ExpGdsRadioAndStereoEquip => {
-                "Exports of radio and stereo equipment, including recorded media"
-            }
+            Self::ExpGdsPrecMetalsExcNonmonGold => "Exports of precious metals except nonmonetary gold",
+            Self::ExpGdsPrivGiftParcelRemitBopAdj => "Exports of goods; balance of payments adjustments, net; private gift parcel remittances",
+            Self::ExpGdsRadioAndStereoEquip => "Exports of radio and stereo equipment, including recorded media",
             Self::ExpGdsRawCotton => "Exports of raw cotton",
-            Self::ExpGdsRepairEquipBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; repair of equipment"
-            }
+            Self::ExpGdsRepairEquipBopAdj => "Exports of goods; balance of payments adjustments, net; repair of equipment",
             Self::ExpGdsRiceOthFoodGrains => "Exports of rice and other food grains",
-            Self::ExpGdsSciHospAndMedEquipAndParts => {
-                "Exports of scientific, hospital, and medical equipment and parts"
-            }
+            Self::ExpGdsSciHospAndMedEquipAndParts => "Exports of scientific, hospital, and medical equipment and parts",
             Self::ExpGdsSemiconductors => "Exports of semiconductors",
             Self::ExpGdsServ => "Exports of goods and services",
             Self::ExpGdsServIncRec => "Exports of goods and services and income receipts (credits)",
@@ -2824,91 +2212,51 @@ impl Indicator {
             Self::ExpGdsTextileSupAndRelMats => "Exports of textile supplies and related materials",
             Self::ExpGdsToilAndCosmet => "Exports of toiletries and cosmetics",
             Self::ExpGdsToysAndSport => "Exports of toys and sporting goods, including bicycles",
-            Self::ExpGdsTrucksBusesSpecPurpVeh => {
-                "Exports of trucks, buses, and special purpose vehicles"
-            }
-            Self::ExpGdsTvsVidRecAndOthVidEquip => {
-                "Exports of televisions, video receivers, and other video equipment"
-            }
+            Self::ExpGdsTrucksBusesSpecPurpVeh => "Exports of trucks, buses, and special purpose vehicles",
+            Self::ExpGdsTvsVidRecAndOthVidEquip => "Exports of televisions, video receivers, and other video equipment",
             Self::ExpGdsUnmanufTobacco => "Exports of unmanufactured tobacco",
-            Self::ExpGdsUsMilAgencyBopAdj => {
-                "Exports of goods; balance of payments adjustments, net; exports under U.S. military agency sales contracts"
-            }
+            Self::ExpGdsUsMilAgencyBopAdj => "Exports of goods; balance of payments adjustments, net; exports under U.S. military agency sales contracts",
             Self::ExpGdsVegFruitNutPreps => "Exports of vegetables, fruits, nuts, and preparations",
             Self::ExpGdsWheat => "Exports of wheat",
             Self::ExpGenMerch => "Exports of general merchandise",
             Self::ExpServ => "Exports of services",
             Self::ExpServArtisticRelated => "Exports of artistic related services",
             Self::ExpServAudVis => "Exports of audiovisual services",
-            Self::ExpServChargesForTheUseOfIpNie => {
-                "Charges for the use of intellectual property n.i.e.; exports"
-            }
-            Self::ExpServCipLicensesAudVis => {
-                "Charges for the use of intellectual property n.i.e.; exports; licenses to reproduce and/or distribute audiovisual products"
-            }
-            Self::ExpServCipLicensesCompSoftware => {
-                "Charges for the use of intellectual property n.i.e.; exports; licenses to reproduce and/or distribute computer software"
-            }
-            Self::ExpServCipLicensesFranchisesTrademarks => {
-                "Charges for the use of intellectual property n.i.e.; exports; franchises and trademarks licensing fees"
-            }
-            Self::ExpServCipLicensesOutcomesResearchAndDev => {
-                "Charges for the use of intellectual property n.i.e.; exports; licenses for the use of outcomes of research and development"
-            }
+            Self::ExpServChargesForTheUseOfIpNie => "Charges for the use of intellectual property n.i.e.; exports",
+            Self::ExpServCipLicensesAudVis => "Charges for the use of intellectual property n.i.e.; exports; licenses to reproduce and/or distribute audiovisual products",
+            Self::ExpServCipLicensesCompSoftware => "Charges for the use of intellectual property n.i.e.; exports; licenses to reproduce and/or distribute computer software",
+            Self::ExpServCipLicensesFranchisesTrademarks => "Charges for the use of intellectual property n.i.e.; exports; franchises and trademarks licensing fees",
+            Self::ExpServCipLicensesOutcomesResearchAndDev => "Charges for the use of intellectual property n.i.e.; exports; licenses for the use of outcomes of research and development",
             Self::ExpServComp => "Exports of computer services",
             Self::ExpServConst => "Exports of construction services",
             Self::ExpServConstAbroad => "Construction abroad",
             Self::ExpServConstExpend => "Foreign contractors' expenditures in the United States",
             Self::ExpServConstruction => "Exports of construction services",
             Self::ExpServFinancial => "Exports of financial services",
-            Self::ExpServFinancialExplicitAndOth => {
-                "Exports of explicitly charged and other financial services"
-            }
+            Self::ExpServFinancialExplicitAndOth => "Exports of explicitly charged and other financial services",
             Self::ExpServFinBrokMarketMak => "Exports of brokerage and market-making services",
-            Self::ExpServFinCredCardOthCredRelated => {
-                "Exports of credit card and other credit-related services"
-            }
+            Self::ExpServFinCredCardOthCredRelated => "Exports of credit card and other credit-related services",
             Self::ExpServFinFinAdvCust => "Exports of financial advisory and custody services",
             Self::ExpServFinFinMan => "Exports of financial management services",
-            Self::ExpServFinSecLendEftOth => {
-                "Exports of securities lending, electronic funds transfer, and other services"
-            }
+            Self::ExpServFinSecLendEftOth => "Exports of securities lending, electronic funds transfer, and other services",
             Self::ExpServFinUwPrivPlace => "Exports of underwriting and private placement services",
-            Self::ExpServFisim => {
-                "Exports of financial intermediation services indirectly measured"
-            }
-            Self::ExpServGovtGoodsAndServicesNie => {
-                "Exports of government goods and services n.i.e."
-            }
+            Self::ExpServFisim => "Exports of financial intermediation services indirectly measured",
+            Self::ExpServGovtGoodsAndServicesNie => "Exports of government goods and services n.i.e.",
             Self::ExpServInfo => "Exports of information services",
             Self::ExpServInsurance => "Exports of insurance services",
             Self::ExpServInsuranceAuxIns => "Exports of auxiliary insurance services",
             Self::ExpServInsuranceDirect => "Exports of direct insurance services",
             Self::ExpServInsuranceReins => "Exports of reinsurance services",
-            Self::ExpServMaintenanceAndRepairNie => {
-                "Exports of maintenance and repair services n.i.e."
-            }
-            Self::ExpServManufacturing => {
-                "Exports of manufacturing services on physical inputs owned by others"
-            }
+            Self::ExpServMaintenanceAndRepairNie => "Exports of maintenance and repair services n.i.e.",
+            Self::ExpServManufacturing => "Exports of manufacturing services on physical inputs owned by others",
             Self::ExpServOtherBusiness => "Exports of other business services",
-            Self::ExpServPersCultAndRec => {
-                "Exports of personal, cultural, and recreational services"
-            }
-            Self::ExpServPersCultAndRecOth => {
-                "Exports of other personal, cultural, and recreational services"
-            }
-            Self::ExpServProfMgmtConsult => {
-                "Exports of professional and management consulting services"
-            }
+            Self::ExpServPersCultAndRec => "Exports of personal, cultural, and recreational services",
+            Self::ExpServPersCultAndRecOth => "Exports of other personal, cultural, and recreational services",
+            Self::ExpServProfMgmtConsult => "Exports of professional and management consulting services",
             Self::ExpServResearchAndDev => "exports of research and development services",
-            Self::ExpServTechTradeRelatedOth => {
-                "exports of technical, trade-related, and other business services"
-            }
+            Self::ExpServTechTradeRelatedOth => "exports of technical, trade-related, and other business services",
             Self::ExpServTelecom => "exports of telecommunications services",
-            Self::ExpServTelecomCompAndInfo => {
-                "Exports of telecommunications, computer, and information services"
-            }
+            Self::ExpServTelecomCompAndInfo => "Exports of telecommunications, computer, and information services",
             Self::ExpServTransport => "Exports of transport services",
             Self::ExpServTransportAir => "Exports of air transport services",
             Self::ExpServTransportAirFreight => "Exports of air freight services",
@@ -2918,43 +2266,27 @@ impl Indicator {
             Self::ExpServTransportSea => "Exports of sea transport services",
             Self::ExpServTransportSeaFreight => "Exports of sea freight services",
             Self::ExpServTransportSeaPort => "Exports of sea port services",
-            Self::ExpServTravel => {
-                "Exports of travel services (for all purposes including education)"
-            }
+            Self::ExpServTravel => "Exports of travel services (for all purposes including education)",
             Self::ExpServTravelBusiness => "Exports of business travel services",
             Self::ExpServTravelBusinessOth => "Exports of other business travel services",
             Self::ExpServTravelEducation => "Exports of education-related services",
             Self::ExpServTravelHealth => "Exports of health-related services",
             Self::ExpServTravelPersonal => "Exports of personal travel services",
             Self::ExpServTravelPersonalOth => "Exports of other personal travel services",
-            Self::ExpServTravelShortTermWork => {
-                "Expenditures in the U.S. by border, seasonal, and other short-term workers"
-            }
-            Self::FinAssetsExclFinDeriv => {
-                "Net U.S. acquisition of financial assets excluding financial derivatives"
-            }
+            Self::ExpServTravelShortTermWork => "Expenditures in the U.S. by border, seasonal, and other short-term workers",
+            Self::FinAssetsExclFinDeriv => "Net U.S. acquisition of financial assets excluding financial derivatives",
             Self::FinDeriv => "Financial derivatives other than reserves, net transactions",
-            Self::FinDerivReserveAssets => {
-                "Net U.S. acquisition of reserve assets; other; financial derivatives"
-            }
-            Self::FinLiabsExclFinDeriv => {
-                "Net U.S. incurrence of liabilities excluding financial derivatives"
-            }
+            Self::FinDerivReserveAssets => "Net U.S. acquisition of reserve assets; other; financial derivatives",
+            Self::FinLiabsExclFinDeriv => "Net U.S. incurrence of liabilities excluding financial derivatives",
             Self::FinLiabsFoa => "Net U.S. incurrence of liabilities to foreign official agencies",
             Self::GoldReserveAssets => "Net U.S. acquisition of reserve assets; monetary gold",
-            Self::ImfReserveAssets => {
-                "Net U.S. acquisition of reserve assets; reserve position in the International Monetary Fund"
-            }
+            Self::ImfReserveAssets => "Net U.S. acquisition of reserve assets; reserve position in the International Monetary Fund",
             Self::ImpGds => "Imports of goods",
-            Self::ImpGdsAgFoodsFeedsAndBevs => {
-                "Imports of agricultural foods, feeds, and beverages"
-            }
+            Self::ImpGdsAgFoodsFeedsAndBevs => "Imports of agricultural foods, feeds, and beverages",
             Self::ImpGdsAgIsm => "Imports of agricultural industrial supplies and materials",
             Self::ImpGdsAppFootAndHouse => "Imports of apparel, footwear, and household goods",
             Self::ImpGdsAutoEngAndEngParts => "Imports of automotive engines and engine parts",
-            Self::ImpGdsAutoVehPartsAndEngines => {
-                "Imports of automotive vehicles, parts, and engines"
-            }
+            Self::ImpGdsAutoVehPartsAndEngines => "Imports of automotive vehicles, parts, and engines",
             Self::ImpGdsBauxAndAlum => "Imports of bauxite and aluminum",
             Self::ImpGdsBopAdj => "Imports of goods; balance of payments adjustments, net",
             Self::ImpGdsBuildMatsExcMetals => "Imports of building materials except metals",
@@ -2966,219 +2298,125 @@ impl Indicator {
             Self::ImpGdsCoalAndRelProds => "Imports of coal and related products",
             Self::ImpGdsCocoaAndSugar => "Imports of cocoa beans and sugar",
             Self::ImpGdsComp => "Imports of computers",
-            Self::ImpGdsCompAccPeriAndParts => {
-                "Imports of computer accessories, peripherals, and parts"
-            }
-            Self::ImpGdsConsGoodsExcFoodAndAuto => {
-                "Imports of consumer goods except food and automotive"
-            }
+            Self::ImpGdsCompAccPeriAndParts => "Imports of computer accessories, peripherals, and parts",
+            Self::ImpGdsConsGoodsExcFoodAndAuto => "Imports of consumer goods except food and automotive",
             Self::ImpGdsCrudePet => "Imports of crude petroleum",
-            Self::ImpGdsDistBevAndOthNonAgFoodsFeedsAndBevs => {
-                "Imports of distilled beverages and other nonagricultural foods, feeds, and beverages"
-            }
+            Self::ImpGdsDistBevAndOthNonAgFoodsFeedsAndBevs => "Imports of distilled beverages and other nonagricultural foods, feeds, and beverages",
             Self::ImpGdsDurCons => "Imports of durable consumer goods",
-            Self::ImpGdsElecGenMachElecAppAndParts => {
-                "Imports of electric-generating machinery, electric apparatus and parts"
-            }
+            Self::ImpGdsElecGenMachElecAppAndParts => "Imports of electric-generating machinery, electric apparatus and parts",
             Self::ImpGdsEnergyProds => "Imports of energy products",
-            Self::ImpGdsEngAndPartsForCivAir => {
-                "Imports of engines and parts for civilian aircraft"
-            }
+            Self::ImpGdsEngAndPartsForCivAir => "Imports of engines and parts for civilian aircraft",
             Self::ImpGdsFertPestAndInsect => "Imports of fertilizers, pesticides, and insecticides",
             Self::ImpGdsFishShellfish => "Imports of fish and shellfish",
             Self::ImpGdsFoodsFeedsAndBevs => "Imports of foods, feeds, and beverages",
             Self::ImpGdsFuelOil => "Imports of fuel oil",
-            Self::ImpGdsGdsProcPortsBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; goods procured in foreign ports by U.S. carriers"
-            }
+            Self::ImpGdsGdsProcPortsBopAdj => "Imports of goods; balance of payments adjustments, net; goods procured in foreign ports by U.S. carriers",
             Self::ImpGdsGemDiamAndOthGem => "Imports of gem diamonds and other gemstones",
             Self::ImpGdsGenMerch => "Imports of general merchandise",
             Self::ImpGdsGreenCoffee => "Imports of green coffee",
             Self::ImpGdsHouseAndKitchApp => "Imports of household and kitchen appliances",
-            Self::ImpGdsHouseFurnAndRelProds => {
-                "Imports of household furnishings and related products"
-            }
-            Self::ImpGdsHouseKitchAppAndOthHouse => {
-                "Imports of household and kitchen appliances and other household goods"
-            }
+            Self::ImpGdsHouseFurnAndRelProds => "Imports of household furnishings and related products",
+            Self::ImpGdsHouseKitchAppAndOthHouse => "Imports of household and kitchen appliances and other household goods",
             Self::ImpGdsIndEngPumpsComps => "Imports of industrial engines, pumps, and compressors",
             Self::ImpGdsIndInorgChems => "Imports of industrial inorganic chemicals",
             Self::ImpGdsIndOrgChems => "Imports of industrial organic chemicals",
-            Self::ImpGdsInlandFreightCanMexBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; inland freight in Canada and Mexico"
-            }
+            Self::ImpGdsInlandFreightCanMexBopAdj => "Imports of goods; balance of payments adjustments, net; inland freight in Canada and Mexico",
             Self::ImpGdsIronAndSteelProds => "Imports of iron and steel products",
             Self::ImpGdsIsm => "Imports of industrial supplies and materials",
             Self::ImpGdsJewelryAndCollect => "Imports of jewelry and collectibles",
             Self::ImpGdsLiqPetGases => "Imports of liquified petroleum gases",
-            Self::ImpGdsLocoRailBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; locomotives and railcars"
-            }
-            Self::ImpGdsMachAndEquipExcCons => {
-                "Imports of machinery and equipment except consumer-type"
-            }
-            Self::ImpGdsMachToolsMetalworkMach => {
-                "Imports of machine tools and metalworking machinery"
-            }
-            Self::ImpGdsMeasTestControlInst => {
-                "Imports of measuring, testing, and control instruments"
-            }
+            Self::ImpGdsLocoRailBopAdj => "Imports of goods; balance of payments adjustments, net; locomotives and railcars",
+            Self::ImpGdsMachAndEquipExcCons => "Imports of machinery and equipment except consumer-type",
+            Self::ImpGdsMachToolsMetalworkMach => "Imports of machine tools and metalworking machinery",
+            Self::ImpGdsMeasTestControlInst => "Imports of measuring, testing, and control instruments",
             Self::ImpGdsMeatProdsPoultry => "Imports of meat products and poultry",
-            Self::ImpGdsMedDentAndPharm => {
-                "Imports of medicinal, dental, and pharmaceutical products"
-            }
+            Self::ImpGdsMedDentAndPharm => "Imports of medicinal, dental, and pharmaceutical products",
             Self::ImpGdsMetalsAndNonmetProds => "Imports of metals and nonmetallic products",
             Self::ImpGdsNaturalGas => "Imports of natural gas",
-            Self::ImpGdsNonAgFoodsFeedsAndBevs => {
-                "Imports of nonagricultural foods, feeds, and beverages"
-            }
+            Self::ImpGdsNonAgFoodsFeedsAndBevs => "Imports of nonagricultural foods, feeds, and beverages",
             Self::ImpGdsNonAgIsm => "Imports of nonagricultural industrial supplies and materials",
             Self::ImpGdsNondurCons => "Imports of nondurable consumer goods",
             Self::ImpGdsNonferrousMetals => "Imports of nonferrous metals",
             Self::ImpGdsNonmonetaryGold => "Imports of nonmonetary gold",
-            Self::ImpGdsNonmonGoldBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; nonmonetary gold"
-            }
+            Self::ImpGdsNonmonGoldBopAdj => "Imports of goods; balance of payments adjustments, net; nonmonetary gold",
             Self::ImpGdsNuclearFuelAndElecEnergy => "Imports of nuclear fuel and electric energy",
-            Self::ImpGdsOilDrillMiningConstMach => {
-                "Imports of oil-drilling, mining, and construction machinery"
-            }
-            Self::ImpGdsOthAgFoodsFeedsAndBevs => {
-                "Imports of other agricultural foods, feeds, and beverages"
-            }
+            Self::ImpGdsOilDrillMiningConstMach => "Imports of oil-drilling, mining, and construction machinery",
+            Self::ImpGdsOthAgFoodsFeedsAndBevs => "Imports of other agricultural foods, feeds, and beverages",
             Self::ImpGdsOthAutoPartsAndAcc => "Imports of other automotive parts and accessories",
-            Self::ImpGdsOthBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; other adjustments, net"
-            }
+            Self::ImpGdsOthBopAdj => "Imports of goods; balance of payments adjustments, net; other adjustments, net",
             Self::ImpGdsOthChems => "Imports of other chemicals",
             Self::ImpGdsOthDurCons => "Imports of other durable consumer goods",
             Self::ImpGdsOthGenMerch => "Imports of other general merchandise",
-            Self::ImpGdsOthHouseIncCellPhones => {
-                "Imports of other household goods, including cell phones"
-            }
+            Self::ImpGdsOthHouseIncCellPhones => "Imports of other household goods, including cell phones",
             Self::ImpGdsOthIndMach => "Imports of other industrial machinery",
-            Self::ImpGdsOthMetalsAndNonmetProds => {
-                "Imports of other metals and nonmetallic products"
-            }
+            Self::ImpGdsOthMetalsAndNonmetProds => "Imports of other metals and nonmetallic products",
             Self::ImpGdsOthNondurCons => "Imports of other nondurable consumer goods",
             Self::ImpGdsOthNonferrousMetals => "Imports of other nonferrous metals",
             Self::ImpGdsOthNonmetals => "Imports of other nonmetals",
             Self::ImpGdsOthOfficeAndBusMach => "Imports of other office and business machines",
             Self::ImpGdsOthPetProds => "Imports of other petroleum products",
-            Self::ImpGdsOthServIndAndAgMach => {
-                "Imports of other service-industry and agricultural machinery"
-            }
+            Self::ImpGdsOthServIndAndAgMach => "Imports of other service-industry and agricultural machinery",
             Self::ImpGdsOthTransEquip => "Imports of other transportation equipment",
             Self::ImpGdsPaperAndPaperBaseStocks => "Imports of paper and paper-base stocks",
             Self::ImpGdsPassCars => "Imports of passenger cars, new and used",
             Self::ImpGdsPetAndProds => "Imports of petroleum and products",
             Self::ImpGdsPlasticMaterials => "Imports of plastic materials",
-            Self::ImpGdsPrecMetalsExcNonmonGold => {
-                "Imports of precious metals except nonmonetary gold"
-            }
-            Self::ImpGdsRadioAndStereoEquip => {
-                "Imports of radio and stereo equipment, including recorded media"
-            }
-            Self::ImpGdsRepairEquipBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; repair of equipment"
-            }
-            Self::ImpGdsSciHospAndMedEquipAndParts => {
-                "Imports of scientific, hospital, and medical equipment and parts"
-            }
+            Self::ImpGdsPrecMetalsExcNonmonGold => "Imports of precious metals except nonmonetary gold",
+            Self::ImpGdsRadioAndStereoEquip => "Imports of radio and stereo equipment, including recorded media",
+            Self::ImpGdsRepairEquipBopAdj => "Imports of goods; balance of payments adjustments, net; repair of equipment",
+            Self::ImpGdsSciHospAndMedEquipAndParts => "Imports of scientific, hospital, and medical equipment and parts",
             Self::ImpGdsSemiconductors => "Imports of semiconductors",
             Self::ImpGdsServ => "Imports of goods and services",
             Self::ImpGdsServIncPay => "Imports of goods and services and income payments (debits)",
-            Self::ImpGdsSoftRevalBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; software revaluation"
-            }
+            Self::ImpGdsSoftRevalBopAdj => "Imports of goods; balance of payments adjustments, net; software revaluation",
             Self::ImpGdsSteelmakingMats => "Imports of steelmaking materials",
             Self::ImpGdsTelecomEquip => "Imports of telecommunications equipment",
             Self::ImpGdsTextileSupAndRelMats => "Imports of textile supplies and related materials",
             Self::ImpGdsToilAndCosmet => "Imports of toiletries and cosmetics",
             Self::ImpGdsToysAndSport => "Imports of toys and sporting goods, including bicycles",
-            Self::ImpGdsTrucksBusesSpecPurpVeh => {
-                "Imports of trucks, buses, and special purpose vehicles"
-            }
-            Self::ImpGdsTvsVidRecAndOthVidEquip => {
-                "Imports of televisions, video receivers, and other video equipment"
-            }
-            Self::ImpGdsUsMilAgencyBopAdj => {
-                "Imports of goods; balance of payments adjustments, net; imports by U.S. military agencies"
-            }
+            Self::ImpGdsTrucksBusesSpecPurpVeh => "Imports of trucks, buses, and special purpose vehicles",
+            Self::ImpGdsTvsVidRecAndOthVidEquip => "Imports of televisions, video receivers, and other video equipment",
+            Self::ImpGdsUsMilAgencyBopAdj => "Imports of goods; balance of payments adjustments, net; imports by U.S. military agencies",
             Self::ImpGdsVegFruitNutPreps => "Imports of vegetables, fruits, nuts, and preparations",
             Self::ImpGdsWineBeerRelProds => "Imports of wine, beer, and related products",
             Self::ImpServ => "Imports of services",
             Self::ImpServArtisticRelated => "Imports of artistic related services",
             Self::ImpServAudVis => "Imports of audiovisual services",
-            Self::ImpServChargesForTheUseOfIpNie => {
-                "Charges for the use of intellectual property n.i.e.; imports"
-            }
-            Self::ImpServCipLicensesAudVis => {
-                "Charges for the use of intellectual property n.i.e.; imports; licenses to reproduce and/or distribute audiovisual products"
-            }
-            Self::ImpServCipLicensesCompSoftware => {
-                "Charges for the use of intellectual property n.i.e.; imports; licenses to reproduce and/or distribute computer software"
-            }
-            Self::ImpServCipLicensesFranchisesTrademarks => {
-                "Charges for the use of intellectual property n.i.e.; imports; franchises and trademarks licensing fees"
-            }
-            Self::ImpServCipLicensesOutcomesResearchAndDev => {
-                "Charges for the use of intellectual property n.i.e.; imports; licenses for the use of outcomes of research and development"
-            }
+            Self::ImpServChargesForTheUseOfIpNie => "Charges for the use of intellectual property n.i.e.; imports",
+            Self::ImpServCipLicensesAudVis => "Charges for the use of intellectual property n.i.e.; imports; licenses to reproduce and/or distribute audiovisual products",
+            Self::ImpServCipLicensesCompSoftware => "Charges for the use of intellectual property n.i.e.; imports; licenses to reproduce and/or distribute computer software",
+            Self::ImpServCipLicensesFranchisesTrademarks => "Charges for the use of intellectual property n.i.e.; imports; franchises and trademarks licensing fees",
+            Self::ImpServCipLicensesOutcomesResearchAndDev => "Charges for the use of intellectual property n.i.e.; imports; licenses for the use of outcomes of research and development",
             Self::ImpServComp => "Imports of computer services",
             Self::ImpServConst => "Imports of construction services",
             Self::ImpServConstExpend => "U.S. contractors' expenditures abroad",
             Self::ImpServConstInTheUs => "Construction in the United States",
             Self::ImpServConstruction => "Imports of construction services",
             Self::ImpServFinancial => "Imports of financial services",
-            Self::ImpServFinancialExplicitAndOth => {
-                "Imports of explicitly charged and other financial services"
-            }
+            Self::ImpServFinancialExplicitAndOth => "Imports of explicitly charged and other financial services",
             Self::ImpServFinBrokMarketMak => "Imports of brokerage and market-making services",
-            Self::ImpServFinCredCardOthCredRelated => {
-                "Imports of credit card and other credit-related services"
-            }
+            Self::ImpServFinCredCardOthCredRelated => "Imports of credit card and other credit-related services",
             Self::ImpServFinFinAdvCust => "Imports of financial advisory and custody services",
             Self::ImpServFinFinMan => "Imports of financial management services",
-            Self::ImpServFinSecLendEftOth => {
-                "Imports of securities lending, electronic funds transfer, and other services"
-            }
+            Self::ImpServFinSecLendEftOth => "Imports of securities lending, electronic funds transfer, and other services",
             Self::ImpServFinUwPrivPlace => "Imports of underwriting and private placement services",
-            Self::ImpServFisim => {
-                "Imports of financial intermediation services indirectly measured"
-            }
-            Self::ImpServGovtGoodsAndServicesNie => {
-                "Imports of government goods and services n.i.e."
-            }
+            Self::ImpServFisim => "Imports of financial intermediation services indirectly measured",
+            Self::ImpServGovtGoodsAndServicesNie => "Imports of government goods and services n.i.e.",
             Self::ImpServInfo => "Imports of information services",
             Self::ImpServInsurance => "Imports of insurance services",
             Self::ImpServInsuranceAuxIns => "Imports of auxiliary insurance services",
             Self::ImpServInsuranceDirect => "Imports of direct insurance services",
             Self::ImpServInsuranceReIns => "Imports of reinsurance services",
-            Self::ImpServMaintenanceAndRepairNie => {
-                "Imports of maintenance and repair services n.i.e."
-            }
-            Self::ImpServManufacturing => {
-                "Imports of manufacturing services on physical inputs owned by others"
-            }
+            Self::ImpServMaintenanceAndRepairNie => "Imports of maintenance and repair services n.i.e.",
+            Self::ImpServManufacturing => "Imports of manufacturing services on physical inputs owned by others",
             Self::ImpServOtherBusiness => "Imports of other business services",
-            Self::ImpServPersCultAndRec => {
-                "Imports of personal, cultural, and recreational services"
-            }
-            Self::ImpServPersCultAndRecOth => {
-                "Imports of other personal, cultural, and recreational services"
-            }
-            Self::ImpServProfMgmtConsult => {
-                "Imports of professional and management consulting services"
-            }
+            Self::ImpServPersCultAndRec => "Imports of personal, cultural, and recreational services",
+            Self::ImpServPersCultAndRecOth => "Imports of other personal, cultural, and recreational services",
+            Self::ImpServProfMgmtConsult => "Imports of professional and management consulting services",
             Self::ImpServResearchAndDev => "Research and development services imports",
-            Self::ImpServTechTradeRelatedOth => {
-                "Other technical, trade-related, and other business services imports"
-            }
+            Self::ImpServTechTradeRelatedOth => "Other technical, trade-related, and other business services imports",
             Self::ImpServTelecom => "Telecommunications services imports",
-            Self::ImpServTelecomCompAndInfo => {
-                "Telecommunications, computer, and information services imports"
-            }
+            Self::ImpServTelecomCompAndInfo => "Telecommunications, computer, and information services imports",
             Self::ImpServTransport => "Transport services imports",
             Self::ImpServTransportAir => "Air transport services imports",
             Self::ImpServTransportAirFreight => "Air freight transport services imports",
@@ -3201,13 +2439,9 @@ impl Indicator {
             Self::InsPremiumsPaid => "Insurance premiums paid",
             Self::InsPremiumsReceived => "Insurance premiums received",
             Self::InsTechReservesAssets => "Insurance technical reserves; assets",
-            Self::InsTechReservesAssetsOthFinNonFin => {
-                "Insurance technical reserves; assets; other financial and nonfinancial institutions"
-            }
+            Self::InsTechReservesAssetsOthFinNonFin => "Insurance technical reserves; assets; other financial and nonfinancial institutions",
             Self::InsTechReservesLiabs => "Insurance technical reserves; liabilities",
-            Self::InsTechReservesLiabsOthFinNonFin => {
-                "Insurance technical reserves; liabilities; other financial and nonfinancial institutions"
-            }
+            Self::InsTechReservesLiabsOthFinNonFin => "Insurance technical reserves; liabilities; other financial and nonfinancial institutions",
             Self::InvFundSharesAssets => "Investment fund shares; assets",
             Self::InvFundSharesIncPay => "Investment fund shares; income payments",
             Self::InvFundSharesIncRec => "Investment fund shares; income receipts",
@@ -3216,580 +2450,238 @@ impl Indicator {
             Self::InvIncPay => "Investment income payments",
             Self::InvIncRec => "Investment income receipts",
             Self::InvRaIncRec => "Investment income receipts; reinvested earnings",
-            Self::InvRaIntIncRec => {
-                "Investment income receipts; reinvested earnings; interest income"
-            }
+            Self::InvRaIntIncRec => "Investment income receipts; reinvested earnings; interest income",
             Self::LoansAssets => "Loans; assets",
             Self::LoansAssetsDepTaking => "Loans; assets; deposit-taking institutions",
             Self::LoansAssetsGenGovt => "Loans; assets; general government",
-            Self::LoansAssetsOthFinNonFin => {
-                "Loans; assets; other financial and nonfinancial institutions"
-            }
+            Self::LoansAssetsOthFinNonFin => "Loans; assets; other financial and nonfinancial institutions",
             Self::LoansLiabs => "Loans; liabilities",
             Self::LoansLiabsDepTaking => "Loans; liabilities; deposit-taking institutions",
             Self::LoansLiabsFoa => "Loans; liabilities; financial account",
-            Self::LoansLiabsOthFinNonFin => {
-                "Loans; liabilities; other financial and nonfinancial institutions"
-            }
-            Self::LoansRepurchaseLiabsOthFinNonFin => {
-                "Loans; repurchase liabilities; other financial and nonfinancial institutions"
-            }
-            Self::LoansResaleAssetsOthFinNonFin => {
-                "Loans; resale assets; other financial and nonfinancial institutions"
-            }
+            Self::LoansLiabsOthFinNonFin => "Loans; liabilities; other financial and nonfinancial institutions",
+            Self::LoansRepurchaseLiabsOthFinNonFin => "Loans; repurchase liabilities; other financial and nonfinancial institutions",
+            Self::LoansResaleAssetsOthFinNonFin => "Loans; resale assets; other financial and nonfinancial institutions",
             Self::LtDebtSecAssets => "Long-term debt securities; assets",
-            Self::LtDebtSecAssetsDepTaking => {
-                "Long-term debt securities; assets; deposit-taking institutions"
-            }
-            Self::LtDebtSecAssetsNonFin => {
-                "Long-term debt securities; assets; nonfinancial institutions"
-            }
-            Self::LtDebtSecAssetsOthFin => {
-                "Long-term debt securities; assets; other financial institutions"
-            }
+            Self::LtDebtSecAssetsDepTaking => "Long-term debt securities; assets; deposit-taking institutions",
+            Self::LtDebtSecAssetsNonFin => "Long-term debt securities; assets; nonfinancial institutions",
+            Self::LtDebtSecAssetsOthFin => "Long-term debt securities; assets; other financial institutions",
             Self::LtDebtSecCorpAssets => "Long-term debt securities; corporate assets",
             Self::LtDebtSecCorpLiabs => "Long-term debt securities; corporate liabilities",
-            Self::LtDebtSecCorpLiabsFoa => {
-                "Long-term debt securities; corporate liabilities; financial account"
-            }
-            Self::LtDebtSecFedSponsorAgencyIncPay => {
-                "Long-term debt securities; federal-sponsored agency income payments"
-            }
-            Self::LtDebtSecFedSponsorAgencyLiabs => {
-                "Long-term debt securities; federal-sponsored agency liabilities"
-            }
-            Self::LtDebtSecFedSponsorAgencyLiabsFoa => {
-                "Long-term debt securities; federal-sponsored agency liabilities; financial account"
-            }
+            Self::LtDebtSecCorpLiabsFoa => "Long-term debt securities; corporate liabilities; financial account",
+            Self::LtDebtSecFedSponsorAgencyIncPay => "Long-term debt securities; federal-sponsored agency income payments",
+            Self::LtDebtSecFedSponsorAgencyLiabs => "Long-term debt securities; federal-sponsored agency liabilities",
+            Self::LtDebtSecFedSponsorAgencyLiabsFoa => "Long-term debt securities; federal-sponsored agency liabilities; financial account",
             Self::LtDebtSecGovtAssets => "Long-term debt securities; government assets",
             Self::LtDebtSecIncPay => "Long-term debt securities; income payments",
-            Self::LtDebtSecIncPayDepTaking => {
-                "Long-term debt securities; income payments; deposit-taking institutions"
-            }
-            Self::LtDebtSecIncPayNonFin => {
-                "Long-term debt securities; income payments; nonfinancial institutions"
-            }
+            Self::LtDebtSecIncPayDepTaking => "Long-term debt securities; income payments; deposit-taking institutions",
+            Self::LtDebtSecIncPayNonFin => "Long-term debt securities; income payments; nonfinancial institutions",
             Self::LtDebtSecIncRec => "Long-term debt securities; income receipts",
-            Self::LtDebtSecIncRecDepTaking => {
-                "Long-term debt securities; income receipts; deposit-taking institutions"
-            }
-            Self::LtDebtSecIncRecNonFin => {
-                "Long-term debt securities; income receipts; nonfinancial institutions"
-            }
-            Self::LtDebtSecIncRecOthFin => {
-                "Long-term debt securities; income receipts; other financial institutions"
-            }
+            Self::LtDebtSecIncRecDepTaking => "Long-term debt securities; income receipts; deposit-taking institutions",
+            Self::LtDebtSecIncRecNonFin => "Long-term debt securities; income receipts; nonfinancial institutions",
+            Self::LtDebtSecIncRecOthFin => "Long-term debt securities; income receipts; other financial institutions",
             Self::LtDebtSecLiabs => "Long-term debt securities; liabilities",
-            Self::LtDebtSecLiabsDepTaking => {
-                "Long-term debt securities; liabilities; deposit-taking institutions"
-            }
+            Self::LtDebtSecLiabsDepTaking => "Long-term debt securities; liabilities; deposit-taking institutions",
             Self::LtDebtSecLiabsFoa => "Long-term debt securities; liabilities; financial account",
-            Self::LtDebtSecLiabsNonFin => {
-                "Long-term debt securities; liabilities; nonfinancial institutions"
-            }
+            Self::LtDebtSecLiabsNonFin => "Long-term debt securities; liabilities; nonfinancial institutions",
             Self::LtDebtSecNegCdAssets => "Long-term debt securities; negative CD assets",
             Self::LtDebtSecNegCdLiabs => "Long-term debt securities; negative CD liabilities",
-            Self::LtDebtSecNegCdLiabsFoa => {
-                "Long-term debt securities; negative CD liabilities; financial account"
-            }
-            Self::LtDebtSecOthThanFedSponsorAgencyIncPayOthFin => {
-                "Long-term debt securities; other than federal-sponsored agency income payments; other financial institutions"
-            }
-            Self::LtDebtSecOthThanFedSponsorAgencyLiabsOthFin => {
-                "Long-term debt securities; other than federal-sponsored agency liabilities; other financial institutions"
-            }
-            Self::LtDebtSecStateLocalGovtIncPay => {
-                "Portfolio investment income payments; interest on state and local government long-term securities"
-            }
-            Self::LtDebtSecStateLocalGovtLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; state and local government long-term securities"
-            }
-            Self::LtDebtSecStateLocalGovtLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; state and local government long-term securities"
-            }
-            Self::LtDebtSecTreasIncPay => {
-                "Portfolio investment income payments; interest on long-term U.S. Treasury securities"
-            }
-            Self::LtDebtSecTreasLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; Treasury bonds and notes"
-            }
-            Self::LtDepAssets => {
-                "Net U.S. acquisition of other investment assets; long-term deposits"
-            }
-            Self::LtDepAssetsDepTaking => {
-                "Net U.S. acquistion of other investment assets; long-term deposits; held by deposit-taking institutions except central bank"
-            }
-            Self::LtDepAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; long-term deposits; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::LtDepLiabs => {
-                "Net U.S. incurrence of other investment liabilities; long-term deposits"
-            }
-            Self::LtDepLiabsDepTaking => {
-                "Net U.S. incurrence of other investment liabilities; long-term deposits; issued by deposit-taking institutions except central bank"
-            }
-            Self::LtDepLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; long-term deposits; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::LtLoansAssets => {
-                "Net U.S. acquisition of other investment assets; long-term loans"
-            }
-            Self::LtLoansAssetsDepTaking => {
-                "Net U.S. acquistion of other investment assets; long-term loans; held by deposit-taking institutions except central bank"
-            }
-            Self::LtLoansAssetsGenGovt => {
-                "Net U.S. acquistion of other investment assets; long-term loans; held by general government"
-            }
-            Self::LtLoansAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; long-term loans; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::LtLoansLiabs => {
-                "Net U.S. incurrence of other investment liabilities; long-term loans"
-            }
-            Self::LtLoansLiabsDepTaking => {
-                "Net U.S. incurrence of other investment liabilities; long-term loans; issued by deposit-taking institutions except central bank"
-            }
-            Self::LtLoansLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; long-term loans; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::LtTrdCredAndAdvAssets => {
-                "Net U.S. acquisition of other investment assets; long-term trade credit and advances"
-            }
-            Self::LtTrdCredAndAdvAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; long-term trade credit and advances; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::LtTrdCredAndAdvLiabs => {
-                "Net U.S. incurrence of other investment liabilities; long-term trade credit and advances"
-            }
-            Self::LtTrdCredAndAdvLiabsGenGovt => {
-                "Net U.S. incurrence of other investment liabilities; long-term trade credit and advances; issued by general government"
-            }
-            Self::LtTrdCredAndAdvLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; long-term trade credit and advances; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::NetLendBorrCurrCapAcct => {
-                "Net lending (+) or net borrowing (-) from current- and capital-account transactions"
-            }
-            Self::NetLendBorrFinAcct => {
-                "Net lending (+) or net borrowing (-) from financial-account transactions"
-            }
-            Self::OthClmReserveAssets => {
-                "Net U.S. acquisition of reserve assets; other; other claims"
-            }
-            Self::OthEquityAssets => {
-                "Net U.S. acquisition of other investment assets; other equity"
-            }
-            Self::OthEquityAssetsGenGovt => {
-                "Net U.S. acquistion of other investment assets; held by general government; other equity"
-            }
-            Self::OthEquityLiabs => {
-                "Net U.S. incurrence of other investment liabilities; other equity"
-            }
-            Self::OthEquityLiabsFoa => {
-                "Net U.S. incurrence of other investment liabilities to foreign official agencies; other equity"
-            }
+            Self::LtDebtSecNegCdLiabsFoa => "Long-term debt securities; negative CD liabilities; financial account",
+            Self::LtDebtSecOthThanFedSponsorAgencyIncPayOthFin => "Long-term debt securities; other than federal-sponsored agency income payments; other financial institutions",
+            Self::LtDebtSecOthThanFedSponsorAgencyLiabsOthFin => "Long-term debt securities; other than federal-sponsored agency liabilities; other financial institutions",
+            Self::LtDebtSecStateLocalGovtIncPay => "Portfolio investment income payments; interest on state and local government long-term securities",
+            Self::LtDebtSecStateLocalGovtLiabs => "Net U.S. incurrence of portfolio investment liabilities; state and local government long-term securities",
+            Self::LtDebtSecStateLocalGovtLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; state and local government long-term securities",
+            Self::LtDebtSecTreasIncPay => "Portfolio investment income payments; interest on long-term U.S. Treasury securities",
+            Self::LtDebtSecTreasLiabs => "Net U.S. incurrence of portfolio investment liabilities; Treasury bonds and notes",
+            Self::LtDepAssets => "Net U.S. acquisition of other investment assets; long-term deposits",
+            Self::LtDepAssetsDepTaking => "Net U.S. acquistion of other investment assets; long-term deposits; held by deposit-taking institutions except central bank",
+            Self::LtDepAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; long-term deposits; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::LtDepLiabs => "Net U.S. incurrence of other investment liabilities; long-term deposits",
+            Self::LtDepLiabsDepTaking => "Net U.S. incurrence of other investment liabilities; long-term deposits; issued by deposit-taking institutions except central bank",
+            Self::LtDepLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; long-term deposits; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::LtLoansAssets => "Net U.S. acquisition of other investment assets; long-term loans",
+            Self::LtLoansAssetsDepTaking => "Net U.S. acquistion of other investment assets; long-term loans; held by deposit-taking institutions except central bank",
+            Self::LtLoansAssetsGenGovt => "Net U.S. acquistion of other investment assets; long-term loans; held by general government",
+            Self::LtLoansAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; long-term loans; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::LtLoansLiabs => "Net U.S. incurrence of other investment liabilities; long-term loans",
+            Self::LtLoansLiabsDepTaking => "Net U.S. incurrence of other investment liabilities; long-term loans; issued by deposit-taking institutions except central bank",
+            Self::LtLoansLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; long-term loans; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::LtTrdCredAndAdvAssets => "Net U.S. acquisition of other investment assets; long-term trade credit and advances",
+            Self::LtTrdCredAndAdvAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; long-term trade credit and advances; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::LtTrdCredAndAdvLiabs => "Net U.S. incurrence of other investment liabilities; long-term trade credit and advances",
+            Self::LtTrdCredAndAdvLiabsGenGovt => "Net U.S. incurrence of other investment liabilities; long-term trade credit and advances; issued by general government",
+            Self::LtTrdCredAndAdvLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; long-term trade credit and advances; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::NetLendBorrCurrCapAcct => "Net lending (+) or net borrowing (-) from current- and capital-account transactions",
+            Self::NetLendBorrFinAcct => "Net lending (+) or net borrowing (-) from financial-account transactions",
+            Self::OthClmReserveAssets => "Net U.S. acquisition of reserve assets; other; other claims",
+            Self::OthEquityAssets => "Net U.S. acquisition of other investment assets; other equity",
+            Self::OthEquityAssetsGenGovt => "Net U.S. acquistion of other investment assets; held by general government; other equity",
+            Self::OthEquityLiabs => "Net U.S. incurrence of other investment liabilities; other equity",
+            Self::OthEquityLiabsFoa => "Net U.S. incurrence of other investment liabilities to foreign official agencies; other equity",
             Self::OthInvAssets => "Net U.S. acquisition of other investment assets",
-            Self::OthInvAssetsCentralBank => {
-                "Net U.S. acquistion of other investment assets; held by central bank"
-            }
-            Self::OthInvAssetsDepTaking => {
-                "Net U.S. acquistion of other investment assets; held by deposit-taking institutions except central bank"
-            }
-            Self::OthInvAssetsGenGovt => {
-                "Net U.S. acquistion of other investment assets; held by general government"
-            }
-            Self::OthInvAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
+            Self::OthInvAssetsCentralBank => "Net U.S. acquistion of other investment assets; held by central bank",
+            Self::OthInvAssetsDepTaking => "Net U.S. acquistion of other investment assets; held by deposit-taking institutions except central bank",
+            Self::OthInvAssetsGenGovt => "Net U.S. acquistion of other investment assets; held by general government",
+            Self::OthInvAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; held by non-deposit-taking institutions and nonfinancial institutions except general government",
             Self::OthInvIncPay => "Other investment income payments",
-            Self::OthInvIncPayBeforeFisim => {
-                "Other investment interest income payments before adjusting for FISIM"
-            }
-            Self::OthInvIncPayCentralBank => {
-                "Other investment income payments; on liabilities issued by central bank"
-            }
-            Self::OthInvIncPayDepTaking => {
-                "Other investment income payments; on liabilities issued by deposit-taking institutions except central bank"
-            }
-            Self::OthInvIncPayGenGovt => {
-                "Other investment income payments; on liabilities issued by general government"
-            }
-            Self::OthInvIncPayOthFinNonFin => {
-                "Other investment income payments; on liabilities issued by non-deposit-taking financial institutions and nonfinancial instutitions except general government"
-            }
+            Self::OthInvIncPayBeforeFisim => "Other investment interest income payments before adjusting for FISIM",
+            Self::OthInvIncPayCentralBank => "Other investment income payments; on liabilities issued by central bank",
+            Self::OthInvIncPayDepTaking => "Other investment income payments; on liabilities issued by deposit-taking institutions except central bank",
+            Self::OthInvIncPayGenGovt => "Other investment income payments; on liabilities issued by general government",
+            Self::OthInvIncPayOthFinNonFin => "Other investment income payments; on liabilities issued by non-deposit-taking financial institutions and nonfinancial instutitions except general government",
             Self::OthInvIncRec => "Other investment income receipts",
-            Self::OthInvIncRecBeforeFisim => {
-                "Other investment interest income receipts before adjusting for FISIM"
-            }
-            Self::OthInvIncRecCentralBank => {
-                "Other investment income receipts; on assets held by central bank"
-            }
-            Self::OthInvIncRecDepTaking => {
-                "Other investment income receipts; on assets held by deposit-taking institutions except central bank"
-            }
-            Self::OthInvIncRecGenGovt => {
-                "Other investment income receipts; on assets held by general government"
-            }
-            Self::OthInvIncRecOthFinNonFin => {
-                "Other investment income receipts; on assets held by non-deposit-taking financial institutions and nonfinancial instutitions except general government"
-            }
-            Self::OthInvInsPolHoldtIncPay => {
-                "Other investment income payments; income attributable to insurance policyholders"
-            }
-            Self::OthInvInsPolHoldtIncPayOthFinNonFin => {
-                "Other investment income payments; income attributable to insurance policyholders; on liabilities issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::OthInvInsPolHoldtIncRec => {
-                "Other investment income receipts; income attributable to insurance policyholders"
-            }
-            Self::OthInvInsPolHoldtIncRecOthFinNonFin => {
-                "Other investment income receipts; income attributable to insurance policyholders; on assets held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::OthInvInterbankAssets => {
-                "Net U.S. acquistion of other investment assets; interbank transactions"
-            }
-            Self::OthInvInterbankLiabsDepTaking => {
-                "Net U.S. incurrence of other investment liabilities; interbank transactions"
-            }
+            Self::OthInvIncRecBeforeFisim => "Other investment interest income receipts before adjusting for FISIM",
+            Self::OthInvIncRecCentralBank => "Other investment income receipts; on assets held by central bank",
+            Self::OthInvIncRecDepTaking => "Other investment income receipts; on assets held by deposit-taking institutions except central bank",
+            Self::OthInvIncRecGenGovt => "Other investment income receipts; on assets held by general government",
+            Self::OthInvIncRecOthFinNonFin => "Other investment income receipts; on assets held by non-deposit-taking financial institutions and nonfinancial instutitions except general government",
+            Self::OthInvInsPolHoldtIncPay => "Other investment income payments; income attributable to insurance policyholders",
+            Self::OthInvInsPolHoldtIncPayOthFinNonFin => "Other investment income payments; income attributable to insurance policyholders; on liabilities issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::OthInvInsPolHoldtIncRec => "Other investment income receipts; income attributable to insurance policyholders",
+            Self::OthInvInsPolHoldtIncRecOthFinNonFin => "Other investment income receipts; income attributable to insurance policyholders; on assets held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::OthInvInterbankAssets => "Net U.S. acquistion of other investment assets; interbank transactions",
+            Self::OthInvInterbankLiabsDepTaking => "Net U.S. incurrence of other investment liabilities; interbank transactions",
             Self::OthInvIntIncPay => "Other investment income payments; interest",
-            Self::OthInvIntIncPayCentralBank => {
-                "Other investment income payments; interest; on liabilities issued by central bank"
-            }
-            Self::OthInvIntIncPayDepTaking => {
-                "Other investment income payments; interest; on liabilities issued by deposit-taking institutions except central bank"
-            }
-            Self::OthInvIntIncPayGenGovt => {
-                "Other investment income payments; interest on special drawing rights allocations"
-            }
-            Self::OthInvIntIncPayOthFinNonFin => {
-                "Other investment income payments; interest; on liabilities issued by non-deposit-taking financial institutions and nonfinancial instutitions except general government"
-            }
+            Self::OthInvIntIncPayCentralBank => "Other investment income payments; interest; on liabilities issued by central bank",
+            Self::OthInvIntIncPayDepTaking => "Other investment income payments; interest; on liabilities issued by deposit-taking institutions except central bank",
+            Self::OthInvIntIncPayGenGovt => "Other investment income payments; interest on special drawing rights allocations",
+            Self::OthInvIntIncPayOthFinNonFin => "Other investment income payments; interest; on liabilities issued by non-deposit-taking financial institutions and nonfinancial instutitions except general government",
             Self::OthInvIntIncRec => "Other investment income receipts; interest",
-            Self::OthInvIntIncRecCentralBank => {
-                "Other investment income receipts; interest; on assets held by central bank"
-            }
-            Self::OthInvIntIncRecDepTaking => {
-                "Other investment income receipts; interest; on assets held by deposit-taking institutions except central bank"
-            }
-            Self::OthInvIntIncRecGenGovt => {
-                "Other investment income receipts; interest; on assets held by general government"
-            }
-            Self::OthInvIntIncRecOthFinNonFin => {
-                "Other investment income receipts; interest; on assets held by non-deposit-taking financial institutions and nonfinancial instutitions except general government"
-            }
+            Self::OthInvIntIncRecCentralBank => "Other investment income receipts; interest; on assets held by central bank",
+            Self::OthInvIntIncRecDepTaking => "Other investment income receipts; interest; on assets held by deposit-taking institutions except central bank",
+            Self::OthInvIntIncRecGenGovt => "Other investment income receipts; interest; on assets held by general government",
+            Self::OthInvIntIncRecOthFinNonFin => "Other investment income receipts; interest; on assets held by non-deposit-taking financial institutions and nonfinancial instutitions except general government",
             Self::OthInvLiabs => "Net U.S. incurrence of other investment liabilities",
-            Self::OthInvLiabsCentralBank => {
-                "Net U.S. incurrence of other investment liabilities; issued by central bank"
-            }
-            Self::OthInvLiabsDepTaking => {
-                "Net U.S. incurrence of other investment liabilities; issued by deposit-taking institutions except central bank"
-            }
-            Self::OthInvLiabsFoa => {
-                "Net U.S. incurrence of other investment liabilities to foreign official agencies"
-            }
-            Self::OthInvLiabsGenGovt => {
-                "Net U.S. incurrence of other investment liabilities; issued by general government"
-            }
-            Self::OthInvLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
+            Self::OthInvLiabsCentralBank => "Net U.S. incurrence of other investment liabilities; issued by central bank",
+            Self::OthInvLiabsDepTaking => "Net U.S. incurrence of other investment liabilities; issued by deposit-taking institutions except central bank",
+            Self::OthInvLiabsFoa => "Net U.S. incurrence of other investment liabilities to foreign official agencies",
+            Self::OthInvLiabsGenGovt => "Net U.S. incurrence of other investment liabilities; issued by general government",
+            Self::OthInvLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
             Self::OthReserveAssets => "Net U.S. acquisition of reserve assets; other",
             Self::PfInvAssets => "Net U.S. acquisition of portfolio investment assets",
-            Self::PfInvAssetsDepTaking => {
-                "Net U.S. acquisition of portfolio investment assets; deposit-taking institutions"
-            }
-            Self::PfInvAssetsNonFin => {
-                "Net U.S. acquisition of portfolio investment assets; nonfinancial institutions"
-            }
-            Self::PfInvAssetsOthFin => {
-                "Net U.S. acquisition of portfolio investment assets; other financial institutions"
-            }
+            Self::PfInvAssetsDepTaking => "Net U.S. acquisition of portfolio investment assets; deposit-taking institutions",
+            Self::PfInvAssetsNonFin => "Net U.S. acquisition of portfolio investment assets; nonfinancial institutions",
+            Self::PfInvAssetsOthFin => "Net U.S. acquisition of portfolio investment assets; other financial institutions",
             Self::PfInvIncPay => "Portfolio investment income payments",
-            Self::PfInvIncPayDepTaking => {
-                "Portfolio investment income payments; deposit-taking institutions"
-            }
+            Self::PfInvIncPayDepTaking => "Portfolio investment income payments; deposit-taking institutions",
             Self::PfInvIncPayGenGovt => "Portfolio investment income payments; general government",
-            Self::PfInvIncPayNonFin => {
-                "Portfolio investment income payments; nonfinancial institutions"
-            }
-            Self::PfInvIncPayOthFin => {
-                "Portfolio investment income payments; other financial institutions"
-            }
+            Self::PfInvIncPayNonFin => "Portfolio investment income payments; nonfinancial institutions",
+            Self::PfInvIncPayOthFin => "Portfolio investment income payments; other financial institutions",
             Self::PfInvIncRec => "Portfolio investment income receipts",
-            Self::PfInvIncRecDepTaking => {
-                "Portfolio investment income receipts; deposit-taking institutions"
-            }
-            Self::PfInvIncRecNonFin => {
-                "Portfolio investment income receipts; nonfinancial institutions"
-            }
-            Self::PfInvIncRecOthFin => {
-                "Portfolio investment income receipts; other financial institutions"
-            }
+            Self::PfInvIncRecDepTaking => "Portfolio investment income receipts; deposit-taking institutions",
+            Self::PfInvIncRecNonFin => "Portfolio investment income receipts; nonfinancial institutions",
+            Self::PfInvIncRecOthFin => "Portfolio investment income receipts; other financial institutions",
             Self::PfInvLiabs => "Net U.S. incurrence of portfolio investment liabilities",
-            Self::PfInvLiabsDepTaking => {
-                "Net U.S. incurrence of portfolio investment liabilities; deposit-taking institutions"
-            }
-            Self::PfInvLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities; financial account"
-            }
-            Self::PfInvLiabsGenGovt => {
-                "Net U.S. incurrence of portfolio investment liabilities; general government"
-            }
-            Self::PfInvLiabsNonFin => {
-                "Net U.S. incurrence of portfolio investment liabilities; nonfinancial institutions"
-            }
-            Self::PfInvLiabsOthFin => {
-                "Net U.S. incurrence of portfolio investment liabilities; other financial institutions"
-            }
+            Self::PfInvLiabsDepTaking => "Net U.S. incurrence of portfolio investment liabilities; deposit-taking institutions",
+            Self::PfInvLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities; financial account",
+            Self::PfInvLiabsGenGovt => "Net U.S. incurrence of portfolio investment liabilities; general government",
+            Self::PfInvLiabsNonFin => "Net U.S. incurrence of portfolio investment liabilities; nonfinancial institutions",
+            Self::PfInvLiabsOthFin => "Net U.S. incurrence of portfolio investment liabilities; other financial institutions",
             Self::PrimIncPay => "Primary income payments",
             Self::PrimIncRec => "Primary income receipts",
             Self::ReserveAssets => "Reserve assets",
             Self::SdrAllocLiabs => "Special drawing rights allocations; liabilities",
-            Self::SdrAllocLiabsFoa => {
-                "Special drawing rights allocations; liabilities; financial account"
-            }
+            Self::SdrAllocLiabsFoa => "Special drawing rights allocations; liabilities; financial account",
             Self::SdrReserveAssets => "Special drawing rights; reserve assets",
             Self::SeasAdjDisc => "Seasonal adjustment discrepancy",
             Self::SecIncPay => "Secondary income (current transfer) payments",
-            Self::SecIncPayCharitableDonations => {
-                "Secondary income (current transfer) payments; charitable donations"
-            }
-            Self::SecIncPayForeignStudents => {
-                "Secondary income (current transfer) payments; foreign students"
-            }
-            Self::SecIncPayGenGovt => {
-                "Secondary income (current transfer) payments; general government transfers"
-            }
-            Self::SecIncPayGenGovtContribIntOrg => {
-                "Secondary income (current transfer) payments; general government contributions to international organizations"
-            }
-            Self::SecIncPayGenGovtIntCoop => {
-                "Secondary income (current transfer) payments; general government international cooperation"
-            }
-            Self::SecIncPayGenGovtOth => {
-                "Secondary income (current transfer) payments; general government other"
-            }
-            Self::SecIncPayGenGovtSocialBenefits => {
-                "Secondary income (current transfer) payments; general government social benefits"
-            }
-            Self::SecIncPayInsuranceRelated => {
-                "Secondary income (current transfer) payments; insurance-related"
-            }
-            Self::SecIncPayOthPrivateTransfer => {
-                "Secondary income (current transfer) payments; other private transfers"
-            }
+            Self::SecIncPayCharitableDonations => "Secondary income (current transfer) payments; charitable donations",
+            Self::SecIncPayForeignStudents => "Secondary income (current transfer) payments; foreign students",
+            Self::SecIncPayGenGovt => "Secondary income (current transfer) payments; general government transfers",
+            Self::SecIncPayGenGovtContribIntOrg => "Secondary income (current transfer) payments; general government contributions to international organizations",
+            Self::SecIncPayGenGovtIntCoop => "Secondary income (current transfer) payments; general government international cooperation",
+            Self::SecIncPayGenGovtOth => "Secondary income (current transfer) payments; general government other",
+            Self::SecIncPayGenGovtSocialBenefits => "Secondary income (current transfer) payments; general government social benefits",
+            Self::SecIncPayInsuranceRelated => "Secondary income (current transfer) payments; insurance-related",
+            Self::SecIncPayOthPrivateTransfer => "Secondary income (current transfer) payments; other private transfers",
             Self::SecIncPayPersonal => "Secondary income (current transfer) payments; personal",
             Self::SecIncPayPrivate => "Secondary income (current transfer) payments; private",
-            Self::SecIncPayPrivateFinesPenalties => {
-                "Secondary income (current transfer) payments; private fines and penalties"
-            }
-            Self::SecIncPayTaxesIncomeWealth => {
-                "Secondary income (current transfer) payments; taxes on income and wealth"
-            }
+            Self::SecIncPayPrivateFinesPenalties => "Secondary income (current transfer) payments; private fines and penalties",
+            Self::SecIncPayTaxesIncomeWealth => "Secondary income (current transfer) payments; taxes on income and wealth",
             Self::SecIncRec => "Secondary income (current transfer) receipts",
-            Self::SecIncRecGenGovt => {
-                "Secondary income (current transfer) receipts; general government transfers"
-            }
-            Self::SecIncRecGenGovtFinesPenalties => {
-                "Secondary income (current transfer) receipts; general government transfers; fines and penalties"
-            }
-            Self::SecIncRecGenGovtIntCoop => {
-                "Secondary income (current transfer) receipts; general government transfers; international cooperation"
-            }
-            Self::SecIncRecGenGovtOth => {
-                "Secondary income (current transfer) receipts; other general government transfers"
-            }
-            Self::SecIncRecGenGovtTaxesIncomeWealth => {
-                "Secondary income (current transfer) receipts; general government transfers; taxes on income, wealth, etc."
-            }
-            Self::SecIncRecInsuranceRelated => {
-                "Secondary income (current transfer) receipts; insurance-related transfers"
-            }
-            Self::SecIncRecOthPrivateTransfer => {
-                "Secondary income (current transfer) receipts; other private transfers"
-            }
-            Self::SecIncRecPrivate => {
-                "Secondary income (current transfer) receipts; private transfers"
-            }
-            Self::SecIncRecPrivateFinesPenalties => {
-                "Secondary income (current transfer) receipts; fines and penalties"
-            }
+            Self::SecIncRecGenGovt => "Secondary income (current transfer) receipts; general government transfers",
+            Self::SecIncRecGenGovtFinesPenalties => "Secondary income (current transfer) receipts; general government transfers; fines and penalties",
+            Self::SecIncRecGenGovtIntCoop => "Secondary income (current transfer) receipts; general government transfers; international cooperation",
+            Self::SecIncRecGenGovtOth => "Secondary income (current transfer) receipts; other general government transfers",
+            Self::SecIncRecGenGovtTaxesIncomeWealth => "Secondary income (current transfer) receipts; general government transfers; taxes on income, wealth, etc.",
+            Self::SecIncRecInsuranceRelated => "Secondary income (current transfer) receipts; insurance-related transfers",
+            Self::SecIncRecOthPrivateTransfer => "Secondary income (current transfer) receipts; other private transfers",
+            Self::SecIncRecPrivate => "Secondary income (current transfer) receipts; private transfers",
+            Self::SecIncRecPrivateFinesPenalties => "Secondary income (current transfer) receipts; fines and penalties",
             Self::SecReserveAssets => "Net U.S. acquisition of reserve assets; other; securities",
             Self::StatDisc => "Statistical discrepancy",
-            Self::StDebtSecAssets => {
-                "Net U.S. acquisition of portfolio investment assets; short-term debt securities"
-            }
-            Self::StDebtSecAssetsDepTaking => {
-                "Net U.S. acquisition of portfolio investment assets; short-term debt securities; held by deposit-taking institutions except central bank"
-            }
-            Self::StDebtSecAssetsNonFin => {
-                "Net U.S. acquisition of portfolio investment assets; short-term debt securities; held by nonfinancial institutions except general government"
-            }
-            Self::StDebtSecAssetsOthFin => {
-                "Net U.S. acquisition of portfolio investment assets; short-term debt securities; held by non-deposit-taking financial institutions"
-            }
-            Self::StDebtSecCommPaperAndOthLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; commercial paper and other short-term debt securities (those not listed in table 7.1)"
-            }
-            Self::StDebtSecCommPaperAndOthLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; commercial paper and other short-term debt securities (those not listed in table 9.1)"
-            }
-            Self::StDebtSecCommPaperAssets => {
-                "Net U.S. acquisition of portfolio investment assets; short-term commercial paper"
-            }
-            Self::StDebtSecFedSponsorAgencyIncPay => {
-                "Portfolio investment income payments; interest on short-term federally sponsored agency securties"
-            }
-            Self::StDebtSecFedSponsorAgencyLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; short-term federally sponsored agency securities"
-            }
-            Self::StDebtSecFedSponsorAgencyLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; short-term federally sponsored agency securities"
-            }
-            Self::StDebtSecIncPay => {
-                "Portfolio investment income payments; interest on short-term debt securities"
-            }
-            Self::StDebtSecIncPayDepTaking => {
-                "Portfolio investment income payments; interest on short-term debt securities; deposit-taking institutions except central bank"
-            }
-            Self::StDebtSecIncPayNonFin => {
-                "Portfolio investment income payments; interest on short-term debt securities; nonfinancial institutions except general government"
-            }
-            Self::StDebtSecIncRec => {
-                "Portfolio investment income receipts; interest on short-term debt securities"
-            }
-            Self::StDebtSecIncRecDepTaking => {
-                "Portfolio investment income receipts; interest on short-term debt securities; deposit-taking institutions except central bank"
-            }
-            Self::StDebtSecIncRecNonFin => {
-                "Portfolio investment income receipts; interest on short-term debt securities; nonfinancial institutions except general government"
-            }
-            Self::StDebtSecIncRecOthFin => {
-                "Portfolio investment income receipts; interest on short-term debt securities; non-deposit-taking financial institutions"
-            }
-            Self::StDebtSecLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities"
-            }
-            Self::StDebtSecLiabsDepTaking => {
-                "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities; issued by deposit-taking institutions except central bank"
-            }
-            Self::StDebtSecLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; short-term debt securities"
-            }
-            Self::StDebtSecLiabsNonFin => {
-                "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities; issued by nonfinancial institutions except general government"
-            }
-            Self::StDebtSecNegCdAssets => {
-                "Net U.S. acquisition of portfolio investment assets; short-term negotiable certificates of deposit"
-            }
-            Self::StDebtSecNegCdLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; short-term negotiable certificates of deposit"
-            }
-            Self::StDebtSecNegCdLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; short-term negotiable certificates of deposit"
-            }
-            Self::StDebtSecOthAssets => {
-                "Net U.S. acquisition of portfolio investment assets; short-term debt securities other than negotiable certificates of deposit and commercial paper"
-            }
-            Self::StDebtSecOthThanFedSponsorAgencyIncPayOthFin => {
-                "Portfolio investment income payments; interest on short-term debt securities other than federally sponsored agency securties; non-deposit-taking financial institutions"
-            }
-            Self::StDebtSecOthThanFedSponsorAgencyLiabsOthFin => {
-                "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities other than federally sponsored agency securities; issued by non-deposit-taking financial institutions"
-            }
-            Self::StDebtSecTreasIncPay => {
-                "Portfolio investment income payments; interest on short-term U.S. Treasury securities"
-            }
-            Self::StDebtSecTreasLiabs => {
-                "Net U.S. incurrence of portfolio investment liabilities; Treasury bills and certificates"
-            }
-            Self::StDebtSecTreasLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; Treasury bills and certificates"
-            }
-            Self::StDepAssets => {
-                "Net U.S. acquisition of other investment assets; short-term deposits"
-            }
-            Self::StDepAssetsCentralBank => {
-                "Net U.S. acquistion of other investment assets; short-term deposits; held by central bank"
-            }
-            Self::StDepAssetsDepTaking => {
-                "Net U.S. acquistion of other investment assets; short-term deposits; held by deposit-taking institutions except central bank"
-            }
-            Self::StDepAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; short-term deposits; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::StDepLiabs => {
-                "Net U.S. incurrence of other investment liabilities; short-term deposits"
-            }
-            Self::StDepLiabsCentralBank => {
-                "Net U.S. incurrence of other investment liabilities; short-term deposits; issued by central bank"
-            }
-            Self::StDepLiabsDepTaking => {
-                "Net U.S. incurrence of other investment liabilities; short-term deposits; issued by deposit-taking institutions except central bank"
-            }
-            Self::StDepLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; short-term deposits; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::StLoansAssets => {
-                "Net U.S. acquisition of other investment assets; short-term loans"
-            }
-            Self::StLoansAssetsDepTaking => {
-                "Net U.S. acquistion of other investment assets; short-term loans; held by deposit-taking institutions except central bank"
-            }
-            Self::StLoansAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; short-term loans; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::StLoansLiabs => {
-                "Net U.S. incurrence of other investment liabilities; short-term loans"
-            }
-            Self::StLoansLiabsDepTaking => {
-                "Net U.S. incurrence of other investment liabilities; short-term loans; issued by deposit-taking institutions except central bank"
-            }
-            Self::StLoansLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; short-term loans; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::StTrdCredAndAdvAssets => {
-                "Net U.S. acquisition of other investment assets; short-term trade credit and advances"
-            }
-            Self::StTrdCredAndAdvAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; short-term trade credit and advances; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::StTrdCredAndAdvLiabs => {
-                "Net U.S. incurrence of other investment liabilities; short-term trade credit and advances"
-            }
-            Self::StTrdCredAndAdvLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; short-term trade credit and advances; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::TrdCredAndAdvAssets => {
-                "Net U.S. acquisition of other investment assets; trade credit and advances"
-            }
-            Self::TrdCredAndAdvAssetsOthFinNonFin => {
-                "Net U.S. acquistion of other investment assets; trade credit and advances; held by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::TrdCredAndAdvLiabs => {
-                "Net U.S. incurrence of other investment liabilities; trade credit and advances"
-            }
-            Self::TrdCredAndAdvLiabsFoa => {
-                "Net U.S. incurrence of other investment liabilities to foreign official agencies; trade credit and advances"
-            }
-            Self::TrdCredAndAdvLiabsGenGovt => {
-                "Net U.S. incurrence of other investment liabilities; trade credit and advances; issued by general government"
-            }
-            Self::TrdCredAndAdvLiabsOthFinNonFin => {
-                "Net U.S. incurrence of other investment liabilities; trade credit and advances; issued by non-deposit-taking institutions and nonfinancial institutions except general government"
-            }
-            Self::TreasBondsAndNotesLiabsFoa => {
-                "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; Treasury bonds and notes"
-            }
-            Self::TsiItaDiInvIncInward => {
-                "Direct investment income on inward investment (foreign direct investment in the United States), directional basis"
-            }
-            Self::TsiItaDiInvIncOutward => {
-                "Direct investment income on outward investment (U.S. direct investment abroad), directional basis"
-            }
-            Self::TsiItaDiInvWithoutCurrCostAdjIncOutward => {
-                "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis"
-            }
+            Self::StDebtSecAssets => "Net U.S. acquisition of portfolio investment assets; short-term debt securities",
+            Self::StDebtSecAssetsDepTaking => "Net U.S. acquisition of portfolio investment assets; short-term debt securities; held by deposit-taking institutions except central bank",
+            Self::StDebtSecAssetsNonFin => "Net U.S. acquisition of portfolio investment assets; short-term debt securities; held by nonfinancial institutions except general government",
+            Self::StDebtSecAssetsOthFin => "Net U.S. acquisition of portfolio investment assets; short-term debt securities; held by non-deposit-taking financial institutions",
+            Self::StDebtSecCommPaperAndOthLiabs => "Net U.S. incurrence of portfolio investment liabilities; commercial paper and other short-term debt securities (those not listed in table 7.1)",
+            Self::StDebtSecCommPaperAndOthLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; commercial paper and other short-term debt securities (those not listed in table 9.1)",
+            Self::StDebtSecCommPaperAssets => "Net U.S. acquisition of portfolio investment assets; short-term commercial paper",
+            Self::StDebtSecFedSponsorAgencyIncPay => "Portfolio investment income payments; interest on short-term federally sponsored agency securties",
+            Self::StDebtSecFedSponsorAgencyLiabs => "Net U.S. incurrence of portfolio investment liabilities; short-term federally sponsored agency securities",
+            Self::StDebtSecFedSponsorAgencyLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; short-term federally sponsored agency securities",
+            Self::StDebtSecIncPay => "Portfolio investment income payments; interest on short-term debt securities",
+            Self::StDebtSecIncPayDepTaking => "Portfolio investment income payments; interest on short-term debt securities; deposit-taking institutions except central bank",
+            Self::StDebtSecIncPayNonFin => "Portfolio investment income payments; interest on short-term debt securities; nonfinancial institutions except general government",
+            Self::StDebtSecIncRec => "Portfolio investment income receipts; interest on short-term debt securities",
+            Self::StDebtSecIncRecDepTaking => "Portfolio investment income receipts; interest on short-term debt securities; deposit-taking institutions except central bank",
+            Self::StDebtSecIncRecNonFin => "Portfolio investment income receipts; interest on short-term debt securities; nonfinancial institutions except general government",
+            Self::StDebtSecIncRecOthFin => "Portfolio investment income receipts; interest on short-term debt securities; non-deposit-taking financial institutions",
+            Self::StDebtSecLiabs => "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities",
+            Self::StDebtSecLiabsDepTaking => "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities; issued by deposit-taking institutions except central bank",
+            Self::StDebtSecLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; short-term debt securities",
+            Self::StDebtSecLiabsNonFin => "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities; issued by nonfinancial institutions except general government",
+            Self::StDebtSecNegCdAssets => "Net U.S. acquisition of portfolio investment assets; short-term negotiable certificates of deposit",
+            Self::StDebtSecNegCdLiabs => "Net U.S. incurrence of portfolio investment liabilities; short-term negotiable certificates of deposit",
+            Self::StDebtSecNegCdLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; short-term negotiable certificates of deposit",
+            Self::StDebtSecOthAssets => "Net U.S. acquisition of portfolio investment assets; short-term debt securities other than negotiable certificates of deposit and commercial paper",
+            Self::StDebtSecOthThanFedSponsorAgencyIncPayOthFin => "Portfolio investment income payments; interest on short-term debt securities other than federally sponsored agency securties; non-deposit-taking financial institutions",
+            Self::StDebtSecOthThanFedSponsorAgencyLiabsOthFin => "Net U.S. incurrence of portfolio investment liabilities; short-term debt securities other than federally sponsored agency securities; issued by non-deposit-taking financial institutions",
+            Self::StDebtSecTreasIncPay => "Portfolio investment income payments; interest on short-term U.S. Treasury securities",
+            Self::StDebtSecTreasLiabs => "Net U.S. incurrence of portfolio investment liabilities; Treasury bills and certificates",
+            Self::StDebtSecTreasLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; Treasury bills and certificates",
+            Self::StDepAssets => "Net U.S. acquisition of other investment assets; short-term deposits",
+            Self::StDepAssetsCentralBank => "Net U.S. acquistion of other investment assets; short-term deposits; held by central bank",
+            Self::StDepAssetsDepTaking => "Net U.S. acquistion of other investment assets; short-term deposits; held by deposit-taking institutions except central bank",
+            Self::StDepAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; short-term deposits; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::StDepLiabs => "Net U.S. incurrence of other investment liabilities; short-term deposits",
+            Self::StDepLiabsCentralBank => "Net U.S. incurrence of other investment liabilities; short-term deposits; issued by central bank",
+            Self::StDepLiabsDepTaking => "Net U.S. incurrence of other investment liabilities; short-term deposits; issued by deposit-taking institutions except central bank",
+            Self::StDepLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; short-term deposits; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::StLoansAssets => "Net U.S. acquisition of other investment assets; short-term loans",
+            Self::StLoansAssetsDepTaking => "Net U.S. acquistion of other investment assets; short-term loans; held by deposit-taking institutions except central bank",
+            Self::StLoansAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; short-term loans; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::StLoansLiabs => "Net U.S. incurrence of other investment liabilities; short-term loans",
+            Self::StLoansLiabsDepTaking => "Net U.S. incurrence of other investment liabilities; short-term loans; issued by deposit-taking institutions except central bank",
+            Self::StLoansLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; short-term loans; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::StTrdCredAndAdvAssets => "Net U.S. acquisition of other investment assets; short-term trade credit and advances",
+            Self::StTrdCredAndAdvAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; short-term trade credit and advances; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::StTrdCredAndAdvLiabs => "Net U.S. incurrence of other investment liabilities; short-term trade credit and advances",
+            Self::StTrdCredAndAdvLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; short-term trade credit and advances; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::TrdCredAndAdvAssets => "Net U.S. acquisition of other investment assets; trade credit and advances",
+            Self::TrdCredAndAdvAssetsOthFinNonFin => "Net U.S. acquistion of other investment assets; trade credit and advances; held by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::TrdCredAndAdvLiabs => "Net U.S. incurrence of other investment liabilities; trade credit and advances",
+            Self::TrdCredAndAdvLiabsFoa => "Net U.S. incurrence of other investment liabilities to foreign official agencies; trade credit and advances",
+            Self::TrdCredAndAdvLiabsGenGovt => "Net U.S. incurrence of other investment liabilities; trade credit and advances; issued by general government",
+            Self::TrdCredAndAdvLiabsOthFinNonFin => "Net U.S. incurrence of other investment liabilities; trade credit and advances; issued by non-deposit-taking institutions and nonfinancial institutions except general government",
+            Self::TreasBondsAndNotesLiabsFoa => "Net U.S. incurrence of portfolio investment liabilities to foreign official agencies; Treasury bonds and notes",
+            Self::TsiItaDiInvIncInward => "Direct investment income on inward investment (foreign direct investment in the United States), directional basis",
+            Self::TsiItaDiInvIncOutward => "Direct investment income on outward investment (U.S. direct investment abroad), directional basis",
+            Self::TsiItaDiInvWithoutCurrCostAdjIncOutward => "Direct investment income without current cost adjustment on outward investment (U.S. direct investment abroad), directional basis",
+        }
+    }
+}
+// @generated end
+
+impl Indicator {
+    /// Converts BEA parameter value strings into a variant of `Indicator`, handling special cases
+    /// where the BEA key is not strictly camel cased.  Such variants display as their BEA key.
+    pub fn from_value(value: &str) -> Result<Self, KeyMissing> {
+        match Indicator::from_str(value) {
+            Ok(result) => Ok(result),
+            Err(_) => Self::iter()
+                .find(|variant| variant.to_string() == value)
+                .ok_or_else(|| KeyMissing::new(value.to_owned(), line!(), file!().to_owned())),
         }
     }
 }
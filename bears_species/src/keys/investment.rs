@@ -83,9 +83,13 @@ impl TryFrom<&ParameterValueTable> for DirectionOfInvestment {
     }
 }
 
+/// Valid values for the `TypeOfInvestment` parameter of the IIP dataset.
+// @generated begin by `bears generate` from parameter_values/IIP_TypeOfInvestment_values.json, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Default,
+    Copy,
     Clone,
     PartialEq,
     Eq,
@@ -94,9 +98,9 @@ impl TryFrom<&ParameterValueTable> for DirectionOfInvestment {
     Hash,
     serde::Serialize,
     serde::Deserialize,
-    strum::EnumIter,
-    derive_more::FromStr,
     derive_more::Display,
+    derive_more::FromStr,
+    strum::EnumIter,
 )]
 pub enum Investment {
     /// U.S. assets; other investment; currency and deposits
@@ -900,416 +904,160 @@ pub enum Investment {
     TreasBondsAndNotesLiabs,
 }
 
+#[rustfmt::skip]
 impl Investment {
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::CurrAndDepAssets => "U.S. assets; other investment; currency and deposits",
             Self::CurrAndDepLiabs => "U.S. liabilities; other investment; currency and deposits",
-            Self::CurrAndDepLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; other investment; currency and deposits"
-            }
-            Self::CurrAndDepReserveAssets => {
-                "U.S. assets; other reserve assets; currency and deposits"
-            }
+            Self::CurrAndDepLiabsFoa => "U.S. liabilities to foreign official agencies; other investment; currency and deposits",
+            Self::CurrAndDepReserveAssets => "U.S. assets; other reserve assets; currency and deposits",
             Self::DebtAssetsExclReserve => "U.S. debt assets except reserve assets",
-            Self::DebtAssetsExclReserveCenBank => {
-                "U.S. debt assets except reserve assets; central bank"
-            }
-            Self::DebtAssetsExclReserveCenBankEuro => {
-                "U.S. debt assets except reserve assets; central bank; euro"
-            }
-            Self::DebtAssetsExclReserveCenBankEuroLt => {
-                "U.S. debt assets except reserve assets; central bank; euro; long term"
-            }
-            Self::DebtAssetsExclReserveCenBankEuroSt => {
-                "U.S. debt assets except reserve assets; central bank; euro; short term"
-            }
-            Self::DebtAssetsExclReserveCenBankFc => {
-                "U.S. debt assets except reserve assets; central bank; foreign currency"
-            }
-            Self::DebtAssetsExclReserveCenBankFcLt => {
-                "U.S. debt assets except reserve assets; central bank; foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveCenBankFcSt => {
-                "U.S. debt assets except reserve assets; central bank; foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveCenBankLt => {
-                "U.S. debt assets except reserve assets; central bank; long term"
-            }
-            Self::DebtAssetsExclReserveCenBankOthFc => {
-                "U.S. debt assets except reserve assets; central bank; other foreign currency"
-            }
-            Self::DebtAssetsExclReserveCenBankOthFcLt => {
-                "U.S. debt assets except reserve assets; central bank; other foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveCenBankOthFcSt => {
-                "U.S. debt assets except reserve assets; central bank; other foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveCenBankSt => {
-                "U.S. debt assets except reserve assets; central bank; short term"
-            }
-            Self::DebtAssetsExclReserveCenBankUsd => {
-                "U.S. debt assets except reserve assets; central bank; U.S. dollar"
-            }
-            Self::DebtAssetsExclReserveCenBankUsdLt => {
-                "U.S. debt assets except reserve assets; central bank; U.S. dollar; long term"
-            }
-            Self::DebtAssetsExclReserveCenBankUsdSt => {
-                "U.S. debt assets except reserve assets; central bank; U.S. dollar; short term"
-            }
-            Self::DebtAssetsExclReserveCenBankYen => {
-                "U.S. debt assets except reserve assets; central bank; yen"
-            }
-            Self::DebtAssetsExclReserveCenBankYenLt => {
-                "U.S. debt assets except reserve assets; central bank; yen; long term"
-            }
-            Self::DebtAssetsExclReserveCenBankYenSt => {
-                "U.S. debt assets except reserve assets; central bank; yen; short term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBank => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankEuro => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; euro"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankEuroLt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; euro; long term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankEuroSt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; euro; short term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankFc => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; foreign currency"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankFcLt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankFcSt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankLt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; long term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankOthFc => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; other foreign currency"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankOthFcLt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; other foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankOthFcSt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; other foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankSt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; short term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankUsd => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; U.S. dollar"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankUsdLt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; U.S. dollar; long term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankUsdSt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; U.S. dollar; short term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankYen => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; yen"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankYenLt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; yen; long term"
-            }
-            Self::DebtAssetsExclReserveDepExclCenBankYenSt => {
-                "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; yen; short term"
-            }
+            Self::DebtAssetsExclReserveCenBank => "U.S. debt assets except reserve assets; central bank",
+            Self::DebtAssetsExclReserveCenBankEuro => "U.S. debt assets except reserve assets; central bank; euro",
+            Self::DebtAssetsExclReserveCenBankEuroLt => "U.S. debt assets except reserve assets; central bank; euro; long term",
+            Self::DebtAssetsExclReserveCenBankEuroSt => "U.S. debt assets except reserve assets; central bank; euro; short term",
+            Self::DebtAssetsExclReserveCenBankFc => "U.S. debt assets except reserve assets; central bank; foreign currency",
+            Self::DebtAssetsExclReserveCenBankFcLt => "U.S. debt assets except reserve assets; central bank; foreign currency; long term",
+            Self::DebtAssetsExclReserveCenBankFcSt => "U.S. debt assets except reserve assets; central bank; foreign currency; short term",
+            Self::DebtAssetsExclReserveCenBankLt => "U.S. debt assets except reserve assets; central bank; long term",
+            Self::DebtAssetsExclReserveCenBankOthFc => "U.S. debt assets except reserve assets; central bank; other foreign currency",
+            Self::DebtAssetsExclReserveCenBankOthFcLt => "U.S. debt assets except reserve assets; central bank; other foreign currency; long term",
+            Self::DebtAssetsExclReserveCenBankOthFcSt => "U.S. debt assets except reserve assets; central bank; other foreign currency; short term",
+            Self::DebtAssetsExclReserveCenBankSt => "U.S. debt assets except reserve assets; central bank; short term",
+            Self::DebtAssetsExclReserveCenBankUsd => "U.S. debt assets except reserve assets; central bank; U.S. dollar",
+            Self::DebtAssetsExclReserveCenBankUsdLt => "U.S. debt assets except reserve assets; central bank; U.S. dollar; long term",
+            Self::DebtAssetsExclReserveCenBankUsdSt => "U.S. debt assets except reserve assets; central bank; U.S. dollar; short term",
+            Self::DebtAssetsExclReserveCenBankYen => "U.S. debt assets except reserve assets; central bank; yen",
+            Self::DebtAssetsExclReserveCenBankYenLt => "U.S. debt assets except reserve assets; central bank; yen; long term",
+            Self::DebtAssetsExclReserveCenBankYenSt => "U.S. debt assets except reserve assets; central bank; yen; short term",
+            Self::DebtAssetsExclReserveDepExclCenBank => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank",
+            Self::DebtAssetsExclReserveDepExclCenBankEuro => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; euro",
+            Self::DebtAssetsExclReserveDepExclCenBankEuroLt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; euro; long term",
+            Self::DebtAssetsExclReserveDepExclCenBankEuroSt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; euro; short term",
+            Self::DebtAssetsExclReserveDepExclCenBankFc => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; foreign currency",
+            Self::DebtAssetsExclReserveDepExclCenBankFcLt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; foreign currency; long term",
+            Self::DebtAssetsExclReserveDepExclCenBankFcSt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; foreign currency; short term",
+            Self::DebtAssetsExclReserveDepExclCenBankLt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; long term",
+            Self::DebtAssetsExclReserveDepExclCenBankOthFc => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; other foreign currency",
+            Self::DebtAssetsExclReserveDepExclCenBankOthFcLt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; other foreign currency; long term",
+            Self::DebtAssetsExclReserveDepExclCenBankOthFcSt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; other foreign currency; short term",
+            Self::DebtAssetsExclReserveDepExclCenBankSt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; short term",
+            Self::DebtAssetsExclReserveDepExclCenBankUsd => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; U.S. dollar",
+            Self::DebtAssetsExclReserveDepExclCenBankUsdLt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; U.S. dollar; long term",
+            Self::DebtAssetsExclReserveDepExclCenBankUsdSt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; U.S. dollar; short term",
+            Self::DebtAssetsExclReserveDepExclCenBankYen => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; yen",
+            Self::DebtAssetsExclReserveDepExclCenBankYenLt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; yen; long term",
+            Self::DebtAssetsExclReserveDepExclCenBankYenSt => "U.S. debt assets except reserve assets; deposit-taking institutions except central bank; yen; short term",
             Self::DebtAssetsExclReserveEuro => "U.S. debt assets except reserve assets; euro",
-            Self::DebtAssetsExclReserveEuroLt => {
-                "U.S. debt assets except reserve assets; euro; long term"
-            }
-            Self::DebtAssetsExclReserveEuroSt => {
-                "U.S. debt assets except reserve assets; euro; short term"
-            }
-            Self::DebtAssetsExclReserveFc => {
-                "U.S. debt assets except reserve assets; foreign currency"
-            }
-            Self::DebtAssetsExclReserveFcLt => {
-                "U.S. debt assets except reserve assets; foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveFcSt => {
-                "U.S. debt assets except reserve assets; foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveGenGovt => {
-                "U.S. debt assets except reserve assets; general government"
-            }
-            Self::DebtAssetsExclReserveGenGovtEuro => {
-                "U.S. debt assets except reserve assets; general government; euro"
-            }
-            Self::DebtAssetsExclReserveGenGovtEuroLt => {
-                "U.S. debt assets except reserve assets; general government; euro; long term"
-            }
-            Self::DebtAssetsExclReserveGenGovtEuroSt => {
-                "U.S. debt assets except reserve assets; general government; euro; short term"
-            }
-            Self::DebtAssetsExclReserveGenGovtFc => {
-                "U.S. debt assets except reserve assets; general government; foreign currency"
-            }
-            Self::DebtAssetsExclReserveGenGovtFcLt => {
-                "U.S. debt assets except reserve assets; general government; foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveGenGovtFcSt => {
-                "U.S. debt assets except reserve assets; general government; foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveGenGovtLt => {
-                "U.S. debt assets except reserve assets; general government; long term"
-            }
-            Self::DebtAssetsExclReserveGenGovtOthFc => {
-                "U.S. debt assets except reserve assets; general government; other foreign currency"
-            }
-            Self::DebtAssetsExclReserveGenGovtOthFcLt => {
-                "U.S. debt assets except reserve assets; general government; other foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveGenGovtOthFcSt => {
-                "U.S. debt assets except reserve assets; general government; other foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveGenGovtSt => {
-                "U.S. debt assets except reserve assets; general government; short term"
-            }
-            Self::DebtAssetsExclReserveGenGovtUsd => {
-                "U.S. debt assets except reserve assets; general government; U.S. dollar"
-            }
-            Self::DebtAssetsExclReserveGenGovtUsdLt => {
-                "U.S. debt assets except reserve assets; general government; U.S. dollar; long term"
-            }
-            Self::DebtAssetsExclReserveGenGovtUsdSt => {
-                "U.S. debt assets except reserve assets; general government; U.S. dollar; short term"
-            }
-            Self::DebtAssetsExclReserveGenGovtYen => {
-                "U.S. debt assets except reserve assets; general government; yen"
-            }
-            Self::DebtAssetsExclReserveGenGovtYenLt => {
-                "U.S. debt assets except reserve assets; general government; yen; long term"
-            }
-            Self::DebtAssetsExclReserveGenGovtYenSt => {
-                "U.S. debt assets except reserve assets; general government; yen; short term"
-            }
+            Self::DebtAssetsExclReserveEuroLt => "U.S. debt assets except reserve assets; euro; long term",
+            Self::DebtAssetsExclReserveEuroSt => "U.S. debt assets except reserve assets; euro; short term",
+            Self::DebtAssetsExclReserveFc => "U.S. debt assets except reserve assets; foreign currency",
+            Self::DebtAssetsExclReserveFcLt => "U.S. debt assets except reserve assets; foreign currency; long term",
+            Self::DebtAssetsExclReserveFcSt => "U.S. debt assets except reserve assets; foreign currency; short term",
+            Self::DebtAssetsExclReserveGenGovt => "U.S. debt assets except reserve assets; general government",
+            Self::DebtAssetsExclReserveGenGovtEuro => "U.S. debt assets except reserve assets; general government; euro",
+            Self::DebtAssetsExclReserveGenGovtEuroLt => "U.S. debt assets except reserve assets; general government; euro; long term",
+            Self::DebtAssetsExclReserveGenGovtEuroSt => "U.S. debt assets except reserve assets; general government; euro; short term",
+            Self::DebtAssetsExclReserveGenGovtFc => "U.S. debt assets except reserve assets; general government; foreign currency",
+            Self::DebtAssetsExclReserveGenGovtFcLt => "U.S. debt assets except reserve assets; general government; foreign currency; long term",
+            Self::DebtAssetsExclReserveGenGovtFcSt => "U.S. debt assets except reserve assets; general government; foreign currency; short term",
+            Self::DebtAssetsExclReserveGenGovtLt => "U.S. debt assets except reserve assets; general government; long term",
+            Self::DebtAssetsExclReserveGenGovtOthFc => "U.S. debt assets except reserve assets; general government; other foreign currency",
+            Self::DebtAssetsExclReserveGenGovtOthFcLt => "U.S. debt assets except reserve assets; general government; other foreign currency; long term",
+            Self::DebtAssetsExclReserveGenGovtOthFcSt => "U.S. debt assets except reserve assets; general government; other foreign currency; short term",
+            Self::DebtAssetsExclReserveGenGovtSt => "U.S. debt assets except reserve assets; general government; short term",
+            Self::DebtAssetsExclReserveGenGovtUsd => "U.S. debt assets except reserve assets; general government; U.S. dollar",
+            Self::DebtAssetsExclReserveGenGovtUsdLt => "U.S. debt assets except reserve assets; general government; U.S. dollar; long term",
+            Self::DebtAssetsExclReserveGenGovtUsdSt => "U.S. debt assets except reserve assets; general government; U.S. dollar; short term",
+            Self::DebtAssetsExclReserveGenGovtYen => "U.S. debt assets except reserve assets; general government; yen",
+            Self::DebtAssetsExclReserveGenGovtYenLt => "U.S. debt assets except reserve assets; general government; yen; long term",
+            Self::DebtAssetsExclReserveGenGovtYenSt => "U.S. debt assets except reserve assets; general government; yen; short term",
             Self::DebtAssetsExclReserveLt => "U.S. debt assets except reserve assets; long term",
-            Self::DebtAssetsExclReserveNonFinExclGenGovt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtEuro => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; euro"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtEuroLt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; euro; long term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtEuroSt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; euro; short term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtFc => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; foreign currency"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtFcLt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtFcSt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtLt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; long term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtOthFc => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; other foreign currency"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtOthFcLt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; other foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtOthFcSt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; other foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtSt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; short term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtUsd => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; U.S. dollar"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtUsdLt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; U.S. dollar; long term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtUsdSt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; U.S. dollar; short term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtYen => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; yen"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtYenLt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; yen; long term"
-            }
-            Self::DebtAssetsExclReserveNonFinExclGenGovtYenSt => {
-                "U.S. debt assets except reserve assets; nonfinancial institutions except general government; yen; short term"
-            }
-            Self::DebtAssetsExclReserveOthFc => {
-                "U.S. debt assets except reserve assets; other foreign currency"
-            }
-            Self::DebtAssetsExclReserveOthFcLt => {
-                "U.S. debt assets except reserve assets; other foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveOthFcSt => {
-                "U.S. debt assets except reserve assets; other foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveOthFin => {
-                "U.S. debt assets except reserve assets; other financial institutions"
-            }
-            Self::DebtAssetsExclReserveOthFinEuro => {
-                "U.S. debt assets except reserve assets; other financial institutions; euro"
-            }
-            Self::DebtAssetsExclReserveOthFinEuroLt => {
-                "U.S. debt assets except reserve assets; other financial institutions; euro; long term"
-            }
-            Self::DebtAssetsExclReserveOthFinEuroSt => {
-                "U.S. debt assets except reserve assets; other financial institutions; euro; short term"
-            }
-            Self::DebtAssetsExclReserveOthFinFc => {
-                "U.S. debt assets except reserve assets; other financial institutions; foreign currency"
-            }
-            Self::DebtAssetsExclReserveOthFinFcLt => {
-                "U.S. debt assets except reserve assets; other financial institutions; foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveOthFinFcSt => {
-                "U.S. debt assets except reserve assets; other financial institutions; foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveOthFinLt => {
-                "U.S. debt assets except reserve assets; other financial institutions; long term"
-            }
-            Self::DebtAssetsExclReserveOthFinOthFc => {
-                "U.S. debt assets except reserve assets; other financial institutions; other foreign currency"
-            }
-            Self::DebtAssetsExclReserveOthFinOthFcLt => {
-                "U.S. debt assets except reserve assets; other financial institutions; other foreign currency; long term"
-            }
-            Self::DebtAssetsExclReserveOthFinOthFcSt => {
-                "U.S. debt assets except reserve assets; other financial institutions; other foreign currency; short term"
-            }
-            Self::DebtAssetsExclReserveOthFinSt => {
-                "U.S. debt assets except reserve assets; other financial institutions; short term"
-            }
-            Self::DebtAssetsExclReserveOthFinUsd => {
-                "U.S. debt assets except reserve assets; other financial institutions; U.S. dollar"
-            }
-            Self::DebtAssetsExclReserveOthFinUsdLt => {
-                "U.S. debt assets except reserve assets; other financial institutions; U.S. dollar; long term"
-            }
-            Self::DebtAssetsExclReserveOthFinUsdSt => {
-                "U.S. debt assets except reserve assets; other financial institutions; U.S. dollar; short term"
-            }
-            Self::DebtAssetsExclReserveOthFinYen => {
-                "U.S. debt assets except reserve assets; other financial institutions; yen"
-            }
-            Self::DebtAssetsExclReserveOthFinYenLt => {
-                "U.S. debt assets except reserve assets; other financial institutions; yen; long term"
-            }
-            Self::DebtAssetsExclReserveOthFinYenSt => {
-                "U.S. debt assets except reserve assets; other financial institutions; yen; short term"
-            }
+            Self::DebtAssetsExclReserveNonFinExclGenGovt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtEuro => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; euro",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtEuroLt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; euro; long term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtEuroSt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; euro; short term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtFc => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; foreign currency",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtFcLt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; foreign currency; long term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtFcSt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; foreign currency; short term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtLt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; long term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtOthFc => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; other foreign currency",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtOthFcLt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; other foreign currency; long term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtOthFcSt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; other foreign currency; short term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtSt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; short term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtUsd => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; U.S. dollar",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtUsdLt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; U.S. dollar; long term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtUsdSt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; U.S. dollar; short term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtYen => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; yen",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtYenLt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; yen; long term",
+            Self::DebtAssetsExclReserveNonFinExclGenGovtYenSt => "U.S. debt assets except reserve assets; nonfinancial institutions except general government; yen; short term",
+            Self::DebtAssetsExclReserveOthFc => "U.S. debt assets except reserve assets; other foreign currency",
+            Self::DebtAssetsExclReserveOthFcLt => "U.S. debt assets except reserve assets; other foreign currency; long term",
+            Self::DebtAssetsExclReserveOthFcSt => "U.S. debt assets except reserve assets; other foreign currency; short term",
+            Self::DebtAssetsExclReserveOthFin => "U.S. debt assets except reserve assets; other financial institutions",
+            Self::DebtAssetsExclReserveOthFinEuro => "U.S. debt assets except reserve assets; other financial institutions; euro",
+            Self::DebtAssetsExclReserveOthFinEuroLt => "U.S. debt assets except reserve assets; other financial institutions; euro; long term",
+            Self::DebtAssetsExclReserveOthFinEuroSt => "U.S. debt assets except reserve assets; other financial institutions; euro; short term",
+            Self::DebtAssetsExclReserveOthFinFc => "U.S. debt assets except reserve assets; other financial institutions; foreign currency",
+            Self::DebtAssetsExclReserveOthFinFcLt => "U.S. debt assets except reserve assets; other financial institutions; foreign currency; long term",
+            Self::DebtAssetsExclReserveOthFinFcSt => "U.S. debt assets except reserve assets; other financial institutions; foreign currency; short term",
+            Self::DebtAssetsExclReserveOthFinLt => "U.S. debt assets except reserve assets; other financial institutions; long term",
+            Self::DebtAssetsExclReserveOthFinOthFc => "U.S. debt assets except reserve assets; other financial institutions; other foreign currency",
+            Self::DebtAssetsExclReserveOthFinOthFcLt => "U.S. debt assets except reserve assets; other financial institutions; other foreign currency; long term",
+            Self::DebtAssetsExclReserveOthFinOthFcSt => "U.S. debt assets except reserve assets; other financial institutions; other foreign currency; short term",
+            Self::DebtAssetsExclReserveOthFinSt => "U.S. debt assets except reserve assets; other financial institutions; short term",
+            Self::DebtAssetsExclReserveOthFinUsd => "U.S. debt assets except reserve assets; other financial institutions; U.S. dollar",
+            Self::DebtAssetsExclReserveOthFinUsdLt => "U.S. debt assets except reserve assets; other financial institutions; U.S. dollar; long term",
+            Self::DebtAssetsExclReserveOthFinUsdSt => "U.S. debt assets except reserve assets; other financial institutions; U.S. dollar; short term",
+            Self::DebtAssetsExclReserveOthFinYen => "U.S. debt assets except reserve assets; other financial institutions; yen",
+            Self::DebtAssetsExclReserveOthFinYenLt => "U.S. debt assets except reserve assets; other financial institutions; yen; long term",
+            Self::DebtAssetsExclReserveOthFinYenSt => "U.S. debt assets except reserve assets; other financial institutions; yen; short term",
             Self::DebtAssetsExclReserveSt => "U.S. debt assets except reserve assets; short term",
             Self::DebtAssetsExclReserveUsd => "U.S. debt assets except reserve assets; U.S. dollar",
-            Self::DebtAssetsExclReserveUsdLt => {
-                "U.S. debt assets except reserve assets; U.S. dollar; long term"
-            }
-            Self::DebtAssetsExclReserveUsdSt => {
-                "U.S. debt assets except reserve assets; U.S. dollar; short term"
-            }
+            Self::DebtAssetsExclReserveUsdLt => "U.S. debt assets except reserve assets; U.S. dollar; long term",
+            Self::DebtAssetsExclReserveUsdSt => "U.S. debt assets except reserve assets; U.S. dollar; short term",
             Self::DebtAssetsExclReserveYen => "U.S. debt assets except reserve assets; yen",
-            Self::DebtAssetsExclReserveYenLt => {
-                "U.S. debt assets except reserve assets; yen; long term"
-            }
-            Self::DebtAssetsExclReserveYenSt => {
-                "U.S. debt assets except reserve assets; yen; short term"
-            }
+            Self::DebtAssetsExclReserveYenLt => "U.S. debt assets except reserve assets; yen; long term",
+            Self::DebtAssetsExclReserveYenSt => "U.S. debt assets except reserve assets; yen; short term",
             Self::DebtLiabs => "U.S. debt liabilities",
             Self::DebtLiabsCenBank => "U.S. debt liabilities; central bank",
             Self::DebtLiabsCenBankEuro => "U.S. debt liabilities; central bank; euro",
             Self::DebtLiabsCenBankEuroLt => "U.S. debt liabilities; central bank; euro; long term",
             Self::DebtLiabsCenBankEuroSt => "U.S. debt liabilities; central bank; euro; short term",
             Self::DebtLiabsCenBankFc => "U.S. debt liabilities; central bank; foreign currency",
-            Self::DebtLiabsCenBankFcLt => {
-                "U.S. debt liabilities; central bank; foreign currency; long term"
-            }
-            Self::DebtLiabsCenBankFcSt => {
-                "U.S. debt liabilities; central bank; foreign currency; short term"
-            }
+            Self::DebtLiabsCenBankFcLt => "U.S. debt liabilities; central bank; foreign currency; long term",
+            Self::DebtLiabsCenBankFcSt => "U.S. debt liabilities; central bank; foreign currency; short term",
             Self::DebtLiabsCenBankLt => "U.S. debt liabilities; central bank; long term",
-            Self::DebtLiabsCenBankOthFc => {
-                "U.S. debt liabilities; central bank; other foreign currency"
-            }
-            Self::DebtLiabsCenBankOthFcLt => {
-                "U.S. debt liabilities; central bank; other foreign currency; long term"
-            }
-            Self::DebtLiabsCenBankOthFcSt => {
-                "U.S. debt liabilities; central bank; other foreign currency; short term"
-            }
+            Self::DebtLiabsCenBankOthFc => "U.S. debt liabilities; central bank; other foreign currency",
+            Self::DebtLiabsCenBankOthFcLt => "U.S. debt liabilities; central bank; other foreign currency; long term",
+            Self::DebtLiabsCenBankOthFcSt => "U.S. debt liabilities; central bank; other foreign currency; short term",
             Self::DebtLiabsCenBankSt => "U.S. debt liabilities; central bank; short term",
             Self::DebtLiabsCenBankUsd => "U.S. debt liabilities; central bank; U.S. dollar",
-            Self::DebtLiabsCenBankUsdLt => {
-                "U.S. debt liabilities; central bank; U.S. dollar; long term"
-            }
-            Self::DebtLiabsCenBankUsdSt => {
-                "U.S. debt liabilities; central bank; U.S. dollar; short term"
-            }
+            Self::DebtLiabsCenBankUsdLt => "U.S. debt liabilities; central bank; U.S. dollar; long term",
+            Self::DebtLiabsCenBankUsdSt => "U.S. debt liabilities; central bank; U.S. dollar; short term",
             Self::DebtLiabsCenBankYen => "U.S. debt liabilities; central bank; yen",
             Self::DebtLiabsCenBankYenLt => "U.S. debt liabilities; central bank; yen; long term",
             Self::DebtLiabsCenBankYenSt => "U.S. debt liabilities; central bank; yen; short term",
-            Self::DebtLiabsDepExclCenBank => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank"
-            }
-            Self::DebtLiabsDepExclCenBankEuro => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; euro"
-            }
-            Self::DebtLiabsDepExclCenBankEuroLt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; euro; long term"
-            }
-            Self::DebtLiabsDepExclCenBankEuroSt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; euro; short term"
-            }
-            Self::DebtLiabsDepExclCenBankFc => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; foreign currency"
-            }
-            Self::DebtLiabsDepExclCenBankFcLt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; foreign currency; long term"
-            }
-            Self::DebtLiabsDepExclCenBankFcSt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; foreign currency; short term"
-            }
-            Self::DebtLiabsDepExclCenBankLt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; long term"
-            }
-            Self::DebtLiabsDepExclCenBankOthFc => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; other foreign currency"
-            }
-            Self::DebtLiabsDepExclCenBankOthFcLt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; other foreign currency; long term"
-            }
-            Self::DebtLiabsDepExclCenBankOthFcSt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; other foreign currency; short term"
-            }
-            Self::DebtLiabsDepExclCenBankSt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; short term"
-            }
-            Self::DebtLiabsDepExclCenBankUsd => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; U.S. dollar"
-            }
-            Self::DebtLiabsDepExclCenBankUsdLt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; U.S. dollar; long term"
-            }
-            Self::DebtLiabsDepExclCenBankUsdSt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; U.S. dollar; short term"
-            }
-            Self::DebtLiabsDepExclCenBankYen => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; yen"
-            }
-            Self::DebtLiabsDepExclCenBankYenLt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; yen; long term"
-            }
-            Self::DebtLiabsDepExclCenBankYenSt => {
-                "U.S. debt liabilities; deposit-taking institutions except central bank; yen; short term"
-            }
+            Self::DebtLiabsDepExclCenBank => "U.S. debt liabilities; deposit-taking institutions except central bank",
+            Self::DebtLiabsDepExclCenBankEuro => "U.S. debt liabilities; deposit-taking institutions except central bank; euro",
+            Self::DebtLiabsDepExclCenBankEuroLt => "U.S. debt liabilities; deposit-taking institutions except central bank; euro; long term",
+            Self::DebtLiabsDepExclCenBankEuroSt => "U.S. debt liabilities; deposit-taking institutions except central bank; euro; short term",
+            Self::DebtLiabsDepExclCenBankFc => "U.S. debt liabilities; deposit-taking institutions except central bank; foreign currency",
+            Self::DebtLiabsDepExclCenBankFcLt => "U.S. debt liabilities; deposit-taking institutions except central bank; foreign currency; long term",
+            Self::DebtLiabsDepExclCenBankFcSt => "U.S. debt liabilities; deposit-taking institutions except central bank; foreign currency; short term",
+            Self::DebtLiabsDepExclCenBankLt => "U.S. debt liabilities; deposit-taking institutions except central bank; long term",
+            Self::DebtLiabsDepExclCenBankOthFc => "U.S. debt liabilities; deposit-taking institutions except central bank; other foreign currency",
+            Self::DebtLiabsDepExclCenBankOthFcLt => "U.S. debt liabilities; deposit-taking institutions except central bank; other foreign currency; long term",
+            Self::DebtLiabsDepExclCenBankOthFcSt => "U.S. debt liabilities; deposit-taking institutions except central bank; other foreign currency; short term",
+            Self::DebtLiabsDepExclCenBankSt => "U.S. debt liabilities; deposit-taking institutions except central bank; short term",
+            Self::DebtLiabsDepExclCenBankUsd => "U.S. debt liabilities; deposit-taking institutions except central bank; U.S. dollar",
+            Self::DebtLiabsDepExclCenBankUsdLt => "U.S. debt liabilities; deposit-taking institutions except central bank; U.S. dollar; long term",
+            Self::DebtLiabsDepExclCenBankUsdSt => "U.S. debt liabilities; deposit-taking institutions except central bank; U.S. dollar; short term",
+            Self::DebtLiabsDepExclCenBankYen => "U.S. debt liabilities; deposit-taking institutions except central bank; yen",
+            Self::DebtLiabsDepExclCenBankYenLt => "U.S. debt liabilities; deposit-taking institutions except central bank; yen; long term",
+            Self::DebtLiabsDepExclCenBankYenSt => "U.S. debt liabilities; deposit-taking institutions except central bank; yen; short term",
             Self::DebtLiabsEuro => "U.S. debt liabilities; euro",
             Self::DebtLiabsEuroLt => "U.S. debt liabilities; euro; long term",
             Self::DebtLiabsEuroSt => "U.S. debt liabilities; euro; short term",
@@ -1318,154 +1066,62 @@ impl Investment {
             Self::DebtLiabsFcSt => "U.S. debt liabilities; foreign currency; short term",
             Self::DebtLiabsGenGovt => "U.S. debt liabilities; general government",
             Self::DebtLiabsGenGovtEuro => "U.S. debt liabilities; general government; euro",
-            Self::DebtLiabsGenGovtEuroLt => {
-                "U.S. debt liabilities; general government; euro; long term"
-            }
-            Self::DebtLiabsGenGovtEuroSt => {
-                "U.S. debt liabilities; general government; euro; short term"
-            }
-            Self::DebtLiabsGenGovtFc => {
-                "U.S. debt liabilities; general government; foreign currency"
-            }
-            Self::DebtLiabsGenGovtFcLt => {
-                "U.S. debt liabilities; general government; foreign currency; long term"
-            }
-            Self::DebtLiabsGenGovtFcSt => {
-                "U.S. debt liabilities; general government; foreign currency; short term"
-            }
+            Self::DebtLiabsGenGovtEuroLt => "U.S. debt liabilities; general government; euro; long term",
+            Self::DebtLiabsGenGovtEuroSt => "U.S. debt liabilities; general government; euro; short term",
+            Self::DebtLiabsGenGovtFc => "U.S. debt liabilities; general government; foreign currency",
+            Self::DebtLiabsGenGovtFcLt => "U.S. debt liabilities; general government; foreign currency; long term",
+            Self::DebtLiabsGenGovtFcSt => "U.S. debt liabilities; general government; foreign currency; short term",
             Self::DebtLiabsGenGovtLt => "U.S. debt liabilities; general government; long term",
-            Self::DebtLiabsGenGovtOthFc => {
-                "U.S. debt liabilities; general government; other foreign currency"
-            }
-            Self::DebtLiabsGenGovtOthFcLt => {
-                "U.S. debt liabilities; general government; other foreign currency; long term"
-            }
-            Self::DebtLiabsGenGovtOthFcSt => {
-                "U.S. debt liabilities; general government; other foreign currency; short term"
-            }
+            Self::DebtLiabsGenGovtOthFc => "U.S. debt liabilities; general government; other foreign currency",
+            Self::DebtLiabsGenGovtOthFcLt => "U.S. debt liabilities; general government; other foreign currency; long term",
+            Self::DebtLiabsGenGovtOthFcSt => "U.S. debt liabilities; general government; other foreign currency; short term",
             Self::DebtLiabsGenGovtSt => "U.S. debt liabilities; general government; short term",
             Self::DebtLiabsGenGovtUsd => "U.S. debt liabilities; general government; U.S. dollar",
-            Self::DebtLiabsGenGovtUsdLt => {
-                "U.S. debt liabilities; general government; U.S. dollar; long term"
-            }
-            Self::DebtLiabsGenGovtUsdSt => {
-                "U.S. debt liabilities; general government; U.S. dollar; short term"
-            }
+            Self::DebtLiabsGenGovtUsdLt => "U.S. debt liabilities; general government; U.S. dollar; long term",
+            Self::DebtLiabsGenGovtUsdSt => "U.S. debt liabilities; general government; U.S. dollar; short term",
             Self::DebtLiabsGenGovtYen => "U.S. debt liabilities; general government; yen",
-            Self::DebtLiabsGenGovtYenLt => {
-                "U.S. debt liabilities; general government; yen; long term"
-            }
-            Self::DebtLiabsGenGovtYenSt => {
-                "U.S. debt liabilities; general government; yen; short term"
-            }
+            Self::DebtLiabsGenGovtYenLt => "U.S. debt liabilities; general government; yen; long term",
+            Self::DebtLiabsGenGovtYenSt => "U.S. debt liabilities; general government; yen; short term",
             Self::DebtLiabsLt => "U.S. debt liabilities; long term",
-            Self::DebtLiabsNonFinExclGenGovt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government"
-            }
-            Self::DebtLiabsNonFinExclGenGovtEuro => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; euro"
-            }
-            Self::DebtLiabsNonFinExclGenGovtEuroLt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; euro; long term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtEuroSt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; euro; short term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtFc => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; foreign currency"
-            }
-            Self::DebtLiabsNonFinExclGenGovtFcLt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; foreign currency; long term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtFcSt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; foreign currency; short term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtLt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; long term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtOthFc => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; other foreign currency"
-            }
-            Self::DebtLiabsNonFinExclGenGovtOthFcLt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; other foreign currency; long term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtOthFcSt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; other foreign currency; short term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtSt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; short term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtUsd => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; U.S. dollar"
-            }
-            Self::DebtLiabsNonFinExclGenGovtUsdLt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; U.S. dollar; long term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtUsdSt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; U.S. dollar; short term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtYen => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; yen"
-            }
-            Self::DebtLiabsNonFinExclGenGovtYenLt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; yen; long term"
-            }
-            Self::DebtLiabsNonFinExclGenGovtYenSt => {
-                "U.S. debt liabilities; nonfinancial institutions except general government; yen; short term"
-            }
+            Self::DebtLiabsNonFinExclGenGovt => "U.S. debt liabilities; nonfinancial institutions except general government",
+            Self::DebtLiabsNonFinExclGenGovtEuro => "U.S. debt liabilities; nonfinancial institutions except general government; euro",
+            Self::DebtLiabsNonFinExclGenGovtEuroLt => "U.S. debt liabilities; nonfinancial institutions except general government; euro; long term",
+            Self::DebtLiabsNonFinExclGenGovtEuroSt => "U.S. debt liabilities; nonfinancial institutions except general government; euro; short term",
+            Self::DebtLiabsNonFinExclGenGovtFc => "U.S. debt liabilities; nonfinancial institutions except general government; foreign currency",
+            Self::DebtLiabsNonFinExclGenGovtFcLt => "U.S. debt liabilities; nonfinancial institutions except general government; foreign currency; long term",
+            Self::DebtLiabsNonFinExclGenGovtFcSt => "U.S. debt liabilities; nonfinancial institutions except general government; foreign currency; short term",
+            Self::DebtLiabsNonFinExclGenGovtLt => "U.S. debt liabilities; nonfinancial institutions except general government; long term",
+            Self::DebtLiabsNonFinExclGenGovtOthFc => "U.S. debt liabilities; nonfinancial institutions except general government; other foreign currency",
+            Self::DebtLiabsNonFinExclGenGovtOthFcLt => "U.S. debt liabilities; nonfinancial institutions except general government; other foreign currency; long term",
+            Self::DebtLiabsNonFinExclGenGovtOthFcSt => "U.S. debt liabilities; nonfinancial institutions except general government; other foreign currency; short term",
+            Self::DebtLiabsNonFinExclGenGovtSt => "U.S. debt liabilities; nonfinancial institutions except general government; short term",
+            Self::DebtLiabsNonFinExclGenGovtUsd => "U.S. debt liabilities; nonfinancial institutions except general government; U.S. dollar",
+            Self::DebtLiabsNonFinExclGenGovtUsdLt => "U.S. debt liabilities; nonfinancial institutions except general government; U.S. dollar; long term",
+            Self::DebtLiabsNonFinExclGenGovtUsdSt => "U.S. debt liabilities; nonfinancial institutions except general government; U.S. dollar; short term",
+            Self::DebtLiabsNonFinExclGenGovtYen => "U.S. debt liabilities; nonfinancial institutions except general government; yen",
+            Self::DebtLiabsNonFinExclGenGovtYenLt => "U.S. debt liabilities; nonfinancial institutions except general government; yen; long term",
+            Self::DebtLiabsNonFinExclGenGovtYenSt => "U.S. debt liabilities; nonfinancial institutions except general government; yen; short term",
             Self::DebtLiabsOthFc => "U.S. debt liabilities; other foreign currency",
             Self::DebtLiabsOthFcLt => "U.S. debt liabilities; other foreign currency; long term",
             Self::DebtLiabsOthFcSt => "U.S. debt liabilities; other foreign currency; short term",
             Self::DebtLiabsOthFin => "U.S. debt liabilities; other financial institutions",
-            Self::DebtLiabsOthFinEuro => {
-                "U.S. debt liabilities; other financial institutions; euro"
-            }
-            Self::DebtLiabsOthFinEuroLt => {
-                "U.S. debt liabilities; other financial institutions; euro; long term"
-            }
-            Self::DebtLiabsOthFinEuroSt => {
-                "U.S. debt liabilities; other financial institutions; euro; short term"
-            }
-            Self::DebtLiabsOthFinFc => {
-                "U.S. debt liabilities; other financial institutions; foreign currency"
-            }
-            Self::DebtLiabsOthFinFcLt => {
-                "U.S. debt liabilities; other financial institutions; foreign currency; long term"
-            }
-            Self::DebtLiabsOthFinFcSt => {
-                "U.S. debt liabilities; other financial institutions; foreign currency; short term"
-            }
-            Self::DebtLiabsOthFinLt => {
-                "U.S. debt liabilities; other financial institutions; long term"
-            }
-            Self::DebtLiabsOthFinOthFc => {
-                "U.S. debt liabilities; other financial institutions; other foreign currency"
-            }
-            Self::DebtLiabsOthFinOthFcLt => {
-                "U.S. debt liabilities; other financial institutions; other foreign currency; long term"
-            }
-            Self::DebtLiabsOthFinOthFcSt => {
-                "U.S. debt liabilities; other financial institutions; other foreign currency; short term"
-            }
-            Self::DebtLiabsOthFinSt => {
-                "U.S. debt liabilities; other financial institutions; short term"
-            }
-            Self::DebtLiabsOthFinUsd => {
-                "U.S. debt liabilities; other financial institutions; U.S. dollar"
-            }
-            Self::DebtLiabsOthFinUsdLt => {
-                "U.S. debt liabilities; other financial institutions; U.S. dollar; long term"
-            }
-            Self::DebtLiabsOthFinUsdSt => {
-                "U.S. debt liabilities; other financial institutions; U.S. dollar; short term"
-            }
+            Self::DebtLiabsOthFinEuro => "U.S. debt liabilities; other financial institutions; euro",
+            Self::DebtLiabsOthFinEuroLt => "U.S. debt liabilities; other financial institutions; euro; long term",
+            Self::DebtLiabsOthFinEuroSt => "U.S. debt liabilities; other financial institutions; euro; short term",
+            Self::DebtLiabsOthFinFc => "U.S. debt liabilities; other financial institutions; foreign currency",
+            Self::DebtLiabsOthFinFcLt => "U.S. debt liabilities; other financial institutions; foreign currency; long term",
+            Self::DebtLiabsOthFinFcSt => "U.S. debt liabilities; other financial institutions; foreign currency; short term",
+            Self::DebtLiabsOthFinLt => "U.S. debt liabilities; other financial institutions; long term",
+            Self::DebtLiabsOthFinOthFc => "U.S. debt liabilities; other financial institutions; other foreign currency",
+            Self::DebtLiabsOthFinOthFcLt => "U.S. debt liabilities; other financial institutions; other foreign currency; long term",
+            Self::DebtLiabsOthFinOthFcSt => "U.S. debt liabilities; other financial institutions; other foreign currency; short term",
+            Self::DebtLiabsOthFinSt => "U.S. debt liabilities; other financial institutions; short term",
+            Self::DebtLiabsOthFinUsd => "U.S. debt liabilities; other financial institutions; U.S. dollar",
+            Self::DebtLiabsOthFinUsdLt => "U.S. debt liabilities; other financial institutions; U.S. dollar; long term",
+            Self::DebtLiabsOthFinUsdSt => "U.S. debt liabilities; other financial institutions; U.S. dollar; short term",
             Self::DebtLiabsOthFinYen => "U.S. debt liabilities; other financial institutions; yen",
-            Self::DebtLiabsOthFinYenLt => {
-                "U.S. debt liabilities; other financial institutions; yen; long term"
-            }
-            Self::DebtLiabsOthFinYenSt => {
-                "U.S. debt liabilities; other financial institutions; yen; short term"
-            }
+            Self::DebtLiabsOthFinYenLt => "U.S. debt liabilities; other financial institutions; yen; long term",
+            Self::DebtLiabsOthFinYenSt => "U.S. debt liabilities; other financial institutions; yen; short term",
             Self::DebtLiabsSt => "U.S. debt liabilities; short term",
             Self::DebtLiabsUsd => "U.S. debt liabilities; U.S. dollar",
             Self::DebtLiabsUsdLt => "U.S. debt liabilities; U.S. dollar; long term",
@@ -1475,480 +1131,189 @@ impl Investment {
             Self::DebtLiabsYenSt => "U.S. debt liabilities; yen; short term",
             Self::DebtSecAssets => "U.S. assets; portfolio investment; debt securities",
             Self::DebtSecLiabs => "U.S. liabilities; portfolio investment; debt securities",
-            Self::DebtSecLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; debt securities"
-            }
-            Self::DiInvAssets => {
-                "U.S. assets; direct investment at market value, asset/liability basis"
-            }
-            Self::DiInvAssetsCurrCost => {
-                "U.S. assets; direct investment at current cost, asset/liability basis"
-            }
-            Self::DiInvAssetsHistCostToMarketValueAdj => {
-                "U.S. assets; direct investment; adjustment to revalue equity from historical cost to market value"
-            }
-            Self::DiInvAssetsNonSpe => {
-                "U.S. assets; direct investment at market value, asset/liability basis; non-SPEs"
-            }
-            Self::DiInvAssetsSpe => {
-                "U.S. assets; direct investment at market value, asset/liability basis; SPEs"
-            }
-            Self::DiInvDebtInstAssets => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments"
-            }
-            Self::DiInvDebtInstAssetsEuro => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; euro"
-            }
-            Self::DiInvDebtInstAssetsEuroLt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; euro; long term"
-            }
-            Self::DiInvDebtInstAssetsEuroSt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; euro; short term"
-            }
-            Self::DiInvDebtInstAssetsFc => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; foreign currency"
-            }
-            Self::DiInvDebtInstAssetsFcLt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; foreign currency; long term"
-            }
-            Self::DiInvDebtInstAssetsFcSt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; foreign currency; short term"
-            }
-            Self::DiInvDebtInstAssetsLt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; long term"
-            }
-            Self::DiInvDebtInstAssetsNonSpe => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; non-SPEs"
-            }
-            Self::DiInvDebtInstAssetsOthFc => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; other foreign currency"
-            }
-            Self::DiInvDebtInstAssetsOthFcLt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; other foreign currency; long term"
-            }
-            Self::DiInvDebtInstAssetsOthFcSt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; other foreign currency; short term"
-            }
-            Self::DiInvDebtInstAssetsSpe => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; SPEs"
-            }
-            Self::DiInvDebtInstAssetsSt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; short term"
-            }
-            Self::DiInvDebtInstAssetsUsd => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; U.S. dollar"
-            }
-            Self::DiInvDebtInstAssetsUsdLt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; U.S. dollar; long term"
-            }
-            Self::DiInvDebtInstAssetsUsdSt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; U.S. dollar; short term"
-            }
-            Self::DiInvDebtInstAssetsYen => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; yen"
-            }
-            Self::DiInvDebtInstAssetsYenLt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; yen; long term"
-            }
-            Self::DiInvDebtInstAssetsYenSt => {
-                "U.S. assets; direct investment, asset/liability basis; debt instruments; yen; short term"
-            }
-            Self::DiInvDebtInstInward => {
-                "Inward direct investment (foreign direct investment in the United States), directional basis; debt instruments"
-            }
-            Self::DiInvDebtInstLiabs => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments"
-            }
-            Self::DiInvDebtInstLiabsEuro => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; euro"
-            }
-            Self::DiInvDebtInstLiabsEuroLt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; euro; long term"
-            }
-            Self::DiInvDebtInstLiabsEuroSt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; euro; short term"
-            }
-            Self::DiInvDebtInstLiabsFc => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; foreign currency"
-            }
-            Self::DiInvDebtInstLiabsFcLt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; foreign currency; long term"
-            }
-            Self::DiInvDebtInstLiabsFcSt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; foreign currency; short term"
-            }
-            Self::DiInvDebtInstLiabsLt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; long term"
-            }
-            Self::DiInvDebtInstLiabsNonSpe => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; non-SPEs"
-            }
-            Self::DiInvDebtInstLiabsOthFc => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; other foreign currency"
-            }
-            Self::DiInvDebtInstLiabsOthFcLt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; other foreign currency; long term"
-            }
-            Self::DiInvDebtInstLiabsOthFcSt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; other foreign currency; short term"
-            }
-            Self::DiInvDebtInstLiabsSpe => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; SPEs"
-            }
-            Self::DiInvDebtInstLiabsSt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; short term"
-            }
-            Self::DiInvDebtInstLiabsUsd => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; U.S. dollar"
-            }
-            Self::DiInvDebtInstLiabsUsdLt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; U.S. dollar; long term"
-            }
-            Self::DiInvDebtInstLiabsUsdSt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; U.S. dollar; short term"
-            }
-            Self::DiInvDebtInstLiabsYen => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; yen"
-            }
-            Self::DiInvDebtInstLiabsYenLt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; yen; long term"
-            }
-            Self::DiInvDebtInstLiabsYenSt => {
-                "U.S. liabilities; direct investment, asset/liability basis; debt instruments; yen; short term"
-            }
-            Self::DiInvDebtInstOutward => {
-                "Outward direct investment (U.S. direct investment abroad), directional basis; debt instruments"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaims => {
-                "Direct investment; debt instruments; U.S. affiliates' claims"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsByNonSpe => {
-                "U.S. non-SPE affiliates' debt asset position in their foreign parent groups"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsBySpe => {
-                "U.S. SPE affiliates' debt asset position in their foreign parent groups"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsNonSpe => {
-                "Direct investment; debt instruments; U.S. affiliates' claims; non-SPEs"
-            }
-            Self::DiInvDebtInstUsAffiliatesClaimsSpe => {
-                "Direct investment; debt instruments; U.S. affiliates' claims; SPEs"
-            }
-            Self::DiInvDebtInstUsAffiliatesLiabs => {
-                "Direct investment; debt instruments; U.S. affiliates' liabilites"
-            }
-            Self::DiInvDebtInstUsAffiliatesLiabsNonSpe => {
-                "Direct investment; debt instruments; U.S. affiliates' liabilites; non-SPEs"
-            }
-            Self::DiInvDebtInstUsAffiliatesLiabsSpe => {
-                "Direct investment; debt instruments; U.S. affiliates' liabilites; SPEs"
-            }
-            Self::DiInvDebtInstUsParentsClaims => {
-                "Direct investment; debt instruments; U.S. parents' claims"
-            }
-            Self::DiInvDebtInstUsParentsClaimsNonSpe => {
-                "Direct investment; debt instruments; U.S. parents' claims; non-SPEs"
-            }
-            Self::DiInvDebtInstUsParentsClaimsSpe => {
-                "Direct investment; debt instruments; U.S. parents' claims; SPEs"
-            }
-            Self::DiInvDebtInstUsParentsLiabs => {
-                "Direct investment; debt instruments; U.S. parents' liabilites"
-            }
-            Self::DiInvDebtInstUsParentsLiabsInNonSpe => {
-                "U.S. parents' debt liability position in their foreign non-SPE affiliates"
-            }
-            Self::DiInvDebtInstUsParentsLiabsInSpe => {
-                "U.S. parents' debt liability position in their foreign SPE affiliates"
-            }
-            Self::DiInvDebtInstUsParentsLiabsNonSpe => {
-                "Direct investment; debt instruments; U.S. parents' liabilites; non-SPEs"
-            }
-            Self::DiInvDebtInstUsParentsLiabsSpe => {
-                "Direct investment; debt instruments; U.S. parents' liabilites; SPEs"
-            }
-            Self::DiInvDirectionalBasisAdj => {
-                "Direct investment; adjustments to convert to directional basis"
-            }
+            Self::DebtSecLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; debt securities",
+            Self::DiInvAssets => "U.S. assets; direct investment at market value, asset/liability basis",
+            Self::DiInvAssetsCurrCost => "U.S. assets; direct investment at current cost, asset/liability basis",
+            Self::DiInvAssetsHistCostToMarketValueAdj => "U.S. assets; direct investment; adjustment to revalue equity from historical cost to market value",
+            Self::DiInvAssetsNonSpe => "U.S. assets; direct investment at market value, asset/liability basis; non-SPEs",
+            Self::DiInvAssetsSpe => "U.S. assets; direct investment at market value, asset/liability basis; SPEs",
+            Self::DiInvDebtInstAssets => "U.S. assets; direct investment, asset/liability basis; debt instruments",
+            Self::DiInvDebtInstAssetsEuro => "U.S. assets; direct investment, asset/liability basis; debt instruments; euro",
+            Self::DiInvDebtInstAssetsEuroLt => "U.S. assets; direct investment, asset/liability basis; debt instruments; euro; long term",
+            Self::DiInvDebtInstAssetsEuroSt => "U.S. assets; direct investment, asset/liability basis; debt instruments; euro; short term",
+            Self::DiInvDebtInstAssetsFc => "U.S. assets; direct investment, asset/liability basis; debt instruments; foreign currency",
+            Self::DiInvDebtInstAssetsFcLt => "U.S. assets; direct investment, asset/liability basis; debt instruments; foreign currency; long term",
+            Self::DiInvDebtInstAssetsFcSt => "U.S. assets; direct investment, asset/liability basis; debt instruments; foreign currency; short term",
+            Self::DiInvDebtInstAssetsLt => "U.S. assets; direct investment, asset/liability basis; debt instruments; long term",
+            Self::DiInvDebtInstAssetsNonSpe => "U.S. assets; direct investment, asset/liability basis; debt instruments; non-SPEs",
+            Self::DiInvDebtInstAssetsOthFc => "U.S. assets; direct investment, asset/liability basis; debt instruments; other foreign currency",
+            Self::DiInvDebtInstAssetsOthFcLt => "U.S. assets; direct investment, asset/liability basis; debt instruments; other foreign currency; long term",
+            Self::DiInvDebtInstAssetsOthFcSt => "U.S. assets; direct investment, asset/liability basis; debt instruments; other foreign currency; short term",
+            Self::DiInvDebtInstAssetsSpe => "U.S. assets; direct investment, asset/liability basis; debt instruments; SPEs",
+            Self::DiInvDebtInstAssetsSt => "U.S. assets; direct investment, asset/liability basis; debt instruments; short term",
+            Self::DiInvDebtInstAssetsUsd => "U.S. assets; direct investment, asset/liability basis; debt instruments; U.S. dollar",
+            Self::DiInvDebtInstAssetsUsdLt => "U.S. assets; direct investment, asset/liability basis; debt instruments; U.S. dollar; long term",
+            Self::DiInvDebtInstAssetsUsdSt => "U.S. assets; direct investment, asset/liability basis; debt instruments; U.S. dollar; short term",
+            Self::DiInvDebtInstAssetsYen => "U.S. assets; direct investment, asset/liability basis; debt instruments; yen",
+            Self::DiInvDebtInstAssetsYenLt => "U.S. assets; direct investment, asset/liability basis; debt instruments; yen; long term",
+            Self::DiInvDebtInstAssetsYenSt => "U.S. assets; direct investment, asset/liability basis; debt instruments; yen; short term",
+            Self::DiInvDebtInstInward => "Inward direct investment (foreign direct investment in the United States), directional basis; debt instruments",
+            Self::DiInvDebtInstLiabs => "U.S. liabilities; direct investment, asset/liability basis; debt instruments",
+            Self::DiInvDebtInstLiabsEuro => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; euro",
+            Self::DiInvDebtInstLiabsEuroLt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; euro; long term",
+            Self::DiInvDebtInstLiabsEuroSt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; euro; short term",
+            Self::DiInvDebtInstLiabsFc => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; foreign currency",
+            Self::DiInvDebtInstLiabsFcLt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; foreign currency; long term",
+            Self::DiInvDebtInstLiabsFcSt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; foreign currency; short term",
+            Self::DiInvDebtInstLiabsLt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; long term",
+            Self::DiInvDebtInstLiabsNonSpe => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; non-SPEs",
+            Self::DiInvDebtInstLiabsOthFc => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; other foreign currency",
+            Self::DiInvDebtInstLiabsOthFcLt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; other foreign currency; long term",
+            Self::DiInvDebtInstLiabsOthFcSt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; other foreign currency; short term",
+            Self::DiInvDebtInstLiabsSpe => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; SPEs",
+            Self::DiInvDebtInstLiabsSt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; short term",
+            Self::DiInvDebtInstLiabsUsd => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; U.S. dollar",
+            Self::DiInvDebtInstLiabsUsdLt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; U.S. dollar; long term",
+            Self::DiInvDebtInstLiabsUsdSt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; U.S. dollar; short term",
+            Self::DiInvDebtInstLiabsYen => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; yen",
+            Self::DiInvDebtInstLiabsYenLt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; yen; long term",
+            Self::DiInvDebtInstLiabsYenSt => "U.S. liabilities; direct investment, asset/liability basis; debt instruments; yen; short term",
+            Self::DiInvDebtInstOutward => "Outward direct investment (U.S. direct investment abroad), directional basis; debt instruments",
+            Self::DiInvDebtInstUsAffiliatesClaims => "Direct investment; debt instruments; U.S. affiliates' claims",
+            Self::DiInvDebtInstUsAffiliatesClaimsByNonSpe => "U.S. non-SPE affiliates' debt asset position in their foreign parent groups",
+            Self::DiInvDebtInstUsAffiliatesClaimsBySpe => "U.S. SPE affiliates' debt asset position in their foreign parent groups",
+            Self::DiInvDebtInstUsAffiliatesClaimsNonSpe => "Direct investment; debt instruments; U.S. affiliates' claims; non-SPEs",
+            Self::DiInvDebtInstUsAffiliatesClaimsSpe => "Direct investment; debt instruments; U.S. affiliates' claims; SPEs",
+            Self::DiInvDebtInstUsAffiliatesLiabs => "Direct investment; debt instruments; U.S. affiliates' liabilites",
+            Self::DiInvDebtInstUsAffiliatesLiabsNonSpe => "Direct investment; debt instruments; U.S. affiliates' liabilites; non-SPEs",
+            Self::DiInvDebtInstUsAffiliatesLiabsSpe => "Direct investment; debt instruments; U.S. affiliates' liabilites; SPEs",
+            Self::DiInvDebtInstUsParentsClaims => "Direct investment; debt instruments; U.S. parents' claims",
+            Self::DiInvDebtInstUsParentsClaimsNonSpe => "Direct investment; debt instruments; U.S. parents' claims; non-SPEs",
+            Self::DiInvDebtInstUsParentsClaimsSpe => "Direct investment; debt instruments; U.S. parents' claims; SPEs",
+            Self::DiInvDebtInstUsParentsLiabs => "Direct investment; debt instruments; U.S. parents' liabilites",
+            Self::DiInvDebtInstUsParentsLiabsInNonSpe => "U.S. parents' debt liability position in their foreign non-SPE affiliates",
+            Self::DiInvDebtInstUsParentsLiabsInSpe => "U.S. parents' debt liability position in their foreign SPE affiliates",
+            Self::DiInvDebtInstUsParentsLiabsNonSpe => "Direct investment; debt instruments; U.S. parents' liabilites; non-SPEs",
+            Self::DiInvDebtInstUsParentsLiabsSpe => "Direct investment; debt instruments; U.S. parents' liabilites; SPEs",
+            Self::DiInvDirectionalBasisAdj => "Direct investment; adjustments to convert to directional basis",
             Self::DiInvEquityAssets => "U.S. assets; direct investment at market value; equity",
-            Self::DiInvEquityAssetsCurrCost => {
-                "U.S. assets; direct investment at current cost; equity"
-            }
-            Self::DiInvEquityAssetsHistCost => {
-                "U.S. assets; direct investment at historical cost; equity"
-            }
-            Self::DiInvEquityAssetsNonSpe => {
-                "U.S. assets; direct investment at market value; equity; non-SPEs"
-            }
-            Self::DiInvEquityAssetsSpe => {
-                "U.S. assets; direct investment at market value; equity; SPEs"
-            }
-            Self::DiInvEquityInward => {
-                "U.S. liabilities; direct investment at market value; equity"
-            }
+            Self::DiInvEquityAssetsCurrCost => "U.S. assets; direct investment at current cost; equity",
+            Self::DiInvEquityAssetsHistCost => "U.S. assets; direct investment at historical cost; equity",
+            Self::DiInvEquityAssetsNonSpe => "U.S. assets; direct investment at market value; equity; non-SPEs",
+            Self::DiInvEquityAssetsSpe => "U.S. assets; direct investment at market value; equity; SPEs",
+            Self::DiInvEquityInward => "U.S. liabilities; direct investment at market value; equity",
             Self::DiInvEquityLiabs => "U.S. liabilities; direct investment at market value; equity",
-            Self::DiInvEquityLiabsCurrCost => {
-                "U.S. liabilities; direct investment at current cost; equity"
-            }
-            Self::DiInvEquityLiabsHistCost => {
-                "U.S. liabilities; direct investment at historical cost; equity"
-            }
-            Self::DiInvEquityLiabsNonSpe => {
-                "U.S. liabilities; direct investment at market value; equity; non-SPEs"
-            }
-            Self::DiInvEquityLiabsSpe => {
-                "U.S. liabilities; direct investment at market value; equity; SPEs"
-            }
-            Self::DiInvInwardCurrCost => {
-                "Inward direct investment (foreign direct investment in the United States) at current cost, directional basis"
-            }
-            Self::DiInvInwardHistCost => {
-                "Inward direct investment (foreign direct investment in the United States) at historical cost, directional basis"
-            }
-            Self::DiInvInwardMarketValue => {
-                "Inward direct investment (foreign direct investment in the United States) at market value, directional basis"
-            }
-            Self::DiInvLiabs => {
-                "U.S. liabilities; direct investment at market value, asset/liability basis"
-            }
-            Self::DiInvLiabsCurrCost => {
-                "U.S. liabilities; direct investment at current cost, asset/liability basis"
-            }
-            Self::DiInvLiabsHistCostToMarketValueAdj => {
-                "U.S. liabilities; direct investment; adjustment to revalue equity from historical cost to market value"
-            }
-            Self::DiInvLiabsNonSpe => {
-                "U.S. liabilities; direct investment at market value, asset/liability basis; non-SPEs"
-            }
-            Self::DiInvLiabsSpe => {
-                "U.S. liabilities; direct investment at market value, asset/liability basis; SPEs"
-            }
-            Self::DiInvOutwardCurrCost => {
-                "Outward direct investment (U.S. direct investment abroad) at current cost, directional basis"
-            }
-            Self::DiInvOutwardHistCost => {
-                "Outward direct investment (U.S. direct investment abroad) at historical cost, directional basis"
-            }
-            Self::DiInvOutwardMarketValue => {
-                "Outward direct investment (U.S. direct investment abroad) at market value, directional basis"
-            }
-            Self::EquityAndInvFundSharesAssets => {
-                "U.S. assets; portfolio investment; equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesLiabs => {
-                "U.S. liabilities; portfolio investment; equity and investment fund shares"
-            }
-            Self::EquityAndInvFundSharesLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; equity and investment fund shares"
-            }
+            Self::DiInvEquityLiabsCurrCost => "U.S. liabilities; direct investment at current cost; equity",
+            Self::DiInvEquityLiabsHistCost => "U.S. liabilities; direct investment at historical cost; equity",
+            Self::DiInvEquityLiabsNonSpe => "U.S. liabilities; direct investment at market value; equity; non-SPEs",
+            Self::DiInvEquityLiabsSpe => "U.S. liabilities; direct investment at market value; equity; SPEs",
+            Self::DiInvInwardCurrCost => "Inward direct investment (foreign direct investment in the United States) at current cost, directional basis",
+            Self::DiInvInwardHistCost => "Inward direct investment (foreign direct investment in the United States) at historical cost, directional basis",
+            Self::DiInvInwardMarketValue => "Inward direct investment (foreign direct investment in the United States) at market value, directional basis",
+            Self::DiInvLiabs => "U.S. liabilities; direct investment at market value, asset/liability basis",
+            Self::DiInvLiabsCurrCost => "U.S. liabilities; direct investment at current cost, asset/liability basis",
+            Self::DiInvLiabsHistCostToMarketValueAdj => "U.S. liabilities; direct investment; adjustment to revalue equity from historical cost to market value",
+            Self::DiInvLiabsNonSpe => "U.S. liabilities; direct investment at market value, asset/liability basis; non-SPEs",
+            Self::DiInvLiabsSpe => "U.S. liabilities; direct investment at market value, asset/liability basis; SPEs",
+            Self::DiInvOutwardCurrCost => "Outward direct investment (U.S. direct investment abroad) at current cost, directional basis",
+            Self::DiInvOutwardHistCost => "Outward direct investment (U.S. direct investment abroad) at historical cost, directional basis",
+            Self::DiInvOutwardMarketValue => "Outward direct investment (U.S. direct investment abroad) at market value, directional basis",
+            Self::EquityAndInvFundSharesAssets => "U.S. assets; portfolio investment; equity and investment fund shares",
+            Self::EquityAndInvFundSharesLiabs => "U.S. liabilities; portfolio investment; equity and investment fund shares",
+            Self::EquityAndInvFundSharesLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; equity and investment fund shares",
             Self::FinAssets => "U.S. assets",
             Self::FinAssetsExclFinDeriv => "U.S. assets excluding financial derivatives",
-            Self::FinDerivAssets => {
-                "U.S. assets; financial derivatives other than reserves, gross positive fair value"
-            }
-            Self::FinDerivExchTradedAssets => {
-                "U.S. assets; financial derivatives other than reserves, gross positive fair value; exchange-traded contracts"
-            }
-            Self::FinDerivExchTradedLiabs => {
-                "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; exchange-traded contracts"
-            }
-            Self::FinDerivForExAssets => {
-                "U.S. assets; financial derivatives other than reserves, gross positive fair value; over-the-counter contracts; foreign exchange contracts"
-            }
-            Self::FinDerivForExLiabs => {
-                "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; over-the-counter contracts; foreign exchange contracts"
-            }
-            Self::FinDerivLiabs => {
-                "U.S. liabilities; financial derivatives other than reserves, gross negative fair value"
-            }
-            Self::FinDerivNet => {
-                "U.S. net international investment position; financial derivatives other than reserves"
-            }
-            Self::FinDerivOtcAssets => {
-                "U.S. assets; financial derivatives other than reserves, gross positive fair value; over-the-counter contracts"
-            }
-            Self::FinDerivOtcLiabs => {
-                "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; over-the-counter contracts"
-            }
-            Self::FinDerivOthAssets => {
-                "U.S. assets; financial derivatives other than reserves, gross positive fair value; other over-the-counter contracts"
-            }
-            Self::FinDerivOthLiabs => {
-                "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; other over-the-counter contracts"
-            }
-            Self::FinDerivReserveAssets => {
-                "U.S. assets; other reserve assets; financial derivatives"
-            }
-            Self::FinDerivSingleCurrAssets => {
-                "U.S. assets; financial derivatives other than reserves, gross positive fair value; over-the-counter contracts; single-currency interest rate contracts"
-            }
-            Self::FinDerivSingleCurrLiabs => {
-                "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; over-the-counter contracts; single-currency interest rate contracts"
-            }
+            Self::FinDerivAssets => "U.S. assets; financial derivatives other than reserves, gross positive fair value",
+            Self::FinDerivExchTradedAssets => "U.S. assets; financial derivatives other than reserves, gross positive fair value; exchange-traded contracts",
+            Self::FinDerivExchTradedLiabs => "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; exchange-traded contracts",
+            Self::FinDerivForExAssets => "U.S. assets; financial derivatives other than reserves, gross positive fair value; over-the-counter contracts; foreign exchange contracts",
+            Self::FinDerivForExLiabs => "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; over-the-counter contracts; foreign exchange contracts",
+            Self::FinDerivLiabs => "U.S. liabilities; financial derivatives other than reserves, gross negative fair value",
+            Self::FinDerivNet => "U.S. net international investment position; financial derivatives other than reserves",
+            Self::FinDerivOtcAssets => "U.S. assets; financial derivatives other than reserves, gross positive fair value; over-the-counter contracts",
+            Self::FinDerivOtcLiabs => "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; over-the-counter contracts",
+            Self::FinDerivOthAssets => "U.S. assets; financial derivatives other than reserves, gross positive fair value; other over-the-counter contracts",
+            Self::FinDerivOthLiabs => "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; other over-the-counter contracts",
+            Self::FinDerivReserveAssets => "U.S. assets; other reserve assets; financial derivatives",
+            Self::FinDerivSingleCurrAssets => "U.S. assets; financial derivatives other than reserves, gross positive fair value; over-the-counter contracts; single-currency interest rate contracts",
+            Self::FinDerivSingleCurrLiabs => "U.S. liabilities; financial derivatives other than reserves, gross negative fair value; over-the-counter contracts; single-currency interest rate contracts",
             Self::FinLiabs => "U.S. liabilities",
             Self::FinLiabsExclFinDeriv => "U.S. liabilities excluding financial derivatives",
             Self::FinLiabsFoa => "U.S. liabilities to foreign official agencies",
             Self::GoldReserveAssets => "U.S. assets; reserve assets; monetary gold",
-            Self::ImfReserveAssets => {
-                "U.S. assets; reserve assets; reserve position in the International Monetary Fund"
-            }
-            Self::InsTechReservesAssets => {
-                "U.S. assets; other investment; insurance technical reserves"
-            }
-            Self::InsTechReservesLiabs => {
-                "U.S. liabilities; other investment; insurance technical reserves"
-            }
+            Self::ImfReserveAssets => "U.S. assets; reserve assets; reserve position in the International Monetary Fund",
+            Self::InsTechReservesAssets => "U.S. assets; other investment; insurance technical reserves",
+            Self::InsTechReservesLiabs => "U.S. liabilities; other investment; insurance technical reserves",
             Self::LoansAssets => "U.S. assets; other investment; loans",
             Self::LoansLiabs => "U.S. liabilities; other investment; loans",
-            Self::LoansLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; other investment; loans"
-            }
+            Self::LoansLiabsFoa => "U.S. liabilities to foreign official agencies; other investment; loans",
             Self::LtDebtSecAssets => "U.S. assets; portfolio investment; long-term debt securities",
-            Self::LtDebtSecLiabs => {
-                "U.S. liabilities; portfolio investment; long-term debt securities"
-            }
-            Self::LtDebtSecLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; long-term debt securities"
-            }
-            Self::LtDebtSecTreasLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; long-term debt securities; Treasury bonds and notes"
-            }
+            Self::LtDebtSecLiabs => "U.S. liabilities; portfolio investment; long-term debt securities",
+            Self::LtDebtSecLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; long-term debt securities",
+            Self::LtDebtSecTreasLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; long-term debt securities; Treasury bonds and notes",
             Self::Net => "U.S. net international investment position",
-            Self::NetExclFinDeriv => {
-                "U.S. net international investment position excluding financial derivatives"
-            }
+            Self::NetExclFinDeriv => "U.S. net international investment position excluding financial derivatives",
             Self::OthClmReserveAssets => "U.S. assets; other reserve assets; other claims",
             Self::OthEquityAssets => "U.S. assets; other investment; other equity",
             Self::OthEquityLiabs => "U.S. liabilities; other investment; other equity",
-            Self::OthEquityLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; other investment; other equity"
-            }
+            Self::OthEquityLiabsFoa => "U.S. liabilities to foreign official agencies; other investment; other equity",
             Self::OthInvAssets => "U.S. assets; other investment",
             Self::OthInvLiabs => "U.S. liabilities; other investment",
-            Self::OthInvLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; other investment"
-            }
-            Self::OthLtDebtSecLiabs => {
-                "U.S. liabilities; portfolio investment; long-term debt securities excluding Treasury bonds and notes"
-            }
-            Self::OthLtDebtSecLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; long-term debt securities excluding Treasury bonds and notes"
-            }
+            Self::OthInvLiabsFoa => "U.S. liabilities to foreign official agencies; other investment",
+            Self::OthLtDebtSecLiabs => "U.S. liabilities; portfolio investment; long-term debt securities excluding Treasury bonds and notes",
+            Self::OthLtDebtSecLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; long-term debt securities excluding Treasury bonds and notes",
             Self::OthReserveAssets => "U.S. assets; other reserve assets",
-            Self::OthStDebtSecLiabs => {
-                "U.S. liabilities; portfolio investment; short-term debt securities excluding Treasury bills and certificates"
-            }
-            Self::OthStDebtSecLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; short-term debt securities excluding Treasury bills and certificates"
-            }
+            Self::OthStDebtSecLiabs => "U.S. liabilities; portfolio investment; short-term debt securities excluding Treasury bills and certificates",
+            Self::OthStDebtSecLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; short-term debt securities excluding Treasury bills and certificates",
             Self::PfInvAssets => "U.S. assets; portfolio investment",
             Self::PfInvLiabs => "U.S. liabilities; portfolio investment",
-            Self::PfInvLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment"
-            }
+            Self::PfInvLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment",
             Self::ReserveAssets => "U.S. assets; reserve assets",
             Self::ReserveAssetsCenBank => "U.S. assets; reserve assets; central bank",
             Self::ReserveAssetsCenBankLt => "U.S. assets; reserve assets; central bank; long term",
-            Self::ReserveAssetsCenBankNotSdrBasket => {
-                "U.S. assets; reserve assets; central bank; not in special drawing rights basket"
-            }
-            Self::ReserveAssetsCenBankNotSdrBasketLt => {
-                "U.S. assets; reserve assets; central bank; not in special drawing rights basket; long term"
-            }
-            Self::ReserveAssetsCenBankNotSdrBasketSt => {
-                "U.S. assets; reserve assets; central bank; not in special drawing rights basket; short term"
-            }
-            Self::ReserveAssetsCenBankSdrBasket => {
-                "U.S. assets; reserve assets; central bank; in special drawing rights basket"
-            }
-            Self::ReserveAssetsCenBankSdrBasketLt => {
-                "U.S. assets; reserve assets; central bank; in special drawing rights basket; long term"
-            }
-            Self::ReserveAssetsCenBankSdrBasketSt => {
-                "U.S. assets; reserve assets; central bank; in special drawing rights basket; short term"
-            }
+            Self::ReserveAssetsCenBankNotSdrBasket => "U.S. assets; reserve assets; central bank; not in special drawing rights basket",
+            Self::ReserveAssetsCenBankNotSdrBasketLt => "U.S. assets; reserve assets; central bank; not in special drawing rights basket; long term",
+            Self::ReserveAssetsCenBankNotSdrBasketSt => "U.S. assets; reserve assets; central bank; not in special drawing rights basket; short term",
+            Self::ReserveAssetsCenBankSdrBasket => "U.S. assets; reserve assets; central bank; in special drawing rights basket",
+            Self::ReserveAssetsCenBankSdrBasketLt => "U.S. assets; reserve assets; central bank; in special drawing rights basket; long term",
+            Self::ReserveAssetsCenBankSdrBasketSt => "U.S. assets; reserve assets; central bank; in special drawing rights basket; short term",
             Self::ReserveAssetsCenBankSt => "U.S. assets; reserve assets; central bank; short term",
             Self::ReserveAssetsGenGovt => "U.S. assets; reserve assets; general government",
-            Self::ReserveAssetsGenGovtLt => {
-                "U.S. assets; reserve assets; general government; long term"
-            }
-            Self::ReserveAssetsGenGovtNotSdrBasket => {
-                "U.S. assets; reserve assets; general government; not in special drawing rights basket"
-            }
-            Self::ReserveAssetsGenGovtNotSdrBasketLt => {
-                "U.S. assets; reserve assets; general government; not in special drawing rights basket; long term"
-            }
-            Self::ReserveAssetsGenGovtNotSdrBasketSt => {
-                "U.S. assets; reserve assets; general government; not in special drawing rights basket; short term"
-            }
-            Self::ReserveAssetsGenGovtSdrBasket => {
-                "U.S. assets; reserve assets; general government; in special drawing rights basket"
-            }
-            Self::ReserveAssetsGenGovtSdrBasketLt => {
-                "U.S. assets; reserve assets; general government; in special drawing rights basket; long term"
-            }
-            Self::ReserveAssetsGenGovtSdrBasketSt => {
-                "U.S. assets; reserve assets; general government; in special drawing rights basket; short term"
-            }
-            Self::ReserveAssetsGenGovtSt => {
-                "U.S. assets; reserve assets; general government; short term"
-            }
+            Self::ReserveAssetsGenGovtLt => "U.S. assets; reserve assets; general government; long term",
+            Self::ReserveAssetsGenGovtNotSdrBasket => "U.S. assets; reserve assets; general government; not in special drawing rights basket",
+            Self::ReserveAssetsGenGovtNotSdrBasketLt => "U.S. assets; reserve assets; general government; not in special drawing rights basket; long term",
+            Self::ReserveAssetsGenGovtNotSdrBasketSt => "U.S. assets; reserve assets; general government; not in special drawing rights basket; short term",
+            Self::ReserveAssetsGenGovtSdrBasket => "U.S. assets; reserve assets; general government; in special drawing rights basket",
+            Self::ReserveAssetsGenGovtSdrBasketLt => "U.S. assets; reserve assets; general government; in special drawing rights basket; long term",
+            Self::ReserveAssetsGenGovtSdrBasketSt => "U.S. assets; reserve assets; general government; in special drawing rights basket; short term",
+            Self::ReserveAssetsGenGovtSt => "U.S. assets; reserve assets; general government; short term",
             Self::ReserveAssetsLt => "U.S. assets; reserve assets; long term",
-            Self::ReserveAssetsNotSdrBasket => {
-                "U.S. assets; reserve assets; not in special drawing rights basket"
-            }
-            Self::ReserveAssetsNotSdrBasketLt => {
-                "U.S. assets; reserve assets; not in special drawing rights basket; long term"
-            }
-            Self::ReserveAssetsNotSdrBasketSt => {
-                "U.S. assets; reserve assets; not in special drawing rights basket; short term"
-            }
-            Self::ReserveAssetsSdrBasket => {
-                "U.S. assets; reserve assets; in special drawing rights basket"
-            }
-            Self::ReserveAssetsSdrBasketLt => {
-                "U.S. assets; reserve assets; in special drawing rights basket; long term"
-            }
-            Self::ReserveAssetsSdrBasketSt => {
-                "U.S. assets; reserve assets; in special drawing rights basket; short term"
-            }
+            Self::ReserveAssetsNotSdrBasket => "U.S. assets; reserve assets; not in special drawing rights basket",
+            Self::ReserveAssetsNotSdrBasketLt => "U.S. assets; reserve assets; not in special drawing rights basket; long term",
+            Self::ReserveAssetsNotSdrBasketSt => "U.S. assets; reserve assets; not in special drawing rights basket; short term",
+            Self::ReserveAssetsSdrBasket => "U.S. assets; reserve assets; in special drawing rights basket",
+            Self::ReserveAssetsSdrBasketLt => "U.S. assets; reserve assets; in special drawing rights basket; long term",
+            Self::ReserveAssetsSdrBasketSt => "U.S. assets; reserve assets; in special drawing rights basket; short term",
             Self::ReserveAssetsSt => "U.S. assets; reserve assets; short term",
-            Self::SdrAllocLiabs => {
-                "U.S. liabilities; other investment; special drawing rights allocations"
-            }
-            Self::SdrAllocLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; other investment; special drawing rights allocations"
-            }
+            Self::SdrAllocLiabs => "U.S. liabilities; other investment; special drawing rights allocations",
+            Self::SdrAllocLiabsFoa => "U.S. liabilities to foreign official agencies; other investment; special drawing rights allocations",
             Self::SdrReserveAssets => "U.S. assets; reserve assets; special drawing rights",
             Self::SecReserveAssets => "U.S. assets; other reserve assets; securities",
-            Self::StDebtSecAssets => {
-                "U.S. assets; portfolio investment; short-term debt securities"
-            }
-            Self::StDebtSecLiabs => {
-                "U.S. liabilities; portfolio investment; short-term debt securities"
-            }
-            Self::StDebtSecLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; short-term debt securities"
-            }
-            Self::StDebtSecTreasLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; portfolio investment; short-term debt securities; Treasury bills and certificates"
-            }
+            Self::StDebtSecAssets => "U.S. assets; portfolio investment; short-term debt securities",
+            Self::StDebtSecLiabs => "U.S. liabilities; portfolio investment; short-term debt securities",
+            Self::StDebtSecLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; short-term debt securities",
+            Self::StDebtSecTreasLiabsFoa => "U.S. liabilities to foreign official agencies; portfolio investment; short-term debt securities; Treasury bills and certificates",
             Self::TrdCredAndAdvAssets => "U.S. assets; other investment; trade credit and advances",
-            Self::TrdCredAndAdvLiabs => {
-                "U.S. liabilities; other investment; trade credit and advances"
-            }
-            Self::TrdCredAndAdvLiabsFoa => {
-                "U.S. liabilities to foreign official agencies; other investment; trade credit and advances"
-            }
-            Self::TreasBillsAndCertsLiabs => {
-                "U.S. liabilities; portfolio investment; short-term debt securities; Treasury bills and certificates"
-            }
-            Self::TreasBondsAndNotesLiabs => {
-                "U.S. liabilities; portfolio investment; long-term debt securities; Treasury bonds and notes"
-            }
+            Self::TrdCredAndAdvLiabs => "U.S. liabilities; other investment; trade credit and advances",
+            Self::TrdCredAndAdvLiabsFoa => "U.S. liabilities to foreign official agencies; other investment; trade credit and advances",
+            Self::TreasBillsAndCertsLiabs => "U.S. liabilities; portfolio investment; short-term debt securities; Treasury bills and certificates",
+            Self::TreasBondsAndNotesLiabs => "U.S. liabilities; portfolio investment; long-term debt securities; Treasury bonds and notes",
         }
     }
+}
+// @generated end
 
+impl Investment {
     /// The String representation of a variant serves as the value for the corresponding parameter
     /// name in BEA API calls. The `params` method formats the parameter name as a key, as the
     /// variant name as the value for use in building complex API queries.
@@ -7,6 +7,7 @@ mod component;
 mod fixed_asset_table;
 mod footnote;
 mod frequency;
+mod geography;
 mod indicator;
 mod industry;
 mod input_output_code;
@@ -42,11 +43,12 @@ pub use investment::{DirectionKind, DirectionOfInvestment, Investment};
 pub use measure::Measure;
 pub use millions::{Millions, MillionsOptions};
 pub use naics::{
-    Naics, NaicsCategory, NaicsIndustry, NaicsInputOutput, NaicsItem, NaicsItems, NaicsSector,
-    NaicsSubcategory, NaicsSubsector, NaicsSupplement,
+    Naics, NaicsCategory, NaicsConcordance, NaicsIndustry, NaicsInputOutput, NaicsItem, NaicsItems,
+    NaicsLevel, NaicsLink, NaicsRelation, NaicsSector, NaicsSubcategory, NaicsSubsector,
+    NaicsSupplement, NaicsVintage,
 };
 pub use numeric::{
-    AnnotatedInteger, BoolInvalid, BoolOptions, Integer, IntegerInvalid, IntegerKind,
+    AnnotatedInteger, BoolInvalid, BoolOptions, Estimate, Integer, IntegerInvalid, IntegerKind,
     IntegerOptions, Nom,
 };
 pub use owner::{AffiliateKind, AffiliateLevel, OwnershipInvalid, OwnershipKind, OwnershipLevel};
@@ -1,4 +1,6 @@
 /// NAICS Category codes
+// @generated begin by `bears generate` from data/naics_codes.csv and data/naics_legacy_codes.csv, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Copy,
@@ -635,8 +637,9 @@ pub enum NaicsCategory {
     UnclassifiedEstablishments,
 }
 
+#[rustfmt::skip]
 impl NaicsCategory {
-    /// Returns the description (NAICS title) for the category
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::OilseedAndGrainFarming => "Oilseed and Grain Farming",
@@ -950,7 +953,9 @@ impl NaicsCategory {
             Self::UnclassifiedEstablishments => "Unclassified Establishments",
         }
     }
-    /// Returns the NAICS code for the category as an i64
+
+    /// Returns the NAICS code of the variant.  Variants spanning several codes return the
+    /// first.
     pub fn code(&self) -> i64 {
         match self {
             Self::OilseedAndGrainFarming => 1111,
@@ -1265,17 +1270,10 @@ impl NaicsCategory {
         }
     }
 
-    /// Attempts to convert a string representing a NAICS code into a NaicsCategory
-    /// Returns Some(NaicsCategory) if successful, None otherwise
-    pub fn from_code(code_str: &str) -> Option<Self> {
-        // Try to parse the input string to i64
-        let code = match code_str.parse::<i64>() {
-            Ok(num) => num,
-            Err(_) => return None,
-        };
-
-        // Match the code against known NAICS codes
-        let category = match code {
+    /// Returns the variant with the NAICS code `code`, or `None` if the code is unknown.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.parse::<i64>().ok()?;
+        let result = match code {
             1111 => Self::OilseedAndGrainFarming,
             1112 => Self::VegetableAndMelonFarming,
             1113 => Self::FruitAndTreeNutFarming,
@@ -1585,10 +1583,9 @@ impl NaicsCategory {
             9271 => Self::SpaceResearchAndTechnology,
             9281 => Self::NationalSecurityAndInternationalAffairs,
             9999 => Self::UnclassifiedEstablishments,
-            _ => return None, // Return None if no matching code is found
+            _ => return None,
         };
-
-        // Wrap the category in Some and return
-        Some(category)
+        Some(result)
     }
 }
+// @generated end
@@ -0,0 +1,304 @@
+use crate::{IoError, KeyMissing, Naics, from_csv};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The `NaicsVintage` enum names the revisions of the NAICS classification used by BEA datasets.
+/// The NAICS enums in this library follow [`NaicsVintage::CURRENT`].
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum NaicsVintage {
+    Naics2012,
+    Naics2017,
+    Naics2022,
+}
+
+impl NaicsVintage {
+    /// The vintage of the NAICS enums in this library.
+    pub const CURRENT: Self = Self::Naics2022;
+
+    /// Year of the revision.
+    pub fn year(&self) -> i16 {
+        match self {
+            Self::Naics2012 => 2012,
+            Self::Naics2017 => 2017,
+            Self::Naics2022 => 2022,
+        }
+    }
+}
+
+/// The `NaicsRelation` enum classifies how a code in the source vintage of a
+/// [`NaicsConcordance`] maps to the target vintage.
+///
+/// * **OneToOne** - The code maps to a single target code that has no other source.
+/// * **Split** - The code maps to several target codes, none of which has another source.
+/// * **Merge** - The code maps to a single target code shared with other sources.
+/// * **ManyToMany** - The code maps to several target codes, some of which have other sources.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum NaicsRelation {
+    OneToOne,
+    Split,
+    Merge,
+    ManyToMany,
+}
+
+/// The `NaicsLink` struct is a single row of a NAICS crosswalk, linking a code in one vintage to a
+/// code in the next.
+///
+/// * **source_code** - Code in the source vintage.
+/// * **source_title** - Title of the source code.
+/// * **target_code** - Code in the target vintage.
+/// * **target_title** - Title of the target code.
+/// * **weight** - Share of the source code allocated to the target code, if known.
+///
+/// The Census Bureau publishes crosswalks between vintages as spreadsheets with the columns
+/// "2017 NAICS Code", "2017 NAICS Title", "2022 NAICS Code" and "2022 NAICS Title".  Save the
+/// spreadsheet as CSV with the headers renamed to the field names above, adding a `weight` column
+/// if allocation shares are available, in the same way that `data/naics_codes.csv` renames the
+/// columns of the Census code list.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct NaicsLink {
+    source_code: i64,
+    source_title: String,
+    target_code: i64,
+    target_title: String,
+    #[serde(default)]
+    weight: Option<f64>,
+}
+
+/// The `NaicsConcordance` struct maps codes between two vintages of the NAICS classification.
+///
+/// * **source** - Vintage of the source codes.
+/// * **target** - Vintage of the target codes.
+/// * **links** - Rows of the crosswalk.
+///
+/// Links without a weight split the source code equally among its targets.  The Census Bureau
+/// does not publish allocation weights, so equal shares are a rough default.  Supply weights from
+/// employment or output in the base year of the revision for a better restatement.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct NaicsConcordance {
+    source: NaicsVintage,
+    target: NaicsVintage,
+    links: Vec<NaicsLink>,
+}
+
+impl NaicsConcordance {
+    /// Reads the crosswalk from `source` to `target` from the CSV file at `path`.  See
+    /// [`NaicsLink`] for the expected columns.  Rows that do not parse, such as notes at the foot
+    /// of the Census spreadsheets, are dropped.
+    pub fn from_csv<P: AsRef<std::path::Path>>(
+        path: P,
+        source: NaicsVintage,
+        target: NaicsVintage,
+    ) -> Result<Self, IoError> {
+        let links = from_csv(path)?;
+        Ok(Self::new(source, target, links))
+    }
+
+    /// The `targets` method returns the target codes of the source code `code` with the share of
+    /// `code` allocated to each.  Returns an empty map if `code` is not in the crosswalk.
+    pub fn targets(&self, code: i64) -> BTreeMap<i64, f64> {
+        let links = self
+            .links
+            .iter()
+            .filter(|link| link.source_code == code)
+            .collect::<Vec<&NaicsLink>>();
+        let count = links.len() as f64;
+        let mut targets = BTreeMap::new();
+        links
+            .iter()
+            .map(|link| {
+                *targets.entry(link.target_code).or_insert(0.0) +=
+                    link.weight.unwrap_or(1.0 / count)
+            })
+            .for_each(drop);
+        targets
+    }
+
+    /// The `sources` method returns the source codes that map to the target code `code`.
+    pub fn sources(&self, code: i64) -> BTreeSet<i64> {
+        let mut sources = BTreeSet::new();
+        self.links
+            .iter()
+            .filter(|link| link.target_code == code)
+            .map(|link| sources.insert(link.source_code))
+            .for_each(drop);
+        sources
+    }
+
+    /// The `relation` method classifies the mapping of the source code `code`, or returns `None`
+    /// if `code` is not in the crosswalk.
+    pub fn relation(&self, code: i64) -> Option<NaicsRelation> {
+        let targets = self.targets(code);
+        let shared = targets.keys().any(|target| self.sources(*target).len() > 1);
+        match (targets.len(), shared) {
+            (0, _) => None,
+            (1, false) => Some(NaicsRelation::OneToOne),
+            (1, true) => Some(NaicsRelation::Merge),
+            (_, false) => Some(NaicsRelation::Split),
+            (_, true) => Some(NaicsRelation::ManyToMany),
+        }
+    }
+
+    /// The `then` method composes self with the concordance `next`, producing a concordance from
+    /// the source of self to the target of `next`, e.g. from 2012 to 2022 given crosswalks from
+    /// 2012 to 2017 and 2017 to 2022.  Weights of the composed links are the products of the
+    /// weights along each path.  Returns a [`KeyMissing`] error if the target of self is not the
+    /// source of `next`.
+    pub fn then(&self, next: &Self) -> Result<Self, KeyMissing> {
+        if self.target != next.source {
+            let clue = format!(
+                "concordance from {} does not follow concordance to {}",
+                next.source, self.target
+            );
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()));
+        }
+        let source_titles = self
+            .links
+            .iter()
+            .map(|link| (link.source_code, link.source_title.clone()))
+            .collect::<BTreeMap<i64, String>>();
+        // Titles from the second crosswalk replace those of codes carried over from the first.
+        let target_titles = self
+            .links
+            .iter()
+            .chain(next.links.iter())
+            .map(|link| (link.target_code, link.target_title.clone()))
+            .collect::<BTreeMap<i64, String>>();
+        let sources = self
+            .links
+            .iter()
+            .map(|link| link.source_code)
+            .collect::<BTreeSet<i64>>();
+        let mut weights = BTreeMap::new();
+        for source in sources {
+            for (middle, first) in self.targets(source) {
+                // Codes missing from the second crosswalk carry over unchanged.
+                let second = match next.targets(middle) {
+                    targets if targets.is_empty() => BTreeMap::from([(middle, 1.0)]),
+                    targets => targets,
+                };
+                for (target, weight) in second {
+                    *weights.entry((source, target)).or_insert(0.0) += first * weight;
+                }
+            }
+        }
+        let links = weights
+            .into_iter()
+            .map(|((source, target), weight)| {
+                let source_title = source_titles.get(&source).cloned().unwrap_or_default();
+                let target_title = target_titles.get(&target).cloned().unwrap_or_default();
+                NaicsLink::new(source, source_title, target, target_title, Some(weight))
+            })
+            .collect();
+        Ok(Self::new(self.source, next.target, links))
+    }
+
+    /// The `at_digits` method collapses the crosswalk to codes with `digits` digits, so that codes
+    /// above the six-digit national industries can be mapped.  The share of a source prefix
+    /// allocated to a target prefix weights each six-digit source code in the prefix equally.
+    pub fn at_digits(&self, digits: usize) -> Self {
+        let prefix = |code: i64| {
+            let code = code.to_string();
+            code.get(..digits)
+                .unwrap_or(&code)
+                .parse::<i64>()
+                .unwrap_or(0)
+        };
+        let mut members = BTreeMap::new();
+        self.links
+            .iter()
+            .map(|link| {
+                members
+                    .entry(prefix(link.source_code))
+                    .or_insert_with(BTreeSet::new)
+                    .insert(link.source_code)
+            })
+            .for_each(drop);
+        let mut weights = BTreeMap::new();
+        for (source, codes) in &members {
+            for code in codes {
+                for (target, weight) in self.targets(*code) {
+                    *weights.entry((*source, prefix(target))).or_insert(0.0) +=
+                        weight / codes.len() as f64;
+                }
+            }
+        }
+        let title = |code: i64| {
+            Naics::from_code(&code.to_string())
+                .map(|naics| naics.description().to_owned())
+                .unwrap_or_default()
+        };
+        let links = weights
+            .into_iter()
+            .map(|((source, target), weight)| {
+                NaicsLink::new(source, String::new(), target, title(target), Some(weight))
+            })
+            .collect();
+        Self::new(self.source, self.target, links)
+    }
+
+    /// The `restate` method maps `naics` onto the target vintage, returning each target code
+    /// with its share of `naics`.  Codes that are not in the crosswalk, such as the special
+    /// codes of the BEA industry accounts, map to themselves.  So do codes with a target that
+    /// is not a variant of the NAICS enums.  Supplemental codes padded with zeros map by the
+    /// prefix returned from [`Naics::digits`], so 4540 maps through the three-digit crosswalk.
+    pub fn restate(&self, naics: &Naics) -> Vec<(Naics, f64)> {
+        let unchanged = vec![(*naics, 1.0)];
+        let Some(code) = naics.digits().and_then(|code| code.parse::<i64>().ok()) else {
+            return unchanged;
+        };
+        let targets = self
+            .targets(code)
+            .into_iter()
+            .map(|(target, weight)| Naics::from_code(&target.to_string()).map(|t| (t, weight)))
+            .collect::<Option<Vec<(Naics, f64)>>>();
+        match targets {
+            Some(targets) if !targets.is_empty() => targets,
+            _ => unchanged,
+        }
+    }
+}
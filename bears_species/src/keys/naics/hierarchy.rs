@@ -0,0 +1,325 @@
+use crate::{
+    Naics, NaicsCategory, NaicsIndustry, NaicsInputOutput, NaicsSector, NaicsSubcategory,
+    NaicsSubsector, NaicsSupplement,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The `NaicsLevel` enum names the levels of the NAICS hierarchy, from the two-digit sector to
+/// the six-digit national industry.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum NaicsLevel {
+    Sector,
+    Subsector,
+    Category,
+    Subcategory,
+    Industry,
+}
+
+impl NaicsLevel {
+    /// Number of digits in the codes at the level.
+    pub fn digits(&self) -> usize {
+        match self {
+            Self::Sector => 2,
+            Self::Subsector => 3,
+            Self::Category => 4,
+            Self::Subcategory => 5,
+            Self::Industry => 6,
+        }
+    }
+
+    /// The `groups` method assigns each code in `codes` to the code at this level that contains
+    /// it, returning a map from code to group.
+    ///
+    /// Codes without an ancestor at this level, such as aggregates above the level or the final
+    /// demand and value added codes of the input-output tables, form a group of their own.  When
+    /// `codes` include both an aggregate and its components, only the most aggregated code in each
+    /// group is mapped, so that summing the values of a group never counts the same activity
+    /// twice.
+    pub fn groups<'a, I: IntoIterator<Item = &'a Naics>>(
+        &self,
+        codes: I,
+    ) -> BTreeMap<Naics, Naics> {
+        let codes = codes.into_iter().copied().collect::<BTreeSet<Naics>>();
+        let mut groups = BTreeMap::new();
+        for code in &codes {
+            let group = code.at_level(*self).unwrap_or(*code);
+            let mut covered = false;
+            if group != *code {
+                for ancestor in code.ancestors() {
+                    if codes.contains(&ancestor) {
+                        covered = true;
+                        break;
+                    }
+                    if ancestor == group {
+                        break;
+                    }
+                }
+            }
+            if !covered {
+                groups.insert(*code, group);
+            }
+        }
+        groups
+    }
+}
+
+impl Naics {
+    /// Returns the level of the code in the NAICS hierarchy, or `None` for supplemental and
+    /// input-output codes.
+    pub fn level(&self) -> Option<NaicsLevel> {
+        match self {
+            Self::Sector(_) => Some(NaicsLevel::Sector),
+            Self::Subsector(_) => Some(NaicsLevel::Subsector),
+            Self::Category(_) => Some(NaicsLevel::Category),
+            Self::Subcategory(_) => Some(NaicsLevel::Subcategory),
+            Self::Industry(_) => Some(NaicsLevel::Industry),
+            Self::Supplement(_) | Self::InputOutput(_) => None,
+        }
+    }
+
+    /// The `digits` method returns the NAICS digits of self, stripping the zeros that pad
+    /// four-digit supplemental codes, so that 4540 returns "454".  Returns `None` for codes that
+    /// are not numeric, such as the combined sectors and most input-output codes.
+    pub fn digits(&self) -> Option<String> {
+        let code = self.code();
+        if !code.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        match self {
+            Self::Supplement(_) if code.len() == 4 => Some(code.trim_end_matches('0').to_owned()),
+            _ => Some(code),
+        }
+    }
+
+    /// The `parent` method returns the code that immediately contains self, or `None` for codes
+    /// at the top of the hierarchy.
+    ///
+    /// * Subsectors, categories, subcategories and industries belong to the longest code that
+    ///   prefixes them, so industry 336111 belongs to subcategory 33611 and subsector 336 belongs
+    ///   to the sector 31-33.
+    /// * Sectors belong to the combined sectors of the BEA industry accounts, which belong to the
+    ///   private goods-producing or private services-producing industries, then to private
+    ///   industries and all industries.  Public administration belongs directly to all industries.
+    /// * Input-output codes beginning with digits belong to the longest standard code covering
+    ///   them, e.g. "3361MV" belongs to subsector 336.  Government, housing and final demand codes
+    ///   follow the layout of the input-output tables.  Value added, totals and other special
+    ///   codes have no parent.
+    /// * Four-digit supplemental codes used in the international surveys pad a NAICS prefix with
+    ///   zeros, e.g. 3110 for subsector 311, and belong to that code when it exists, or else to
+    ///   the nearest supplemental or standard code covering them.
+    pub fn parent(&self) -> Option<Naics> {
+        match self {
+            Self::Sector(naics) => sector_parent(naics),
+            Self::Subsector(_) | Self::Category(_) | Self::Subcategory(_) | Self::Industry(_) => {
+                let code = self.code();
+                (2..code.len()).rev().find_map(|len| standard(&code[..len]))
+            }
+            Self::Supplement(naics) => supplement_parent(naics),
+            Self::InputOutput(naics) => input_output_parent(naics),
+        }
+    }
+
+    /// The `ancestors` method returns the parent of self, the parent of the parent, and so on up
+    /// to the top of the hierarchy.
+    pub fn ancestors(&self) -> Vec<Naics> {
+        let mut ancestors = Vec::new();
+        let mut current = *self;
+        while let Some(parent) = current.parent() {
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors
+    }
+
+    /// The `children` method returns the codes whose parent is self.
+    pub fn children(&self) -> BTreeSet<Naics> {
+        let mut children = BTreeSet::new();
+        Self::variants()
+            .into_iter()
+            .filter(|v| v.parent().as_ref() == Some(self))
+            .map(|v| children.insert(v))
+            .for_each(drop);
+        children
+    }
+
+    /// The `descendants` method returns every code within self, excluding self.
+    pub fn descendants(&self) -> BTreeSet<Naics> {
+        let mut descendants = BTreeSet::new();
+        Self::variants()
+            .into_iter()
+            .filter(|v| v != self && v.is_within(self))
+            .map(|v| descendants.insert(v))
+            .for_each(drop);
+        descendants
+    }
+
+    /// The `is_within` method returns `true` if self is `other` or a descendant of `other`.
+    pub fn is_within(&self, other: &Naics) -> bool {
+        self == other || self.ancestors().contains(other)
+    }
+
+    /// The `at_level` method returns the code at `level` that contains self, which is self when
+    /// self is at `level`.  Returns `None` if self has no ancestor at `level`.
+    pub fn at_level(&self, level: NaicsLevel) -> Option<Naics> {
+        std::iter::once(*self)
+            .chain(self.ancestors())
+            .find(|naics| naics.level() == Some(level))
+    }
+
+    /// Returns the NAICS sector containing the code.  Returns `None` for codes that span more
+    /// than one sector, and for input-output codes outside the industry classification, such as
+    /// final demand, value added and table totals.
+    pub fn sector(&self) -> Option<NaicsSector> {
+        match self.at_level(NaicsLevel::Sector)? {
+            Self::Sector(sector) => Some(sector),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the standard NAICS code with the digits `code`, choosing the level by length.
+fn standard(code: &str) -> Option<Naics> {
+    match code.len() {
+        2 => NaicsSector::from_code(code).map(Naics::from),
+        3 => NaicsSubsector::from_code(code).map(Naics::from),
+        4 => NaicsCategory::from_code(code).map(Naics::from),
+        5 => NaicsSubcategory::from_code(code).map(Naics::from),
+        6 => NaicsIndustry::from_code(code).map(Naics::from),
+        _ => None,
+    }
+}
+
+/// Parent of a sector among the combined sectors of the BEA industry accounts.
+fn sector_parent(naics: &NaicsSector) -> Option<Naics> {
+    use NaicsInputOutput as Io;
+    use NaicsSector as S;
+    let parent = match naics {
+        S::FinanceInsurance | S::RealEstateRentalLeasing => {
+            Io::FinanceInsuranceRealEstateRentalLeasing.into()
+        }
+        S::ProfessionalScientificTechnicalServices
+        | S::ManagementOfCompaniesAndEnterprises
+        | S::AdministrativeSupportWasteManagementRemediationServices => {
+            Io::ProfessionalBusinessServices.into()
+        }
+        S::EducationalServices | S::HealthCareSocialAssistance => {
+            NaicsSupplement::EducationalServicesHealthCareSocialAssistance.into()
+        }
+        S::ArtsEntertainmentRecreation | S::AccommodationFoodServices => {
+            NaicsSupplement::ArtsEntertainmentRecreationAccommodationFoodServices.into()
+        }
+        S::AgricultureForestryFishingHunting
+        | S::MiningQuarryingOilGasExtraction
+        | S::Construction
+        | S::Manufacturing => Io::PrivateGoodsProducingIndustries.into(),
+        S::Utilities
+        | S::WholesaleTrade
+        | S::RetailTrade
+        | S::TransportationWarehousing
+        | S::Information
+        | S::OtherServicesExceptPublicAdministration => {
+            Io::PrivateServicesProducingIndustries.into()
+        }
+        S::PublicAdministration => Io::AllIndustries.into(),
+        S::UnclassifiedEstablishments => return None,
+    };
+    Some(parent)
+}
+
+/// Parent of a supplemental code.  See [`Naics::parent`].
+fn supplement_parent(naics: &NaicsSupplement) -> Option<Naics> {
+    if matches!(
+        naics,
+        NaicsSupplement::EducationalServicesHealthCareSocialAssistance
+            | NaicsSupplement::ArtsEntertainmentRecreationAccommodationFoodServices
+    ) {
+        return Some(NaicsInputOutput::PrivateServicesProducingIndustries.into());
+    }
+    let code = naics.code().to_string();
+    let digits = if code.len() == 4 {
+        code.trim_end_matches('0')
+    } else {
+        &code
+    };
+    if digits.len() < code.len()
+        && let Some(parent) = standard(digits)
+    {
+        return Some(parent);
+    }
+    (2..digits.len()).rev().find_map(|len| {
+        let prefix = &digits[..len];
+        NaicsSupplement::from_code(&format!("{prefix:0<4}"))
+            .map(Naics::from)
+            .or_else(|| standard(prefix))
+    })
+}
+
+/// Parent of an input-output code.  See [`Naics::parent`].
+fn input_output_parent(naics: &NaicsInputOutput) -> Option<Naics> {
+    use NaicsInputOutput as Io;
+    let parent = match naics {
+        Io::FederalGeneralGovernmentDefense | Io::FederalGeneralGovernmentNondefense => {
+            Io::GeneralGovernment
+        }
+        Io::GeneralGovernment | Io::FederalGovernmentEnterprises => Io::Federal,
+        Io::StateLocalGovernmentEducationalServices
+        | Io::StateLocalGovernmentHospitalsHealthServices
+        | Io::StateLocalGovernmentOtherServices => Io::StateLocalGeneralGovernment,
+        Io::StateLocalGeneralGovernment | Io::StateLocalGovernmentEnterprises => Io::StateLocal,
+        Io::Federal | Io::StateLocal => Io::Government,
+        Io::Government => return Some(NaicsSector::PublicAdministration.into()),
+        Io::OwnerOccupiedHousing | Io::TenantOccupiedHousing => Io::Housing,
+        Io::Housing | Io::OtherRealEstate => return Some(NaicsSubsector::RealEstate.into()),
+        Io::AllOtherRetail => Io::OtherRetail,
+        Io::OtherRetail => return Some(NaicsSector::RetailTrade.into()),
+        Io::FinanceInsuranceRealEstateRentalLeasing | Io::ProfessionalBusinessServices => {
+            Io::PrivateServicesProducingIndustries
+        }
+        Io::PrivateGoodsProducingIndustries | Io::PrivateServicesProducingIndustries => {
+            Io::PrivateIndustries
+        }
+        Io::PrivateIndustries => Io::AllIndustries,
+        Io::NonresidentialPrivateFixedInvestmentEquipment
+        | Io::NonresidentialPrivateFixedInvestmentIntellectualPropertyRights
+        | Io::NonresidentialPrivateFixedInvestmentStructures
+        | Io::ResidentialPrivateFixedInvestment => Io::PrivateFixedInvestment,
+        Io::NationalDefenseConsumptionExpenditures
+        | Io::FederalNationalDefenseGrossInvestmentEquipment
+        | Io::FederalNationalDefenseGrossInvestmentIntellectualPropertyProducts
+        | Io::FederalNationalDefenseGrossInvestmentStructures
+        | Io::NondefenseConsumptionExpenditures
+        | Io::FederalNondefenseGrossInvestmentEquipment
+        | Io::FederalNondefenseGrossInvestmentIntellectualPropertyProducts
+        | Io::FederalNondefenseGrossInvestmentStructures
+        | Io::StateLocalGovernmentConsumptionExpenditures
+        | Io::StateLocalGrossInvestmentEquipment
+        | Io::StateLocalGrossInvestmentIntellectualPropertyProducts
+        | Io::StateLocalGrossInvestmentStructures => {
+            Io::GovernmentConsumptionExpendituresGrossInvestment
+        }
+        _ => {
+            // Industry and commodity codes begin with the digits of the codes they cover.  A code
+            // such as "311FT" spans more than subsector 311, so the full run of digits is only used
+            // for two-digit sectors.
+            let code = naics.code();
+            let digits = code.len() - code.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let longest = if digits > 2 { digits - 1 } else { digits };
+            return (2..=longest).rev().find_map(|len| standard(&code[..len]));
+        }
+    };
+    Some(parent.into())
+}
@@ -1,4 +1,6 @@
 /// North American Industry Classification System (NAICS) industry codes
+// @generated begin by `bears generate` from data/naics_codes.csv and data/naics_legacy_codes.csv, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Copy,
@@ -797,7 +799,7 @@ pub enum NaicsIndustry {
     OtherCommunicationsEquipmentManufacturing,
     /// Audio and Video Equipment Manufacturing
     AudioAndVideoEquipmentManufacturing,
-    /// Bare Printed Circuit Board Manufacturing  
+    /// Bare Printed Circuit Board Manufacturing
     BarePrintedCircuitBoardManufacturing,
     /// Semiconductor and Related Device Manufacturing
     SemiconductorAndRelatedDeviceManufacturing,
@@ -1379,10 +1381,10 @@ pub enum NaicsIndustry {
     WirelessTelecommunicationsCarriersExceptSatellite,
     /// Telecommunications Resellers
     TelecommunicationsResellers,
-    /// Satellite Telecommunications
-    SatelliteTelecommunications,
     /// Agents for Wireless Telecommunications Services
     AgentsForWirelessTelecommunicationsServices,
+    /// Satellite Telecommunications
+    SatelliteTelecommunications,
     /// All Other Telecommunications
     AllOtherTelecommunications,
     /// Computing Infrastructure Providers, Data Processing, Web Hosting, and Related Services
@@ -2043,8 +2045,9 @@ pub enum NaicsIndustry {
     UnclassifiedEstablishments,
 }
 
+#[rustfmt::skip]
 impl NaicsIndustry {
-    /// Returns the industry description corresponding to this NAICS code
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::SoybeanFarming => "Soybean Farming",
@@ -2056,9 +2059,7 @@ impl NaicsIndustry {
             Self::OilseedAndGrainCombinationFarming => "Oilseed and Grain Combination Farming",
             Self::AllOtherGrainFarming => "All Other Grain Farming",
             Self::PotatoFarming => "Potato Farming",
-            Self::OtherVegetableExceptPotatoAndMelonFarming => {
-                "Other Vegetable (except Potato) and Melon Farming"
-            }
+            Self::OtherVegetableExceptPotatoAndMelonFarming => "Other Vegetable (except Potato) and Melon Farming",
             Self::OrangeGroves => "Orange Groves",
             Self::CitrusExceptOrangeGroves => "Citrus (except Orange) Groves",
             Self::AppleOrchards => "Apple Orchards",
@@ -2085,9 +2086,7 @@ impl NaicsIndustry {
             Self::DualpurposeCattleRanchingAndFarming => "Dual-Purpose Cattle Ranching and Farming",
             Self::HogAndPigFarming => "Hog and Pig Farming",
             Self::ChickenEggProduction => "Chicken Egg Production",
-            Self::BroilersAndOtherMeatTypeChickenProduction => {
-                "Broilers and Other Meat Type Chicken Production"
-            }
+            Self::BroilersAndOtherMeatTypeChickenProduction => "Broilers and Other Meat Type Chicken Production",
             Self::TurkeyProduction => "Turkey Production",
             Self::PoultryHatcheries => "Poultry Hatcheries",
             Self::OtherPoultryProduction => "Other Poultry Production",
@@ -2101,27 +2100,19 @@ impl NaicsIndustry {
             Self::FurbearingAnimalAndRabbitProduction => "Fur-Bearing Animal and Rabbit Production",
             Self::AllOtherAnimalProduction => "All Other Animal Production",
             Self::TimberTractOperations => "Timber Tract Operations",
-            Self::ForestNurseriesAndGatheringOfForestProducts => {
-                "Forest Nurseries and Gathering of Forest Products"
-            }
+            Self::ForestNurseriesAndGatheringOfForestProducts => "Forest Nurseries and Gathering of Forest Products",
             Self::Logging => "Logging",
             Self::FinfishFishing => "Finfish Fishing",
             Self::ShellfishFishing => "Shellfish Fishing",
             Self::OtherMarineFishing => "Other Marine Fishing",
             Self::HuntingAndTrapping => "Hunting and Trapping",
             Self::CottonGinning => "Cotton Ginning",
-            Self::SoilPreparationPlantingAndCultivating => {
-                "Soil Preparation, Planting, and Cultivating"
-            }
+            Self::SoilPreparationPlantingAndCultivating => "Soil Preparation, Planting, and Cultivating",
             Self::CropHarvestingPrimarilyByMachine => "Crop Harvesting, Primarily by Machine",
-            Self::PostharvestCropActivitiesExceptCottonGinning => {
-                "Postharvest Crop Activities (except Cotton Ginning)"
-            }
+            Self::PostharvestCropActivitiesExceptCottonGinning => "Postharvest Crop Activities (except Cotton Ginning)",
             Self::FarmLaborContractorsAndCrewLeaders => "Farm Labor Contractors and Crew Leaders",
             Self::FarmManagementServices => "Farm Management Services",
-            Self::SupportActivitiesForAnimalProduction => {
-                "Support Activities for Animal Production"
-            }
+            Self::SupportActivitiesForAnimalProduction => "Support Activities for Animal Production",
             Self::SupportActivitiesForForestry => "Support Activities for Forestry",
             Self::CrudePetroleumExtraction => "Crude Petroleum Extraction",
             Self::NaturalGasExtraction => "Natural Gas Extraction",
@@ -2132,32 +2123,18 @@ impl NaicsIndustry {
             Self::CopperNickelLeadAndZincMining => "Copper, Nickel, Lead, and Zinc Mining",
             Self::OtherMetalOreMining => "Other Metal Ore Mining",
             Self::DimensionStoneMiningAndQuarrying => "Dimension Stone Mining and Quarrying",
-            Self::CrushedAndBrokenLimestoneMiningAndQuarrying => {
-                "Crushed and Broken Limestone Mining and Quarrying"
-            }
-            Self::CrushedAndBrokenGraniteMiningAndQuarrying => {
-                "Crushed and Broken Granite Mining and Quarrying"
-            }
-            Self::OtherCrushedAndBrokenStoneMiningAndQuarrying => {
-                "Other Crushed and Broken Stone Mining and Quarrying"
-            }
+            Self::CrushedAndBrokenLimestoneMiningAndQuarrying => "Crushed and Broken Limestone Mining and Quarrying",
+            Self::CrushedAndBrokenGraniteMiningAndQuarrying => "Crushed and Broken Granite Mining and Quarrying",
+            Self::OtherCrushedAndBrokenStoneMiningAndQuarrying => "Other Crushed and Broken Stone Mining and Quarrying",
             Self::ConstructionSandAndGravelMining => "Construction Sand and Gravel Mining",
             Self::IndustrialSandMining => "Industrial Sand Mining",
-            Self::KaolinClayAndCeramicAndRefractoryMineralsMining => {
-                "Kaolin, Clay, and Ceramic and Refractory Minerals Mining"
-            }
-            Self::OtherNonmetallicMineralMiningAndQuarrying => {
-                "Other Nonmetallic Mineral Mining and Quarrying"
-            }
+            Self::KaolinClayAndCeramicAndRefractoryMineralsMining => "Kaolin, Clay, and Ceramic and Refractory Minerals Mining",
+            Self::OtherNonmetallicMineralMiningAndQuarrying => "Other Nonmetallic Mineral Mining and Quarrying",
             Self::DrillingOilAndGasWells => "Drilling Oil and Gas Wells",
-            Self::SupportActivitiesForOilAndGasOperations => {
-                "Support Activities for Oil and Gas Operations"
-            }
+            Self::SupportActivitiesForOilAndGasOperations => "Support Activities for Oil and Gas Operations",
             Self::SupportActivitiesForCoalMining => "Support Activities for Coal Mining",
             Self::SupportActivitiesForMetalMining => "Support Activities for Metal Mining",
-            Self::SupportActivitiesForNonmetallicMineralsExceptFuelsMining => {
-                "Support Activities for Nonmetallic Minerals (except Fuels) Mining"
-            }
+            Self::SupportActivitiesForNonmetallicMineralsExceptFuelsMining => "Support Activities for Nonmetallic Minerals (except Fuels) Mining",
             Self::HydroelectricPowerGeneration => "Hydroelectric Power Generation",
             Self::FossilFuelElectricPowerGeneration => "Fossil Fuel Electric Power Generation",
             Self::NuclearElectricPowerGeneration => "Nuclear Electric Power Generation",
@@ -2166,60 +2143,34 @@ impl NaicsIndustry {
             Self::GeothermalElectricPowerGeneration => "Geothermal Electric Power Generation",
             Self::BiomassElectricPowerGeneration => "Biomass Electric Power Generation",
             Self::OtherElectricPowerGeneration => "Other Electric Power Generation",
-            Self::ElectricBulkPowerTransmissionAndControl => {
-                "Electric Bulk Power Transmission and Control"
-            }
+            Self::ElectricBulkPowerTransmissionAndControl => "Electric Bulk Power Transmission and Control",
             Self::ElectricPowerDistribution => "Electric Power Distribution",
             Self::NaturalGasDistribution => "Natural Gas Distribution",
             Self::WaterSupplyAndIrrigationSystems => "Water Supply and Irrigation Systems",
             Self::SewageTreatmentFacilities => "Sewage Treatment Facilities",
             Self::SteamAndAirconditioningSupply => "Steam and Air-Conditioning Supply",
-            Self::NewSinglefamilyHousingConstructionExceptForsaleBuilders => {
-                "New Single-Family Housing Construction (except For-Sale Builders)"
-            }
-            Self::NewMultifamilyHousingConstructionExceptForsaleBuilders => {
-                "New Multifamily Housing Construction (except For-Sale Builders)"
-            }
+            Self::NewSinglefamilyHousingConstructionExceptForsaleBuilders => "New Single-Family Housing Construction (except For-Sale Builders)",
+            Self::NewMultifamilyHousingConstructionExceptForsaleBuilders => "New Multifamily Housing Construction (except For-Sale Builders)",
             Self::NewHousingForsaleBuilders => "New Housing For-Sale Builders",
             Self::ResidentialRemodelers => "Residential Remodelers",
             Self::IndustrialBuildingConstruction => "Industrial Building Construction",
-            Self::CommercialAndInstitutionalBuildingConstruction => {
-                "Commercial and Institutional Building Construction"
-            }
-            Self::WaterAndSewerLineAndRelatedStructuresConstruction => {
-                "Water and Sewer Line and Related Structures Construction"
-            }
-            Self::OilAndGasPipelineAndRelatedStructuresConstruction => {
-                "Oil and Gas Pipeline and Related Structures Construction"
-            }
-            Self::PowerAndCommunicationLineAndRelatedStructuresConstruction => {
-                "Power and Communication Line and Related Structures Construction"
-            }
+            Self::CommercialAndInstitutionalBuildingConstruction => "Commercial and Institutional Building Construction",
+            Self::WaterAndSewerLineAndRelatedStructuresConstruction => "Water and Sewer Line and Related Structures Construction",
+            Self::OilAndGasPipelineAndRelatedStructuresConstruction => "Oil and Gas Pipeline and Related Structures Construction",
+            Self::PowerAndCommunicationLineAndRelatedStructuresConstruction => "Power and Communication Line and Related Structures Construction",
             Self::LandSubdivision => "Land Subdivision",
             Self::HighwayStreetAndBridgeConstruction => "Highway, Street, and Bridge Construction",
-            Self::OtherHeavyAndCivilEngineeringConstruction => {
-                "Other Heavy and Civil Engineering Construction"
-            }
-            Self::PouredConcreteFoundationAndStructureContractors => {
-                "Poured Concrete Foundation and Structure Contractors"
-            }
-            Self::StructuralSteelAndPrecastConcreteContractors => {
-                "Structural Steel and Precast Concrete Contractors"
-            }
+            Self::OtherHeavyAndCivilEngineeringConstruction => "Other Heavy and Civil Engineering Construction",
+            Self::PouredConcreteFoundationAndStructureContractors => "Poured Concrete Foundation and Structure Contractors",
+            Self::StructuralSteelAndPrecastConcreteContractors => "Structural Steel and Precast Concrete Contractors",
             Self::FramingContractors => "Framing Contractors",
             Self::MasonryContractors => "Masonry Contractors",
             Self::GlassAndGlazingContractors => "Glass and Glazing Contractors",
             Self::RoofingContractors => "Roofing Contractors",
             Self::SidingContractors => "Siding Contractors",
-            Self::OtherFoundationStructureAndBuildingExteriorContractors => {
-                "Other Foundation, Structure, and Building Exterior Contractors"
-            }
-            Self::ElectricalContractorsAndOtherWiringInstallationContractors => {
-                "Electrical Contractors and Other Wiring Installation Contractors"
-            }
-            Self::PlumbingHeatingAndAirconditioningContractors => {
-                "Plumbing, Heating, and Air-Conditioning Contractors"
-            }
+            Self::OtherFoundationStructureAndBuildingExteriorContractors => "Other Foundation, Structure, and Building Exterior Contractors",
+            Self::ElectricalContractorsAndOtherWiringInstallationContractors => "Electrical Contractors and Other Wiring Installation Contractors",
+            Self::PlumbingHeatingAndAirconditioningContractors => "Plumbing, Heating, and Air-Conditioning Contractors",
             Self::OtherBuildingEquipmentContractors => "Other Building Equipment Contractors",
             Self::DrywallAndInsulationContractors => "Drywall and Insulation Contractors",
             Self::PaintingAndWallCoveringContractors => "Painting and Wall Covering Contractors",
@@ -2234,26 +2185,16 @@ impl NaicsIndustry {
             Self::FlourMilling => "Flour Milling",
             Self::RiceMilling => "Rice Milling",
             Self::MaltManufacturing => "Malt Manufacturing",
-            Self::WetCornMillingAndStarchManufacturing => {
-                "Wet Corn Milling and Starch Manufacturing"
-            }
+            Self::WetCornMillingAndStarchManufacturing => "Wet Corn Milling and Starch Manufacturing",
             Self::SoybeanAndOtherOilseedProcessing => "Soybean and Other Oilseed Processing",
             Self::FatsAndOilsRefiningAndBlending => "Fats and Oils Refining and Blending",
             Self::BreakfastCerealManufacturing => "Breakfast Cereal Manufacturing",
             Self::BeetSugarManufacturing => "Beet Sugar Manufacturing",
             Self::CaneSugarManufacturing => "Cane Sugar Manufacturing",
-            Self::NonchocolateConfectioneryManufacturing => {
-                "Nonchocolate Confectionery Manufacturing"
-            }
-            Self::ChocolateAndConfectioneryManufacturingFromCacaoBeans => {
-                "Chocolate and Confectionery Manufacturing from Cacao Beans"
-            }
-            Self::ConfectioneryManufacturingFromPurchasedChocolate => {
-                "Confectionery Manufacturing from Purchased Chocolate"
-            }
-            Self::FrozenFruitJuiceAndVegetableManufacturing => {
-                "Frozen Fruit, Juice, and Vegetable Manufacturing"
-            }
+            Self::NonchocolateConfectioneryManufacturing => "Nonchocolate Confectionery Manufacturing",
+            Self::ChocolateAndConfectioneryManufacturingFromCacaoBeans => "Chocolate and Confectionery Manufacturing from Cacao Beans",
+            Self::ConfectioneryManufacturingFromPurchasedChocolate => "Confectionery Manufacturing from Purchased Chocolate",
+            Self::FrozenFruitJuiceAndVegetableManufacturing => "Frozen Fruit, Juice, and Vegetable Manufacturing",
             Self::FrozenSpecialtyFoodManufacturing => "Frozen Specialty Food Manufacturing",
             Self::FruitAndVegetableCanning => "Fruit and Vegetable Canning",
             Self::SpecialtyCanning => "Specialty Canning",
@@ -2261,45 +2202,27 @@ impl NaicsIndustry {
             Self::FluidMilkManufacturing => "Fluid Milk Manufacturing",
             Self::CreameryButterManufacturing => "Creamery Butter Manufacturing",
             Self::CheeseManufacturing => "Cheese Manufacturing",
-            Self::DryCondensedAndEvaporatedDairyProductManufacturing => {
-                "Dry, Condensed, and Evaporated Dairy Product Manufacturing"
-            }
-            Self::IceCreamAndFrozenDessertManufacturing => {
-                "Ice Cream and Frozen Dessert Manufacturing"
-            }
+            Self::DryCondensedAndEvaporatedDairyProductManufacturing => "Dry, Condensed, and Evaporated Dairy Product Manufacturing",
+            Self::IceCreamAndFrozenDessertManufacturing => "Ice Cream and Frozen Dessert Manufacturing",
             Self::AnimalExceptPoultrySlaughtering => "Animal (except Poultry) Slaughtering",
             Self::MeatProcessedFromCarcasses => "Meat Processed from Carcasses",
             Self::RenderingAndMeatByproductProcessing => "Rendering and Meat Byproduct Processing",
             Self::PoultryProcessing => "Poultry Processing",
-            Self::SeafoodProductPreparationAndPackaging => {
-                "Seafood Product Preparation and Packaging"
-            }
+            Self::SeafoodProductPreparationAndPackaging => "Seafood Product Preparation and Packaging",
             Self::RetailBakeries => "Retail Bakeries",
             Self::CommercialBakeries => "Commercial Bakeries",
-            Self::FrozenCakesPiesAndOtherPastriesManufacturing => {
-                "Frozen Cakes, Pies, and Other Pastries Manufacturing"
-            }
+            Self::FrozenCakesPiesAndOtherPastriesManufacturing => "Frozen Cakes, Pies, and Other Pastries Manufacturing",
             Self::CookieAndCrackerManufacturing => "Cookie and Cracker Manufacturing",
-            Self::DryPastaDoughAndFlourMixesManufacturingFromPurchasedFlour => {
-                "Dry Pasta, Dough, and Flour Mixes Manufacturing from Purchased Flour"
-            }
+            Self::DryPastaDoughAndFlourMixesManufacturingFromPurchasedFlour => "Dry Pasta, Dough, and Flour Mixes Manufacturing from Purchased Flour",
             Self::TortillaManufacturing => "Tortilla Manufacturing",
-            Self::RoastedNutsAndPeanutButterManufacturing => {
-                "Roasted Nuts and Peanut Butter Manufacturing"
-            }
+            Self::RoastedNutsAndPeanutButterManufacturing => "Roasted Nuts and Peanut Butter Manufacturing",
             Self::OtherSnackFoodManufacturing => "Other Snack Food Manufacturing",
             Self::CoffeeAndTeaManufacturing => "Coffee and Tea Manufacturing",
-            Self::FlavoringSyrupAndConcentrateManufacturing => {
-                "Flavoring Syrup and Concentrate Manufacturing"
-            }
-            Self::MayonnaiseDressingAndOtherPreparedSauceManufacturing => {
-                "Mayonnaise, Dressing, and Other Prepared Sauce Manufacturing"
-            }
+            Self::FlavoringSyrupAndConcentrateManufacturing => "Flavoring Syrup and Concentrate Manufacturing",
+            Self::MayonnaiseDressingAndOtherPreparedSauceManufacturing => "Mayonnaise, Dressing, and Other Prepared Sauce Manufacturing",
             Self::SpiceAndExtractManufacturing => "Spice and Extract Manufacturing",
             Self::PerishablePreparedFoodManufacturing => "Perishable Prepared Food Manufacturing",
-            Self::AllOtherMiscellaneousFoodManufacturing => {
-                "All Other Miscellaneous Food Manufacturing"
-            }
+            Self::AllOtherMiscellaneousFoodManufacturing => "All Other Miscellaneous Food Manufacturing",
             Self::SoftDrinkManufacturing => "Soft Drink Manufacturing",
             Self::BottledWaterManufacturing => "Bottled Water Manufacturing",
             Self::IceManufacturing => "Ice Manufacturing",
@@ -2309,9 +2232,7 @@ impl NaicsIndustry {
             Self::TobaccoManufacturing => "Tobacco Manufacturing",
             Self::FiberYarnAndThreadMills => "Fiber, Yarn, and Thread Mills",
             Self::BroadwovenFabricMills => "Broadwoven Fabric Mills",
-            Self::NarrowFabricMillsAndSchiffliMachineEmbroidery => {
-                "Narrow Fabric Mills and Schiffli Machine Embroidery"
-            }
+            Self::NarrowFabricMillsAndSchiffliMachineEmbroidery => "Narrow Fabric Mills and Schiffli Machine Embroidery",
             Self::NonwovenFabricMills => "Nonwoven Fabric Mills",
             Self::KnitFabricMills => "Knit Fabric Mills",
             Self::TextileAndFabricFinishingMills => "Textile and Fabric Finishing Mills",
@@ -2319,196 +2240,104 @@ impl NaicsIndustry {
             Self::CarpetAndRugMills => "Carpet and Rug Mills",
             Self::CurtainAndLinenMills => "Curtain and Linen Mills",
             Self::TextileBagAndCanvasMills => "Textile Bag and Canvas Mills",
-            Self::RopeCordageTwineTireCordAndTireFabricMills => {
-                "Rope, Cordage, Twine, Tire Cord, and Tire Fabric Mills"
-            }
-            Self::AllOtherMiscellaneousTextileProductMills => {
-                "All Other Miscellaneous Textile Product Mills"
-            }
+            Self::RopeCordageTwineTireCordAndTireFabricMills => "Rope, Cordage, Twine, Tire Cord, and Tire Fabric Mills",
+            Self::AllOtherMiscellaneousTextileProductMills => "All Other Miscellaneous Textile Product Mills",
             Self::ApparelKnittingMills => "Apparel Knitting Mills",
             Self::CutAndSewApparelContractors => "Cut and Sew Apparel Contractors",
-            Self::CutAndSewApparelManufacturingExceptContractors => {
-                "Cut and Sew Apparel Manufacturing (except Contractors)"
-            }
-            Self::ApparelAccessoriesAndOtherApparelManufacturing => {
-                "Apparel Accessories and Other Apparel Manufacturing"
-            }
+            Self::CutAndSewApparelManufacturingExceptContractors => "Cut and Sew Apparel Manufacturing (except Contractors)",
+            Self::ApparelAccessoriesAndOtherApparelManufacturing => "Apparel Accessories and Other Apparel Manufacturing",
             Self::LeatherAndHideTanningAndFinishing => "Leather and Hide Tanning and Finishing",
             Self::FootwearManufacturing => "Footwear Manufacturing",
-            Self::OtherLeatherAndAlliedProductManufacturing => {
-                "Other Leather and Allied Product Manufacturing"
-            }
+            Self::OtherLeatherAndAlliedProductManufacturing => "Other Leather and Allied Product Manufacturing",
             Self::Sawmills => "Sawmills",
             Self::WoodPreservation => "Wood Preservation",
-            Self::HardwoodVeneerAndPlywoodManufacturing => {
-                "Hardwood Veneer and Plywood Manufacturing"
-            }
-            Self::SoftwoodVeneerAndPlywoodManufacturing => {
-                "Softwood Veneer and Plywood Manufacturing"
-            }
+            Self::HardwoodVeneerAndPlywoodManufacturing => "Hardwood Veneer and Plywood Manufacturing",
+            Self::SoftwoodVeneerAndPlywoodManufacturing => "Softwood Veneer and Plywood Manufacturing",
             Self::EngineeredWoodMemberManufacturing => "Engineered Wood Member Manufacturing",
-            Self::ReconstitutedWoodProductManufacturing => {
-                "Reconstituted Wood Product Manufacturing"
-            }
+            Self::ReconstitutedWoodProductManufacturing => "Reconstituted Wood Product Manufacturing",
             Self::WoodWindowAndDoorManufacturing => "Wood Window and Door Manufacturing",
             Self::CutStockResawingLumberAndPlaning => "Cut Stock, Resawing Lumber, and Planing",
             Self::OtherMillworkIncludingFlooring => "Other Millwork (including Flooring)",
             Self::WoodContainerAndPalletManufacturing => "Wood Container and Pallet Manufacturing",
-            Self::ManufacturedHomeMobileHomeManufacturing => {
-                "Manufactured Home (Mobile Home) Manufacturing"
-            }
-            Self::PrefabricatedWoodBuildingManufacturing => {
-                "Prefabricated Wood Building Manufacturing"
-            }
-            Self::AllOtherMiscellaneousWoodProductManufacturing => {
-                "All Other Miscellaneous Wood Product Manufacturing"
-            }
+            Self::ManufacturedHomeMobileHomeManufacturing => "Manufactured Home (Mobile Home) Manufacturing",
+            Self::PrefabricatedWoodBuildingManufacturing => "Prefabricated Wood Building Manufacturing",
+            Self::AllOtherMiscellaneousWoodProductManufacturing => "All Other Miscellaneous Wood Product Manufacturing",
             Self::PulpMills => "Pulp Mills",
             Self::PaperMills => "Paper Mills",
             Self::PaperboardMills => "Paperboard Mills",
-            Self::CorrugatedAndSolidFiberBoxManufacturing => {
-                "Corrugated and Solid Fiber Box Manufacturing"
-            }
+            Self::CorrugatedAndSolidFiberBoxManufacturing => "Corrugated and Solid Fiber Box Manufacturing",
             Self::FoldingPaperboardBoxManufacturing => "Folding Paperboard Box Manufacturing",
-            Self::OtherPaperboardContainerManufacturing => {
-                "Other Paperboard Container Manufacturing"
-            }
-            Self::PaperBagAndCoatedAndTreatedPaperManufacturing => {
-                "Paper Bag and Coated and Treated Paper Manufacturing"
-            }
+            Self::OtherPaperboardContainerManufacturing => "Other Paperboard Container Manufacturing",
+            Self::PaperBagAndCoatedAndTreatedPaperManufacturing => "Paper Bag and Coated and Treated Paper Manufacturing",
             Self::StationeryProductManufacturing => "Stationery Product Manufacturing",
             Self::SanitaryPaperProductManufacturing => "Sanitary Paper Product Manufacturing",
-            Self::AllOtherConvertedPaperProductManufacturing => {
-                "All Other Converted Paper Product Manufacturing"
-            }
-            Self::CommercialPrintingExceptScreenAndBooks => {
-                "Commercial Printing (except Screen and Books)"
-            }
+            Self::AllOtherConvertedPaperProductManufacturing => "All Other Converted Paper Product Manufacturing",
+            Self::CommercialPrintingExceptScreenAndBooks => "Commercial Printing (except Screen and Books)",
             Self::CommercialScreenPrinting => "Commercial Screen Printing",
             Self::BooksPrinting => "Books Printing",
             Self::SupportActivitiesForPrinting => "Support Activities for Printing",
             Self::PetroleumRefineries => "Petroleum Refineries",
-            Self::AsphaltPavingMixtureAndBlockManufacturing => {
-                "Asphalt Paving Mixture and Block Manufacturing"
-            }
-            Self::AsphaltShingleAndCoatingMaterialsManufacturing => {
-                "Asphalt Shingle and Coating Materials Manufacturing"
-            }
-            Self::PetroleumLubricatingOilAndGreaseManufacturing => {
-                "Petroleum Lubricating Oil and Grease Manufacturing"
-            }
-            Self::AllOtherPetroleumAndCoalProductsManufacturing => {
-                "All Other Petroleum and Coal Products Manufacturing"
-            }
+            Self::AsphaltPavingMixtureAndBlockManufacturing => "Asphalt Paving Mixture and Block Manufacturing",
+            Self::AsphaltShingleAndCoatingMaterialsManufacturing => "Asphalt Shingle and Coating Materials Manufacturing",
+            Self::PetroleumLubricatingOilAndGreaseManufacturing => "Petroleum Lubricating Oil and Grease Manufacturing",
+            Self::AllOtherPetroleumAndCoalProductsManufacturing => "All Other Petroleum and Coal Products Manufacturing",
             Self::PetrochemicalManufacturing => "Petrochemical Manufacturing",
             Self::IndustrialGasManufacturing => "Industrial Gas Manufacturing",
             Self::SyntheticDyeAndPigmentManufacturing => "Synthetic Dye and Pigment Manufacturing",
-            Self::OtherBasicInorganicChemicalManufacturing => {
-                "Other Basic Inorganic Chemical Manufacturing"
-            }
+            Self::OtherBasicInorganicChemicalManufacturing => "Other Basic Inorganic Chemical Manufacturing",
             Self::EthylAlcoholManufacturing => "Ethyl Alcohol Manufacturing",
-            Self::CyclicCrudeIntermediateAndGumAndWoodChemicalManufacturing => {
-                "Cyclic Crude, Intermediate, and Gum and Wood Chemical Manufacturing "
-            }
-            Self::AllOtherBasicOrganicChemicalManufacturing => {
-                "All Other Basic Organic Chemical Manufacturing "
-            }
-            Self::PlasticsMaterialAndResinManufacturing => {
-                "Plastics Material and Resin Manufacturing "
-            }
-            Self::SyntheticRubberManufacturing => "Synthetic Rubber Manufacturing ",
-            Self::ArtificialAndSyntheticFibersAndFilamentsManufacturing => {
-                "Artificial and Synthetic Fibers and Filaments Manufacturing"
-            }
-            Self::NitrogenousFertilizerManufacturing => "Nitrogenous Fertilizer Manufacturing ",
-            Self::PhosphaticFertilizerManufacturing => "Phosphatic Fertilizer Manufacturing ",
-            Self::FertilizerMixingOnlyManufacturing => "Fertilizer (Mixing Only) Manufacturing ",
+            Self::CyclicCrudeIntermediateAndGumAndWoodChemicalManufacturing => "Cyclic Crude, Intermediate, and Gum and Wood Chemical Manufacturing",
+            Self::AllOtherBasicOrganicChemicalManufacturing => "All Other Basic Organic Chemical Manufacturing",
+            Self::PlasticsMaterialAndResinManufacturing => "Plastics Material and Resin Manufacturing",
+            Self::SyntheticRubberManufacturing => "Synthetic Rubber Manufacturing",
+            Self::ArtificialAndSyntheticFibersAndFilamentsManufacturing => "Artificial and Synthetic Fibers and Filaments Manufacturing",
+            Self::NitrogenousFertilizerManufacturing => "Nitrogenous Fertilizer Manufacturing",
+            Self::PhosphaticFertilizerManufacturing => "Phosphatic Fertilizer Manufacturing",
+            Self::FertilizerMixingOnlyManufacturing => "Fertilizer (Mixing Only) Manufacturing",
             Self::CompostManufacturing => "Compost Manufacturing",
-            Self::PesticideAndOtherAgriculturalChemicalManufacturing => {
-                "Pesticide and Other Agricultural Chemical Manufacturing"
-            }
-            Self::MedicinalAndBotanicalManufacturing => "Medicinal and Botanical Manufacturing ",
-            Self::PharmaceuticalPreparationManufacturing => {
-                "Pharmaceutical Preparation Manufacturing "
-            }
-            Self::InvitroDiagnosticSubstanceManufacturing => {
-                "In-Vitro Diagnostic Substance Manufacturing "
-            }
-            Self::BiologicalProductExceptDiagnosticManufacturing => {
-                "Biological Product (except Diagnostic) Manufacturing "
-            }
+            Self::PesticideAndOtherAgriculturalChemicalManufacturing => "Pesticide and Other Agricultural Chemical Manufacturing",
+            Self::MedicinalAndBotanicalManufacturing => "Medicinal and Botanical Manufacturing",
+            Self::PharmaceuticalPreparationManufacturing => "Pharmaceutical Preparation Manufacturing",
+            Self::InvitroDiagnosticSubstanceManufacturing => "In-Vitro Diagnostic Substance Manufacturing",
+            Self::BiologicalProductExceptDiagnosticManufacturing => "Biological Product (except Diagnostic) Manufacturing",
             Self::PaintAndCoatingManufacturing => "Paint and Coating Manufacturing",
             Self::AdhesiveManufacturing => "Adhesive Manufacturing",
-            Self::SoapAndOtherDetergentManufacturing => "Soap and Other Detergent Manufacturing ",
-            Self::PolishAndOtherSanitationGoodManufacturing => {
-                "Polish and Other Sanitation Good Manufacturing "
-            }
-            Self::SurfaceActiveAgentManufacturing => "Surface Active Agent Manufacturing ",
+            Self::SoapAndOtherDetergentManufacturing => "Soap and Other Detergent Manufacturing",
+            Self::PolishAndOtherSanitationGoodManufacturing => "Polish and Other Sanitation Good Manufacturing",
+            Self::SurfaceActiveAgentManufacturing => "Surface Active Agent Manufacturing",
             Self::ToiletPreparationManufacturing => "Toilet Preparation Manufacturing",
             Self::PrintingInkManufacturing => "Printing Ink Manufacturing",
             Self::ExplosivesManufacturing => "Explosives Manufacturing",
-            Self::CustomCompoundingOfPurchasedResins => "Custom Compounding of Purchased Resins ",
-            Self::PhotographicFilmPaperPlateChemicalAndCopyTonerManufacturing => {
-                "Photographic Film, Paper, Plate, Chemical, and Copy Toner Manufacturing "
-            }
-            Self::AllOtherMiscellaneousChemicalProductAndPreparationManufacturing => {
-                "All Other Miscellaneous Chemical Product and Preparation Manufacturing "
-            }
-            Self::PlasticsBagAndPouchManufacturing => "Plastics Bag and Pouch Manufacturing ",
-            Self::PlasticsPackagingFilmAndSheetIncludingLaminatedManufacturing => {
-                "Plastics Packaging Film and Sheet (including Laminated) Manufacturing "
-            }
-            Self::UnlaminatedPlasticsFilmAndSheetExceptPackagingManufacturing => {
-                "Unlaminated Plastics Film and Sheet (except Packaging) Manufacturing "
-            }
-            Self::UnlaminatedPlasticsProfileShapeManufacturing => {
-                "Unlaminated Plastics Profile Shape Manufacturing "
-            }
-            Self::PlasticsPipeAndPipeFittingManufacturing => {
-                "Plastics Pipe and Pipe Fitting Manufacturing "
-            }
-            Self::LaminatedPlasticsPlateSheetExceptPackagingAndShapeManufacturing => {
-                "Laminated Plastics Plate, Sheet (except Packaging), and Shape Manufacturing"
-            }
+            Self::CustomCompoundingOfPurchasedResins => "Custom Compounding of Purchased Resins",
+            Self::PhotographicFilmPaperPlateChemicalAndCopyTonerManufacturing => "Photographic Film, Paper, Plate, Chemical, and Copy Toner Manufacturing",
+            Self::AllOtherMiscellaneousChemicalProductAndPreparationManufacturing => "All Other Miscellaneous Chemical Product and Preparation Manufacturing",
+            Self::PlasticsBagAndPouchManufacturing => "Plastics Bag and Pouch Manufacturing",
+            Self::PlasticsPackagingFilmAndSheetIncludingLaminatedManufacturing => "Plastics Packaging Film and Sheet (including Laminated) Manufacturing",
+            Self::UnlaminatedPlasticsFilmAndSheetExceptPackagingManufacturing => "Unlaminated Plastics Film and Sheet (except Packaging) Manufacturing",
+            Self::UnlaminatedPlasticsProfileShapeManufacturing => "Unlaminated Plastics Profile Shape Manufacturing",
+            Self::PlasticsPipeAndPipeFittingManufacturing => "Plastics Pipe and Pipe Fitting Manufacturing",
+            Self::LaminatedPlasticsPlateSheetExceptPackagingAndShapeManufacturing => "Laminated Plastics Plate, Sheet (except Packaging), and Shape Manufacturing",
             Self::PolystyreneFoamProductManufacturing => "Polystyrene Foam Product Manufacturing",
-            Self::UrethaneAndOtherFoamProductExceptPolystyreneManufacturing => {
-                "Urethane and Other Foam Product (except Polystyrene) Manufacturing"
-            }
+            Self::UrethaneAndOtherFoamProductExceptPolystyreneManufacturing => "Urethane and Other Foam Product (except Polystyrene) Manufacturing",
             Self::PlasticsBottleManufacturing => "Plastics Bottle Manufacturing",
-            Self::PlasticsPlumbingFixtureManufacturing => {
-                "Plastics Plumbing Fixture Manufacturing "
-            }
-            Self::AllOtherPlasticsProductManufacturing => {
-                "All Other Plastics Product Manufacturing "
-            }
-            Self::TireManufacturingExceptRetreading => "Tire Manufacturing (except Retreading) ",
-            Self::TireRetreading => "Tire Retreading ",
-            Self::RubberAndPlasticsHosesAndBeltingManufacturing => {
-                "Rubber and Plastics Hoses and Belting Manufacturing"
-            }
-            Self::RubberProductManufacturingForMechanicalUse => {
-                "Rubber Product Manufacturing for Mechanical Use "
-            }
-            Self::AllOtherRubberProductManufacturing => "All Other Rubber Product Manufacturing ",
-            Self::PotteryCeramicsAndPlumbingFixtureManufacturing => {
-                "Pottery, Ceramics, and Plumbing Fixture Manufacturing "
-            }
-            Self::ClayBuildingMaterialAndRefractoriesManufacturing => {
-                "Clay Building Material and Refractories Manufacturing "
-            }
-            Self::FlatGlassManufacturing => "Flat Glass Manufacturing ",
-            Self::OtherPressedAndBlownGlassAndGlasswareManufacturing => {
-                "Other Pressed and Blown Glass and Glassware Manufacturing "
-            }
-            Self::GlassContainerManufacturing => "Glass Container Manufacturing ",
-            Self::GlassProductManufacturingMadeOfPurchasedGlass => {
-                "Glass Product Manufacturing Made of Purchased Glass "
-            }
+            Self::PlasticsPlumbingFixtureManufacturing => "Plastics Plumbing Fixture Manufacturing",
+            Self::AllOtherPlasticsProductManufacturing => "All Other Plastics Product Manufacturing",
+            Self::TireManufacturingExceptRetreading => "Tire Manufacturing (except Retreading)",
+            Self::TireRetreading => "Tire Retreading",
+            Self::RubberAndPlasticsHosesAndBeltingManufacturing => "Rubber and Plastics Hoses and Belting Manufacturing",
+            Self::RubberProductManufacturingForMechanicalUse => "Rubber Product Manufacturing for Mechanical Use",
+            Self::AllOtherRubberProductManufacturing => "All Other Rubber Product Manufacturing",
+            Self::PotteryCeramicsAndPlumbingFixtureManufacturing => "Pottery, Ceramics, and Plumbing Fixture Manufacturing",
+            Self::ClayBuildingMaterialAndRefractoriesManufacturing => "Clay Building Material and Refractories Manufacturing",
+            Self::FlatGlassManufacturing => "Flat Glass Manufacturing",
+            Self::OtherPressedAndBlownGlassAndGlasswareManufacturing => "Other Pressed and Blown Glass and Glassware Manufacturing",
+            Self::GlassContainerManufacturing => "Glass Container Manufacturing",
+            Self::GlassProductManufacturingMadeOfPurchasedGlass => "Glass Product Manufacturing Made of Purchased Glass",
             Self::CementManufacturing => "Cement Manufacturing",
             Self::ReadymixConcreteManufacturing => "Ready-Mix Concrete Manufacturing",
-            Self::ConcreteBlockAndBrickManufacturing => "Concrete Block and Brick Manufacturing ",
-            Self::ConcretePipeManufacturing => "Concrete Pipe Manufacturing ",
-            Self::OtherConcreteProductManufacturing => "Other Concrete Product Manufacturing ",
+            Self::ConcreteBlockAndBrickManufacturing => "Concrete Block and Brick Manufacturing",
+            Self::ConcretePipeManufacturing => "Concrete Pipe Manufacturing",
+            Self::OtherConcreteProductManufacturing => "Other Concrete Product Manufacturing",
             Self::LimeManufacturing => "Lime Manufacturing",
             Self::GypsumProductManufacturing => "Gypsum Product Manufacturing",
             Self::AbrasiveProductManufacturing => "Abrasive Product Manufacturing",
@@ -2612,44 +2441,44 @@ impl NaicsIndustry {
             Self::RadioAndTelevisionBroadcastingAndWirelessCommunicationsEquipmentManufacturing => "Radio and Television Broadcasting and Wireless Communications Equipment Manufacturing",
             Self::OtherCommunicationsEquipmentManufacturing => "Other Communications Equipment Manufacturing",
             Self::AudioAndVideoEquipmentManufacturing => "Audio and Video Equipment Manufacturing",
-            Self::BarePrintedCircuitBoardManufacturing => "Bare Printed Circuit Board Manufacturing  ",
-            Self::SemiconductorAndRelatedDeviceManufacturing => "Semiconductor and Related Device Manufacturing ",
-            Self::CapacitorResistorCoilTransformerAndOtherInductorManufacturing => "Capacitor, Resistor, Coil, Transformer, and Other Inductor Manufacturing ",
-            Self::ElectronicConnectorManufacturing => "Electronic Connector Manufacturing ",
-            Self::PrintedCircuitAssemblyElectronicAssemblyManufacturing => "Printed Circuit Assembly (Electronic Assembly) Manufacturing ",
-            Self::OtherElectronicComponentManufacturing => "Other Electronic Component Manufacturing ",
-            Self::ElectromedicalAndElectrotherapeuticApparatusManufacturing => "Electromedical and Electrotherapeutic Apparatus Manufacturing ",
-            Self::SearchDetectionNavigationGuidanceAeronauticalAndNauticalSystemAndInstrumentManufacturing => "Search, Detection, Navigation, Guidance, Aeronautical, and Nautical System and Instrument Manufacturing ",
-            Self::AutomaticEnvironmentalControlManufacturingForResidentialCommercialAndApplianceUse => "Automatic Environmental Control Manufacturing for Residential, Commercial, and Appliance Use ",
-            Self::InstrumentsAndRelatedProductsManufacturingForMeasuringDisplayingAndControllingIndustrialProcessVariables => "Instruments and Related Products Manufacturing for Measuring, Displaying, and Controlling Industrial Process Variables ",
-            Self::TotalizingFluidMeterAndCountingDeviceManufacturing => "Totalizing Fluid Meter and Counting Device Manufacturing ",
-            Self::InstrumentManufacturingForMeasuringAndTestingElectricityAndElectricalSignals => "Instrument Manufacturing for Measuring and Testing Electricity and Electrical Signals ",
-            Self::AnalyticalLaboratoryInstrumentManufacturing => "Analytical Laboratory Instrument Manufacturing ",
-            Self::IrradiationApparatusManufacturing => "Irradiation Apparatus Manufacturing ",
-            Self::OtherMeasuringAndControllingDeviceManufacturing => "Other Measuring and Controlling Device Manufacturing ",
-            Self::ManufacturingAndReproducingMagneticAndOpticalMedia => "Manufacturing and Reproducing Magnetic and Optical Media ",
-            Self::ResidentialElectricLightingFixtureManufacturing => "Residential Electric Lighting Fixture Manufacturing ",
-            Self::CommercialIndustrialAndInstitutionalElectricLightingFixtureManufacturing => "Commercial, Industrial, and Institutional Electric Lighting Fixture Manufacturing ",
-            Self::ElectricLampBulbAndOtherLightingEquipmentManufacturing => "Electric Lamp Bulb and Other Lighting Equipment Manufacturing ",
+            Self::BarePrintedCircuitBoardManufacturing => "Bare Printed Circuit Board Manufacturing",
+            Self::SemiconductorAndRelatedDeviceManufacturing => "Semiconductor and Related Device Manufacturing",
+            Self::CapacitorResistorCoilTransformerAndOtherInductorManufacturing => "Capacitor, Resistor, Coil, Transformer, and Other Inductor Manufacturing",
+            Self::ElectronicConnectorManufacturing => "Electronic Connector Manufacturing",
+            Self::PrintedCircuitAssemblyElectronicAssemblyManufacturing => "Printed Circuit Assembly (Electronic Assembly) Manufacturing",
+            Self::OtherElectronicComponentManufacturing => "Other Electronic Component Manufacturing",
+            Self::ElectromedicalAndElectrotherapeuticApparatusManufacturing => "Electromedical and Electrotherapeutic Apparatus Manufacturing",
+            Self::SearchDetectionNavigationGuidanceAeronauticalAndNauticalSystemAndInstrumentManufacturing => "Search, Detection, Navigation, Guidance, Aeronautical, and Nautical System and Instrument Manufacturing",
+            Self::AutomaticEnvironmentalControlManufacturingForResidentialCommercialAndApplianceUse => "Automatic Environmental Control Manufacturing for Residential, Commercial, and Appliance Use",
+            Self::InstrumentsAndRelatedProductsManufacturingForMeasuringDisplayingAndControllingIndustrialProcessVariables => "Instruments and Related Products Manufacturing for Measuring, Displaying, and Controlling Industrial Process Variables",
+            Self::TotalizingFluidMeterAndCountingDeviceManufacturing => "Totalizing Fluid Meter and Counting Device Manufacturing",
+            Self::InstrumentManufacturingForMeasuringAndTestingElectricityAndElectricalSignals => "Instrument Manufacturing for Measuring and Testing Electricity and Electrical Signals",
+            Self::AnalyticalLaboratoryInstrumentManufacturing => "Analytical Laboratory Instrument Manufacturing",
+            Self::IrradiationApparatusManufacturing => "Irradiation Apparatus Manufacturing",
+            Self::OtherMeasuringAndControllingDeviceManufacturing => "Other Measuring and Controlling Device Manufacturing",
+            Self::ManufacturingAndReproducingMagneticAndOpticalMedia => "Manufacturing and Reproducing Magnetic and Optical Media",
+            Self::ResidentialElectricLightingFixtureManufacturing => "Residential Electric Lighting Fixture Manufacturing",
+            Self::CommercialIndustrialAndInstitutionalElectricLightingFixtureManufacturing => "Commercial, Industrial, and Institutional Electric Lighting Fixture Manufacturing",
+            Self::ElectricLampBulbAndOtherLightingEquipmentManufacturing => "Electric Lamp Bulb and Other Lighting Equipment Manufacturing",
             Self::SmallElectricalApplianceManufacturing => "Small Electrical Appliance Manufacturing",
-            Self::MajorHouseholdApplianceManufacturing => "Major Household Appliance Manufacturing ",
-            Self::PowerDistributionAndSpecialtyTransformerManufacturing => "Power, Distribution, and Specialty Transformer Manufacturing ",
-            Self::MotorAndGeneratorManufacturing => "Motor and Generator Manufacturing ",
-            Self::SwitchgearAndSwitchboardApparatusManufacturing => "Switchgear and Switchboard Apparatus Manufacturing ",
-            Self::RelayAndIndustrialControlManufacturing => "Relay and Industrial Control Manufacturing ",
-            Self::BatteryManufacturing => "Battery Manufacturing ",
-            Self::FiberOpticCableManufacturing => "Fiber Optic Cable Manufacturing ",
-            Self::OtherCommunicationAndEnergyWireManufacturing => "Other Communication and Energy Wire Manufacturing ",
-            Self::CurrentcarryingWiringDeviceManufacturing => "Current-Carrying Wiring Device Manufacturing ",
-            Self::NoncurrentcarryingWiringDeviceManufacturing => "Noncurrent-Carrying Wiring Device Manufacturing ",
-            Self::CarbonAndGraphiteProductManufacturing => "Carbon and Graphite Product Manufacturing ",
-            Self::AllOtherMiscellaneousElectricalEquipmentAndComponentManufacturing => "All Other Miscellaneous Electrical Equipment and Component Manufacturing ",
-            Self::AutomobileAndLightDutyMotorVehicleManufacturing => "Automobile and Light Duty Motor Vehicle Manufacturing ",
+            Self::MajorHouseholdApplianceManufacturing => "Major Household Appliance Manufacturing",
+            Self::PowerDistributionAndSpecialtyTransformerManufacturing => "Power, Distribution, and Specialty Transformer Manufacturing",
+            Self::MotorAndGeneratorManufacturing => "Motor and Generator Manufacturing",
+            Self::SwitchgearAndSwitchboardApparatusManufacturing => "Switchgear and Switchboard Apparatus Manufacturing",
+            Self::RelayAndIndustrialControlManufacturing => "Relay and Industrial Control Manufacturing",
+            Self::BatteryManufacturing => "Battery Manufacturing",
+            Self::FiberOpticCableManufacturing => "Fiber Optic Cable Manufacturing",
+            Self::OtherCommunicationAndEnergyWireManufacturing => "Other Communication and Energy Wire Manufacturing",
+            Self::CurrentcarryingWiringDeviceManufacturing => "Current-Carrying Wiring Device Manufacturing",
+            Self::NoncurrentcarryingWiringDeviceManufacturing => "Noncurrent-Carrying Wiring Device Manufacturing",
+            Self::CarbonAndGraphiteProductManufacturing => "Carbon and Graphite Product Manufacturing",
+            Self::AllOtherMiscellaneousElectricalEquipmentAndComponentManufacturing => "All Other Miscellaneous Electrical Equipment and Component Manufacturing",
+            Self::AutomobileAndLightDutyMotorVehicleManufacturing => "Automobile and Light Duty Motor Vehicle Manufacturing",
             Self::HeavyDutyTruckManufacturing => "Heavy Duty Truck Manufacturing",
-            Self::MotorVehicleBodyManufacturing => "Motor Vehicle Body Manufacturing ",
-            Self::TruckTrailerManufacturing => "Truck Trailer Manufacturing ",
-            Self::MotorHomeManufacturing => "Motor Home Manufacturing ",
-            Self::TravelTrailerAndCamperManufacturing => "Travel Trailer and Camper Manufacturing ",
+            Self::MotorVehicleBodyManufacturing => "Motor Vehicle Body Manufacturing",
+            Self::TruckTrailerManufacturing => "Truck Trailer Manufacturing",
+            Self::MotorHomeManufacturing => "Motor Home Manufacturing",
+            Self::TravelTrailerAndCamperManufacturing => "Travel Trailer and Camper Manufacturing",
             Self::MotorVehicleGasolineEngineAndEnginePartsManufacturing => "Motor Vehicle Gasoline Engine and Engine Parts Manufacturing",
             Self::MotorVehicleElectricalAndElectronicEquipmentManufacturing => "Motor Vehicle Electrical and Electronic Equipment Manufacturing",
             Self::MotorVehicleSteeringAndSuspensionComponentsExceptSpringManufacturing => "Motor Vehicle Steering and Suspension Components (except Spring) Manufacturing",
@@ -2658,45 +2487,45 @@ impl NaicsIndustry {
             Self::MotorVehicleSeatingAndInteriorTrimManufacturing => "Motor Vehicle Seating and Interior Trim Manufacturing",
             Self::MotorVehicleMetalStamping => "Motor Vehicle Metal Stamping",
             Self::OtherMotorVehiclePartsManufacturing => "Other Motor Vehicle Parts Manufacturing",
-            Self::AircraftManufacturing => "Aircraft Manufacturing ",
-            Self::AircraftEngineAndEnginePartsManufacturing => "Aircraft Engine and Engine Parts Manufacturing ",
-            Self::OtherAircraftPartsAndAuxiliaryEquipmentManufacturing => "Other Aircraft Parts and Auxiliary Equipment Manufacturing ",
-            Self::GuidedMissileAndSpaceVehicleManufacturing => "Guided Missile and Space Vehicle Manufacturing ",
-            Self::GuidedMissileAndSpaceVehiclePropulsionUnitAndPropulsionUnitPartsManufacturing => "Guided Missile and Space Vehicle Propulsion Unit and Propulsion Unit Parts Manufacturing ",
-            Self::OtherGuidedMissileAndSpaceVehiclePartsAndAuxiliaryEquipmentManufacturing => "Other Guided Missile and Space Vehicle Parts and Auxiliary Equipment Manufacturing ",
+            Self::AircraftManufacturing => "Aircraft Manufacturing",
+            Self::AircraftEngineAndEnginePartsManufacturing => "Aircraft Engine and Engine Parts Manufacturing",
+            Self::OtherAircraftPartsAndAuxiliaryEquipmentManufacturing => "Other Aircraft Parts and Auxiliary Equipment Manufacturing",
+            Self::GuidedMissileAndSpaceVehicleManufacturing => "Guided Missile and Space Vehicle Manufacturing",
+            Self::GuidedMissileAndSpaceVehiclePropulsionUnitAndPropulsionUnitPartsManufacturing => "Guided Missile and Space Vehicle Propulsion Unit and Propulsion Unit Parts Manufacturing",
+            Self::OtherGuidedMissileAndSpaceVehiclePartsAndAuxiliaryEquipmentManufacturing => "Other Guided Missile and Space Vehicle Parts and Auxiliary Equipment Manufacturing",
             Self::RailroadRollingStockManufacturing => "Railroad Rolling Stock Manufacturing",
-            Self::ShipBuildingAndRepairing => "Ship Building and Repairing ",
-            Self::BoatBuilding => "Boat Building ",
-            Self::MotorcycleBicycleAndPartsManufacturing => "Motorcycle, Bicycle, and Parts Manufacturing ",
-            Self::MilitaryArmoredVehicleTankAndTankComponentManufacturing => "Military Armored Vehicle, Tank, and Tank Component Manufacturing ",
-            Self::AllOtherTransportationEquipmentManufacturing => "All Other Transportation Equipment Manufacturing ",
+            Self::ShipBuildingAndRepairing => "Ship Building and Repairing",
+            Self::BoatBuilding => "Boat Building",
+            Self::MotorcycleBicycleAndPartsManufacturing => "Motorcycle, Bicycle, and Parts Manufacturing",
+            Self::MilitaryArmoredVehicleTankAndTankComponentManufacturing => "Military Armored Vehicle, Tank, and Tank Component Manufacturing",
+            Self::AllOtherTransportationEquipmentManufacturing => "All Other Transportation Equipment Manufacturing",
             Self::WoodKitchenCabinetAndCountertopManufacturing => "Wood Kitchen Cabinet and Countertop Manufacturing",
-            Self::UpholsteredHouseholdFurnitureManufacturing => "Upholstered Household Furniture Manufacturing ",
-            Self::NonupholsteredWoodHouseholdFurnitureManufacturing => "Nonupholstered Wood Household Furniture Manufacturing ",
-            Self::HouseholdFurnitureExceptWoodAndUpholsteredManufacturing => "Household Furniture (except Wood and Upholstered) Manufacturing ",
-            Self::InstitutionalFurnitureManufacturing => "Institutional Furniture Manufacturing ",
-            Self::WoodOfficeFurnitureManufacturing => "Wood Office Furniture Manufacturing ",
-            Self::CustomArchitecturalWoodworkAndMillworkManufacturing => "Custom Architectural Woodwork and Millwork Manufacturing ",
-            Self::OfficeFurnitureExceptWoodManufacturing => "Office Furniture (except Wood) Manufacturing ",
-            Self::ShowcasePartitionShelvingAndLockerManufacturing => "Showcase, Partition, Shelving, and Locker Manufacturing ",
+            Self::UpholsteredHouseholdFurnitureManufacturing => "Upholstered Household Furniture Manufacturing",
+            Self::NonupholsteredWoodHouseholdFurnitureManufacturing => "Nonupholstered Wood Household Furniture Manufacturing",
+            Self::HouseholdFurnitureExceptWoodAndUpholsteredManufacturing => "Household Furniture (except Wood and Upholstered) Manufacturing",
+            Self::InstitutionalFurnitureManufacturing => "Institutional Furniture Manufacturing",
+            Self::WoodOfficeFurnitureManufacturing => "Wood Office Furniture Manufacturing",
+            Self::CustomArchitecturalWoodworkAndMillworkManufacturing => "Custom Architectural Woodwork and Millwork Manufacturing",
+            Self::OfficeFurnitureExceptWoodManufacturing => "Office Furniture (except Wood) Manufacturing",
+            Self::ShowcasePartitionShelvingAndLockerManufacturing => "Showcase, Partition, Shelving, and Locker Manufacturing",
             Self::MattressManufacturing => "Mattress Manufacturing",
             Self::BlindAndShadeManufacturing => "Blind and Shade Manufacturing",
-            Self::SurgicalAndMedicalInstrumentManufacturing => "Surgical and Medical Instrument Manufacturing ",
-            Self::SurgicalApplianceAndSuppliesManufacturing => "Surgical Appliance and Supplies Manufacturing ",
-            Self::DentalEquipmentAndSuppliesManufacturing => "Dental Equipment and Supplies Manufacturing ",
-            Self::OphthalmicGoodsManufacturing => "Ophthalmic Goods Manufacturing ",
-            Self::DentalLaboratories => "Dental Laboratories ",
-            Self::JewelryAndSilverwareManufacturing => "Jewelry and Silverware Manufacturing ",
+            Self::SurgicalAndMedicalInstrumentManufacturing => "Surgical and Medical Instrument Manufacturing",
+            Self::SurgicalApplianceAndSuppliesManufacturing => "Surgical Appliance and Supplies Manufacturing",
+            Self::DentalEquipmentAndSuppliesManufacturing => "Dental Equipment and Supplies Manufacturing",
+            Self::OphthalmicGoodsManufacturing => "Ophthalmic Goods Manufacturing",
+            Self::DentalLaboratories => "Dental Laboratories",
+            Self::JewelryAndSilverwareManufacturing => "Jewelry and Silverware Manufacturing",
             Self::SportingAndAthleticGoodsManufacturing => "Sporting and Athletic Goods Manufacturing",
             Self::DollToyAndGameManufacturing => "Doll, Toy, and Game Manufacturing",
             Self::OfficeSuppliesExceptPaperManufacturing => "Office Supplies (except Paper) Manufacturing",
             Self::SignManufacturing => "Sign Manufacturing",
-            Self::GasketPackingAndSealingDeviceManufacturing => "Gasket, Packing, and Sealing Device Manufacturing ",
-            Self::MusicalInstrumentManufacturing => "Musical Instrument Manufacturing ",
-            Self::FastenerButtonNeedleAndPinManufacturing => "Fastener, Button, Needle, and Pin Manufacturing ",
-            Self::BroomBrushAndMopManufacturing => "Broom, Brush, and Mop Manufacturing ",
-            Self::BurialCasketManufacturing => "Burial Casket Manufacturing ",
-            Self::AllOtherMiscellaneousManufacturing => "All Other Miscellaneous Manufacturing ",
+            Self::GasketPackingAndSealingDeviceManufacturing => "Gasket, Packing, and Sealing Device Manufacturing",
+            Self::MusicalInstrumentManufacturing => "Musical Instrument Manufacturing",
+            Self::FastenerButtonNeedleAndPinManufacturing => "Fastener, Button, Needle, and Pin Manufacturing",
+            Self::BroomBrushAndMopManufacturing => "Broom, Brush, and Mop Manufacturing",
+            Self::BurialCasketManufacturing => "Burial Casket Manufacturing",
+            Self::AllOtherMiscellaneousManufacturing => "All Other Miscellaneous Manufacturing",
             Self::AutomobileAndOtherMotorVehicleMerchantWholesalers => "Automobile and Other Motor Vehicle Merchant Wholesalers",
             Self::MotorVehicleSuppliesAndNewPartsMerchantWholesalers => "Motor Vehicle Supplies and New Parts Merchant Wholesalers",
             Self::TireAndTubeMerchantWholesalers => "Tire and Tube Merchant Wholesalers",
@@ -2711,106 +2540,56 @@ impl NaicsIndustry {
             Self::OfficeEquipmentMerchantWholesalers => "Office Equipment Merchant Wholesalers",
             Self::ComputerAndComputerPeripheralEquipmentAndSoftwareMerchantWholesalers => "Computer and Computer Peripheral Equipment and Software Merchant Wholesalers",
             Self::OtherCommercialEquipmentMerchantWholesalers => "Other Commercial Equipment Merchant Wholesalers",
-            Self::MedicalDentalAndHospitalEquipmentAndSuppliesMerchantWholesalers => 
-                "Medical, Dental, and Hospital Equipment and Supplies Merchant Wholesalers",
-            Self::OphthalmicGoodsMerchantWholesalers => 
-                "Ophthalmic Goods Merchant Wholesalers",
-            Self::OtherProfessionalEquipmentAndSuppliesMerchantWholesalers => 
-                "Other Professional Equipment and Supplies Merchant Wholesalers",
-            Self::MetalServiceCentersAndOtherMetalMerchantWholesalers => 
-                "Metal Service Centers and Other Metal Merchant Wholesalers",
-            Self::CoalAndOtherMineralAndOreMerchantWholesalers => 
-                "Coal and Other Mineral and Ore Merchant Wholesalers",
-            Self::ElectricalApparatusAndEquipmentWiringSuppliesAndRelatedEquipmentMerchantWholesalers => 
-                "Electrical Apparatus and Equipment, Wiring Supplies, and Related Equipment Merchant Wholesalers",
-            Self::HouseholdAppliancesElectricHousewaresAndConsumerElectronicsMerchantWholesalers => 
-                "Household Appliances, Electric Housewares, and Consumer Electronics Merchant Wholesalers",
-            Self::OtherElectronicPartsAndEquipmentMerchantWholesalers => 
-                "Other Electronic Parts and Equipment Merchant Wholesalers",
-            Self::HardwareMerchantWholesalers => 
-                "Hardware Merchant Wholesalers",
-            Self::PlumbingAndHeatingEquipmentAndSuppliesHydronicsMerchantWholesalers => 
-                "Plumbing and Heating Equipment and Supplies (Hydronics) Merchant Wholesalers",
-            Self::WarmAirHeatingAndAirconditioningEquipmentAndSuppliesMerchantWholesalers => 
-                "Warm Air Heating and Air-Conditioning Equipment and Supplies Merchant Wholesalers",
-            Self::RefrigerationEquipmentAndSuppliesMerchantWholesalers => 
-                "Refrigeration Equipment and Supplies Merchant Wholesalers",
-            Self::ConstructionAndMiningExceptOilWellMachineryAndEquipmentMerchantWholesalers => 
-                "Construction and Mining (except Oil Well) Machinery and Equipment Merchant Wholesalers",
-            Self::FarmAndGardenMachineryAndEquipmentMerchantWholesalers => 
-                "Farm and Garden Machinery and Equipment Merchant Wholesalers",
-            Self::IndustrialMachineryAndEquipmentMerchantWholesalers => 
-                "Industrial Machinery and Equipment Merchant Wholesalers",
-            Self::IndustrialSuppliesMerchantWholesalers => 
-                "Industrial Supplies Merchant Wholesalers",
-            Self::ServiceEstablishmentEquipmentAndSuppliesMerchantWholesalers => 
-                "Service Establishment Equipment and Supplies Merchant Wholesalers",
-            Self::TransportationEquipmentAndSuppliesExceptMotorVehicleMerchantWholesalers => 
-                "Transportation Equipment and Supplies (except Motor Vehicle) Merchant Wholesalers",
-            Self::SportingAndRecreationalGoodsAndSuppliesMerchantWholesalers => 
-                "Sporting and Recreational Goods and Supplies Merchant Wholesalers",
-            Self::ToyAndHobbyGoodsAndSuppliesMerchantWholesalers => 
-                "Toy and Hobby Goods and Supplies Merchant Wholesalers",
-            Self::RecyclableMaterialMerchantWholesalers => 
-                "Recyclable Material Merchant Wholesalers",
-            Self::JewelryWatchPreciousStoneAndPreciousMetalMerchantWholesalers => 
-                "Jewelry, Watch, Precious Stone, and Precious Metal Merchant Wholesalers",
-            Self::OtherMiscellaneousDurableGoodsMerchantWholesalers => 
-                "Other Miscellaneous Durable Goods Merchant Wholesalers",
-            Self::PrintingAndWritingPaperMerchantWholesalers => 
-                "Printing and Writing Paper Merchant Wholesalers",
-            Self::StationeryAndOfficeSuppliesMerchantWholesalers => 
-                "Stationery and Office Supplies Merchant Wholesalers",
-            Self::IndustrialAndPersonalServicePaperMerchantWholesalers => 
-                "Industrial and Personal Service Paper Merchant Wholesalers",
-            Self::DrugsAndDruggistsSundriesMerchantWholesalers => 
-                "Drugs and Druggists' Sundries Merchant Wholesalers",
-            Self::PieceGoodsNotionsAndOtherDryGoodsMerchantWholesalers => 
-                "Piece Goods, Notions, and Other Dry Goods Merchant Wholesalers",
-            Self::FootwearMerchantWholesalers => 
-                "Footwear Merchant Wholesalers",
-            Self::ClothingAndClothingAccessoriesMerchantWholesalers => 
-                "Clothing and Clothing Accessories Merchant Wholesalers",
-            Self::GeneralLineGroceryMerchantWholesalers => 
-                "General Line Grocery Merchant Wholesalers",
-            Self::PackagedFrozenFoodMerchantWholesalers => 
-                "Packaged Frozen Food Merchant Wholesalers",
-            Self::DairyProductExceptDriedOrCannedMerchantWholesalers => 
-                "Dairy Product (except Dried or Canned) Merchant Wholesalers",
-            Self::PoultryAndPoultryProductMerchantWholesalers => 
-                "Poultry and Poultry Product Merchant Wholesalers",
-            Self::ConfectioneryMerchantWholesalers => 
-                "Confectionery Merchant Wholesalers",
-            Self::FishAndSeafoodMerchantWholesalers => 
-                "Fish and Seafood Merchant Wholesalers",
-            Self::MeatAndMeatProductMerchantWholesalers => 
-                "Meat and Meat Product Merchant Wholesalers",
-            Self::FreshFruitAndVegetableMerchantWholesalers => 
-                "Fresh Fruit and Vegetable Merchant Wholesalers",
-            Self::OtherGroceryAndRelatedProductsMerchantWholesalers => 
-                "Other Grocery and Related Products Merchant Wholesalers",
-            Self::GrainAndFieldBeanMerchantWholesalers => 
-                "Grain and Field Bean Merchant Wholesalers",
-            Self::LivestockMerchantWholesalers => 
-                "Livestock Merchant Wholesalers",
-            Self::OtherFarmProductRawMaterialMerchantWholesalers => 
-                "Other Farm Product Raw Material Merchant Wholesalers",
-            Self::PlasticsMaterialsAndBasicFormsAndShapesMerchantWholesalers => 
-                "Plastics Materials and Basic Forms and Shapes Merchant Wholesalers",
-            Self::OtherChemicalAndAlliedProductsMerchantWholesalers => 
-                "Other Chemical and Allied Products Merchant Wholesalers",
-            Self::PetroleumBulkStationsAndTerminals => 
-                "Petroleum Bulk Stations and Terminals",
-            Self::PetroleumAndPetroleumProductsMerchantWholesalersExceptBulkStationsAndTerminals => 
-                "Petroleum and Petroleum Products Merchant Wholesalers (except Bulk Stations and Terminals)",
-            Self::BeerAndAleMerchantWholesalers => 
-                "Beer and Ale Merchant Wholesalers",
-            Self::WineAndDistilledAlcoholicBeverageMerchantWholesalers => 
-                "Wine and Distilled Alcoholic Beverage Merchant Wholesalers",
-            Self::FarmSuppliesMerchantWholesalers => 
-                "Farm Supplies Merchant Wholesalers",
-            Self::BookPeriodicalAndNewspaperMerchantWholesalers => 
-                "Book, Periodical, and Newspaper Merchant Wholesalers",
+            Self::MedicalDentalAndHospitalEquipmentAndSuppliesMerchantWholesalers => "Medical, Dental, and Hospital Equipment and Supplies Merchant Wholesalers",
+            Self::OphthalmicGoodsMerchantWholesalers => "Ophthalmic Goods Merchant Wholesalers",
+            Self::OtherProfessionalEquipmentAndSuppliesMerchantWholesalers => "Other Professional Equipment and Supplies Merchant Wholesalers",
+            Self::MetalServiceCentersAndOtherMetalMerchantWholesalers => "Metal Service Centers and Other Metal Merchant Wholesalers",
+            Self::CoalAndOtherMineralAndOreMerchantWholesalers => "Coal and Other Mineral and Ore Merchant Wholesalers",
+            Self::ElectricalApparatusAndEquipmentWiringSuppliesAndRelatedEquipmentMerchantWholesalers => "Electrical Apparatus and Equipment, Wiring Supplies, and Related Equipment Merchant Wholesalers",
+            Self::HouseholdAppliancesElectricHousewaresAndConsumerElectronicsMerchantWholesalers => "Household Appliances, Electric Housewares, and Consumer Electronics Merchant Wholesalers",
+            Self::OtherElectronicPartsAndEquipmentMerchantWholesalers => "Other Electronic Parts and Equipment Merchant Wholesalers",
+            Self::HardwareMerchantWholesalers => "Hardware Merchant Wholesalers",
+            Self::PlumbingAndHeatingEquipmentAndSuppliesHydronicsMerchantWholesalers => "Plumbing and Heating Equipment and Supplies (Hydronics) Merchant Wholesalers",
+            Self::WarmAirHeatingAndAirconditioningEquipmentAndSuppliesMerchantWholesalers => "Warm Air Heating and Air-Conditioning Equipment and Supplies Merchant Wholesalers",
+            Self::RefrigerationEquipmentAndSuppliesMerchantWholesalers => "Refrigeration Equipment and Supplies Merchant Wholesalers",
+            Self::ConstructionAndMiningExceptOilWellMachineryAndEquipmentMerchantWholesalers => "Construction and Mining (except Oil Well) Machinery and Equipment Merchant Wholesalers",
+            Self::FarmAndGardenMachineryAndEquipmentMerchantWholesalers => "Farm and Garden Machinery and Equipment Merchant Wholesalers",
+            Self::IndustrialMachineryAndEquipmentMerchantWholesalers => "Industrial Machinery and Equipment Merchant Wholesalers",
+            Self::IndustrialSuppliesMerchantWholesalers => "Industrial Supplies Merchant Wholesalers",
+            Self::ServiceEstablishmentEquipmentAndSuppliesMerchantWholesalers => "Service Establishment Equipment and Supplies Merchant Wholesalers",
+            Self::TransportationEquipmentAndSuppliesExceptMotorVehicleMerchantWholesalers => "Transportation Equipment and Supplies (except Motor Vehicle) Merchant Wholesalers",
+            Self::SportingAndRecreationalGoodsAndSuppliesMerchantWholesalers => "Sporting and Recreational Goods and Supplies Merchant Wholesalers",
+            Self::ToyAndHobbyGoodsAndSuppliesMerchantWholesalers => "Toy and Hobby Goods and Supplies Merchant Wholesalers",
+            Self::RecyclableMaterialMerchantWholesalers => "Recyclable Material Merchant Wholesalers",
+            Self::JewelryWatchPreciousStoneAndPreciousMetalMerchantWholesalers => "Jewelry, Watch, Precious Stone, and Precious Metal Merchant Wholesalers",
+            Self::OtherMiscellaneousDurableGoodsMerchantWholesalers => "Other Miscellaneous Durable Goods Merchant Wholesalers",
+            Self::PrintingAndWritingPaperMerchantWholesalers => "Printing and Writing Paper Merchant Wholesalers",
+            Self::StationeryAndOfficeSuppliesMerchantWholesalers => "Stationery and Office Supplies Merchant Wholesalers",
+            Self::IndustrialAndPersonalServicePaperMerchantWholesalers => "Industrial and Personal Service Paper Merchant Wholesalers",
+            Self::DrugsAndDruggistsSundriesMerchantWholesalers => "Drugs and Druggists' Sundries Merchant Wholesalers",
+            Self::PieceGoodsNotionsAndOtherDryGoodsMerchantWholesalers => "Piece Goods, Notions, and Other Dry Goods Merchant Wholesalers",
+            Self::FootwearMerchantWholesalers => "Footwear Merchant Wholesalers",
+            Self::ClothingAndClothingAccessoriesMerchantWholesalers => "Clothing and Clothing Accessories Merchant Wholesalers",
+            Self::GeneralLineGroceryMerchantWholesalers => "General Line Grocery Merchant Wholesalers",
+            Self::PackagedFrozenFoodMerchantWholesalers => "Packaged Frozen Food Merchant Wholesalers",
+            Self::DairyProductExceptDriedOrCannedMerchantWholesalers => "Dairy Product (except Dried or Canned) Merchant Wholesalers",
+            Self::PoultryAndPoultryProductMerchantWholesalers => "Poultry and Poultry Product Merchant Wholesalers",
+            Self::ConfectioneryMerchantWholesalers => "Confectionery Merchant Wholesalers",
+            Self::FishAndSeafoodMerchantWholesalers => "Fish and Seafood Merchant Wholesalers",
+            Self::MeatAndMeatProductMerchantWholesalers => "Meat and Meat Product Merchant Wholesalers",
+            Self::FreshFruitAndVegetableMerchantWholesalers => "Fresh Fruit and Vegetable Merchant Wholesalers",
+            Self::OtherGroceryAndRelatedProductsMerchantWholesalers => "Other Grocery and Related Products Merchant Wholesalers",
+            Self::GrainAndFieldBeanMerchantWholesalers => "Grain and Field Bean Merchant Wholesalers",
+            Self::LivestockMerchantWholesalers => "Livestock Merchant Wholesalers",
+            Self::OtherFarmProductRawMaterialMerchantWholesalers => "Other Farm Product Raw Material Merchant Wholesalers",
+            Self::PlasticsMaterialsAndBasicFormsAndShapesMerchantWholesalers => "Plastics Materials and Basic Forms and Shapes Merchant Wholesalers",
+            Self::OtherChemicalAndAlliedProductsMerchantWholesalers => "Other Chemical and Allied Products Merchant Wholesalers",
+            Self::PetroleumBulkStationsAndTerminals => "Petroleum Bulk Stations and Terminals",
+            Self::PetroleumAndPetroleumProductsMerchantWholesalersExceptBulkStationsAndTerminals => "Petroleum and Petroleum Products Merchant Wholesalers (except Bulk Stations and Terminals)",
+            Self::BeerAndAleMerchantWholesalers => "Beer and Ale Merchant Wholesalers",
+            Self::WineAndDistilledAlcoholicBeverageMerchantWholesalers => "Wine and Distilled Alcoholic Beverage Merchant Wholesalers",
+            Self::FarmSuppliesMerchantWholesalers => "Farm Supplies Merchant Wholesalers",
+            Self::BookPeriodicalAndNewspaperMerchantWholesalers => "Book, Periodical, and Newspaper Merchant Wholesalers",
             Self::FlowerNurseryStockAndFloristsSuppliesMerchantWholesalers => "Flower, Nursery Stock, and Florists' Supplies Merchant Wholesalers",
             Self::TobaccoProductAndElectronicCigaretteMerchantWholesalers => "Tobacco Product and Electronic Cigarette Merchant Wholesalers",
             Self::PaintVarnishAndSuppliesMerchantWholesalers => "Paint, Varnish, and Supplies Merchant Wholesalers",
@@ -2953,11 +2732,10 @@ impl NaicsIndustry {
             Self::WiredTelecommunicationsCarriers => "Wired Telecommunications Carriers",
             Self::WirelessTelecommunicationsCarriersExceptSatellite => "Wireless Telecommunications Carriers (except Satellite)",
             Self::TelecommunicationsResellers => "Telecommunications Resellers",
-            Self::SatelliteTelecommunications => "Satellite Telecommunications",
             Self::AgentsForWirelessTelecommunicationsServices => "Agents for Wireless Telecommunications Services",
+            Self::SatelliteTelecommunications => "Satellite Telecommunications",
             Self::AllOtherTelecommunications => "All Other Telecommunications",
-            Self::ComputingInfrastructureProvidersDataProcessingWebHostingAndRelatedServices => 
-                "Computing Infrastructure Providers, Data Processing, Web Hosting, and Related Services",
+            Self::ComputingInfrastructureProvidersDataProcessingWebHostingAndRelatedServices => "Computing Infrastructure Providers, Data Processing, Web Hosting, and Related Services",
             Self::LibrariesAndArchives => "Libraries and Archives",
             Self::WebSearchPortalsAndAllOtherInformationServices => "Web Search Portals and All Other Information Services",
             Self::MonetaryAuthoritiesCentralBank => "Monetary Authorities-Central Bank",
@@ -3288,7 +3066,8 @@ impl NaicsIndustry {
         }
     }
 
-    /// Returns the NAICS code for this industry as an i64
+    /// Returns the NAICS code of the variant.  Variants spanning several codes return the
+    /// first.
     pub fn code(&self) -> i64 {
         match self {
             Self::SoybeanFarming => 111110,
@@ -4307,17 +4086,10 @@ impl NaicsIndustry {
         }
     }
 
-    /// Creates a new NaicsIndustry from a NAICS code string
-    /// Returns Some(NaicsIndustry) if the code matches a known industry, or None if not found
-    pub fn from_code(code_str: &str) -> Option<Self> {
-        // Parse the string to an i64
-        let code = match code_str.parse::<i64>() {
-            Ok(num) => num,
-            Err(_) => return None,
-        };
-
-        // Match the code to an industry
-        let industry = match code {
+    /// Returns the variant with the NAICS code `code`, or `None` if the code is unknown.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.parse::<i64>().ok()?;
+        let result = match code {
             111110 => Self::SoybeanFarming,
             111120 => Self::OilseedExceptSoybeanFarming,
             111130 => Self::DryPeaAndBeanFarming,
@@ -5333,7 +5105,7 @@ impl NaicsIndustry {
             999999 => Self::UnclassifiedEstablishments,
             _ => return None,
         };
-
-        Some(industry)
+        Some(result)
     }
 }
+// @generated end
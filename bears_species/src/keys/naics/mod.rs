@@ -1,5 +1,7 @@
 mod category;
+mod concordance;
 mod core;
+mod hierarchy;
 mod industry;
 mod input_output;
 mod item;
@@ -9,7 +11,9 @@ mod subsector;
 mod supplement;
 
 pub use category::NaicsCategory;
+pub use concordance::{NaicsConcordance, NaicsLink, NaicsRelation, NaicsVintage};
 pub use core::Naics;
+pub use hierarchy::NaicsLevel;
 pub use industry::NaicsIndustry;
 pub use input_output::NaicsInputOutput;
 pub use item::{NaicsItem, NaicsItems};
@@ -1,6 +1,8 @@
 /// Represents the North American Industry Classification System (NAICS) sectors.
 ///
 /// Each variant corresponds to a major industry sector as defined by NAICS.
+// @generated begin by `bears generate` from data/naics_codes.csv and data/naics_legacy_codes.csv, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Default,
@@ -18,8 +20,8 @@
     strum::EnumIter,
 )]
 pub enum NaicsSector {
-    #[default]
     /// Agriculture, Forestry, Fishing and Hunting
+    #[default]
     AgricultureForestryFishingHunting,
     /// Mining, Quarrying, and Oil and Gas Extraction
     MiningQuarryingOilGasExtraction,
@@ -63,14 +65,13 @@ pub enum NaicsSector {
     UnclassifiedEstablishments,
 }
 
+#[rustfmt::skip]
 impl NaicsSector {
-    /// Returns the official NAICS title for this sector.
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::AgricultureForestryFishingHunting => "Agriculture, Forestry, Fishing and Hunting",
-            Self::MiningQuarryingOilGasExtraction => {
-                "Mining, Quarrying, and Oil and Gas Extraction"
-            }
+            Self::MiningQuarryingOilGasExtraction => "Mining, Quarrying, and Oil and Gas Extraction",
             Self::Utilities => "Utilities",
             Self::Construction => "Construction",
             Self::Manufacturing => "Manufacturing",
@@ -80,38 +81,31 @@ impl NaicsSector {
             Self::Information => "Information",
             Self::FinanceInsurance => "Finance and Insurance",
             Self::RealEstateRentalLeasing => "Real Estate and Rental and Leasing",
-            Self::ProfessionalScientificTechnicalServices => {
-                "Professional, Scientific, and Technical Services"
-            }
+            Self::ProfessionalScientificTechnicalServices => "Professional, Scientific, and Technical Services",
             Self::ManagementOfCompaniesAndEnterprises => "Management of Companies and Enterprises",
-            Self::AdministrativeSupportWasteManagementRemediationServices => {
-                "Administrative and Support and Waste Management and Remediation Services"
-            }
+            Self::AdministrativeSupportWasteManagementRemediationServices => "Administrative and Support and Waste Management and Remediation Services",
             Self::EducationalServices => "Educational Services",
             Self::HealthCareSocialAssistance => "Health Care and Social Assistance",
             Self::ArtsEntertainmentRecreation => "Arts, Entertainment, and Recreation",
             Self::AccommodationFoodServices => "Accommodation and Food Services",
-            Self::OtherServicesExceptPublicAdministration => {
-                "Other Services (except Public Administration)"
-            }
+            Self::OtherServicesExceptPublicAdministration => "Other Services (except Public Administration)",
             Self::PublicAdministration => "Public Administration",
             Self::UnclassifiedEstablishments => "Unclassified Establishments",
         }
     }
 
-    /// Returns the NAICS code for this sector as an integer.
-    ///
-    /// For sectors with a range of codes (e.g., 31-33), only the first number in the range is returned.
+    /// Returns the NAICS code of the variant.  Variants spanning several codes return the
+    /// first.
     pub fn code(&self) -> i64 {
         match self {
             Self::AgricultureForestryFishingHunting => 11,
             Self::MiningQuarryingOilGasExtraction => 21,
             Self::Utilities => 22,
             Self::Construction => 23,
-            Self::Manufacturing => 31, // Taking only first number from 31-33
+            Self::Manufacturing => 31,
             Self::WholesaleTrade => 42,
-            Self::RetailTrade => 44, // Taking only first number from 44-45
-            Self::TransportationWarehousing => 48, // Taking only first number from 48-49
+            Self::RetailTrade => 44,
+            Self::TransportationWarehousing => 48,
             Self::Information => 51,
             Self::FinanceInsurance => 52,
             Self::RealEstateRentalLeasing => 53,
@@ -128,16 +122,9 @@ impl NaicsSector {
         }
     }
 
-    /// Creates a NaicsSector from a string representation of a NAICS code.
-    ///
-    /// Returns Some(NaicsSector) if the string can be parsed to a valid NAICS sector code,
-    /// or None if the string is not a valid NAICS code or cannot be parsed.
-    pub fn from_code(key: &str) -> Option<Self> {
-        let code = match key.parse::<i64>() {
-            Ok(num) => num,
-            Err(_) => return None,
-        };
-
+    /// Returns the variant with the NAICS code `code`, or `None` if the code is unknown.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.parse::<i64>().ok()?;
         let result = match code {
             11 => Self::AgricultureForestryFishingHunting,
             21 => Self::MiningQuarryingOilGasExtraction,
@@ -162,7 +149,7 @@ impl NaicsSector {
             99 => Self::UnclassifiedEstablishments,
             _ => return None,
         };
-
         Some(result)
     }
 }
+// @generated end
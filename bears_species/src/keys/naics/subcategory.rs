@@ -1,4 +1,6 @@
 /// Represents NAICS subcategories
+// @generated begin by `bears generate` from data/naics_codes.csv and data/naics_legacy_codes.csv, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Copy,
@@ -267,12 +269,12 @@ pub enum NaicsSubcategory {
     ApparelKnittingMills,
     /// Cut and Sew Apparel Contractors
     CutAndSewApparelContractors,
-    /// Cut and Sew Apparel Manufacturing (except Contractors)
-    CutAndSewApparelManufacturingExceptContractors,
     /// Men's and Boys' Cut and Sew Apparel Manufacturing
     MensAndBoysCutAndSewApparelManufacturing,
     /// Women's and Girls' Cut and Sew Apparel Manufacturing
     WomensAndGirlsCutAndSewApparelManufacturing,
+    /// Cut and Sew Apparel Manufacturing (except Contractors)
+    CutAndSewApparelManufacturingExceptContractors,
     /// Other Cut and Sew Apparel Manufacturing
     OtherCutAndSewApparelManufacturing,
     /// Apparel Accessories and Other Apparel Manufacturing
@@ -867,18 +869,6 @@ pub enum NaicsSubcategory {
     FarmProductWarehousingAndStorage,
     /// Other Warehousing and Storage
     OtherWarehousingAndStorage,
-    /// Newspaper Publishers
-    NewspaperPublishers,
-    /// Periodical Publishers
-    PeriodicalPublishers,
-    /// Book Publishers
-    BookPublishers,
-    /// Directory and Mailing List Publishers
-    DirectoryAndMailingListPublishers,
-    /// Other Publishers
-    OtherPublishers,
-    /// Software Publishers
-    SoftwarePublishers,
     /// Motion Picture and Video Production
     MotionPictureAndVideoProduction,
     /// Motion Picture and Video Distribution
@@ -895,6 +885,18 @@ pub enum NaicsSubcategory {
     RecordProductionAndDistribution,
     /// Other Sound Recording Industries
     OtherSoundRecordingIndustries,
+    /// Newspaper Publishers
+    NewspaperPublishers,
+    /// Periodical Publishers
+    PeriodicalPublishers,
+    /// Book Publishers
+    BookPublishers,
+    /// Directory and Mailing List Publishers
+    DirectoryAndMailingListPublishers,
+    /// Other Publishers
+    OtherPublishers,
+    /// Software Publishers
+    SoftwarePublishers,
     /// Radio Broadcasting
     RadioBroadcasting,
     /// Television Broadcasting
@@ -1413,8 +1415,9 @@ pub enum NaicsSubcategory {
     UnclassifiedEstablishments,
 }
 
+#[rustfmt::skip]
 impl NaicsSubcategory {
-    /// Returns the NAICS title description for this subcategory
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::SoybeanFarming => "Soybean Farming",
@@ -1435,16 +1438,12 @@ impl NaicsSubcategory {
             Self::SugarcaneFarming => "Sugarcane Farming",
             Self::HayFarming => "Hay Farming",
             Self::AllOtherCropFarming => "All Other Crop Farming",
-            Self::BeefCattleRanchingAndFarmingIncludingFeedlots => {
-                "Beef Cattle Ranching and Farming, including Feedlots"
-            }
+            Self::BeefCattleRanchingAndFarmingIncludingFeedlots => "Beef Cattle Ranching and Farming, including Feedlots",
             Self::DairyCattleAndMilkProduction => "Dairy Cattle and Milk Production",
             Self::DualpurposeCattleRanchingAndFarming => "Dual-Purpose Cattle Ranching and Farming",
             Self::HogAndPigFarming => "Hog and Pig Farming",
             Self::ChickenEggProduction => "Chicken Egg Production",
-            Self::BroilersAndOtherMeatTypeChickenProduction => {
-                "Broilers and Other Meat Type Chicken Production"
-            }
+            Self::BroilersAndOtherMeatTypeChickenProduction => "Broilers and Other Meat Type Chicken Production",
             Self::TurkeyProduction => "Turkey Production",
             Self::PoultryHatcheries => "Poultry Hatcheries",
             Self::OtherPoultryProduction => "Other Poultry Production",
@@ -1456,16 +1455,12 @@ impl NaicsSubcategory {
             Self::FurbearingAnimalAndRabbitProduction => "Fur-Bearing Animal and Rabbit Production",
             Self::AllOtherAnimalProduction => "All Other Animal Production",
             Self::TimberTractOperations => "Timber Tract Operations",
-            Self::ForestNurseriesAndGatheringOfForestProducts => {
-                "Forest Nurseries and Gathering of Forest Products"
-            }
+            Self::ForestNurseriesAndGatheringOfForestProducts => "Forest Nurseries and Gathering of Forest Products",
             Self::Logging => "Logging",
             Self::Fishing => "Fishing",
             Self::HuntingAndTrapping => "Hunting and Trapping",
             Self::SupportActivitiesForCropProduction => "Support Activities for Crop Production",
-            Self::SupportActivitiesForAnimalProduction => {
-                "Support Activities for Animal Production"
-            }
+            Self::SupportActivitiesForAnimalProduction => "Support Activities for Animal Production",
             Self::SupportActivitiesForForestry => "Support Activities for Forestry",
             Self::CrudePetroleumExtraction => "Crude Petroleum Extraction",
             Self::NaturalGasExtraction => "Natural Gas Extraction",
@@ -1475,60 +1470,34 @@ impl NaicsSubcategory {
             Self::CopperNickelLeadAndZincMining => "Copper, Nickel, Lead, and Zinc Mining",
             Self::OtherMetalOreMining => "Other Metal Ore Mining",
             Self::StoneMiningAndQuarrying => "Stone Mining and Quarrying",
-            Self::SandGravelClayAndCeramicAndRefractoryMineralsMiningAndQuarrying => {
-                "Sand, Gravel, Clay, and Ceramic and Refractory Minerals Mining and Quarrying"
-            }
-            Self::OtherNonmetallicMineralMiningAndQuarrying => {
-                "Other Nonmetallic Mineral Mining and Quarrying"
-            }
+            Self::SandGravelClayAndCeramicAndRefractoryMineralsMiningAndQuarrying => "Sand, Gravel, Clay, and Ceramic and Refractory Minerals Mining and Quarrying",
+            Self::OtherNonmetallicMineralMiningAndQuarrying => "Other Nonmetallic Mineral Mining and Quarrying",
             Self::SupportActivitiesForMining => "Support Activities for Mining",
-            Self::ElectricPowerGeneration => "Electric Power Generation ",
-            Self::ElectricPowerTransmissionControlAndDistribution => {
-                "Electric Power Transmission, Control, and Distribution "
-            }
-            Self::NaturalGasDistribution => "Natural Gas Distribution ",
-            Self::WaterSupplyAndIrrigationSystems => "Water Supply and Irrigation Systems ",
-            Self::SewageTreatmentFacilities => "Sewage Treatment Facilities ",
-            Self::SteamAndAirconditioningSupply => "Steam and Air-Conditioning Supply ",
+            Self::ElectricPowerGeneration => "Electric Power Generation",
+            Self::ElectricPowerTransmissionControlAndDistribution => "Electric Power Transmission, Control, and Distribution",
+            Self::NaturalGasDistribution => "Natural Gas Distribution",
+            Self::WaterSupplyAndIrrigationSystems => "Water Supply and Irrigation Systems",
+            Self::SewageTreatmentFacilities => "Sewage Treatment Facilities",
+            Self::SteamAndAirconditioningSupply => "Steam and Air-Conditioning Supply",
             Self::ResidentialBuildingConstruction => "Residential Building Construction",
             Self::IndustrialBuildingConstruction => "Industrial Building Construction",
-            Self::CommercialAndInstitutionalBuildingConstruction => {
-                "Commercial and Institutional Building Construction"
-            }
-            Self::WaterAndSewerLineAndRelatedStructuresConstruction => {
-                "Water and Sewer Line and Related Structures Construction"
-            }
-            Self::OilAndGasPipelineAndRelatedStructuresConstruction => {
-                "Oil and Gas Pipeline and Related Structures Construction"
-            }
-            Self::PowerAndCommunicationLineAndRelatedStructuresConstruction => {
-                "Power and Communication Line and Related Structures Construction"
-            }
+            Self::CommercialAndInstitutionalBuildingConstruction => "Commercial and Institutional Building Construction",
+            Self::WaterAndSewerLineAndRelatedStructuresConstruction => "Water and Sewer Line and Related Structures Construction",
+            Self::OilAndGasPipelineAndRelatedStructuresConstruction => "Oil and Gas Pipeline and Related Structures Construction",
+            Self::PowerAndCommunicationLineAndRelatedStructuresConstruction => "Power and Communication Line and Related Structures Construction",
             Self::LandSubdivision => "Land Subdivision",
             Self::HighwayStreetAndBridgeConstruction => "Highway, Street, and Bridge Construction",
-            Self::OtherHeavyAndCivilEngineeringConstruction => {
-                "Other Heavy and Civil Engineering Construction"
-            }
-            Self::PouredConcreteFoundationAndStructureContractors => {
-                "Poured Concrete Foundation and Structure Contractors "
-            }
-            Self::StructuralSteelAndPrecastConcreteContractors => {
-                "Structural Steel and Precast Concrete Contractors "
-            }
-            Self::FramingContractors => "Framing Contractors ",
-            Self::MasonryContractors => "Masonry Contractors ",
-            Self::GlassAndGlazingContractors => "Glass and Glazing Contractors ",
-            Self::RoofingContractors => "Roofing Contractors ",
-            Self::SidingContractors => "Siding Contractors ",
-            Self::OtherFoundationStructureAndBuildingExteriorContractors => {
-                "Other Foundation, Structure, and Building Exterior Contractors "
-            }
-            Self::ElectricalContractorsAndOtherWiringInstallationContractors => {
-                "Electrical Contractors and Other Wiring Installation Contractors"
-            }
-            Self::PlumbingHeatingAndAirconditioningContractors => {
-                "Plumbing, Heating, and Air-Conditioning Contractors"
-            }
+            Self::OtherHeavyAndCivilEngineeringConstruction => "Other Heavy and Civil Engineering Construction",
+            Self::PouredConcreteFoundationAndStructureContractors => "Poured Concrete Foundation and Structure Contractors",
+            Self::StructuralSteelAndPrecastConcreteContractors => "Structural Steel and Precast Concrete Contractors",
+            Self::FramingContractors => "Framing Contractors",
+            Self::MasonryContractors => "Masonry Contractors",
+            Self::GlassAndGlazingContractors => "Glass and Glazing Contractors",
+            Self::RoofingContractors => "Roofing Contractors",
+            Self::SidingContractors => "Siding Contractors",
+            Self::OtherFoundationStructureAndBuildingExteriorContractors => "Other Foundation, Structure, and Building Exterior Contractors",
+            Self::ElectricalContractorsAndOtherWiringInstallationContractors => "Electrical Contractors and Other Wiring Installation Contractors",
+            Self::PlumbingHeatingAndAirconditioningContractors => "Plumbing, Heating, and Air-Conditioning Contractors",
             Self::OtherBuildingEquipmentContractors => "Other Building Equipment Contractors",
             Self::DrywallAndInsulationContractors => "Drywall and Insulation Contractors",
             Self::PaintingAndWallCoveringContractors => "Painting and Wall Covering Contractors",
@@ -1540,39 +1509,23 @@ impl NaicsSubcategory {
             Self::AllOtherSpecialtyTradeContractors => "All Other Specialty Trade Contractors",
             Self::AnimalFoodManufacturing => "Animal Food Manufacturing",
             Self::FlourMillingAndMaltManufacturing => "Flour Milling and Malt Manufacturing",
-            Self::StarchAndVegetableFatsAndOilsManufacturing => {
-                "Starch and Vegetable Fats and Oils Manufacturing"
-            }
+            Self::StarchAndVegetableFatsAndOilsManufacturing => "Starch and Vegetable Fats and Oils Manufacturing",
             Self::BreakfastCerealManufacturing => "Breakfast Cereal Manufacturing",
             Self::SugarManufacturing => "Sugar Manufacturing",
-            Self::NonchocolateConfectioneryManufacturing => {
-                "Nonchocolate Confectionery Manufacturing"
-            }
-            Self::ChocolateAndConfectioneryManufacturing => {
-                "Chocolate and Confectionery Manufacturing"
-            }
+            Self::NonchocolateConfectioneryManufacturing => "Nonchocolate Confectionery Manufacturing",
+            Self::ChocolateAndConfectioneryManufacturing => "Chocolate and Confectionery Manufacturing",
             Self::FrozenFoodManufacturing => "Frozen Food Manufacturing",
-            Self::FruitAndVegetableCanningPicklingAndDrying => {
-                "Fruit and Vegetable Canning, Pickling, and Drying"
-            }
-            Self::DairyProductExceptFrozenManufacturing => {
-                "Dairy Product (except Frozen) Manufacturing"
-            }
-            Self::IceCreamAndFrozenDessertManufacturing => {
-                "Ice Cream and Frozen Dessert Manufacturing"
-            }
+            Self::FruitAndVegetableCanningPicklingAndDrying => "Fruit and Vegetable Canning, Pickling, and Drying",
+            Self::DairyProductExceptFrozenManufacturing => "Dairy Product (except Frozen) Manufacturing",
+            Self::IceCreamAndFrozenDessertManufacturing => "Ice Cream and Frozen Dessert Manufacturing",
             Self::AnimalSlaughteringAndProcessing => "Animal Slaughtering and Processing",
-            Self::SeafoodProductPreparationAndPackaging => {
-                "Seafood Product Preparation and Packaging"
-            }
+            Self::SeafoodProductPreparationAndPackaging => "Seafood Product Preparation and Packaging",
             Self::BreadAndBakeryProductManufacturing => "Bread and Bakery Product Manufacturing",
             Self::CookieCrackerAndPastaManufacturing => "Cookie, Cracker, and Pasta Manufacturing",
             Self::TortillaManufacturing => "Tortilla Manufacturing",
             Self::SnackFoodManufacturing => "Snack Food Manufacturing",
             Self::CoffeeAndTeaManufacturing => "Coffee and Tea Manufacturing",
-            Self::FlavoringSyrupAndConcentrateManufacturing => {
-                "Flavoring Syrup and Concentrate Manufacturing"
-            }
+            Self::FlavoringSyrupAndConcentrateManufacturing => "Flavoring Syrup and Concentrate Manufacturing",
             Self::SeasoningAndDressingManufacturing => "Seasoning and Dressing Manufacturing",
             Self::AllOtherFoodManufacturing => "All Other Food Manufacturing",
             Self::SoftDrinkAndIceManufacturing => "Soft Drink and Ice Manufacturing",
@@ -1582,9 +1535,7 @@ impl NaicsSubcategory {
             Self::TobaccoManufacturing => "Tobacco Manufacturing",
             Self::FiberYarnAndThreadMills => "Fiber, Yarn, and Thread Mills",
             Self::BroadwovenFabricMills => "Broadwoven Fabric Mills",
-            Self::NarrowFabricMillsAndSchiffliMachineEmbroidery => {
-                "Narrow Fabric Mills and Schiffli Machine Embroidery"
-            }
+            Self::NarrowFabricMillsAndSchiffliMachineEmbroidery => "Narrow Fabric Mills and Schiffli Machine Embroidery",
             Self::NonwovenFabricMills => "Nonwoven Fabric Mills",
             Self::KnitFabricMills => "Knit Fabric Mills",
             Self::TextileAndFabricFinishingMills => "Textile and Fabric Finishing Mills",
@@ -1595,28 +1546,16 @@ impl NaicsSubcategory {
             Self::AllOtherTextileProductMills => "All Other Textile Product Mills",
             Self::ApparelKnittingMills => "Apparel Knitting Mills",
             Self::CutAndSewApparelContractors => "Cut and Sew Apparel Contractors",
-            Self::CutAndSewApparelManufacturingExceptContractors => {
-                "Cut and Sew Apparel Manufacturing (except Contractors)"
-            }
-            Self::MensAndBoysCutAndSewApparelManufacturing => {
-                "Men's and Boys' Cut and Sew Apparel Manufacturing"
-            }
-            Self::WomensAndGirlsCutAndSewApparelManufacturing => {
-                "Women's and Girls' Cut and Sew Apparel Manufacturing"
-            }
+            Self::MensAndBoysCutAndSewApparelManufacturing => "Men's and Boys' Cut and Sew Apparel Manufacturing",
+            Self::WomensAndGirlsCutAndSewApparelManufacturing => "Women's and Girls' Cut and Sew Apparel Manufacturing",
+            Self::CutAndSewApparelManufacturingExceptContractors => "Cut and Sew Apparel Manufacturing (except Contractors)",
             Self::OtherCutAndSewApparelManufacturing => "Other Cut and Sew Apparel Manufacturing",
-            Self::ApparelAccessoriesAndOtherApparelManufacturing => {
-                "Apparel Accessories and Other Apparel Manufacturing"
-            }
+            Self::ApparelAccessoriesAndOtherApparelManufacturing => "Apparel Accessories and Other Apparel Manufacturing",
             Self::LeatherAndHideTanningAndFinishing => "Leather and Hide Tanning and Finishing",
             Self::FootwearManufacturing => "Footwear Manufacturing",
-            Self::OtherLeatherAndAlliedProductManufacturing => {
-                "Other Leather and Allied Product Manufacturing"
-            }
+            Self::OtherLeatherAndAlliedProductManufacturing => "Other Leather and Allied Product Manufacturing",
             Self::SawmillsAndWoodPreservation => "Sawmills and Wood Preservation",
-            Self::VeneerPlywoodAndEngineeredWoodProductManufacturing => {
-                "Veneer, Plywood, and Engineered Wood Product Manufacturing"
-            }
+            Self::VeneerPlywoodAndEngineeredWoodProductManufacturing => "Veneer, Plywood, and Engineered Wood Product Manufacturing",
             Self::Millwork => "Millwork",
             Self::WoodContainerAndPalletManufacturing => "Wood Container and Pallet Manufacturing",
             Self::AllOtherWoodProductManufacturing => "All Other Wood Product Manufacturing",
@@ -1624,126 +1563,66 @@ impl NaicsSubcategory {
             Self::PaperMills => "Paper Mills",
             Self::PaperboardMills => "Paperboard Mills",
             Self::PaperboardContainerManufacturing => "Paperboard Container Manufacturing",
-            Self::PaperBagAndCoatedAndTreatedPaperManufacturing => {
-                "Paper Bag and Coated and Treated Paper Manufacturing"
-            }
+            Self::PaperBagAndCoatedAndTreatedPaperManufacturing => "Paper Bag and Coated and Treated Paper Manufacturing",
             Self::StationeryProductManufacturing => "Stationery Product Manufacturing",
-            Self::OtherConvertedPaperProductManufacturing => {
-                "Other Converted Paper Product Manufacturing"
-            }
+            Self::OtherConvertedPaperProductManufacturing => "Other Converted Paper Product Manufacturing",
             Self::Printing => "Printing",
             Self::SupportActivitiesForPrinting => "Support Activities for Printing",
             Self::PetroleumRefineries => "Petroleum Refineries",
-            Self::AsphaltPavingRoofingAndSaturatedMaterialsManufacturing => {
-                "Asphalt Paving, Roofing, and Saturated Materials Manufacturing"
-            }
-            Self::OtherPetroleumAndCoalProductsManufacturing => {
-                "Other Petroleum and Coal Products Manufacturing"
-            }
+            Self::AsphaltPavingRoofingAndSaturatedMaterialsManufacturing => "Asphalt Paving, Roofing, and Saturated Materials Manufacturing",
+            Self::OtherPetroleumAndCoalProductsManufacturing => "Other Petroleum and Coal Products Manufacturing",
             Self::PetrochemicalManufacturing => "Petrochemical Manufacturing",
             Self::IndustrialGasManufacturing => "Industrial Gas Manufacturing",
             Self::SyntheticDyeAndPigmentManufacturing => "Synthetic Dye and Pigment Manufacturing",
-            Self::OtherBasicInorganicChemicalManufacturing => {
-                "Other Basic Inorganic Chemical Manufacturing"
-            }
-            Self::OtherBasicOrganicChemicalManufacturing => {
-                "Other Basic Organic Chemical Manufacturing"
-            }
-            Self::ResinAndSyntheticRubberManufacturing => {
-                "Resin and Synthetic Rubber Manufacturing"
-            }
-            Self::ArtificialAndSyntheticFibersAndFilamentsManufacturing => {
-                "Artificial and Synthetic Fibers and Filaments Manufacturing"
-            }
+            Self::OtherBasicInorganicChemicalManufacturing => "Other Basic Inorganic Chemical Manufacturing",
+            Self::OtherBasicOrganicChemicalManufacturing => "Other Basic Organic Chemical Manufacturing",
+            Self::ResinAndSyntheticRubberManufacturing => "Resin and Synthetic Rubber Manufacturing",
+            Self::ArtificialAndSyntheticFibersAndFilamentsManufacturing => "Artificial and Synthetic Fibers and Filaments Manufacturing",
             Self::FertilizerAndCompostManufacturing => "Fertilizer and Compost Manufacturing",
-            Self::PesticideAndOtherAgriculturalChemicalManufacturing => {
-                "Pesticide and Other Agricultural Chemical Manufacturing"
-            }
-            Self::PharmaceuticalAndMedicineManufacturing => {
-                "Pharmaceutical and Medicine Manufacturing"
-            }
+            Self::PesticideAndOtherAgriculturalChemicalManufacturing => "Pesticide and Other Agricultural Chemical Manufacturing",
+            Self::PharmaceuticalAndMedicineManufacturing => "Pharmaceutical and Medicine Manufacturing",
             Self::PaintAndCoatingManufacturing => "Paint and Coating Manufacturing",
             Self::AdhesiveManufacturing => "Adhesive Manufacturing",
-            Self::SoapAndCleaningCompoundManufacturing => {
-                "Soap and Cleaning Compound Manufacturing"
-            }
+            Self::SoapAndCleaningCompoundManufacturing => "Soap and Cleaning Compound Manufacturing",
             Self::ToiletPreparationManufacturing => "Toilet Preparation Manufacturing",
             Self::PrintingInkManufacturing => "Printing Ink Manufacturing",
             Self::ExplosivesManufacturing => "Explosives Manufacturing",
-            Self::AllOtherChemicalProductAndPreparationManufacturing => {
-                "All Other Chemical Product and Preparation Manufacturing"
-            }
-            Self::PlasticsPackagingMaterialsAndUnlaminatedFilmAndSheetManufacturing => {
-                "Plastics Packaging Materials and Unlaminated Film and Sheet Manufacturing"
-            }
-            Self::PlasticsPipePipeFittingAndUnlaminatedProfileShapeManufacturing => {
-                "Plastics Pipe, Pipe Fitting, and Unlaminated Profile Shape Manufacturing"
-            }
-            Self::LaminatedPlasticsPlateSheetExceptPackagingAndShapeManufacturing => {
-                "Laminated Plastics Plate, Sheet (except Packaging), and Shape Manufacturing"
-            }
+            Self::AllOtherChemicalProductAndPreparationManufacturing => "All Other Chemical Product and Preparation Manufacturing",
+            Self::PlasticsPackagingMaterialsAndUnlaminatedFilmAndSheetManufacturing => "Plastics Packaging Materials and Unlaminated Film and Sheet Manufacturing",
+            Self::PlasticsPipePipeFittingAndUnlaminatedProfileShapeManufacturing => "Plastics Pipe, Pipe Fitting, and Unlaminated Profile Shape Manufacturing",
+            Self::LaminatedPlasticsPlateSheetExceptPackagingAndShapeManufacturing => "Laminated Plastics Plate, Sheet (except Packaging), and Shape Manufacturing",
             Self::PolystyreneFoamProductManufacturing => "Polystyrene Foam Product Manufacturing",
-            Self::UrethaneAndOtherFoamProductExceptPolystyreneManufacturing => {
-                "Urethane and Other Foam Product (except Polystyrene) Manufacturing"
-            }
+            Self::UrethaneAndOtherFoamProductExceptPolystyreneManufacturing => "Urethane and Other Foam Product (except Polystyrene) Manufacturing",
             Self::PlasticsBottleManufacturing => "Plastics Bottle Manufacturing",
             Self::OtherPlasticsProductManufacturing => "Other Plastics Product Manufacturing",
             Self::TireManufacturing => "Tire Manufacturing",
-            Self::RubberAndPlasticsHosesAndBeltingManufacturing => {
-                "Rubber and Plastics Hoses and Belting Manufacturing"
-            }
+            Self::RubberAndPlasticsHosesAndBeltingManufacturing => "Rubber and Plastics Hoses and Belting Manufacturing",
             Self::OtherRubberProductManufacturing => "Other Rubber Product Manufacturing",
-            Self::PotteryCeramicsAndPlumbingFixtureManufacturing => {
-                "Pottery, Ceramics, and Plumbing Fixture Manufacturing"
-            }
-            Self::ClayBuildingMaterialAndRefractoriesManufacturing => {
-                "Clay Building Material and Refractories Manufacturing"
-            }
+            Self::PotteryCeramicsAndPlumbingFixtureManufacturing => "Pottery, Ceramics, and Plumbing Fixture Manufacturing",
+            Self::ClayBuildingMaterialAndRefractoriesManufacturing => "Clay Building Material and Refractories Manufacturing",
             Self::GlassAndGlassProductManufacturing => "Glass and Glass Product Manufacturing",
             Self::CementManufacturing => "Cement Manufacturing",
             Self::ReadymixConcreteManufacturing => "Ready-Mix Concrete Manufacturing",
-            Self::ConcretePipeBrickAndBlockManufacturing => {
-                "Concrete Pipe, Brick, and Block Manufacturing"
-            }
+            Self::ConcretePipeBrickAndBlockManufacturing => "Concrete Pipe, Brick, and Block Manufacturing",
             Self::OtherConcreteProductManufacturing => "Other Concrete Product Manufacturing",
             Self::LimeManufacturing => "Lime Manufacturing",
             Self::GypsumProductManufacturing => "Gypsum Product Manufacturing",
             Self::AbrasiveProductManufacturing => "Abrasive Product Manufacturing",
-            Self::AllOtherNonmetallicMineralProductManufacturing => {
-                "All Other Nonmetallic Mineral Product Manufacturing"
-            }
-            Self::IronAndSteelMillsAndFerroalloyManufacturing => {
-                "Iron and Steel Mills and Ferroalloy Manufacturing"
-            }
-            Self::IronAndSteelPipeAndTubeManufacturingFromPurchasedSteel => {
-                "Iron and Steel Pipe and Tube Manufacturing from Purchased Steel"
-            }
+            Self::AllOtherNonmetallicMineralProductManufacturing => "All Other Nonmetallic Mineral Product Manufacturing",
+            Self::IronAndSteelMillsAndFerroalloyManufacturing => "Iron and Steel Mills and Ferroalloy Manufacturing",
+            Self::IronAndSteelPipeAndTubeManufacturingFromPurchasedSteel => "Iron and Steel Pipe and Tube Manufacturing from Purchased Steel",
             Self::RollingAndDrawingOfPurchasedSteel => "Rolling and Drawing of Purchased Steel",
-            Self::AluminaAndAluminumProductionAndProcessing => {
-                "Alumina and Aluminum Production and Processing"
-            }
-            Self::NonferrousMetalExceptAluminumSmeltingAndRefining => {
-                "Nonferrous Metal (except Aluminum) Smelting and Refining"
-            }
-            Self::CopperRollingDrawingExtrudingAndAlloying => {
-                "Copper Rolling, Drawing, Extruding, and Alloying"
-            }
-            Self::NonferrousMetalExceptCopperAndAluminumRollingDrawingExtrudingAndAlloying => {
-                "Nonferrous Metal (except Copper and Aluminum) Rolling, Drawing, Extruding, and Alloying"
-            }
+            Self::AluminaAndAluminumProductionAndProcessing => "Alumina and Aluminum Production and Processing",
+            Self::NonferrousMetalExceptAluminumSmeltingAndRefining => "Nonferrous Metal (except Aluminum) Smelting and Refining",
+            Self::CopperRollingDrawingExtrudingAndAlloying => "Copper Rolling, Drawing, Extruding, and Alloying",
+            Self::NonferrousMetalExceptCopperAndAluminumRollingDrawingExtrudingAndAlloying => "Nonferrous Metal (except Copper and Aluminum) Rolling, Drawing, Extruding, and Alloying",
             Self::FerrousMetalFoundries => "Ferrous Metal Foundries",
             Self::NonferrousMetalFoundries => "Nonferrous Metal Foundries",
             Self::ForgingAndStamping => "Forging and Stamping",
             Self::CutleryAndHandtoolManufacturing => "Cutlery and Handtool Manufacturing",
-            Self::PlateWorkAndFabricatedStructuralProductManufacturing => {
-                "Plate Work and Fabricated Structural Product Manufacturing"
-            }
-            Self::OrnamentalAndArchitecturalMetalProductsManufacturing => {
-                "Ornamental and Architectural Metal Products Manufacturing"
-            }
-            Self::PowerBoilerAndHeatExchangerManufacturing => {
-                "Power Boiler and Heat Exchanger Manufacturing"
-            }
+            Self::PlateWorkAndFabricatedStructuralProductManufacturing => "Plate Work and Fabricated Structural Product Manufacturing",
+            Self::OrnamentalAndArchitecturalMetalProductsManufacturing => "Ornamental and Architectural Metal Products Manufacturing",
+            Self::PowerBoilerAndHeatExchangerManufacturing => "Power Boiler and Heat Exchanger Manufacturing",
             Self::MetalTankHeavyGaugeManufacturing => "Metal Tank (Heavy Gauge) Manufacturing",
             Self::MetalCanBoxAndOtherMetalContainerLightGaugeManufacturing => "Metal Can, Box, and Other Metal Container (Light Gauge) Manufacturing",
             Self::HardwareManufacturing => "Hardware Manufacturing",
@@ -1774,7 +1653,7 @@ impl NaicsSubcategory {
             Self::ManufacturingAndReproducingMagneticAndOpticalMedia => "Manufacturing and Reproducing Magnetic and Optical Media",
             Self::ElectricLightingEquipmentManufacturing => "Electric Lighting Equipment Manufacturing",
             Self::SmallElectricalApplianceManufacturing => "Small Electrical Appliance Manufacturing",
-            Self::MajorHouseholdApplianceManufacturing => "Major Household Appliance Manufacturing ",
+            Self::MajorHouseholdApplianceManufacturing => "Major Household Appliance Manufacturing",
             Self::ElectricalEquipmentManufacturing => "Electrical Equipment Manufacturing",
             Self::BatteryManufacturing => "Battery Manufacturing",
             Self::CommunicationAndEnergyWireAndCableManufacturing => "Communication and Energy Wire and Cable Manufacturing",
@@ -1967,12 +1846,6 @@ impl NaicsSubcategory {
             Self::RefrigeratedWarehousingAndStorage => "Refrigerated Warehousing and Storage",
             Self::FarmProductWarehousingAndStorage => "Farm Product Warehousing and Storage",
             Self::OtherWarehousingAndStorage => "Other Warehousing and Storage",
-            Self::NewspaperPublishers => "Newspaper Publishers",
-            Self::PeriodicalPublishers => "Periodical Publishers",
-            Self::BookPublishers => "Book Publishers",
-            Self::DirectoryAndMailingListPublishers => "Directory and Mailing List Publishers",
-            Self::OtherPublishers => "Other Publishers",
-            Self::SoftwarePublishers => "Software Publishers",
             Self::MotionPictureAndVideoProduction => "Motion Picture and Video Production",
             Self::MotionPictureAndVideoDistribution => "Motion Picture and Video Distribution",
             Self::MotionPictureAndVideoExhibition => "Motion Picture and Video Exhibition",
@@ -1981,6 +1854,12 @@ impl NaicsSubcategory {
             Self::SoundRecordingStudios => "Sound Recording Studios",
             Self::RecordProductionAndDistribution => "Record Production and Distribution",
             Self::OtherSoundRecordingIndustries => "Other Sound Recording Industries",
+            Self::NewspaperPublishers => "Newspaper Publishers",
+            Self::PeriodicalPublishers => "Periodical Publishers",
+            Self::BookPublishers => "Book Publishers",
+            Self::DirectoryAndMailingListPublishers => "Directory and Mailing List Publishers",
+            Self::OtherPublishers => "Other Publishers",
+            Self::SoftwarePublishers => "Software Publishers",
             Self::RadioBroadcasting => "Radio Broadcasting",
             Self::TelevisionBroadcasting => "Television Broadcasting",
             Self::CableAndOtherSubscriptionProgramming => "Cable and Other Subscription Programming",
@@ -2241,7 +2120,9 @@ impl NaicsSubcategory {
             Self::UnclassifiedEstablishments => "Unclassified Establishments",
         }
     }
-    /// Returns the NAICS code for this subcategory as an i64
+
+    /// Returns the NAICS code of the variant.  Variants spanning several codes return the
+    /// first.
     pub fn code(&self) -> i64 {
         match self {
             Self::SoybeanFarming => 11111,
@@ -2370,9 +2251,9 @@ impl NaicsSubcategory {
             Self::AllOtherTextileProductMills => 31499,
             Self::ApparelKnittingMills => 31512,
             Self::CutAndSewApparelContractors => 31521,
-            Self::CutAndSewApparelManufacturingExceptContractors => 31525,
             Self::MensAndBoysCutAndSewApparelManufacturing => 31522,
             Self::WomensAndGirlsCutAndSewApparelManufacturing => 31523,
+            Self::CutAndSewApparelManufacturingExceptContractors => 31525,
             Self::OtherCutAndSewApparelManufacturing => 31529,
             Self::ApparelAccessoriesAndOtherApparelManufacturing => 31599,
             Self::LeatherAndHideTanningAndFinishing => 31611,
@@ -2670,12 +2551,6 @@ impl NaicsSubcategory {
             Self::RefrigeratedWarehousingAndStorage => 49312,
             Self::FarmProductWarehousingAndStorage => 49313,
             Self::OtherWarehousingAndStorage => 49319,
-            Self::NewspaperPublishers => 51311,
-            Self::PeriodicalPublishers => 51312,
-            Self::BookPublishers => 51313,
-            Self::DirectoryAndMailingListPublishers => 51314,
-            Self::OtherPublishers => 51319,
-            Self::SoftwarePublishers => 51321,
             Self::MotionPictureAndVideoProduction => 51211,
             Self::MotionPictureAndVideoDistribution => 51212,
             Self::MotionPictureAndVideoExhibition => 51213,
@@ -2684,6 +2559,12 @@ impl NaicsSubcategory {
             Self::SoundRecordingStudios => 51224,
             Self::RecordProductionAndDistribution => 51225,
             Self::OtherSoundRecordingIndustries => 51229,
+            Self::NewspaperPublishers => 51311,
+            Self::PeriodicalPublishers => 51312,
+            Self::BookPublishers => 51313,
+            Self::DirectoryAndMailingListPublishers => 51314,
+            Self::OtherPublishers => 51319,
+            Self::SoftwarePublishers => 51321,
             Self::RadioBroadcasting => 51611,
             Self::TelevisionBroadcasting => 51612,
             Self::CableAndOtherSubscriptionProgramming => 51621,
@@ -2945,11 +2826,9 @@ impl NaicsSubcategory {
         }
     }
 
-    /// Tries to create a NaicsSubcategory from a string representing a NAICS code
-    /// Returns Some(NaicsSubcategory) if the code is valid, None otherwise
+    /// Returns the variant with the NAICS code `code`, or `None` if the code is unknown.
     pub fn from_code(code: &str) -> Option<Self> {
         let code = code.parse::<i64>().ok()?;
-
         let result = match code {
             11111 => Self::SoybeanFarming,
             11112 => Self::OilseedExceptSoybeanFarming,
@@ -3077,9 +2956,9 @@ impl NaicsSubcategory {
             31499 => Self::AllOtherTextileProductMills,
             31512 => Self::ApparelKnittingMills,
             31521 => Self::CutAndSewApparelContractors,
-            31525 => Self::CutAndSewApparelManufacturingExceptContractors,
             31522 => Self::MensAndBoysCutAndSewApparelManufacturing,
             31523 => Self::WomensAndGirlsCutAndSewApparelManufacturing,
+            31525 => Self::CutAndSewApparelManufacturingExceptContractors,
             31529 => Self::OtherCutAndSewApparelManufacturing,
             31599 => Self::ApparelAccessoriesAndOtherApparelManufacturing,
             31611 => Self::LeatherAndHideTanningAndFinishing,
@@ -3377,12 +3256,6 @@ impl NaicsSubcategory {
             49312 => Self::RefrigeratedWarehousingAndStorage,
             49313 => Self::FarmProductWarehousingAndStorage,
             49319 => Self::OtherWarehousingAndStorage,
-            51311 => Self::NewspaperPublishers,
-            51312 => Self::PeriodicalPublishers,
-            51313 => Self::BookPublishers,
-            51314 => Self::DirectoryAndMailingListPublishers,
-            51319 => Self::OtherPublishers,
-            51321 => Self::SoftwarePublishers,
             51211 => Self::MotionPictureAndVideoProduction,
             51212 => Self::MotionPictureAndVideoDistribution,
             51213 => Self::MotionPictureAndVideoExhibition,
@@ -3391,6 +3264,12 @@ impl NaicsSubcategory {
             51224 => Self::SoundRecordingStudios,
             51225 => Self::RecordProductionAndDistribution,
             51229 => Self::OtherSoundRecordingIndustries,
+            51311 => Self::NewspaperPublishers,
+            51312 => Self::PeriodicalPublishers,
+            51313 => Self::BookPublishers,
+            51314 => Self::DirectoryAndMailingListPublishers,
+            51319 => Self::OtherPublishers,
+            51321 => Self::SoftwarePublishers,
             51611 => Self::RadioBroadcasting,
             51612 => Self::TelevisionBroadcasting,
             51621 => Self::CableAndOtherSubscriptionProgramming,
@@ -3651,7 +3530,7 @@ impl NaicsSubcategory {
             99999 => Self::UnclassifiedEstablishments,
             _ => return None,
         };
-
         Some(result)
     }
 }
+// @generated end
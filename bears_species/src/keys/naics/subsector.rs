@@ -1,6 +1,8 @@
 /// NAICS Subsector definitions based on North American Industry Classification System
 ///
 /// This enum represents subsector codes and descriptions from the NAICS classification system.
+// @generated begin by `bears generate` from data/naics_codes.csv and data/naics_legacy_codes.csv, do not edit.
+#[rustfmt::skip]
 #[derive(
     Debug,
     Copy,
@@ -217,65 +219,43 @@ pub enum NaicsSubsector {
     UnclassifiedEstablishments,
 }
 
+#[rustfmt::skip]
 impl NaicsSubsector {
-    /// Returns the descriptive title of the NAICS subsector
-    ///
-    /// # Returns
-    /// A static string containing the title/description of the subsector
+    /// Returns the description of the variant.
     pub fn description(&self) -> &'static str {
         match self {
             Self::CropProduction => "Crop Production",
             Self::AnimalProductionAndAquaculture => "Animal Production and Aquaculture",
             Self::ForestryAndLogging => "Forestry and Logging",
             Self::FishingHuntingAndTrapping => "Fishing, Hunting and Trapping",
-            Self::SupportActivitiesForAgricultureAndForestry => {
-                "Support Activities for Agriculture and Forestry"
-            }
+            Self::SupportActivitiesForAgricultureAndForestry => "Support Activities for Agriculture and Forestry",
             Self::OilAndGasExtraction => "Oil and Gas Extraction",
             Self::MiningExceptOilAndGas => "Mining (except Oil and Gas)",
             Self::SupportActivitiesForMining => "Support Activities for Mining",
             Self::Utilities => "Utilities",
             Self::ConstructionOfBuildings => "Construction of Buildings",
-            Self::HeavyAndCivilEngineeringConstruction => {
-                "Heavy and Civil Engineering Construction"
-            }
+            Self::HeavyAndCivilEngineeringConstruction => "Heavy and Civil Engineering Construction",
             Self::SpecialtyTradeContractors => "Specialty Trade Contractors",
             Self::FoodManufacturing => "Food Manufacturing",
-            Self::BeverageAndTobaccoProductManufacturing => {
-                "Beverage and Tobacco Product Manufacturing"
-            }
+            Self::BeverageAndTobaccoProductManufacturing => "Beverage and Tobacco Product Manufacturing",
             Self::TextileMills => "Textile Mills",
             Self::TextileProductMills => "Textile Product Mills",
             Self::ApparelManufacturing => "Apparel Manufacturing",
-            Self::LeatherAndAlliedProductManufacturing => {
-                "Leather and Allied Product Manufacturing"
-            }
+            Self::LeatherAndAlliedProductManufacturing => "Leather and Allied Product Manufacturing",
             Self::WoodProductManufacturing => "Wood Product Manufacturing",
             Self::PaperManufacturing => "Paper Manufacturing",
             Self::PrintingAndRelatedSupportActivities => "Printing and Related Support Activities",
-            Self::PetroleumAndCoalProductsManufacturing => {
-                "Petroleum and Coal Products Manufacturing"
-            }
+            Self::PetroleumAndCoalProductsManufacturing => "Petroleum and Coal Products Manufacturing",
             Self::ChemicalManufacturing => "Chemical Manufacturing",
-            Self::PlasticsAndRubberProductsManufacturing => {
-                "Plastics and Rubber Products Manufacturing"
-            }
-            Self::NonmetallicMineralProductManufacturing => {
-                "Nonmetallic Mineral Product Manufacturing"
-            }
+            Self::PlasticsAndRubberProductsManufacturing => "Plastics and Rubber Products Manufacturing",
+            Self::NonmetallicMineralProductManufacturing => "Nonmetallic Mineral Product Manufacturing",
             Self::PrimaryMetalManufacturing => "Primary Metal Manufacturing",
             Self::FabricatedMetalProductManufacturing => "Fabricated Metal Product Manufacturing",
             Self::MachineryManufacturing => "Machinery Manufacturing",
-            Self::ComputerAndElectronicProductManufacturing => {
-                "Computer and Electronic Product Manufacturing"
-            }
-            Self::ElectricalEquipmentApplianceAndComponentManufacturing => {
-                "Electrical Equipment, Appliance, and Component Manufacturing"
-            }
+            Self::ComputerAndElectronicProductManufacturing => "Computer and Electronic Product Manufacturing",
+            Self::ElectricalEquipmentApplianceAndComponentManufacturing => "Electrical Equipment, Appliance, and Component Manufacturing",
             Self::TransportationEquipmentManufacturing => "Transportation Equipment Manufacturing",
-            Self::FurnitureAndRelatedProductManufacturing => {
-                "Furniture and Related Product Manufacturing"
-            }
+            Self::FurnitureAndRelatedProductManufacturing => "Furniture and Related Product Manufacturing",
             Self::MiscellaneousManufacturing => "Miscellaneous Manufacturing",
             Self::MerchantWholesalersDurableGoods => "Merchant Wholesalers, Durable Goods",
             Self::MerchantWholesalersNondurableGoods => "Merchant Wholesalers, Nondurable Goods",
@@ -283,29 +263,19 @@ impl NaicsSubsector {
             Self::MotorVehicleAndPartsDealers => "Motor Vehicle and Parts Dealers",
             Self::FurnitureAndHomeFurnishingsStores => "Furniture and Home Furnishings Stores",
             Self::ElectronicsAndApplianceStores => "Electronics and Appliance Stores",
-            Self::BuildingMaterialAndGardenEquipmentAndSuppliesDealers => {
-                "Building Material and Garden Equipment and Supplies Dealers"
-            }
+            Self::BuildingMaterialAndGardenEquipmentAndSuppliesDealers => "Building Material and Garden Equipment and Supplies Dealers",
             Self::FoodAndBeverageRetailers => "Food and Beverage Retailers",
-            Self::FurnitureHomeFurnishingsElectronicsAndApplianceRetailers => {
-                "Furniture, Home Furnishings, Electronics, and Appliance Retailers"
-            }
+            Self::FurnitureHomeFurnishingsElectronicsAndApplianceRetailers => "Furniture, Home Furnishings, Electronics, and Appliance Retailers",
             Self::GeneralMerchandiseRetailers => "General Merchandise Retailers",
             Self::HealthAndPersonalCareRetailers => "Health and Personal Care Retailers",
             Self::GasolineStationsAndFuelDealers => "Gasoline Stations and Fuel Dealers",
-            Self::ClothingClothingAccessoriesShoeAndJewelryRetailers => {
-                "Clothing, Clothing Accessories, Shoe, and Jewelry Retailers"
-            }
-            Self::SportingGoodsHobbyMusicalInstrumentBookAndMiscellaneousRetailers => {
-                "Sporting Goods, Hobby, Musical Instrument, Book, and Miscellaneous Retailers"
-            }
+            Self::ClothingClothingAccessoriesShoeAndJewelryRetailers => "Clothing, Clothing Accessories, Shoe, and Jewelry Retailers",
+            Self::SportingGoodsHobbyMusicalInstrumentBookAndMiscellaneousRetailers => "Sporting Goods, Hobby, Musical Instrument, Book, and Miscellaneous Retailers",
             Self::AirTransportation => "Air Transportation",
             Self::RailTransportation => "Rail Transportation",
             Self::WaterTransportation => "Water Transportation",
             Self::TruckTransportation => "Truck Transportation",
-            Self::TransitAndGroundPassengerTransportation => {
-                "Transit and Ground Passenger Transportation"
-            }
+            Self::TransitAndGroundPassengerTransportation => "Transit and Ground Passenger Transportation",
             Self::PipelineTransportation => "Pipeline Transportation",
             Self::ScenicAndSightseeingTransportation => "Scenic and Sightseeing Transportation",
             Self::SupportActivitiesForTransportation => "Support Activities for Transportation",
@@ -313,91 +283,51 @@ impl NaicsSubsector {
             Self::CouriersAndMessengers => "Couriers and Messengers",
             Self::WarehousingAndStorage => "Warehousing and Storage",
             Self::PublishingIndustriesExceptInternet => "Publishing Industries (except Internet)",
-            Self::MotionPictureAndSoundRecordingIndustries => {
-                "Motion Picture and Sound Recording Industries"
-            }
+            Self::MotionPictureAndSoundRecordingIndustries => "Motion Picture and Sound Recording Industries",
             Self::BroadcastingExceptInternet => "Broadcasting (except Internet)",
             Self::Telecommunications => "Telecommunications",
-            Self::ComputingInfrastructureProvidersDataProcessingWebHostingAndRelatedServices => {
-                "Computing Infrastructure Providers, Data Processing, Web Hosting, and Related Services"
-            }
+            Self::ComputingInfrastructureProvidersDataProcessingWebHostingAndRelatedServices => "Computing Infrastructure Providers, Data Processing, Web Hosting, and Related Services",
             Self::OtherInformationServices => "Other Information Services",
             Self::MonetaryAuthoritiesCentralBank => "Monetary Authorities-Central Bank",
-            Self::CreditIntermediationAndRelatedActivities => {
-                "Credit Intermediation and Related Activities"
-            }
-            Self::SecuritiesCommodityContractsAndOtherFinancialInvestmentsAndRelatedActivities => {
-                "Securities, Commodity Contracts, and Other Financial Investments and Related Activities"
-            }
-            Self::InsuranceCarriersAndRelatedActivities => {
-                "Insurance Carriers and Related Activities"
-            }
-            Self::FundsTrustsAndOtherFinancialVehicles => {
-                "Funds, Trusts, and Other Financial Vehicles"
-            }
+            Self::CreditIntermediationAndRelatedActivities => "Credit Intermediation and Related Activities",
+            Self::SecuritiesCommodityContractsAndOtherFinancialInvestmentsAndRelatedActivities => "Securities, Commodity Contracts, and Other Financial Investments and Related Activities",
+            Self::InsuranceCarriersAndRelatedActivities => "Insurance Carriers and Related Activities",
+            Self::FundsTrustsAndOtherFinancialVehicles => "Funds, Trusts, and Other Financial Vehicles",
             Self::RealEstate => "Real Estate",
             Self::RentalAndLeasingServices => "Rental and Leasing Services",
-            Self::LessorsOfNonfinancialIntangibleAssetsExceptCopyrightedWorks => {
-                "Lessors of Nonfinancial Intangible Assets (except Copyrighted Works)"
-            }
-            Self::ProfessionalScientificAndTechnicalServices => {
-                "Professional, Scientific, and Technical Services"
-            }
+            Self::LessorsOfNonfinancialIntangibleAssetsExceptCopyrightedWorks => "Lessors of Nonfinancial Intangible Assets (except Copyrighted Works)",
+            Self::ProfessionalScientificAndTechnicalServices => "Professional, Scientific, and Technical Services",
             Self::ManagementOfCompaniesAndEnterprises => "Management of Companies and Enterprises",
             Self::AdministrativeAndSupportServices => "Administrative and Support Services",
-            Self::WasteManagementAndRemediationServices => {
-                "Waste Management and Remediation Services"
-            }
+            Self::WasteManagementAndRemediationServices => "Waste Management and Remediation Services",
             Self::EducationalServices => "Educational Services",
             Self::AmbulatoryHealthCareServices => "Ambulatory Health Care Services",
             Self::Hospitals => "Hospitals",
             Self::NursingAndResidentialCareFacilities => "Nursing and Residential Care Facilities",
             Self::SocialAssistance => "Social Assistance",
-            Self::PerformingArtsSpectatorSportsAndRelatedIndustries => {
-                "Performing Arts, Spectator Sports, and Related Industries"
-            }
-            Self::MuseumsHistoricalSitesAndSimilarInstitutions => {
-                "Museums, Historical Sites, and Similar Institutions"
-            }
-            Self::AmusementGamblingAndRecreationIndustries => {
-                "Amusement, Gambling, and Recreation Industries"
-            }
+            Self::PerformingArtsSpectatorSportsAndRelatedIndustries => "Performing Arts, Spectator Sports, and Related Industries",
+            Self::MuseumsHistoricalSitesAndSimilarInstitutions => "Museums, Historical Sites, and Similar Institutions",
+            Self::AmusementGamblingAndRecreationIndustries => "Amusement, Gambling, and Recreation Industries",
             Self::Accommodation => "Accommodation",
             Self::FoodServicesAndDrinkingPlaces => "Food Services and Drinking Places",
             Self::RepairAndMaintenance => "Repair and Maintenance",
             Self::PersonalAndLaundryServices => "Personal and Laundry Services",
-            Self::ReligiousGrantmakingCivicProfessionalAndSimilarOrganizations => {
-                "Religious, Grantmaking, Civic, Professional, and Similar Organizations"
-            }
+            Self::ReligiousGrantmakingCivicProfessionalAndSimilarOrganizations => "Religious, Grantmaking, Civic, Professional, and Similar Organizations",
             Self::PrivateHouseholds => "Private Households",
-            Self::ExecutiveLegislativeAndOtherGeneralGovernmentSupport => {
-                "Executive, Legislative, and Other General Government Support"
-            }
-            Self::JusticePublicOrderAndSafetyActivities => {
-                "Justice, Public Order, and Safety Activities"
-            }
-            Self::AdministrationOfHumanResourcePrograms => {
-                "Administration of Human Resource Programs"
-            }
-            Self::AdministrationOfEnvironmentalQualityPrograms => {
-                "Administration of Environmental Quality Programs"
-            }
-            Self::AdministrationOfHousingProgramsUrbanPlanningAndCommunityDevelopment => {
-                "Administration of Housing Programs, Urban Planning, and Community Development"
-            }
+            Self::ExecutiveLegislativeAndOtherGeneralGovernmentSupport => "Executive, Legislative, and Other General Government Support",
+            Self::JusticePublicOrderAndSafetyActivities => "Justice, Public Order, and Safety Activities",
+            Self::AdministrationOfHumanResourcePrograms => "Administration of Human Resource Programs",
+            Self::AdministrationOfEnvironmentalQualityPrograms => "Administration of Environmental Quality Programs",
+            Self::AdministrationOfHousingProgramsUrbanPlanningAndCommunityDevelopment => "Administration of Housing Programs, Urban Planning, and Community Development",
             Self::AdministrationOfEconomicPrograms => "Administration of Economic Programs",
             Self::SpaceResearchAndTechnology => "Space Research and Technology",
-            Self::NationalSecurityAndInternationalAffairs => {
-                "National Security and International Affairs"
-            }
+            Self::NationalSecurityAndInternationalAffairs => "National Security and International Affairs",
             Self::UnclassifiedEstablishments => "Unclassified Establishments",
         }
     }
 
-    /// Returns the NAICS code for the subsector
-    ///
-    /// # Returns
-    /// The numeric NAICS code as an i64
+    /// Returns the NAICS code of the variant.  Variants spanning several codes return the
+    /// first.
     pub fn code(&self) -> i64 {
         match self {
             Self::CropProduction => 111,
@@ -466,9 +396,7 @@ impl NaicsSubsector {
             Self::OtherInformationServices => 519,
             Self::MonetaryAuthoritiesCentralBank => 521,
             Self::CreditIntermediationAndRelatedActivities => 522,
-            Self::SecuritiesCommodityContractsAndOtherFinancialInvestmentsAndRelatedActivities => {
-                523
-            }
+            Self::SecuritiesCommodityContractsAndOtherFinancialInvestmentsAndRelatedActivities => 523,
             Self::InsuranceCarriersAndRelatedActivities => 524,
             Self::FundsTrustsAndOtherFinancialVehicles => 525,
             Self::RealEstate => 531,
@@ -504,16 +432,9 @@ impl NaicsSubsector {
         }
     }
 
-    /// Attempts to find a subsector by its NAICS code
-    ///
-    /// # Arguments
-    /// * `key` - A string representation of the NAICS code
-    ///
-    /// # Returns
-    /// `Some(NaicsSubsector)` if a matching code is found, `None` otherwise
-    pub fn from_code(key: &str) -> Option<Self> {
-        let code = key.parse::<i64>().ok()?;
-
+    /// Returns the variant with the NAICS code `code`, or `None` if the code is unknown.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.parse::<i64>().ok()?;
         let result = match code {
             111 => Self::CropProduction,
             112 => Self::AnimalProductionAndAquaculture,
@@ -581,9 +502,7 @@ impl NaicsSubsector {
             519 => Self::OtherInformationServices,
             521 => Self::MonetaryAuthoritiesCentralBank,
             522 => Self::CreditIntermediationAndRelatedActivities,
-            523 => {
-                Self::SecuritiesCommodityContractsAndOtherFinancialInvestmentsAndRelatedActivities
-            }
+            523 => Self::SecuritiesCommodityContractsAndOtherFinancialInvestmentsAndRelatedActivities,
             524 => Self::InsuranceCarriersAndRelatedActivities,
             525 => Self::FundsTrustsAndOtherFinancialVehicles,
             531 => Self::RealEstate,
@@ -618,7 +537,7 @@ impl NaicsSubsector {
             999 => Self::UnclassifiedEstablishments,
             _ => return None,
         };
-
         Some(result)
     }
 }
+// @generated end
@@ -318,6 +318,142 @@ impl AnnotatedInteger {
     }
 }
 
+impl AnnotatedInteger {
+    /// The reported value.  A cell marked `(*)`, less than half the unit in absolute value, has a
+    /// value of zero.  Returns `None` for suppressed cells and for cells without data, such as
+    /// `n.a.`.
+    pub fn value(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) | Self::Both(_, value) => Some(*value),
+            Self::Annotation(Annotation::Star) => Some(0),
+            Self::Annotation(_) => None,
+        }
+    }
+
+    /// The least value consistent with the cell.  For a size-class letter, the lower bound of its
+    /// employment range.  Returns `None` if the cell could take any value, as when marked `(D)`.
+    pub fn lower_bound(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) | Self::Both(_, value) => Some(*value as f64),
+            Self::Annotation(Annotation::Star) => Some(-0.5),
+            Self::Annotation(anno) => anno.employment_range().map(|(lower, _)| lower as f64),
+        }
+    }
+
+    /// The greatest value consistent with the cell.  For a size-class letter, the upper bound of
+    /// its employment range.  Returns `None` if the cell has no upper bound, as when marked `(D)`
+    /// or size class M.
+    pub fn upper_bound(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) | Self::Both(_, value) => Some(*value as f64),
+            Self::Annotation(Annotation::Star) => Some(0.5),
+            Self::Annotation(anno) => anno
+                .employment_range()
+                .and_then(|(_, upper)| upper)
+                .map(|upper| upper as f64),
+        }
+    }
+
+    /// Whether the value of the cell is withheld to avoid disclosing the data of individual
+    /// companies.
+    pub fn is_suppressed(&self) -> bool {
+        match self {
+            Self::Annotation(anno) => anno.is_suppressed(),
+            Self::Integer(_) | Self::Both(_, _) => false,
+        }
+    }
+
+    /// The value, bounds and suppression flag of the cell as an [`Estimate`], which can be summed
+    /// across cells.
+    pub fn estimate(&self) -> Estimate {
+        Estimate {
+            value: self.value().map(|value| value as f64),
+            lower: self.lower_bound(),
+            upper: self.upper_bound(),
+            suppressed: self.is_suppressed(),
+        }
+    }
+}
+
+/// The `Estimate` struct holds a value from an [`AnnotatedInteger`], or a sum of such values,
+/// together with the bounds implied by its annotations.  Summing estimates propagates
+/// suppression, so a total over suppressed cells has no value and carries the `suppressed` flag,
+/// instead of treating the suppressed cells as zero.
+///
+/// * **value** - The value, or `None` if any summed cell is suppressed or without data.
+/// * **lower** - The lower bound, or `None` if unbounded below.
+/// * **upper** - The upper bound, or `None` if unbounded above.
+/// * **suppressed** - Whether any summed cell is suppressed.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Estimate {
+    value: Option<f64>,
+    lower: Option<f64>,
+    upper: Option<f64>,
+    suppressed: bool,
+}
+
+impl Estimate {
+    /// An exact estimate of `value`.
+    pub fn exact(value: f64) -> Self {
+        Self {
+            value: Some(value),
+            lower: Some(value),
+            upper: Some(value),
+            suppressed: false,
+        }
+    }
+
+    /// Compares self with `other` by their bounds.  Returns `None` if the bounds overlap, so that
+    /// the order cannot be determined, unless both values are exact and equal.
+    pub fn compare(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let below = |a: &Self, b: &Self| match (a.upper, b.lower) {
+            (Some(upper), Some(lower)) => upper < lower,
+            _ => false,
+        };
+        if below(self, other) {
+            Some(std::cmp::Ordering::Less)
+        } else if below(other, self) {
+            Some(std::cmp::Ordering::Greater)
+        } else if self.lower == self.upper
+            && other.lower == other.upper
+            && self.lower == other.lower
+        {
+            self.lower.map(|_| std::cmp::Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::ops::Add for Estimate {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = |a: Option<f64>, b: Option<f64>| a.zip(b).map(|(a, b)| a + b);
+        Self {
+            value: sum(self.value, other.value),
+            lower: sum(self.lower, other.lower),
+            upper: sum(self.upper, other.upper),
+            suppressed: self.suppressed || other.suppressed,
+        }
+    }
+}
+
+impl std::iter::Sum for Estimate {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::exact(0.0), |total, estimate| total + estimate)
+    }
+}
+
 impl Default for AnnotatedInteger {
     fn default() -> Self {
         Self::Integer(0)
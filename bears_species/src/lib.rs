@@ -1,43 +1,57 @@
+mod codegen;
 mod data;
 mod dataset;
+mod deflate;
 mod error;
 mod free;
+mod identity;
+mod io_matrix;
 mod key_sets;
 mod keys;
 mod method;
 mod parameter;
 mod parameter_value;
+mod query;
 mod request;
+mod resample;
 mod results;
+mod series;
+mod table_view;
+mod transforms;
 
-pub use data::{Data, MneDiData, result_to_data};
+pub use codegen::{KeyGenerator, KeyTable, KeyTarget, KeyVariant};
+pub use data::{Data, MneDiData, MneDiDatum, result_to_data};
 pub use dataset::{Dataset, DatasetDetails, Datasets};
+pub use deflate::{PriceIndex, Provenance, RealSeries};
 // investment invalid unused
-// check unused
 pub use error::{
-    BTreeKeyMissing, BeaErr, BeaErrorKind, Csv, DatasetMissing, DeriveFromStr, EnvError,
-    FromStrError, IoError, Jiff, JsonParseError, JsonParseErrorKind, KeyMissing, NotArray,
-    NotObject, ParameterValueTableVariant, ParseInt, Progress, RateLimit, ReqwestError, SerdeJson,
-    Set, VariantMissing,
+    BTreeKeyMissing, BeaErr, BeaErrorKind, Check, Csv, DatasetMissing, DeriveFromStr, EnvError,
+    FromStrError, IoError, Jiff, JsonParseError, JsonParseErrorKind, KeyMissing, MatrixInvalid,
+    NotArray, NotObject, ParameterValueTableVariant, ParseInt, Progress, RateLimit, ReqwestError,
+    SerdeJson, Set, VariantMissing,
 };
 pub use free::{
     NotFloat, NotInteger, ParseFloat, ParseInteger, UrlParseError, from_csv, json_bool, json_str,
     map_to_bool, map_to_float, map_to_int, map_to_string, to_csv,
 };
+pub use identity::Violation;
+pub use io_matrix::IoMatrix;
 pub use key_sets::{
-    ApiMetadata, FixedAssetCodes, FixedAssetData, FixedAssets, GdpByIndustry, GdpCodes, GdpData,
-    GdpDatum, Iip, IipData, InputOutput, InputOutputData, IntlServSta, IntlServTrade, IoCodes, Ita,
-    ItaData, ItaDatum, Mne, NiUnderlyingDetail, Nipa, NipaData, NipaIterator, Regional,
-    UnderlyingGdpByIndustry, UnderlyingGdpData,
+    ApiMetadata, FixedAssetCodes, FixedAssetData, FixedAssetDatum, FixedAssets, GdpByIndustry,
+    GdpCodes, GdpData, GdpDatum, Iip, IipData, IipDatum, InputOutput, InputOutputData,
+    InputOutputDatum, IntlServSta, IntlServTrade, IoCodes, Ita, ItaData, ItaDatum, Mne,
+    NiUnderlyingDetail, Nipa, NipaData, NipaDatum, NipaIterator, Regional, UnderlyingGdpByIndustry,
+    UnderlyingGdpData, UnderlyingGdpDatum,
 };
 pub use keys::{
     AffiliateKind, AffiliateLevel, Affiliation, AnnotatedInteger, Annotation, AnnotationMissing,
     AocSta, AreaOrCountry, BoolInvalid, BoolOptions, Channel, Component, DirectionKind,
-    DirectionOfInvestment, FixedAssetTable, Footnotes, Frequencies, Frequency, FrequencyOptions,
-    IipIndustry, Indicator, InputOutputCode, InputOutputTable, Integer, IntegerInvalid,
-    IntegerKind, IntegerOptions, Investment, ItaFrequencies, ItaFrequency, Measure, Millions,
-    MillionsOptions, Naics, NaicsCategory, NaicsIndustry, NaicsInputOutput, NaicsItem, NaicsItems,
-    NaicsSector, NaicsSubcategory, NaicsSubsector, NaicsSupplement, NipaRange, NipaRangeIterator,
+    DirectionOfInvestment, Estimate, FixedAssetTable, Footnotes, Frequencies, Frequency,
+    FrequencyOptions, IipIndustry, Indicator, InputOutputCode, InputOutputTable, Integer,
+    IntegerInvalid, IntegerKind, IntegerOptions, Investment, ItaFrequencies, ItaFrequency, Measure,
+    Millions, MillionsOptions, Naics, NaicsCategory, NaicsConcordance, NaicsIndustry,
+    NaicsInputOutput, NaicsItem, NaicsItems, NaicsLevel, NaicsLink, NaicsRelation, NaicsSector,
+    NaicsSubcategory, NaicsSubsector, NaicsSupplement, NaicsVintage, NipaRange, NipaRangeIterator,
     NipaRanges, NipaTableName, Nom, NotQuarter, OwnershipInvalid, OwnershipKind, OwnershipLevel,
     RowCode, RowCodeMissing, SelectionKind, SelectionSet, Service, State, StateKind, TableName,
     TradeDirection, Year, YearInvalid, YearKind, YearOptions, YearRange, date_by_period,
@@ -49,5 +63,10 @@ pub use parameter_value::{
     Metadata, MneDoi, NipaFrequency, NipaShowMillions, NipaTable, NipaYear, ParameterFields,
     ParameterValueTable, ParameterValues,
 };
+pub use query::{Query, SeriesKey};
 pub use request::{RequestParameter, RequestParameters};
+pub use resample::Aggregation;
 pub use results::{BeaResponse, Results};
+pub use series::{Period, Quantity, Series, SeriesSet};
+pub use table_view::{NipaRow, NipaTableView};
+pub use transforms::Change;
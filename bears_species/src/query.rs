@@ -0,0 +1,298 @@
+use crate::series::ita_id;
+use crate::{AreaOrCountry, Data, Dataset, Frequency, Naics, RowCode};
+use std::collections::BTreeMap;
+
+/// The `SeriesKey` struct describes a series contained in a [`Data`] response, holding the
+/// attributes used to select series with a [`Query`] without loading the observations.
+///
+/// * **id** - The series identifier, as used by [`SeriesSet`](crate::SeriesSet).
+/// * **frequency** - Frequency of the observations.
+/// * **table** - The table reporting the series, for datasets organized by table.
+/// * **industry** - The industry of the series, for datasets reported by industry.
+/// * **area** - The country or area of the series, for datasets reported by area.
+/// * **start** - Date of the first observation.
+/// * **end** - Date of the last observation.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct SeriesKey {
+    id: String,
+    frequency: Frequency,
+    table: Option<String>,
+    industry: Option<Naics>,
+    area: Option<AreaOrCountry>,
+    start: jiff::civil::Date,
+    end: jiff::civil::Date,
+}
+
+/// Collects [`SeriesKey`] values from observations, widening the span of each series to cover
+/// every observed date.
+#[derive(Debug, Default)]
+struct KeyCollector(BTreeMap<(String, Frequency), SeriesKey>);
+
+impl KeyCollector {
+    fn observe(
+        &mut self,
+        id: String,
+        frequency: Frequency,
+        date: jiff::civil::Date,
+        table: Option<String>,
+        industry: Option<Naics>,
+        area: Option<AreaOrCountry>,
+    ) {
+        self.0
+            .entry((id.clone(), frequency))
+            .and_modify(|key| {
+                key.start = key.start.min(date);
+                key.end = key.end.max(date);
+            })
+            .or_insert_with(|| SeriesKey::new(id, frequency, table, industry, area, date, date));
+    }
+}
+
+impl Data {
+    /// The `keys` method describes each series contained in self, without copying the
+    /// observations.  Series are identified as in [`Data::series`].
+    pub fn keys(&self) -> Vec<SeriesKey> {
+        let mut keys = KeyCollector::default();
+        match self {
+            Self::Nipa(data) => data
+                .iter()
+                .map(|d| {
+                    keys.observe(
+                        d.series_code().to_owned(),
+                        *d.frequency(),
+                        *d.time_period(),
+                        Some(d.table_name().to_owned()),
+                        None,
+                        None,
+                    )
+                })
+                .for_each(drop),
+            Self::FixedAssets(data) => data
+                .iter()
+                .map(|d| {
+                    keys.observe(
+                        d.series_code().to_owned(),
+                        Frequency::Annual,
+                        *d.time_period(),
+                        Some(d.table_name().to_string()),
+                        None,
+                        None,
+                    )
+                })
+                .for_each(drop),
+            Self::MneDi(data) => data
+                .iter()
+                .map(|d| {
+                    let (industry, area) = match d.row_code() {
+                        RowCode::Naics(naics) | RowCode::Parent(naics) => (Some(*naics), None),
+                        RowCode::Region(area) => (None, Some(*area)),
+                        RowCode::State(_) | RowCode::Addendum(_) => (None, None),
+                    };
+                    keys.observe(
+                        format!("{}_{}_{}", d.series_id(), d.row(), d.column()),
+                        Frequency::Annual,
+                        *d.year(),
+                        None,
+                        industry,
+                        area,
+                    )
+                })
+                .for_each(drop),
+            Self::Gdp(data) => data
+                .iter()
+                .map(|d| {
+                    let date = match d.frequency() {
+                        Frequency::Annual => *d.year(),
+                        _ => *d.quarter(),
+                    };
+                    keys.observe(
+                        format!("{}_{}", d.table_id(), d.industry().code()),
+                        *d.frequency(),
+                        date,
+                        Some(d.table_id().to_string()),
+                        Some(*d.industry()),
+                        None,
+                    )
+                })
+                .for_each(drop),
+            Self::UnderlyingGdp(data) => data
+                .iter()
+                .map(|d| {
+                    keys.observe(
+                        format!("{}_{}", d.table_id(), d.industry().code()),
+                        *d.frequency(),
+                        *d.year(),
+                        Some(d.table_id().to_string()),
+                        Some(*d.industry()),
+                        None,
+                    )
+                })
+                .for_each(drop),
+            Self::ItaData(data) => data
+                .iter()
+                .map(|d| {
+                    keys.observe(
+                        ita_id(d.time_series_id(), d.frequency()),
+                        Frequency::from(*d.frequency()),
+                        *d.time_period(),
+                        None,
+                        None,
+                        Some(*d.area_or_country()),
+                    )
+                })
+                .for_each(drop),
+            Self::Iip(data) => data
+                .iter()
+                .map(|d| {
+                    keys.observe(
+                        ita_id(d.time_series_id(), d.frequency()),
+                        Frequency::from(*d.frequency()),
+                        *d.time_period(),
+                        None,
+                        None,
+                        None,
+                    )
+                })
+                .for_each(drop),
+            Self::InputOutput(data) => data
+                .iter()
+                .map(|d| {
+                    keys.observe(
+                        format!(
+                            "{}_{}_{}",
+                            d.table_id().key(),
+                            d.row_code().code(),
+                            d.column_code().code()
+                        ),
+                        Frequency::Annual,
+                        *d.year(),
+                        Some(d.table_id().key().to_string()),
+                        None,
+                        None,
+                    )
+                })
+                .for_each(drop),
+        }
+        keys.0.into_values().collect()
+    }
+}
+
+/// The `Query` struct selects series from the local data store by their [`SeriesKey`].  Each
+/// filter left unset matches every series.
+///
+/// * **dataset** - The dataset reporting the series.
+/// * **table** - The table reporting the series, e.g. `T10105` for NIPA.
+/// * **series** - The series identifier.
+/// * **industry** - An industry, matching series of the industry or of any industry within it.
+/// * **area** - The country or area of the series.
+/// * **frequency** - Frequency of the observations.
+/// * **start** - Matches series with observations on or after this date.
+/// * **end** - Matches series with observations on or before this date.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Query {
+    dataset: Option<Dataset>,
+    table: Option<String>,
+    series: Option<String>,
+    industry: Option<Naics>,
+    area: Option<AreaOrCountry>,
+    frequency: Option<Frequency>,
+    start: Option<jiff::civil::Date>,
+    end: Option<jiff::civil::Date>,
+}
+
+impl Query {
+    /// Restricts the query to series of `dataset`.
+    pub fn with_dataset(mut self, dataset: Dataset) -> Self {
+        self.dataset = Some(dataset);
+        self
+    }
+
+    /// Restricts the query to series reported in `table`.
+    pub fn with_table(mut self, table: &str) -> Self {
+        self.table = Some(table.to_owned());
+        self
+    }
+
+    /// Restricts the query to the series with identifier `series`.
+    pub fn with_series(mut self, series: &str) -> Self {
+        self.series = Some(series.to_owned());
+        self
+    }
+
+    /// Restricts the query to series of `industry` and the industries within it.
+    pub fn with_industry(mut self, industry: Naics) -> Self {
+        self.industry = Some(industry);
+        self
+    }
+
+    /// Restricts the query to series of `area`.
+    pub fn with_area(mut self, area: AreaOrCountry) -> Self {
+        self.area = Some(area);
+        self
+    }
+
+    /// Restricts the query to series observed at `frequency`.
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Restricts the query to observations from `start` to `end`, inclusive.  Either bound may be
+    /// `None` to leave the range open.
+    pub fn with_range(
+        mut self,
+        start: Option<jiff::civil::Date>,
+        end: Option<jiff::civil::Date>,
+    ) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    /// Whether the series `key` of `dataset` matches every filter of the query.  A series matches
+    /// the time range if any of its observations falls within it.
+    pub fn matches(&self, dataset: Dataset, key: &SeriesKey) -> bool {
+        self.dataset.is_none_or(|d| d == dataset)
+            && self
+                .table
+                .as_ref()
+                .is_none_or(|table| key.table.as_ref() == Some(table))
+            && self.series.as_ref().is_none_or(|series| key.id == *series)
+            && self
+                .industry
+                .is_none_or(|industry| key.industry.is_some_and(|naics| naics.is_within(&industry)))
+            && self.area.is_none_or(|area| key.area == Some(area))
+            && self.frequency.is_none_or(|f| key.frequency == f)
+            && self.start.is_none_or(|start| key.end >= start)
+            && self.end.is_none_or(|end| key.start <= end)
+    }
+
+    /// Whether `date` falls within the time range of the query.
+    pub fn contains(&self, date: jiff::civil::Date) -> bool {
+        self.start.is_none_or(|start| date >= start) && self.end.is_none_or(|end| date <= end)
+    }
+}
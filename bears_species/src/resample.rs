@@ -0,0 +1,279 @@
+use crate::io_matrix::invert;
+use crate::{
+    BeaErr, Check, Dataset, Frequency, KeyMissing, MatrixInvalid, Period, Quantity, Series,
+};
+use std::collections::BTreeMap;
+
+/// The `Aggregation` enum selects how [`Series::aggregate`] combines the periods of a higher
+/// frequency into a period of a lower frequency, and which constraint [`Series::disaggregate`]
+/// preserves, according to the kind of measure in the series.
+///
+/// * **Sum** - Flows reported per period, such as ITA transactions: the annual value is the sum
+///   of the quarters.
+/// * **Average** - Rates, indexes and flows reported at annual rates, such as the seasonally
+///   adjusted NIPA estimates: the annual value is the mean of the quarters.
+/// * **EndOfPeriod** - Stocks, such as IIP positions: the annual value is the value of the last
+///   quarter.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum Aggregation {
+    Sum,
+    Average,
+    EndOfPeriod,
+}
+
+impl Aggregation {
+    /// The usual aggregation of the series in `dataset`.  Returns `None` for datasets that mix
+    /// stocks and flows, such as FixedAssets, or that report a single frequency.
+    pub fn for_dataset(dataset: Dataset) -> Option<Self> {
+        match dataset {
+            Dataset::Nipa
+            | Dataset::NIUnderlyingDetail
+            | Dataset::GDPbyIndustry
+            | Dataset::UnderlyingGDPbyIndustry
+            | Dataset::Regional => Some(Self::Average),
+            Dataset::Ita | Dataset::IntlServTrade | Dataset::IntlServSTA => Some(Self::Sum),
+            Dataset::Iip => Some(Self::EndOfPeriod),
+            Dataset::Mne
+            | Dataset::FixedAssets
+            | Dataset::InputOutput
+            | Dataset::APIDatasetMetadata => None,
+        }
+    }
+
+    /// Weight of each of `size` subperiods in the constraint tying them to their period.
+    fn weights(&self, size: usize) -> Vec<f64> {
+        match self {
+            Self::Sum => vec![1.0; size],
+            Self::Average => vec![1.0 / size as f64; size],
+            Self::EndOfPeriod => {
+                let mut weights = vec![0.0; size];
+                weights[size - 1] = 1.0;
+                weights
+            }
+        }
+    }
+}
+
+/// Returns the number of periods of `high` in each period of `low`, or a [`Check`] error naming
+/// both frequencies if `high` is not a higher frequency than `low`.
+fn ratio(high: Frequency, low: Frequency) -> Result<usize, BeaErr> {
+    if high.per_year() <= low.per_year() || high.per_year() % low.per_year() != 0 {
+        let desc = format!("unsupported conversion between {high} and {low} frequencies");
+        return Err(Check::new(desc).into());
+    }
+    Ok((high.per_year() / low.per_year()) as usize)
+}
+
+/// The period of frequency `frequency` containing `period`.
+fn containing(period: &Period, frequency: Frequency) -> Period {
+    let months = (12 / frequency.per_year()) as i8;
+    let month = (period.date().month() - 1) / months * months + 1;
+    Period::new(jiff::civil::date(period.year(), month, 1), frequency)
+}
+
+/// The `size` periods of frequency `frequency` making up `period`.
+fn subperiods(period: &Period, frequency: Frequency, size: usize) -> Vec<Period> {
+    let first = Period::new(*period.date(), frequency);
+    (0..size as i64).filter_map(|i| first.shift(i)).collect()
+}
+
+impl Series {
+    /// The `aggregate` method converts self to the lower frequency `frequency`, combining the
+    /// periods of each lower-frequency period by `aggregation`.
+    ///
+    /// A period is missing unless every subperiod is observed, or for
+    /// [`Aggregation::EndOfPeriod`], unless the last subperiod is observed.  Returns an error if
+    /// `frequency` is not lower than the frequency of self.
+    pub fn aggregate(
+        &self,
+        frequency: Frequency,
+        aggregation: Aggregation,
+    ) -> Result<Self, BeaErr> {
+        let size = ratio(*self.frequency(), frequency)?;
+        let mut groups = BTreeMap::<Period, Vec<Period>>::new();
+        self.observations()
+            .iter()
+            .map(|(period, _)| {
+                groups
+                    .entry(containing(period, frequency))
+                    .or_default()
+                    .push(*period)
+            })
+            .for_each(drop);
+        let observations = groups
+            .into_keys()
+            .map(|period| {
+                let values = subperiods(&period, *self.frequency(), size)
+                    .iter()
+                    .map(|p| self.get(p))
+                    .collect::<Vec<Option<f64>>>();
+                let value = match aggregation {
+                    Aggregation::EndOfPeriod => values.last().copied().flatten(),
+                    _ => values
+                        .iter()
+                        .copied()
+                        .sum::<Option<f64>>()
+                        .map(|sum| match aggregation {
+                            Aggregation::Average => sum / size as f64,
+                            _ => sum,
+                        }),
+                };
+                let quantity = match value {
+                    Some(value) => Quantity::Value(value),
+                    None => Quantity::Missing,
+                };
+                (period, quantity)
+            })
+            .collect();
+        Ok(Self::new(
+            self.id().to_owned(),
+            self.description().to_owned(),
+            frequency,
+            self.unit().to_owned(),
+            *self.unit_mult(),
+            observations,
+        ))
+    }
+
+    /// The `disaggregate` method distributes self over the periods of the higher-frequency
+    /// series `indicator` by the proportional Denton method, so that the result aggregates back
+    /// to self by `aggregation` and moves as closely as possible with the indicator.
+    ///
+    /// The Denton method minimizes the sum of squared changes in the ratio of the result to the
+    /// indicator between consecutive periods, subject to the aggregation constraint in each
+    /// period of self.  The result covers the observed periods of self, which must be
+    /// consecutive, and the indicator must be observed and nonzero in each of their subperiods.
+    pub fn disaggregate(
+        &self,
+        indicator: &Series,
+        aggregation: Aggregation,
+    ) -> Result<Self, BeaErr> {
+        let frequency = *indicator.frequency();
+        let size = ratio(frequency, *self.frequency())?;
+        let benchmarks = self.values();
+        if let Some(window) = benchmarks
+            .windows(2)
+            .find(|w| w[0].0.next() != Some(w[1].0))
+        {
+            let clue = format!(
+                "gap in {} between {} and {}",
+                self.id(),
+                window[0].0,
+                window[1].0
+            );
+            return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+        }
+        let periods = benchmarks
+            .iter()
+            .flat_map(|(period, _)| subperiods(period, frequency, size))
+            .collect::<Vec<Period>>();
+        let mut levels = Vec::with_capacity(periods.len());
+        for period in &periods {
+            match indicator.get(period) {
+                Some(value) if value != 0.0 => levels.push(value),
+                _ => {
+                    let clue = format!("{} in {period}", indicator.id());
+                    return Err(KeyMissing::new(clue, line!(), file!().to_string()).into());
+                }
+            }
+        }
+        let ratios = denton(&levels, &benchmarks, &aggregation.weights(size))?;
+        let observations = periods
+            .into_iter()
+            .zip(levels.iter().zip(ratios))
+            .map(|(period, (level, ratio))| (period, Quantity::Value(level * ratio)))
+            .collect();
+        Ok(Self::new(
+            self.id().to_owned(),
+            self.description().to_owned(),
+            frequency,
+            self.unit().to_owned(),
+            *self.unit_mult(),
+            observations,
+        ))
+    }
+
+    /// The `interpolate` method distributes self over the periods of the higher frequency
+    /// `frequency` without an indicator, by the Denton method with a constant indicator.  The
+    /// result is as smooth as possible while aggregating back to self by `aggregation`.
+    pub fn interpolate(
+        &self,
+        frequency: Frequency,
+        aggregation: Aggregation,
+    ) -> Result<Self, BeaErr> {
+        let size = ratio(frequency, *self.frequency())?;
+        let observations = self
+            .values()
+            .iter()
+            .flat_map(|(period, _)| subperiods(period, frequency, size))
+            .map(|period| (period, Quantity::Value(1.0)))
+            .collect();
+        let indicator = Self::new(
+            self.id().to_owned(),
+            self.description().to_owned(),
+            frequency,
+            String::new(),
+            None,
+            observations,
+        );
+        self.disaggregate(&indicator, aggregation)
+    }
+}
+
+/// Solves the proportional Denton problem for the indicator `levels`, returning the ratio of the
+/// result to the indicator in each period.
+///
+/// With `r` the ratios, `D` the first-difference operator and `A` the constraint matrix scaled by
+/// the indicator, minimizes `r'D'Dr` subject to `Ar = b` by solving the linear system
+/// `[D'D A'; A 0] [r; l] = [0; b]`.
+fn denton(
+    levels: &[f64],
+    benchmarks: &[(Period, f64)],
+    weights: &[f64],
+) -> Result<Vec<f64>, BeaErr> {
+    let n = levels.len();
+    let size = weights.len();
+    let dim = n + benchmarks.len();
+    let mut system = vec![0.0; dim * dim];
+    for t in 0..n.saturating_sub(1) {
+        system[t * dim + t] += 1.0;
+        system[(t + 1) * dim + t + 1] += 1.0;
+        system[t * dim + t + 1] -= 1.0;
+        system[(t + 1) * dim + t] -= 1.0;
+    }
+    for (b, _) in benchmarks.iter().enumerate() {
+        for (k, weight) in weights.iter().enumerate() {
+            let t = b * size + k;
+            system[(n + b) * dim + t] = weight * levels[t];
+            system[t * dim + n + b] = weight * levels[t];
+        }
+    }
+    let Some(inverse) = invert(dim, system) else {
+        let clue = format!("Denton system for {} periods is singular", benchmarks.len());
+        return Err(MatrixInvalid::new(clue, line!(), file!().to_string()).into());
+    };
+    let ratios = (0..n)
+        .map(|t| {
+            benchmarks
+                .iter()
+                .enumerate()
+                .map(|(b, (_, value))| inverse[t * dim + n + b] * value)
+                .sum()
+        })
+        .collect();
+    Ok(ratios)
+}
@@ -334,6 +334,7 @@ impl TryFrom<&serde_json::Value> for BeaResponse {
     Hash,
     serde::Deserialize,
     serde::Serialize,
+    derive_getters::Getters,
     derive_new::new,
     derive_more::Display,
 )]
@@ -403,6 +404,7 @@ impl TryFrom<&serde_json::Value> for ApiError {
     Hash,
     serde::Deserialize,
     serde::Serialize,
+    derive_getters::Getters,
     derive_new::new,
     derive_more::Display,
 )]
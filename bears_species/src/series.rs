@@ -0,0 +1,474 @@
+use crate::{
+    AnnotatedInteger, Data, FixedAssetData, Frequency, GdpData, IipData, InputOutputData, ItaData,
+    ItaFrequency, MneDiData, NipaData, UnderlyingGdpData,
+};
+use std::collections::BTreeMap;
+
+/// The `Period` struct represents a single observation period of a time series.
+///
+/// BEA responses date each observation to the first day of the period, so the `date` field alone
+/// cannot distinguish the annual observation for 2020 from the first quarter of 2020.  The
+/// `frequency` field makes the period semantics explicit.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Period {
+    date: jiff::civil::Date,
+    frequency: Frequency,
+}
+
+impl Period {
+    /// Returns the year of the period.
+    pub fn year(&self) -> i16 {
+        self.date.year()
+    }
+
+    /// Returns the quarter of the period (1-4) for quarterly periods, otherwise `None`.
+    pub fn quarter(&self) -> Option<i8> {
+        match self.frequency {
+            Frequency::Quarterly => Some((self.date.month() - 1) / 3 + 1),
+            _ => None,
+        }
+    }
+
+    /// Returns the month of the period (1-12) for monthly periods, otherwise `None`.
+    pub fn month(&self) -> Option<i8> {
+        match self.frequency {
+            Frequency::Monthly => Some(self.date.month()),
+            _ => None,
+        }
+    }
+
+    /// Number of periods of this frequency in a year.
+    pub fn per_year(&self) -> i64 {
+        self.frequency.per_year()
+    }
+
+    /// The `shift` method returns the period `n` periods after self, or before self if `n` is
+    /// negative.  Returns `None` if the resulting date is out of range.
+    pub fn shift(&self, n: i64) -> Option<Self> {
+        let months = n * 12 / self.per_year();
+        let date = self
+            .date
+            .checked_add(jiff::Span::new().months(months))
+            .ok()?;
+        Some(Self::new(date, self.frequency))
+    }
+
+    /// Returns the period immediately following self.
+    pub fn next(&self) -> Option<Self> {
+        self.shift(1)
+    }
+
+    /// Returns the period immediately preceding self.
+    pub fn previous(&self) -> Option<Self> {
+        self.shift(-1)
+    }
+}
+
+impl std::fmt::Display for Period {
+    /// Formats the period using the BEA `TimePeriod` convention, e.g. "2020", "2020Q1" or
+    /// "2020M01".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.quarter(), self.month()) {
+            (Some(quarter), _) => write!(f, "{}Q{quarter}", self.year()),
+            (_, Some(month)) => write!(f, "{}M{month:02}", self.year()),
+            _ => write!(f, "{}", self.year()),
+        }
+    }
+}
+
+/// The `Quantity` enum represents the value of a single observation.
+///
+/// * **Value** - A reported value, in the units of the parent [`Series`].
+/// * **Missing** - The observation is not available, including values suppressed by the BEA to
+///   avoid disclosure of individual company data.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+pub enum Quantity {
+    Value(f64),
+    Missing,
+}
+
+impl Quantity {
+    /// Returns the reported value, or `None` if the observation is missing.
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            Self::Value(value) => Some(*value),
+            Self::Missing => None,
+        }
+    }
+}
+
+impl From<f64> for Quantity {
+    fn from(value: f64) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl From<Option<i64>> for Quantity {
+    fn from(value: Option<i64>) -> Self {
+        match value {
+            Some(value) => Self::Value(value as f64),
+            None => Self::Missing,
+        }
+    }
+}
+
+impl From<&AnnotatedInteger> for Quantity {
+    /// Annotations without an accompanying value, such as suppressed cells, map to `Missing`.
+    fn from(value: &AnnotatedInteger) -> Self {
+        match value {
+            AnnotatedInteger::Integer(value) | AnnotatedInteger::Both(_, value) => {
+                Self::Value(*value as f64)
+            }
+            AnnotatedInteger::Annotation(_) => Self::Missing,
+        }
+    }
+}
+
+/// The `Series` struct is a dataset-independent representation of a single time series.
+///
+/// * **id** - A stable series identifier.  See [`SeriesSet`] for the identifier used by each
+///   dataset.
+/// * **description** - Human-readable description of the series.
+/// * **frequency** - Frequency of the observations.
+/// * **unit** - Unit of measure reported by the BEA, or an empty string if not reported.
+/// * **unit_mult** - Base-10 exponent of the unit multiplier, or `None` if values are not scaled.
+/// * **observations** - Observations ordered by period.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Series {
+    id: String,
+    description: String,
+    frequency: Frequency,
+    unit: String,
+    unit_mult: Option<i64>,
+    observations: Vec<(Period, Quantity)>,
+}
+
+impl Series {
+    /// Returns the value observed in `period`, if present and not missing.
+    pub fn get(&self, period: &Period) -> Option<f64> {
+        self.observations
+            .binary_search_by(|(p, _)| p.cmp(period))
+            .ok()
+            .and_then(|i| self.observations[i].1.value())
+    }
+
+    /// Returns the first and last periods of the series.
+    pub fn span(&self) -> Option<(Period, Period)> {
+        let first = self.observations.first()?.0;
+        let last = self.observations.last()?.0;
+        Some((first, last))
+    }
+
+    /// Returns the periods and values of the series, skipping missing observations.
+    pub fn values(&self) -> Vec<(Period, f64)> {
+        self.observations
+            .iter()
+            .filter_map(|(period, quantity)| quantity.value().map(|v| (*period, v)))
+            .collect()
+    }
+
+    /// Returns the number of observations in the series.
+    pub fn len(&self) -> usize {
+        self.observations.len()
+    }
+
+    /// Returns `true` if the series has no observations.
+    pub fn is_empty(&self) -> bool {
+        self.observations.is_empty()
+    }
+
+    /// Adds an observation, replacing any observation with the same period.
+    pub fn insert(&mut self, period: Period, quantity: Quantity) {
+        match self.observations.binary_search_by(|(p, _)| p.cmp(&period)) {
+            Ok(i) => self.observations[i] = (period, quantity),
+            Err(i) => self.observations.insert(i, (period, quantity)),
+        }
+    }
+}
+
+/// The `SeriesSet` struct holds the [`Series`] extracted from a [`Data`] response, keyed by series
+/// id and frequency.
+///
+/// Series identifiers by dataset:
+///
+/// * **NIPA, NIUnderlyingDetail and FixedAssets** - `SeriesCode`.
+/// * **ITA and IIP** - `TimeSeriesId`.  Quarterly not seasonally adjusted series carry a `_QNSA`
+///   suffix, since they share the identifier of the seasonally adjusted series.
+/// * **GDPbyIndustry and UnderlyingGDPbyIndustry** - `{TableID}_{Industry}`.
+/// * **InputOutput** - `{TableID}_{RowCode}_{ColCode}`.
+/// * **MNE** - `{SeriesID}_{Row}_{Column}`.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Deref,
+    derive_more::DerefMut,
+    derive_more::From,
+)]
+#[from(BTreeMap<(String, Frequency), Series>)]
+pub struct SeriesSet(BTreeMap<(String, Frequency), Series>);
+
+impl SeriesSet {
+    /// Returns the series with identifier `id` at frequency `frequency`.
+    pub fn series(&self, id: &str, frequency: Frequency) -> Option<&Series> {
+        self.get(&(id.to_owned(), frequency))
+    }
+
+    /// Returns the series with identifier `id` at every available frequency.
+    pub fn by_id(&self, id: &str) -> Vec<&Series> {
+        self.values().filter(|s| s.id() == id).collect()
+    }
+
+    /// Adds an observation to the series `id`, creating the series from the provided metadata if
+    /// it is not yet present.
+    pub fn observe(
+        &mut self,
+        id: String,
+        description: &str,
+        unit: &str,
+        unit_mult: Option<i64>,
+        period: Period,
+        quantity: Quantity,
+    ) {
+        let frequency = *period.frequency();
+        self.entry((id.clone(), frequency))
+            .or_insert_with(|| {
+                Series::new(
+                    id,
+                    description.to_owned(),
+                    frequency,
+                    unit.to_owned(),
+                    unit_mult,
+                    Vec::new(),
+                )
+            })
+            .insert(period, quantity);
+    }
+}
+
+/// Returns the series id for ITA and IIP observations, marking unadjusted quarterly series.
+pub(crate) fn ita_id(id: &str, frequency: &ItaFrequency) -> String {
+    match frequency {
+        ItaFrequency::Qnsa => format!("{id}_QNSA"),
+        _ => id.to_owned(),
+    }
+}
+
+impl From<&NipaData> for SeriesSet {
+    fn from(value: &NipaData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                set.observe(
+                    d.series_code().to_owned(),
+                    d.line_description(),
+                    d.cl_unit(),
+                    *d.unit_mult(),
+                    Period::new(*d.time_period(), *d.frequency()),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&FixedAssetData> for SeriesSet {
+    fn from(value: &FixedAssetData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                set.observe(
+                    d.series_code().to_owned(),
+                    d.line_description(),
+                    &d.cl_unit().to_string(),
+                    *d.unit_mult(),
+                    Period::new(*d.time_period(), Frequency::Annual),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&ItaData> for SeriesSet {
+    fn from(value: &ItaData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                set.observe(
+                    ita_id(d.time_series_id(), d.frequency()),
+                    d.time_series_description(),
+                    d.cl_unit(),
+                    *d.unit_mult(),
+                    Period::new(*d.time_period(), Frequency::from(*d.frequency())),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&IipData> for SeriesSet {
+    fn from(value: &IipData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                set.observe(
+                    ita_id(d.time_series_id(), d.frequency()),
+                    d.time_series_description(),
+                    &d.cl_unit().to_string(),
+                    *d.unit_mult(),
+                    Period::new(*d.time_period(), Frequency::from(*d.frequency())),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&GdpData> for SeriesSet {
+    fn from(value: &GdpData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                // Annual observations carry the year in the quarter field as well.
+                let date = match d.frequency() {
+                    Frequency::Annual => *d.year(),
+                    _ => *d.quarter(),
+                };
+                set.observe(
+                    format!("{}_{}", d.table_id(), d.industry().code()),
+                    d.industry_description(),
+                    "",
+                    None,
+                    Period::new(date, *d.frequency()),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&UnderlyingGdpData> for SeriesSet {
+    fn from(value: &UnderlyingGdpData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                set.observe(
+                    format!("{}_{}", d.table_id(), d.industry().code()),
+                    d.industry_description(),
+                    "",
+                    None,
+                    Period::new(*d.year(), *d.frequency()),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&InputOutputData> for SeriesSet {
+    fn from(value: &InputOutputData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                let description = format!("{} / {}", d.row_description(), d.column_description());
+                set.observe(
+                    format!(
+                        "{}_{}_{}",
+                        d.table_id().key(),
+                        d.row_code().code(),
+                        d.column_code().code()
+                    ),
+                    &description,
+                    "",
+                    None,
+                    Period::new(*d.year(), Frequency::Annual),
+                    Quantity::from(*d.data_value()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&MneDiData> for SeriesSet {
+    fn from(value: &MneDiData) -> Self {
+        let mut set = Self::default();
+        value
+            .iter()
+            .map(|d| {
+                set.observe(
+                    format!("{}_{}_{}", d.series_id(), d.row(), d.column()),
+                    d.series_name(),
+                    d.table_scale(),
+                    None,
+                    Period::new(*d.year(), Frequency::Annual),
+                    Quantity::from(d.data_value_unformatted()),
+                )
+            })
+            .for_each(drop);
+        set
+    }
+}
+
+impl From<&Data> for SeriesSet {
+    fn from(value: &Data) -> Self {
+        match value {
+            Data::Nipa(data) => Self::from(data),
+            Data::FixedAssets(data) => Self::from(data),
+            Data::MneDi(data) => Self::from(data),
+            Data::Gdp(data) => Self::from(data),
+            Data::UnderlyingGdp(data) => Self::from(data),
+            Data::ItaData(data) => Self::from(data),
+            Data::Iip(data) => Self::from(data),
+            Data::InputOutput(data) => Self::from(data),
+        }
+    }
+}
+
+impl Data {
+    /// The `series` method extracts the time series contained in self into a [`SeriesSet`].
+    pub fn series(&self) -> SeriesSet {
+        SeriesSet::from(self)
+    }
+}
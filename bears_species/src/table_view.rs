@@ -0,0 +1,310 @@
+use crate::{BeaErr, Csv, Frequency, IoError, NipaData, Period};
+use std::collections::BTreeMap;
+
+/// Number of most recent periods compared when inferring the hierarchy of a table.
+const HIERARCHY_PERIODS: usize = 4;
+
+/// The `NipaRow` struct holds a single line of a [`NipaTableView`].
+///
+/// * **line_number** - Line number in the published table.
+/// * **line_description** - Line description in the published table.
+/// * **series_code** - Series code of the line.
+/// * **depth** - Indentation level of the line, with top-level lines at depth zero.
+/// * **parent** - Line number of the parent line, or `None` for top-level lines.
+/// * **values** - Observed values by period.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct NipaRow {
+    line_number: i64,
+    line_description: String,
+    series_code: String,
+    depth: usize,
+    parent: Option<i64>,
+    values: BTreeMap<Period, f64>,
+}
+
+impl NipaRow {
+    /// Lines labeled "Less:" and imports enter the total of their parent with a negative sign.
+    fn sign(&self) -> f64 {
+        let description = self.line_description.trim_start();
+        if description.starts_with("Less:") || description.starts_with("Imports") {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Number of decimal places, up to three, needed to display every value in the row.
+    fn precision(&self) -> usize {
+        (0..3)
+            .find(|d| {
+                let scale = 10_f64.powi(*d as i32);
+                self.values
+                    .values()
+                    .all(|v| ((v * scale).round() - v * scale).abs() < 1e-6)
+            })
+            .unwrap_or(3)
+    }
+}
+
+/// The `NipaTableView` struct pivots the flat records of a single NIPA table at a single frequency
+/// into the two-dimensional layout of the published table, with one row per line number and one
+/// column per time period.
+///
+/// The BEA API does not report the indentation of table lines, so the view recovers the hierarchy
+/// from the values: a line is the parent of the lines that immediately follow it when their sum
+/// (subtracting imports and lines labeled "Less:") matches its value in the most recent periods,
+/// allowing for rounding.  Lines of tables that are not additive, such as percent changes and
+/// price indexes, are all placed at the top level.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, derive_getters::Getters,
+)]
+pub struct NipaTableView {
+    table_name: String,
+    frequency: Frequency,
+    periods: Vec<Period>,
+    rows: Vec<NipaRow>,
+}
+
+impl NipaTableView {
+    /// Creates the view of the records in `data` with frequency `frequency`.  The table name is
+    /// taken from the first record, and records from other tables are ignored.
+    #[tracing::instrument(skip_all)]
+    pub fn new(data: &NipaData, frequency: Frequency) -> Self {
+        let table_name = data
+            .first()
+            .map(|d| d.table_name().to_owned())
+            .unwrap_or_default();
+        let mut rows = BTreeMap::new();
+        let mut periods = std::collections::BTreeSet::new();
+        data.iter()
+            .filter(|d| *d.frequency() == frequency && d.table_name() == &table_name)
+            .map(|d| {
+                let period = Period::new(*d.time_period(), frequency);
+                periods.insert(period);
+                rows.entry(*d.line_number())
+                    .or_insert_with(|| NipaRow {
+                        line_number: *d.line_number(),
+                        line_description: d.line_description().to_owned(),
+                        series_code: d.series_code().to_owned(),
+                        depth: 0,
+                        parent: None,
+                        values: BTreeMap::new(),
+                    })
+                    .values
+                    .insert(period, *d.data_value());
+            })
+            .for_each(drop);
+        let mut view = Self {
+            table_name,
+            frequency,
+            periods: periods.into_iter().collect(),
+            rows: rows.into_values().collect(),
+        };
+        view.infer_hierarchy();
+        view
+    }
+
+    /// Restricts the columns of the view to the most recent `n` periods.
+    pub fn with_last(mut self, n: usize) -> Self {
+        let skip = self.periods.len().saturating_sub(n);
+        self.periods = self.periods.split_off(skip);
+        self
+    }
+
+    /// Returns the rows whose parent is the line `line_number`.
+    pub fn children(&self, line_number: i64) -> Vec<&NipaRow> {
+        self.rows
+            .iter()
+            .filter(|r| r.parent == Some(line_number))
+            .collect()
+    }
+
+    /// Assigns the `depth` and `parent` fields of each row.
+    fn infer_hierarchy(&mut self) {
+        let periods = self
+            .periods
+            .iter()
+            .rev()
+            .take(HIERARCHY_PERIODS)
+            .copied()
+            .collect::<Vec<Period>>();
+        let ends = self.subtree_ends(&periods);
+        let mut i = 0;
+        while i < self.rows.len() {
+            self.assign(i, 0, None, &ends);
+            i = ends[i];
+        }
+    }
+
+    /// Returns the index of the row following the subtree of each row.
+    ///
+    /// The subtree of a row consists of the shortest run of at least two following subtrees whose
+    /// sum matches the value of the row.  Rows without such a run are leaves.  Computed from the
+    /// last row to the first, so the subtrees of following rows are always known.
+    fn subtree_ends(&self, periods: &[Period]) -> Vec<usize> {
+        let mut ends = (1..=self.rows.len()).collect::<Vec<usize>>();
+        for i in (0..self.rows.len()).rev() {
+            let mut sums = vec![0.0; periods.len()];
+            let mut next = i + 1;
+            let mut count = 0;
+            while next < self.rows.len() {
+                let sign = self.rows[next].sign();
+                for (sum, period) in sums.iter_mut().zip(periods) {
+                    *sum += sign
+                        * self.rows[next]
+                            .values
+                            .get(period)
+                            .copied()
+                            .unwrap_or(f64::NAN);
+                }
+                count += 1;
+                next = ends[next];
+                if self.matches(i, &sums, periods, count) {
+                    ends[i] = next;
+                    break;
+                }
+            }
+        }
+        ends
+    }
+
+    /// Assigns `depth` and `parent` to row `i`, and recursively to its descendants.
+    fn assign(&mut self, i: usize, depth: usize, parent: Option<i64>, ends: &[usize]) {
+        self.rows[i].depth = depth;
+        self.rows[i].parent = parent;
+        let line = self.rows[i].line_number;
+        let mut next = i + 1;
+        while next < ends[i] {
+            self.assign(next, depth + 1, Some(line), ends);
+            next = ends[next];
+        }
+    }
+
+    /// Returns `true` if `sums` match the values of row `i`, allowing one unit of rounding error
+    /// per component.  Requires at least two components.
+    fn matches(&self, i: usize, sums: &[f64], periods: &[Period], count: usize) -> bool {
+        count >= 2
+            && !periods.is_empty()
+            && periods
+                .iter()
+                .zip(sums)
+                .all(|(period, sum)| match self.rows[i].values.get(period) {
+                    Some(value) => {
+                        let tolerance = count as f64 + value.abs() * 1e-9;
+                        sum.is_finite() && *value != 0.0 && (value - sum).abs() <= tolerance
+                    }
+                    None => false,
+                })
+    }
+
+    /// Renders the view as an aligned text table, indenting line descriptions by depth.
+    pub fn to_text(&self) -> String {
+        let descriptions = self
+            .rows
+            .iter()
+            .map(|r| format!("{}{}", "    ".repeat(r.depth), r.line_description))
+            .collect::<Vec<String>>();
+        let cells = self
+            .rows
+            .iter()
+            .map(|r| {
+                let precision = r.precision();
+                self.periods
+                    .iter()
+                    .map(|p| match r.values.get(p) {
+                        Some(v) => format!("{v:.precision$}"),
+                        None => "---".to_owned(),
+                    })
+                    .collect::<Vec<String>>()
+            })
+            .collect::<Vec<Vec<String>>>();
+        let headers = self
+            .periods
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>();
+        let line_width = self
+            .rows
+            .iter()
+            .map(|r| r.line_number.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let description_width = descriptions.iter().map(|d| d.len()).max().unwrap_or(0);
+        let widths = headers
+            .iter()
+            .enumerate()
+            .map(|(j, h)| {
+                cells
+                    .iter()
+                    .map(|row| row[j].len())
+                    .max()
+                    .unwrap_or(0)
+                    .max(h.len())
+            })
+            .collect::<Vec<usize>>();
+
+        let mut text = format!("Table {}\n", self.table_name);
+        text.push_str(&format!(
+            "{:>line_width$}  {:description_width$}",
+            "Line", ""
+        ));
+        for (header, width) in headers.iter().zip(&widths) {
+            text.push_str(&format!("  {header:>width$}"));
+        }
+        text.push('\n');
+        for ((row, description), cells) in self.rows.iter().zip(&descriptions).zip(&cells) {
+            text.push_str(&format!(
+                "{:>line_width$}  {description:description_width$}",
+                row.line_number
+            ));
+            for (cell, width) in cells.iter().zip(&widths) {
+                text.push_str(&format!("  {cell:>width$}"));
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Writes the view to a CSV file at `path`, with one column per period.  Missing values are
+    /// written as empty fields.
+    pub fn to_csv<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), BeaErr> {
+        let path = std::path::PathBuf::from(path.as_ref());
+        let mut wtr = csv::Writer::from_path(&path)
+            .map_err(|e| Csv::new(path.clone(), e, line!(), file!().to_string()))?;
+        let mut header = vec![
+            "Line".to_owned(),
+            "Description".to_owned(),
+            "Depth".to_owned(),
+            "SeriesCode".to_owned(),
+        ];
+        header.extend(self.periods.iter().map(|p| p.to_string()));
+        wtr.write_record(&header)
+            .map_err(|e| Csv::new(path.clone(), e, line!(), file!().to_string()))?;
+        for row in &self.rows {
+            let mut record = vec![
+                row.line_number.to_string(),
+                row.line_description.clone(),
+                row.depth.to_string(),
+                row.series_code.clone(),
+            ];
+            record.extend(self.periods.iter().map(|p| match row.values.get(p) {
+                Some(v) => v.to_string(),
+                None => String::new(),
+            }));
+            wtr.write_record(&record)
+                .map_err(|e| Csv::new(path.clone(), e, line!(), file!().to_string()))?;
+        }
+        wtr.flush()
+            .map_err(|e| IoError::new(path, e, line!(), file!().to_string()))?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for NipaTableView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Period, Quantity, Series, SeriesSet};
+
+/// The `Change` enum selects the calculation used by [`Series::change`].
+///
+/// * **Level** - Change in level from the preceding period, `x_t - x_{t-1}`.
+/// * **Percent** - Percent change from the preceding period, `(x_t / x_{t-1} - 1) * 100`.
+/// * **Annualized** - Percent change from the preceding period at an annual rate,
+///   `((x_t / x_{t-1})^n - 1) * 100`, where `n` is the number of periods per year.  Matches the
+///   BEA convention for quarterly and monthly percent changes, and equals `Percent` for annual
+///   series.
+/// * **YearOverYear** - Percent change from the same period one year earlier,
+///   `(x_t / x_{t-n} - 1) * 100`.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+    derive_more::FromStr,
+)]
+pub enum Change {
+    Level,
+    Percent,
+    Annualized,
+    YearOverYear,
+}
+
+impl Change {
+    /// Unit label for the derived series.
+    pub fn unit(&self, unit: &str) -> String {
+        match self {
+            Self::Level => unit.to_owned(),
+            Self::Percent => "Percent change".to_owned(),
+            Self::Annualized => "Percent change, annual rate".to_owned(),
+            Self::YearOverYear => "Percent change from one year ago".to_owned(),
+        }
+    }
+}
+
+impl Series {
+    /// Creates a series with the metadata of self, the unit `unit` and the observations produced by
+    /// `f` for each observed period.  Periods where `f` returns `None` are marked missing.
+    fn derive<F: Fn(&Period, f64) -> Option<f64>>(
+        &self,
+        unit: String,
+        unit_mult: Option<i64>,
+        f: F,
+    ) -> Self {
+        let observations = self
+            .observations()
+            .iter()
+            .map(|(period, quantity)| {
+                let value = quantity.value().and_then(|v| f(period, v));
+                let quantity = match value {
+                    Some(value) if value.is_finite() => Quantity::Value(value),
+                    _ => Quantity::Missing,
+                };
+                (*period, quantity)
+            })
+            .collect();
+        Self::new(
+            self.id().to_owned(),
+            self.description().to_owned(),
+            *self.frequency(),
+            unit,
+            unit_mult,
+            observations,
+        )
+    }
+
+    /// The `change` method computes the change of kind `change` for each period in self.
+    ///
+    /// Each observation is compared to the observation exactly one period (or one year, for
+    /// [`Change::YearOverYear`]) earlier.  If the earlier observation is absent, missing or zero,
+    /// the result for that period is [`Quantity::Missing`], so gaps in a series never produce
+    /// changes across more than one period.
+    pub fn change(&self, change: Change) -> Self {
+        let unit_mult = match change {
+            Change::Level => *self.unit_mult(),
+            _ => None,
+        };
+        self.derive(change.unit(self.unit()), unit_mult, |period, value| {
+            let lag = match change {
+                Change::YearOverYear => period.per_year(),
+                _ => 1,
+            };
+            let prior = self.get(&period.shift(-lag)?)?;
+            match change {
+                Change::Level => Some(value - prior),
+                _ if prior == 0.0 => None,
+                Change::Percent | Change::YearOverYear => Some((value / prior - 1.0) * 100.0),
+                Change::Annualized => {
+                    Some(((value / prior).powi(period.per_year() as i32) - 1.0) * 100.0)
+                }
+            }
+        })
+    }
+
+    /// The `share` method expresses self as a percent of the series `total` in each period.
+    /// Periods without a nonzero observation in `total` are marked missing.
+    pub fn share(&self, total: &Series) -> Self {
+        self.derive("Percent of total".to_owned(), None, |period, value| {
+            let total = total.get(period)?;
+            if total == 0.0 {
+                None
+            } else {
+                Some(value / total * 100.0)
+            }
+        })
+    }
+
+    /// The `contribution` method computes the contribution of self to the percent change of the
+    /// aggregate series `total`, in percentage points, where self is an additive component of
+    /// `total`.
+    ///
+    /// The contribution is the change in the component as a fraction of the change in the
+    /// aggregate, times the [`Change::Annualized`] percent change of the aggregate.  Contributions
+    /// of a complete set of components sum to the published percent change of the aggregate.  For
+    /// annual series this reduces to `(x_t - x_{t-1}) / X_{t-1} * 100`.
+    ///
+    /// The calculation is exact for current-dollar levels.  Chained-dollar levels are not
+    /// additive, so contributions to real growth published by the BEA use price-weighted formulas
+    /// that this method does not reproduce.
+    pub fn contribution(&self, total: &Series) -> Self {
+        let growth = total.change(Change::Annualized);
+        self.derive("Percentage points".to_owned(), None, |period, value| {
+            let previous = period.previous()?;
+            let component = value - self.get(&previous)?;
+            let aggregate = total.get(period)? - total.get(&previous)?;
+            let growth = growth.get(period)?;
+            if aggregate == 0.0 {
+                // Zero change in the aggregate: use the unannualized share of the prior level.
+                Some(component / total.get(&previous)? * 100.0)
+            } else {
+                Some(component / aggregate * growth)
+            }
+        })
+    }
+}
+
+impl SeriesSet {
+    /// Applies [`Series::change`] to every series in the set.
+    pub fn change(&self, change: Change) -> Self {
+        let mut set = self.clone();
+        set.values_mut()
+            .map(|series| *series = series.change(change))
+            .for_each(drop);
+        set
+    }
+
+    /// Expresses every series in the set as a percent of the series `total` with the same
+    /// frequency.  Series without a matching total at their frequency are omitted.
+    pub fn shares(&self, total: &str) -> Self {
+        let mut set = Self::default();
+        self.iter()
+            .filter_map(|(key, series)| {
+                let total = self.series(total, key.1)?;
+                Some((key.clone(), series.share(total)))
+            })
+            .map(|(key, series)| set.insert(key, series))
+            .for_each(drop);
+        set
+    }
+
+    /// Computes the contribution of every series in the set to the percent change of the series
+    /// `total` with the same frequency.  See [`Series::contribution`].
+    pub fn contributions(&self, total: &str) -> Self {
+        let mut set = Self::default();
+        self.iter()
+            .filter_map(|(key, series)| {
+                let total = self.series(total, key.1)?;
+                Some((key.clone(), series.contribution(total)))
+            })
+            .map(|(key, series)| set.insert(key, series))
+            .for_each(drop);
+        set
+    }
+}